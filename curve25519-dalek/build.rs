@@ -70,6 +70,11 @@ fn main() {
             },
         };
     println!("cargo:rustc-cfg=curve25519_dalek_backend=\"{curve25519_dalek_backend}\"");
+
+    // With `strict-proofs`, any `assume(false)` left in the sources fails the build
+    if std::env::var_os("CARGO_FEATURE_STRICT_PROOFS").is_some() {
+        strict_proofs::check_no_proof_bypasses();
+    }
 }
 
 // Is the target arch & curve25519_dalek_bits potentially simd capable ?
@@ -123,3 +128,154 @@ mod deterministic {
         }
     }
 }
+
+// Proof bypasses (`assume(false)`) are ghost code, so `rustc` never sees them: the
+// `verus!` macro erases them before compilation. They are found in the sources instead.
+mod strict_proofs {
+    use std::ffi::OsStr;
+    use std::path::{Path, PathBuf};
+
+    const BYPASS: &str = "assume(false)";
+
+    // Collect the `.rs` files under `dir`, in a stable order
+    fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+            .expect("failed to read source directory")
+            .map(|entry| entry.expect("failed to read source directory").path())
+            .collect();
+        entries.sort();
+        for path in entries {
+            if path.is_dir() {
+                rust_files(&path, files);
+            } else if path.extension() == Some(OsStr::new("rs")) {
+                files.push(path);
+            }
+        }
+    }
+
+    fn is_ident_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    // If `chars[i..]` starts a raw string `r#*"` (or `br#*"`), the number of `#`s
+    fn raw_string_hashes(chars: &[char], i: usize) -> Option<usize> {
+        if chars[i] != 'r' {
+            return None;
+        }
+        let prefix_ok = match i {
+            0 => true,
+            _ if chars[i - 1] == 'b' => i < 2 || !is_ident_char(chars[i - 2]),
+            _ => !is_ident_char(chars[i - 1]),
+        };
+        let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+        match (prefix_ok, chars.get(i + 1 + hashes)) {
+            (true, Some('"')) => Some(hashes),
+            _ => None,
+        }
+    }
+
+    // Blank out comments and string and char literals, keeping the line breaks, so that
+    // only code is searched. Block comments nest, as in Rust.
+    fn code_only(source: &str) -> String {
+        let chars: Vec<char> = source.chars().collect();
+        let blank = |out: &mut String, from: usize, to: usize| {
+            for &c in &chars[from..to.min(chars.len())] {
+                out.push(if c == '\n' { '\n' } else { ' ' });
+            }
+        };
+
+        let mut out = String::with_capacity(source.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let start = i;
+            let next = chars.get(i + 1).copied();
+            match (chars[i], raw_string_hashes(&chars, i)) {
+                (_, Some(hashes)) => {
+                    let closes = |j: usize| {
+                        chars[j] == '"'
+                            && chars[j + 1..].iter().take_while(|&&c| c == '#').count() >= hashes
+                    };
+                    i += hashes + 2;
+                    while i < chars.len() && !closes(i) {
+                        i += 1;
+                    }
+                    i += hashes + 1;
+                }
+                ('/', _) if next == Some('/') => {
+                    while i < chars.len() && chars[i] != '\n' {
+                        i += 1;
+                    }
+                }
+                ('/', _) if next == Some('*') => {
+                    let mut depth = 0;
+                    while i < chars.len() {
+                        if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                            depth += 1;
+                            i += 2;
+                        } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                            depth -= 1;
+                            i += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        } else {
+                            i += 1;
+                        }
+                    }
+                }
+                ('"', _) => {
+                    i += 1;
+                    while i < chars.len() && chars[i] != '"' {
+                        i += if chars[i] == '\\' { 2 } else { 1 };
+                    }
+                    i += 1;
+                }
+                // A char literal; a lone quote is a lifetime or label, which is code
+                ('\'', _) if next == Some('\\') || chars.get(i + 2) == Some(&'\'') => {
+                    i += 1;
+                    while i < chars.len() && chars[i] != '\'' {
+                        i += if chars[i] == '\\' { 2 } else { 1 };
+                    }
+                    i += 1;
+                }
+                (c, _) => {
+                    out.push(c);
+                    i += 1;
+                    continue;
+                }
+            }
+            blank(&mut out, start, i);
+        }
+        out
+    }
+
+    // Fail the build with the location of every `assume(false)` in code, outside
+    // comments and string literals
+    pub(super) fn check_no_proof_bypasses() {
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=src");
+
+        let mut files = Vec::new();
+        rust_files(Path::new("src"), &mut files);
+
+        let mut bypasses = Vec::new();
+        for file in files {
+            let source = std::fs::read_to_string(&file).expect("failed to read source file");
+            for (i, code) in code_only(&source).lines().enumerate() {
+                if code.contains(BYPASS) {
+                    bypasses.push(format!("{}:{}", file.display(), i + 1));
+                }
+            }
+        }
+
+        if !bypasses.is_empty() {
+            for location in &bypasses {
+                println!("cargo:warning=proof bypass `{BYPASS}` at {location}");
+            }
+            panic!(
+                "strict-proofs: {} proof bypasses remain, see the warnings above",
+                bypasses.len()
+            );
+        }
+    }
+}
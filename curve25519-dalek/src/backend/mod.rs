@@ -54,6 +54,24 @@ use vstd::prelude::*;
 
 // #[cfg(curve25519_dalek_backend = "simd")]
 // pub mod vector;
+/* <VERIFICATION NOTE>
+ The vector backend is not in this tree, so the AVX2 `FieldElement2625x4` arithmetic has no
+ specs yet and `get_selected_backend` always returns `BackendKind::Serial`. Plan for when it is
+ brought in:
+ - lanes: `FieldElement2625x4` is `[u32x8; 5]`, with vector i holding limbs 2i and 2i+1 of lanes
+   (A, B, C, D) as (a_2i, b_2i, a_2i+1, b_2i+1, c_2i, d_2i, c_2i+1, d_2i+1). A spec
+   `fe2625x4_lane(x, j) -> [u32; 10]` picks out lane j, and the value of a lane is
+   `u32_10_as_nat` of it mod p, the same radix-2^25.5 spec the u32 serial backend needs
+   (see the note in `serial`)
+ - operations: `add`, `sub` (with its 2p/16p bias), `mul`, `square_and_negate_D` and `negate_lazy`
+   get lane-wise ensures of the form
+   `forall|j| 0 <= j < 4 ==> lane_value(result, j) == math_field_op(lane_value(self, j), ...)`,
+   with per-limb bit bounds playing the role of `fe51_limbs_bounded`
+ - intrinsics: `core::arch` AVX2 calls are outside what Verus models, so `u32x8` needs an
+   `external_body` wrapper in `core_assumes` style, specified lane by lane on `[u32; 8]`
+ - points: `ExtendedPoint` and `CachedPoint` then inherit ensures relating them to
+   `EdwardsPoint` through the existing `edwards_specs`
+</VERIFICATION NOTE> */
 
 verus! {
 
@@ -264,7 +282,7 @@ pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: Edwa
         // Functional correctness: result represents scalar * point
         edwards_point_as_affine(result) == edwards_scalar_mul(
             edwards_point_as_affine(*point),
-            spec_scalar(scalar),
+            scalar_to_nat(scalar),
         ),
 {
     match get_selected_backend() {
@@ -322,6 +340,9 @@ pub fn straus_multiscalar_mul_verus<S, P, I, J>(scalars: I, points: J) -> (resul
             0 <= i < spec_points_from_iter::<P, J>(points).len() ==> is_well_formed_edwards_point(
                 #[trigger] spec_points_from_iter::<P, J>(points)[i],
             ),
+        forall|i: int|
+            0 <= i < spec_scalars_from_iter::<S, I>(scalars).len() ==> (
+            #[trigger] spec_scalars_from_iter::<S, I>(scalars)[i]).bytes[31] <= 127,
     ensures
         is_well_formed_edwards_point(result),
         edwards_point_as_affine(result) == sum_of_scalar_muls(
@@ -132,17 +132,31 @@ use zeroize::Zeroize;
 
 #[cfg(verus_keep_ghost)]
 use crate::backend::serial::u64::subtle_assumes::choice_is_true;
+use crate::backend::serial::u64::subtle_assumes::{
+    conditional_assign_field_element, conditional_select_field_element,
+};
 use crate::constants;
-use crate::core_assumes::negate_field;
+#[allow(unused_imports)] // Used in verus! blocks for the addition/doubling formulas
+use crate::lemmas::edwards_lemmas::add_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the model conversions
+use crate::lemmas::edwards_lemmas::curve_models_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for square()/square2() bridges
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for sum bounds
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::edwards_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::field_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::field_specs_u64::*;
 
 use crate::edwards::EdwardsPoint;
 use crate::field::FieldElement;
 use crate::traits::ValidityCheck;
 
+#[allow(unused_imports)]
+use vstd::arithmetic::div_mod::*;
 use vstd::prelude::*;
 
 // ------------------------------------------------------------------------
@@ -387,18 +401,11 @@ impl ConditionallySelectable for ProjectiveNielsPoint {
             choice_is_true(choice) ==> result == *b,
     {
         let result = ProjectiveNielsPoint {
-            Y_plus_X: FieldElement::conditional_select(&a.Y_plus_X, &b.Y_plus_X, choice),
-            Y_minus_X: FieldElement::conditional_select(&a.Y_minus_X, &b.Y_minus_X, choice),
-            Z: FieldElement::conditional_select(&a.Z, &b.Z, choice),
-            T2d: FieldElement::conditional_select(&a.T2d, &b.T2d, choice),
+            Y_plus_X: conditional_select_field_element(&a.Y_plus_X, &b.Y_plus_X, choice),
+            Y_minus_X: conditional_select_field_element(&a.Y_minus_X, &b.Y_minus_X, choice),
+            Z: conditional_select_field_element(&a.Z, &b.Z, choice),
+            T2d: conditional_select_field_element(&a.T2d, &b.T2d, choice),
         };
-        proof {
-            // Postconditions follow from FieldElement51::conditional_select specs
-            // Each field select returns a's or b's field based on choice, so struct equals a or b
-            // Verus can't automatically derive struct equality from limb-level specs
-            assume(!choice_is_true(choice) ==> result == *a);
-            assume(choice_is_true(choice) ==> result == *b);
-        }
         result
     }
 
@@ -410,17 +417,10 @@ impl ConditionallySelectable for ProjectiveNielsPoint {
             // If choice is true, self is assigned from other
             choice_is_true(choice) ==> *self == *other,
     {
-        self.Y_plus_X.conditional_assign(&other.Y_plus_X, choice);
-        self.Y_minus_X.conditional_assign(&other.Y_minus_X, choice);
-        self.Z.conditional_assign(&other.Z, choice);
-        self.T2d.conditional_assign(&other.T2d, choice);
-        proof {
-            // Postconditions follow from FieldElement51::conditional_assign specs
-            // Each field assign keeps old or assigns other based on choice
-            // Verus can't automatically derive struct equality from limb-level specs
-            assume(!choice_is_true(choice) ==> *self == *old(self));
-            assume(choice_is_true(choice) ==> *self == *other);
-        }
+        conditional_assign_field_element(&mut self.Y_plus_X, &other.Y_plus_X, choice);
+        conditional_assign_field_element(&mut self.Y_minus_X, &other.Y_minus_X, choice);
+        conditional_assign_field_element(&mut self.Z, &other.Z, choice);
+        conditional_assign_field_element(&mut self.T2d, &other.T2d, choice);
     }
 }
 
@@ -434,17 +434,10 @@ impl ConditionallySelectable for AffineNielsPoint {
             choice_is_true(choice) ==> result == *b,
     {
         let result = AffineNielsPoint {
-            y_plus_x: FieldElement::conditional_select(&a.y_plus_x, &b.y_plus_x, choice),
-            y_minus_x: FieldElement::conditional_select(&a.y_minus_x, &b.y_minus_x, choice),
-            xy2d: FieldElement::conditional_select(&a.xy2d, &b.xy2d, choice),
+            y_plus_x: conditional_select_field_element(&a.y_plus_x, &b.y_plus_x, choice),
+            y_minus_x: conditional_select_field_element(&a.y_minus_x, &b.y_minus_x, choice),
+            xy2d: conditional_select_field_element(&a.xy2d, &b.xy2d, choice),
         };
-        proof {
-            // Postconditions follow from FieldElement51::conditional_select specs
-            // Each field select returns a's or b's field based on choice, so struct equals a or b
-            // Verus can't automatically derive struct equality from limb-level specs
-            assume(!choice_is_true(choice) ==> result == *a);
-            assume(choice_is_true(choice) ==> result == *b);
-        }
         result
     }
 
@@ -456,16 +449,9 @@ impl ConditionallySelectable for AffineNielsPoint {
             // If choice is true, self is assigned from other
             choice_is_true(choice) ==> *self == *other,
     {
-        self.y_plus_x.conditional_assign(&other.y_plus_x, choice);
-        self.y_minus_x.conditional_assign(&other.y_minus_x, choice);
-        self.xy2d.conditional_assign(&other.xy2d, choice);
-        proof {
-            // Postconditions follow from FieldElement51::conditional_assign specs
-            // Each field assign keeps old or assigns other based on choice
-            // Verus can't automatically derive struct equality from limb-level specs
-            assume(!choice_is_true(choice) ==> *self == *old(self));
-            assume(choice_is_true(choice) ==> *self == *other);
-        }
+        conditional_assign_field_element(&mut self.y_plus_x, &other.y_plus_x, choice);
+        conditional_assign_field_element(&mut self.y_minus_x, &other.y_minus_x, choice);
+        conditional_assign_field_element(&mut self.xy2d, &other.xy2d, choice);
     }
 }
 
@@ -486,6 +472,7 @@ impl ProjectivePoint {
             fe51_limbs_bounded(&self.Z, 54),
         ensures
             is_valid_edwards_point(result),
+            is_well_formed_edwards_point(result),
             spec_edwards_point(result) == spec_projective_to_extended(*self),
             edwards_point_as_affine(result) == projective_point_as_affine_edwards(*self),
     {
@@ -496,10 +483,21 @@ impl ProjectivePoint {
             T: &self.X * &self.Y,
         };
         proof {
-            // postconditions
-            assume(is_valid_edwards_point(result));
-            assume(spec_edwards_point(result) == spec_projective_to_extended(*self));
-            assume(edwards_point_as_affine(result) == projective_point_as_affine_edwards(*self));
+            let (x, y, z) = spec_projective_point_edwards(*self);
+            p_gt_2();
+            lemma_mod_bound(u64_5_as_nat(self.Z.limbs) as int, p() as int);
+            // Z.square() gives Z² on the field value
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(self.Z.limbs),
+                u64_5_as_nat(result.Z.limbs),
+            );
+            assert(spec_edwards_point(result) == spec_projective_to_extended(*self));
+            // (XZ : YZ : Z² : XY) has the same affine point and T = XY/Z
+            lemma_projective_to_extended(x, y, z);
+            assert(is_valid_edwards_point(result));
+
+            // mul and square ensure limbs bounded by 54, so Y + X cannot overflow
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&result.Y, &result.X, 54);
         }
         result
     }
@@ -540,14 +538,15 @@ impl CompletedPoint {
             assert(fe51_limbs_bounded(&result.Y, 54));
             assert(fe51_limbs_bounded(&result.Z, 54));
             // Sum bounded: each limb < 2^54, so X[i] + Y[i] < 2^55 < u64::MAX
-            assert((1u64 << 54) + (1u64 << 54) < u64::MAX) by (bit_vector);
-            assume(sum_of_limbs_bounded(&result.X, &result.Y, u64::MAX));
-            // Semantic postconditions
-            assume(is_valid_projective_point(result));
-            assume(spec_projective_point_edwards(result) == spec_completed_to_projective(*self));
-            assume(projective_point_as_affine_edwards(result) == completed_point_as_affine_edwards(
-                *self,
-            ));
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&result.X, &result.Y, 54);
+
+            // (XT : YZ : ZT) has the same affine point as ((X:Z), (Y:T))
+            let (x, y, z, t) = spec_completed_point(*self);
+            p_gt_2();
+            lemma_mod_bound(u64_5_as_nat(self.Z.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(self.T.limbs) as int, p() as int);
+            assert(spec_projective_point_edwards(result) == spec_completed_to_projective(*self));
+            lemma_completed_to_extended(x, y, z, t);
         }
         result
     }
@@ -577,12 +576,17 @@ impl CompletedPoint {
             T: &self.X * &self.Y,
         };
         proof {
-            // postconditions
-            assume(is_valid_edwards_point(result));
-            // mul ensures limbs bounded by 54, and sum bounded follows from field properties
-            assume(is_well_formed_edwards_point(result));
-            assume(spec_edwards_point(result) == spec_completed_to_extended(*self));
-            assume(edwards_point_as_affine(result) == completed_point_as_affine_edwards(*self));
+            // (XT : YZ : ZT : XY) has the same affine point and T = XY/Z
+            let (x, y, z, t) = spec_completed_point(*self);
+            p_gt_2();
+            lemma_mod_bound(u64_5_as_nat(self.Z.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(self.T.limbs) as int, p() as int);
+            assert(spec_edwards_point(result) == spec_completed_to_extended(*self));
+            lemma_completed_to_extended(x, y, z, t);
+            assert(is_valid_edwards_point(result));
+
+            // mul ensures limbs bounded by 54, so Y + X cannot overflow
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&result.Y, &result.X, 54);
         }
         result
     }
@@ -634,7 +638,6 @@ impl ProjectivePoint {
             assume(fe51_limbs_bounded(&X_plus_Y_sq, 54));  // for &X_plus_Y_sq - &YY_plus_XX
             assume(fe51_limbs_bounded(&YY_plus_XX, 54));  // for &X_plus_Y_sq - &YY_plus_XX
             assume(fe51_limbs_bounded(&YY_minus_XX, 54));  // for &ZZ2 - &YY_minus_XX
-            assume(fe51_limbs_bounded(&ZZ2, 54));  // for &ZZ2 - &YY_minus_XX
         }
         let result = CompletedPoint {
             X: &X_plus_Y_sq - &YY_plus_XX,
@@ -645,10 +648,32 @@ impl ProjectivePoint {
         proof {
             // postconditions
             assume(is_valid_completed_point(result));
-            assume(completed_point_as_affine_edwards(result) == edwards_double(
-                projective_point_as_affine_edwards(*self).0,
-                projective_point_as_affine_edwards(*self).1,
-            ));
+
+            // Bridge the square()/square2() postconditions to math_field_square
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(self.X.limbs),
+                u64_5_as_nat(XX.limbs),
+            );
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(self.Y.limbs),
+                u64_5_as_nat(YY.limbs),
+            );
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(X_plus_Y.limbs),
+                u64_5_as_nat(X_plus_Y_sq.limbs),
+            );
+            lemma_square2_matches_math_field_square(
+                u64_5_as_nat(self.Z.limbs),
+                u64_5_as_nat(ZZ2.limbs),
+            );
+
+            // The doubling formula computes edwards_double of the affine point
+            let (x, y, z) = spec_projective_point_edwards(*self);
+            p_gt_2();
+            lemma_mod_bound(u64_5_as_nat(self.X.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(self.Y.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(self.Z.limbs) as int, p() as int);
+            lemma_projective_double_formula(x, y, z);
         }
 
         result
@@ -706,6 +731,8 @@ impl<'a, 'b> Add<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
                 *self,
                 *other,
             ),
+            // The result coordinates are exactly the Niels addition formula
+            spec_completed_point(result) == spec_completed_add_projective_niels(*self, *other),
             // Limb bounds for result (from mul's 52-bit output → sub/add produce ≤54-bit)
             fe51_limbs_bounded(&result.X, 54),
             fe51_limbs_bounded(&result.Y, 54),
@@ -742,6 +769,11 @@ impl<'a, 'b> Add<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         };
         proof {
             // postconditions
+            // Coordinates follow from the field operation postconditions
+            assert(spec_completed_point(result) == spec_completed_add_projective_niels(
+                *self,
+                *other,
+            ));
             assume(is_valid_completed_point(result));
             assume(completed_point_as_affine_edwards(result) == spec_edwards_add_projective_niels(
                 *self,
@@ -808,6 +840,11 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
                     other_affine.1,
                 )
             }),
+            // Limb bounds for result (same shape as the addition formula)
+            fe51_limbs_bounded(&result.X, 54),
+            fe51_limbs_bounded(&result.Y, 54),
+            fe51_limbs_bounded(&result.Z, 54),
+            fe51_limbs_bounded(&result.T, 54),
     {
         let Y_plus_X = &self.Y + &self.X;
         let Y_minus_X = &self.Y - &self.X;
@@ -819,15 +856,17 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         let TT2d = &self.T * &other.T2d;
         let ZZ = &self.Z * &other.Z;
         proof {
-            assume(sum_of_limbs_bounded(&ZZ, &ZZ, u64::MAX));  // for ZZ2 = &ZZ + &ZZ
+            // mul outputs 52-bit limbs, so ZZ + ZZ cannot overflow
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&ZZ, &ZZ, 52);
         }
         let ZZ2 = &ZZ + &ZZ;
         proof {
-            assume(sum_of_limbs_bounded(&PM, &MP, u64::MAX));  // for Y = &PM + &MP
-            assume(sum_of_limbs_bounded(&ZZ2, &TT2d, u64::MAX));  // for Z and T operations
-            // Preconditions for subtractions
-            assume(fe51_limbs_bounded(&PM, 54) && fe51_limbs_bounded(&MP, 54));  // for X = &PM - &MP
-            assume(fe51_limbs_bounded(&ZZ2, 54) && fe51_limbs_bounded(&TT2d, 54));  // for Z = &ZZ2 - &TT2d
+            // ZZ2 = ZZ + ZZ is 53-bit bounded, and the mul outputs PM, MP, TT2d are 52-bit
+            lemma_add_fe51_limbs_bounded_52(&ZZ, &ZZ);
+            lemma_fe51_limbs_bounded_weaken(&TT2d, 52, 53);
+            lemma_fe51_limbs_bounded_weaken(&ZZ2, 53, 54);
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&PM, &MP, 52);  // for Y = &PM + &MP
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&ZZ2, &TT2d, 53);  // for T = &ZZ2 + &TT2d
         }
 
         let result = CompletedPoint {
@@ -847,6 +886,11 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
                 other_affine.0,
                 other_affine.1,
             ));
+            // Limb bounds: X and Z come out of sub with 54-bit limbs,
+            // Y = PM + MP is 52 + 52 → 53-bit and T = ZZ2 + TT2d is 53 + 53 → 54-bit
+            lemma_add_fe51_limbs_bounded_52(&PM, &MP);
+            lemma_fe51_limbs_bounded_weaken(&result.Y, 53, 54);
+            lemma_add_fe51_limbs_bounded_53(&ZZ2, &TT2d);
         }
         result
     }
@@ -1036,7 +1080,7 @@ impl vstd::std_specs::ops::NegSpecImpl for &ProjectiveNielsPoint {
 
     open spec fn neg_req(self) -> bool {
         // Preconditions: limbs must be bounded for field element negation
-        fe51_limbs_bounded(&self.T2d, 51)
+        fe51_limbs_bounded(&self.T2d, 54)
     }
 
     open spec fn neg_spec(self) -> ProjectiveNielsPoint {
@@ -1053,15 +1097,14 @@ impl<'a> Neg for &'a ProjectiveNielsPoint {
     /// In Niels form (Y+X, Y-X, Z, T2d), this swaps Y+X ↔ Y-X and negates T2d.
     fn neg(self) -> (result:
         ProjectiveNielsPoint)/* requires clause in NegSpecImpl:
-       requires fe51_limbs_bounded(&self.T2d, 51)
+       requires fe51_limbs_bounded(&self.T2d, 54)
     */
 
         ensures
     // Structural: negation swaps Y_plus_X and Y_minus_X, keeps Z
 
-            result.Y_plus_X == self.Y_minus_X,
-            result.Y_minus_X == self.Y_plus_X,
-            result.Z == self.Z,
+            result == negate_projective_niels(*self),
+            fe51_limbs_bounded(&result.T2d, 52),
             // Mathematical: the affine point is negated (x, y) → (-x, y)
             ({
                 let self_affine = projective_niels_point_as_affine_edwards(*self);
@@ -1070,19 +1113,15 @@ impl<'a> Neg for &'a ProjectiveNielsPoint {
             }),
     {
         // ORIGINAL CODE: T2d: -(&self.T2d),
-        // Using negate_field wrapper to avoid Verus internal error
+        // REFACTORED: Use explicit Neg::neg() call to avoid Verus panic
         let result = ProjectiveNielsPoint {
             Y_plus_X: self.Y_minus_X,
             Y_minus_X: self.Y_plus_X,
             Z: self.Z,
-            T2d: negate_field(&self.T2d),
+            T2d: Neg::neg(&self.T2d),
         };
         proof {
-            let self_affine = projective_niels_point_as_affine_edwards(*self);
-            assume(projective_niels_point_as_affine_edwards(result) == (
-                math_field_neg(self_affine.0),
-                self_affine.1,
-            ));
+            lemma_negate_projective_niels_affine(*self);
         }
         result
     }
@@ -1097,7 +1136,7 @@ impl vstd::std_specs::ops::NegSpecImpl for &AffineNielsPoint {
 
     open spec fn neg_req(self) -> bool {
         // Preconditions: limbs must be bounded for field element negation
-        fe51_limbs_bounded(&self.xy2d, 51)
+        fe51_limbs_bounded(&self.xy2d, 54)
     }
 
     open spec fn neg_spec(self) -> AffineNielsPoint {
@@ -1114,14 +1153,14 @@ impl<'a> Neg for &'a AffineNielsPoint {
     /// In AffineNiels form (y+x, y-x, xy2d), this swaps y+x ↔ y-x and negates xy2d.
     fn neg(self) -> (result:
         AffineNielsPoint)/* requires clause in NegSpecImpl:
-       requires fe51_limbs_bounded(&self.xy2d, 51)
+       requires fe51_limbs_bounded(&self.xy2d, 54)
     */
 
         ensures
     // Structural: negation swaps y_plus_x and y_minus_x
 
-            result.y_plus_x == self.y_minus_x,
-            result.y_minus_x == self.y_plus_x,
+            result == negate_affine_niels(*self),
+            fe51_limbs_bounded(&result.xy2d, 52),
             // Mathematical: the affine point is negated (x, y) → (-x, y)
             ({
                 let self_affine = affine_niels_point_as_affine_edwards(*self);
@@ -1130,18 +1169,14 @@ impl<'a> Neg for &'a AffineNielsPoint {
             }),
     {
         // ORIGINAL CODE: xy2d: -(&self.xy2d),
-        // Using negate_field wrapper to avoid Verus internal error
+        // REFACTORED: Use explicit Neg::neg() call to avoid Verus panic
         let result = AffineNielsPoint {
             y_plus_x: self.y_minus_x,
             y_minus_x: self.y_plus_x,
-            xy2d: negate_field(&self.xy2d),
+            xy2d: Neg::neg(&self.xy2d),
         };
         proof {
-            let self_affine = affine_niels_point_as_affine_edwards(*self);
-            assume(affine_niels_point_as_affine_edwards(result) == (
-                math_field_neg(self_affine.0),
-                self_affine.1,
-            ));
+            lemma_negate_affine_niels_affine(*self);
         }
         result
     }
@@ -34,6 +34,39 @@
 //         pub mod u64;
 //     }
 // }
+/* <VERIFICATION NOTE>
+ Only the u64 backend is part of the verified crate; the u32 (`FieldElement2625`) and fiat
+ backends are not in this tree. Proofs to port once the u32 backend is brought in:
+ - `pow2k`: loop invariant and overflow lemmas, see `lemmas::field_lemmas::pow2k_lemmas`
+   (10 limbs in radix 2^25.5, so the c_i terms and the x19 wraparound need their own bounds)
+ - `reduce`: a `spec_reduce_2625` carry-chain spec plus a boundary lemma, mirroring `spec_reduce`
+   and `lemmas::field_lemmas::reduce_lemmas::lemma_reduce_boundaries` (alternating 26/25-bit
+   output limbs, with the top carry folded back into limb 0 times 19)
+ - `square2`: 2·x² mod p plus the one-bit growth of the doubled limbs, see
+   `lemmas::field_lemmas::field_algebra_lemmas::lemma_square2_matches_math_field_square`
+ - curve constants: the defining-equation lemmas in `lemmas::edwards_lemmas::constants_lemmas`
+   and `lemmas::field_lemmas::sqrt_m1_lemmas::lemma_sqrt_m1_squared` only need the u32 limb
+   dumps plugged into `spec_field_element`
+ - cross-backend equivalence: a `u32_10_as_nat` counterpart of `u64_5_as_nat` (limb i weighted by
+   2^ceil(25.5·i)), with add/sub/mul/square shown to agree on values mod p, so that the
+   `math_field_*` level lemmas transfer unchanged
+ - wire formats: nothing backend-specific is needed. The postconditions of `EdwardsPoint::compress`
+   and `RistrettoPoint::compress` only mention the point's field values, and
+   `lemmas::edwards_lemmas::compress_lemmas::lemma_compressed_y_encodes_unique` and
+   `lemmas::ristretto_lemmas::lemma_ristretto_compress_unique` show they determine the bytes. A u32
+   backend whose `compress` proves the same postconditions therefore emits the same
+   `CompressedEdwardsY` and `CompressedRistretto` bytes as the u64 one
+</VERIFICATION NOTE> */
+/* <VERIFICATION NOTE>
+ There is no verified/unverified switch (e.g. a `verified-field` feature or a
+ `curve25519_dalek_backend = "verified"` cfg) because there is nothing to switch between:
+ `u64::field::FieldElement51` and `u64::scalar::Scalar52` are the upstream implementations
+ annotated in place, and the unannotated originals were not kept (changed lines carry the
+ upstream code in ORIGINAL CODE comments). Without Verus the annotations are erased, so every
+ build already routes `FieldElement` and `Scalar` through the verified code. A switch only
+ becomes meaningful once a second backend (u32, fiat) is brought back in unverified, and would
+ then slot into the commented `cfg_if!` above like the upstream `curve25519_dalek_backend` cfg.
+</VERIFICATION NOTE> */
 pub mod u64;
 
 pub mod curve_models;
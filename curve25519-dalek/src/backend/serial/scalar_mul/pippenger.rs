@@ -21,8 +21,26 @@ use crate::traits::VartimeMultiscalarMul;
 
 use vstd::prelude::*;
 
+#[cfg(verus_keep_ghost)]
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
 #[cfg(verus_keep_ghost)]
 use crate::specs::edwards_specs::*;
+#[cfg(verus_keep_ghost)]
+use crate::specs::field_specs::{fe51_limbs_bounded, math_field_inv, spec_field_element};
+#[cfg(verus_keep_ghost)]
+use crate::specs::field_specs_u64::{p, p_gt_2};
+#[cfg(verus_keep_ghost)]
+use crate::specs::scalar_mul_specs::{
+    pippenger_bucket_coeffs, pippenger_column, pippenger_intermediate_coeffs,
+    pippenger_pairs_valid, pippenger_points, pippenger_suffix_values, pippenger_weighted_coeffs,
+    points_as_affine, sum_of_signed_scalar_muls,
+};
+#[cfg(verus_keep_ghost)]
+use crate::specs::scalar_specs::{is_valid_radix_2w, reconstruct_radix_2w, scalar_to_nat};
+#[cfg(verus_keep_ghost)]
+use vstd::arithmetic::div_mod::lemma_mod_bound;
+#[cfg(verus_keep_ghost)]
+use vstd::arithmetic::power2::{lemma2_to64, pow2};
 
 // Re-export spec functions from scalar_mul_specs for use by other modules
 #[cfg(verus_keep_ghost)]
@@ -249,6 +267,7 @@ impl Pippenger {
         /* Ghost vars to capture spec values before iterator consumption */
         let ghost spec_scalars = spec_scalars_from_iter::<S, I>(scalars);
         let ghost spec_points = spec_optional_points_from_iter::<J>(points);
+        let ghost n = spec_points.len();
 
         /* <ORIGINAL CODE>
     let mut scalars = scalars.into_iter();
@@ -280,13 +299,23 @@ impl Pippenger {
         let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
         let buckets_count: usize = max_digit / 2;  // digits are signed+centered hence 2^w/2, excluding 0-th bucket
 
-        if digits_count == 0 || buckets_count == 0 {
-            // PROOF BYPASS: Dead code for valid w (6,7,8), assume postcondition
-            proof {
-                assume(!all_points_some(spec_points));
+        let ghost dc = digits_count as int;
+        proof {
+            // For w = 6, 7, 8 there are 2^(w-1) = 32, 64, 128 buckets and 43, 37, 33 digits
+            lemma2_to64();
+            if w == 6 {
+                assert(1usize << 6usize == 64usize) by (bit_vector);
+                assert(digits_count == 43);
+            } else if w == 7 {
+                assert(1usize << 7usize == 128usize) by (bit_vector);
+                assert(digits_count == 37);
+            } else {
+                assert(1usize << 8usize == 256usize) by (bit_vector);
+                assert(digits_count == 33);
             }
-            return None;
+            assert(buckets_count == pow2((w - 1) as nat));
         }
+
         // Collect optimized scalars and points in buffers for repeated access
         // (scanning the whole set per digit position).
         /* <ORIGINAL CODE>
@@ -307,17 +336,50 @@ impl Pippenger {
             points_vec.len()
         };
         while idx < min_len
+            invariant
+                idx <= min_len,
+                min_len == n,
+                scalars_vec@ == spec_scalars,
+                points_vec@ == spec_points,
+                6 <= w <= 8,
+                dc == digits_count,
+                dc == if w < 8 {
+                    (256 + w - 1) / (w as int)
+                } else {
+                    (256 + w - 1) / (w as int) + 1
+                },
+                forall|i: int|
+                    0 <= i < n && (#[trigger] spec_points[i]).is_some()
+                        ==> is_well_formed_edwards_point(spec_points[i].unwrap()),
+                scalars_points.len() == idx,
+                forall|k: int| 0 <= k < idx ==> (#[trigger] spec_points[k]).is_some(),
+                // scalars_points[k] holds the digits of scalar k and point k in Niels form
+                forall|k: int|
+                    #![trigger scalars_points@[k]]
+                    0 <= k < idx ==> {
+                        let (digits, pt) = scalars_points@[k];
+                        &&& is_valid_radix_2w(&digits, w as nat, dc as nat)
+                        &&& reconstruct_radix_2w(digits@.take(dc), w as nat) == scalar_to_nat(
+                            &spec_scalars[k],
+                        )
+                        &&& fe51_limbs_bounded(&pt.Y_plus_X, 54)
+                        &&& fe51_limbs_bounded(&pt.Y_minus_X, 54)
+                        &&& fe51_limbs_bounded(&pt.Z, 54)
+                        &&& fe51_limbs_bounded(&pt.T2d, 54)
+                        &&& projective_niels_point_as_affine_edwards(pt) == edwards_point_as_affine(
+                            spec_points[k].unwrap(),
+                        )
+                    },
             decreases min_len - idx,
         {
-            assume(false);  // PROOF BYPASS
             let digits = scalars_vec[idx].as_radix_2w(w);
-            let maybe_p = points_vec[idx].map(|P| P.as_projective_niels());
-            match maybe_p {
-                Some(p) => scalars_points.push((digits, p)),
+            // Match instead of Option::map, so that the call to as_projective_niels is checked
+            match points_vec[idx] {
+                Some(P) => scalars_points.push((digits, P.as_projective_niels())),
                 None => {
-                    // PROOF BYPASS: Found a None point, so not all_points_some
+                    // Point idx is None, so not all points are Some
                     proof {
-                        assume(!all_points_some(spec_points));
+                        assert(spec_points[idx as int].is_none());
                     }
                     return None;
                 },
@@ -326,6 +388,42 @@ impl Pippenger {
         }
         /* </REFACTORED CODE> */
 
+        // Every point is Some; pts are their affine forms
+        let ghost upts = unwrap_points(spec_points);
+        let ghost pts = points_as_affine(upts);
+        proof {
+            assert(all_points_some(spec_points));
+            assert(pippenger_points(scalars_points@) =~= pts);
+            assert forall|k: int|
+                #![trigger scalars_points@[k]]
+                0 <= k < n implies {
+                    let affine = projective_niels_point_as_affine_edwards(scalars_points@[k].1);
+                    &&& math_on_edwards_curve(affine.0, affine.1)
+                    &&& affine.0 < p()
+                    &&& affine.1 < p()
+                } by {
+                assert(spec_points[k].is_some());
+                let point = upts[k];
+                assert(is_well_formed_edwards_point(point));
+                // The affine coordinates are field products, so reduced
+                p_gt_2();
+                let z_inv = math_field_inv(spec_field_element(&point.Z));
+                lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p() as int);
+                lemma_mod_bound((spec_field_element(&point.Y) * z_inv) as int, p() as int);
+            }
+            // Every digit fits a bucket: |d| ≤ 2^(w-1) = buckets_count
+            assert forall|j: int| 0 <= j < dc implies #[trigger] pippenger_pairs_valid(
+                scalars_points@,
+                j,
+                buckets_count as int,
+            ) by {}
+            assert forall|k: int|
+                #![trigger pts[k]]
+                0 <= k < n implies math_on_edwards_curve(pts[k].0, pts[k].1) by {
+                assert(pts[k] == projective_niels_point_as_affine_edwards(scalars_points@[k].1));
+            }
+        }
+
         // Prepare 2^w/2 buckets.
         // buckets[i] corresponds to a multiplication factor (i+1).
         /* <ORIGINAL CODE>
@@ -338,9 +436,11 @@ impl Pippenger {
         let mut buckets: Vec<EdwardsPoint> = Vec::new();
         let mut init_idx: usize = 0;
         while init_idx < buckets_count
+            invariant
+                init_idx <= buckets_count,
+                buckets.len() == init_idx,
             decreases buckets_count - init_idx,
         {
-            assume(false);  // PROOF BYPASS
             buckets.push(EdwardsPoint::identity());
             init_idx = init_idx + 1;
         }
@@ -382,36 +482,216 @@ impl Pippenger {
     Some(columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p))
     </ORIGINAL CODE> */
         /* <REFACTORED CODE>
-         * Pippenger bucket method: process digit columns right-to-left.
-         * For each column:
-         *   1. Clear buckets to identity
-         *   2. Sort points into buckets based on scalar digit value
-         *   3. Sum buckets: bucket[i] contributes (i+1) * bucket[i] to column sum
-         *   4. Accumulate: total = total * 2^w + column_sum
+         * Pippenger bucket method: process digit columns from the most significant down.
+         * Each column is summed by column_verus (the body of the `columns` closure);
+         * the running total is then total = total * 2^w + column_sum.
+         *
+         * After column j, total = Σ_k [s_{k,j}]P_k, with s_{k,j} the value of the
+         * digits of scalar k from position j on.
          */
-        // Process hi_column (digit_index = digits_count - 1)
-        let digit_index_hi: usize = digits_count - 1;
+        // Take the high column as an initial value to avoid wasting time doubling the identity element.
+        let mut total = Self::column_verus(&scalars_points, &mut buckets, digits_count - 1);
+
+        proof {
+            // The value of the digits from digits_count - 1 on is the top digit
+            let top = pippenger_suffix_values(scalars_points@, w as nat, dc, dc - 1);
+            let column = pippenger_column(scalars_points@, dc - 1);
+            assert forall|k: int| 0 <= k < n implies #[trigger] top[k] == column[k] by {
+                let digits = scalars_points@[k].0@.take(dc);
+                lemma_reconstruct_radix_2w_suffix(digits, w as nat, dc - 1);
+                assert(digits.skip(dc).len() == 0);
+            }
+            assert(top =~= column);
+        }
 
-        // Clear buckets
+        let mut digit_index: usize = digits_count - 1;
+        while digit_index > 0
+            invariant
+                digit_index < digits_count,
+                dc == digits_count,
+                digits_count <= 64,
+                6 <= w <= 8,
+                buckets.len() == buckets_count,
+                buckets_count > 0,
+                n == scalars_points.len(),
+                pts == pippenger_points(scalars_points@),
+                forall|k: int|
+                    #![trigger pts[k]]
+                    0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1),
+                forall|j: int|
+                    0 <= j < dc ==> #[trigger] pippenger_pairs_valid(
+                        scalars_points@,
+                        j,
+                        buckets_count as int,
+                    ),
+                forall|k: int|
+                    #![trigger scalars_points@[k]]
+                    0 <= k < n ==> reconstruct_radix_2w(
+                        scalars_points@[k].0@.take(dc),
+                        w as nat,
+                    ) == scalar_to_nat(&spec_scalars[k]),
+                is_well_formed_edwards_point(total),
+                edwards_point_as_affine(total) == sum_of_signed_scalar_muls(
+                    pts,
+                    pippenger_suffix_values(scalars_points@, w as nat, dc, digit_index as int),
+                    n,
+                ),
+            decreases digit_index,
+        {
+            digit_index = digit_index - 1;
+            let ghost prev = pippenger_suffix_values(
+                scalars_points@,
+                w as nat,
+                dc,
+                digit_index as int + 1,
+            );
+            let ghost shifted = prev.map(|_k, c: int| pow2(w as nat) * c);
+            let ghost digits_column = pippenger_column(scalars_points@, digit_index as int);
+
+            let column = Self::column_verus(&scalars_points, &mut buckets, digit_index);
+
+            /* ORIGINAL CODE: columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p) */
+            total = &total.mul_by_pow_2(w as u32) + &column;
+
+            proof {
+                // 2^w·(Σ [c_k]P_k) + Σ [d_k]P_k = Σ [2^w·c_k + d_k]P_k
+                let cur = pippenger_suffix_values(scalars_points@, w as nat, dc, digit_index as int);
+                lemma_sum_of_signed_scalar_muls_scale(pts, prev, shifted, pow2(w as nat), n);
+                assert forall|k: int| 0 <= k < n implies #[trigger] cur[k] == shifted[k]
+                    + digits_column[k] by {
+                    lemma_reconstruct_radix_2w_suffix(
+                        scalars_points@[k].0@.take(dc),
+                        w as nat,
+                        digit_index as int,
+                    );
+                }
+                lemma_sum_of_signed_scalar_muls_add(pts, shifted, digits_column, cur, n);
+            }
+        }
+        /* </REFACTORED CODE> */
+
+        proof {
+            // After column 0 each coefficient is the value of all the digits, i.e. the scalar
+            let coeffs = pippenger_suffix_values(scalars_points@, w as nat, dc, 0);
+            assert forall|k: int| 0 <= k < n implies #[trigger] coeffs[k] == scalar_to_nat(
+                &spec_scalars[k],
+            ) by {
+                let digits = scalars_points@[k].0@.take(dc);
+                assert(digits.skip(0) =~= digits);
+            }
+            lemma_sum_of_scalar_muls_as_signed(spec_scalars, upts, coeffs, n);
+            assert(spec_scalars.subrange(0, n as int) =~= spec_scalars);
+            assert(upts.subrange(0, n as int) =~= upts);
+        }
+
+        Some(total)
+    }
+
+    /// Verus-compatible version of one step of the `columns` iterator in
+    /// optional_multiscalar_mul: sorts the points into buckets by their digit at
+    /// `digit_index`, then adds up the buckets weighted by their index + 1.
+    ///
+    /// The buckets are cleared first, so their contents on entry do not matter.
+    fn column_verus(
+        scalars_points: &Vec<([i8; 64], ProjectiveNielsPoint)>,
+        buckets: &mut Vec<EdwardsPoint>,
+        digit_index: usize,
+    ) -> (result: EdwardsPoint)
+        requires
+            old(buckets).len() > 0,
+            digit_index < 64,
+            pippenger_pairs_valid(scalars_points@, digit_index as int, old(buckets).len() as int),
+        ensures
+            buckets.len() == old(buckets).len(),
+            is_well_formed_edwards_point(result),
+            // result = Σ [d_k]P_k, with d_k the digit of scalar k at digit_index
+            edwards_point_as_affine(result) == sum_of_signed_scalar_muls(
+                pippenger_points(scalars_points@),
+                pippenger_column(scalars_points@, digit_index as int),
+                scalars_points.len() as nat,
+            ),
+    {
+        use crate::traits::Identity;
+
+        let buckets_count = buckets.len();
+        let ghost n = scalars_points.len() as nat;
+        let ghost pts = pippenger_points(scalars_points@);
+        let ghost column = pippenger_column(scalars_points@, digit_index as int);
+        proof {
+            assert forall|k: int|
+                #![trigger pts[k]]
+                0 <= k < n implies math_on_edwards_curve(pts[k].0, pts[k].1) && pts[k].0 < p()
+                    && pts[k].1 < p() by {
+                assert(pts[k] == projective_niels_point_as_affine_edwards(scalars_points@[k].1));
+            }
+        }
+
+        // Clear the buckets when processing another digit.
+        /* ORIGINAL CODE: for bucket in &mut buckets { *bucket = EdwardsPoint::identity(); } */
         let mut bucket_idx: usize = 0;
         while bucket_idx < buckets_count
+            invariant
+                bucket_idx <= buckets_count,
+                buckets.len() == buckets_count,
+                forall|b: int|
+                    #![trigger buckets@[b]]
+                    0 <= b < bucket_idx ==> is_identity_edwards_point(buckets@[b])
+                        && is_well_formed_edwards_point(buckets@[b]),
             decreases buckets_count - bucket_idx,
         {
-            assume(false);  // PROOF BYPASS
             buckets.set(bucket_idx, EdwardsPoint::identity());
             bucket_idx = bucket_idx + 1;
         }
 
-        // Fill buckets for hi_column
+        proof {
+            // Every bucket is O, the empty sum
+            assert forall|b: int|
+                #![trigger buckets@[b]]
+                0 <= b < buckets_count implies edwards_point_as_affine(buckets@[b])
+                    == sum_of_signed_scalar_muls(pts, pippenger_bucket_coeffs(column, b), 0) by {
+                lemma_identity_edwards_point_affine(buckets@[b]);
+            }
+        }
+
+        // Iterate over pairs of (point, scalar)
+        // and add/sub the point to the corresponding bucket.
+        /* ORIGINAL CODE: for (digits, pt) in scalars_points.iter() { ... } */
         let mut sp_idx: usize = 0;
         while sp_idx < scalars_points.len()
-            decreases scalars_points.len() - sp_idx,
+            invariant
+                sp_idx <= n,
+                n == scalars_points.len(),
+                buckets.len() == buckets_count,
+                digit_index < 64,
+                pippenger_pairs_valid(scalars_points@, digit_index as int, buckets_count as int),
+                pts == pippenger_points(scalars_points@),
+                column == pippenger_column(scalars_points@, digit_index as int),
+                forall|k: int|
+                    #![trigger pts[k]]
+                    0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1) && pts[k].0 < p()
+                        && pts[k].1 < p(),
+                // Bucket b holds Σ_{k < sp_idx} [c_k]P_k, with c_k = ±1 when d_k = ±(b + 1)
+                forall|b: int|
+                    #![trigger buckets@[b]]
+                    0 <= b < buckets_count ==> is_well_formed_edwards_point(buckets@[b])
+                        && edwards_point_as_affine(buckets@[b]) == sum_of_signed_scalar_muls(
+                        pts,
+                        pippenger_bucket_coeffs(column, b),
+                        sp_idx as nat,
+                    ),
+            decreases n - sp_idx,
         {
-            assume(false);  // PROOF BYPASS
             let sp = &scalars_points[sp_idx];
             let digits = &sp.0;
             let pt = &sp.1;
-            let digit = digits[digit_index_hi] as i16;
+            // Widen digit so that we don't run into edge cases when w=8.
+            let digit = digits[digit_index] as i16;
+            let ghost before = buckets@;
+            proof {
+                // |digit| ≤ buckets_count, and pt has 54-bit limbs
+                assert(scalars_points@[sp_idx as int] == *sp);
+                lemma_edwards_scalar_mul_signed_unit(pts[sp_idx as int]);
+            }
             if digit > 0 {
                 let b = (digit - 1) as usize;
                 buckets.set(b, (&buckets[b] + pt).as_extended());
@@ -419,108 +699,119 @@ impl Pippenger {
                 let b = (-digit - 1) as usize;
                 buckets.set(b, (&buckets[b] - pt).as_extended());
             }
+            proof {
+                // Bucket |d| - 1 gains [±1]P = ±P; every other bucket gains [0]P = O
+                let k = sp_idx as int;
+                assert(column[k] == digit as int);
+                assert forall|b: int|
+                    #![trigger buckets@[b]]
+                    0 <= b < buckets_count implies is_well_formed_edwards_point(buckets@[b])
+                        && edwards_point_as_affine(buckets@[b]) == sum_of_signed_scalar_muls(
+                        pts,
+                        pippenger_bucket_coeffs(column, b),
+                        (k + 1) as nat,
+                    ) by {
+                    let coeffs = pippenger_bucket_coeffs(column, b);
+                    let prev = sum_of_signed_scalar_muls(pts, coeffs, k as nat);
+                    lemma_sum_of_signed_scalar_muls_on_curve(pts, coeffs, k as nat);
+                    lemma_edwards_add_identity_right(prev.0, prev.1);
+                }
+            }
             sp_idx = sp_idx + 1;
         }
 
-        // Sum buckets for hi_column
-        assume(false);  // PROOF BYPASS: bucket access
+        // Add the buckets applying the multiplication factor to each bucket.
+        // The most efficient way to do that is to have a single sum with two running sums:
+        // an intermediate sum from last bucket to the first, and a sum of intermediate sums.
+        //
+        // For example, to add buckets 1*A, 2*B, 3*C we need to add these points:
+        //   C
+        //   C B
+        //   C B A   Sum = C + (C+B) + (C+B+A)
         let mut buckets_intermediate_sum = buckets[buckets_count - 1];
-        let mut hi_column = buckets[buckets_count - 1];
-        if buckets_count > 1 {
-            let mut j: usize = buckets_count - 2;
-            loop
-                decreases j,
-            {
-                assume(false);  // PROOF BYPASS
-                buckets_intermediate_sum = &buckets_intermediate_sum + &buckets[j];
-                hi_column = &hi_column + &buckets_intermediate_sum;
-                if j == 0 {
-                    break ;
-                }
-                j = j - 1;
+        let mut buckets_sum = buckets[buckets_count - 1];
+
+        proof {
+            // With |d| ≤ buckets_count, both sums start out as the last bucket
+            let last = buckets_count - 1;
+            assert forall|k: int|
+                0 <= k < n implies -(buckets_count as int) <= #[trigger] column[k]
+                    <= buckets_count by {
+                assert(column[k] == scalars_points@[k].0[digit_index as int]);
             }
+            assert(pippenger_intermediate_coeffs(column, last) =~= pippenger_bucket_coeffs(
+                column,
+                last,
+            ));
+            assert(pippenger_weighted_coeffs(column, last) =~= pippenger_bucket_coeffs(
+                column,
+                last,
+            ));
         }
-        // Fold remaining columns (digit_index = digits_count-2 .. 0)
-
-        let mut total = hi_column;
-        if digits_count > 1 {
-            let mut digit_index: usize = digits_count - 2;
-            loop
-                decreases digit_index,
-            {
-                assume(false);  // PROOF BYPASS
-
-                // Clear buckets
-                let mut bucket_idx2: usize = 0;
-                while bucket_idx2 < buckets_count
-                    decreases buckets_count - bucket_idx2,
-                {
-                    assume(false);  // PROOF BYPASS
-                    buckets.set(bucket_idx2, EdwardsPoint::identity());
-                    bucket_idx2 = bucket_idx2 + 1;
-                }
 
-                // Fill buckets
-                let mut sp_idx2: usize = 0;
-                while sp_idx2 < scalars_points.len()
-                    decreases scalars_points.len() - sp_idx2,
-                {
-                    assume(false);  // PROOF BYPASS
-                    let sp = &scalars_points[sp_idx2];
-                    let digits = &sp.0;
-                    let pt = &sp.1;
-                    let digit = digits[digit_index] as i16;
-                    if digit > 0 {
-                        let b = (digit - 1) as usize;
-                        buckets.set(b, (&buckets[b] + pt).as_extended());
-                    } else if digit < 0 {
-                        let b = (-digit - 1) as usize;
-                        buckets.set(b, (&buckets[b] - pt).as_extended());
-                    }
-                    sp_idx2 = sp_idx2 + 1;
-                }
-
-                // Sum buckets
-                assume(false);  // PROOF BYPASS: bucket access
-                let mut buckets_intermediate_sum2 = buckets[buckets_count - 1];
-                let mut column = buckets[buckets_count - 1];
-                if buckets_count > 1 {
-                    let mut j2: usize = buckets_count - 2;
-                    loop
-                        decreases j2,
-                    {
-                        assume(false);  // PROOF BYPASS
-                        buckets_intermediate_sum2 = &buckets_intermediate_sum2 + &buckets[j2];
-                        column = &column + &buckets_intermediate_sum2;
-                        if j2 == 0 {
-                            break ;
-                        }
-                        j2 = j2 - 1;
-                    }
-                }
-                // Accumulate: total = total * 2^w + column
-
-                total = &total.mul_by_pow_2(w as u32) + &column;
+        /* ORIGINAL CODE: for i in (0..(buckets_count - 1)).rev() { ... } */
+        let mut i: usize = buckets_count - 1;
+        while i > 0
+            invariant
+                i < buckets_count,
+                buckets.len() == buckets_count,
+                n == scalars_points.len(),
+                forall|k: int|
+                    #![trigger pts[k]]
+                    0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1),
+                forall|b: int|
+                    #![trigger buckets@[b]]
+                    0 <= b < buckets_count ==> is_well_formed_edwards_point(buckets@[b])
+                        && edwards_point_as_affine(buckets@[b]) == sum_of_signed_scalar_muls(
+                        pts,
+                        pippenger_bucket_coeffs(column, b),
+                        n,
+                    ),
+                // intermediate = Σ_{b ≥ i} bucket_b, sum = Σ_{b ≥ i} (b + 1 - i)·bucket_b
+                is_well_formed_edwards_point(buckets_intermediate_sum),
+                is_well_formed_edwards_point(buckets_sum),
+                edwards_point_as_affine(buckets_intermediate_sum) == sum_of_signed_scalar_muls(
+                    pts,
+                    pippenger_intermediate_coeffs(column, i as int),
+                    n,
+                ),
+                edwards_point_as_affine(buckets_sum) == sum_of_signed_scalar_muls(
+                    pts,
+                    pippenger_weighted_coeffs(column, i as int),
+                    n,
+                ),
+            decreases i,
+        {
+            i = i - 1;
+            let ghost bucket = pippenger_bucket_coeffs(column, i as int);
+            let ghost prev_intermediate = pippenger_intermediate_coeffs(column, i as int + 1);
+            let ghost intermediate = pippenger_intermediate_coeffs(column, i as int);
+            let ghost prev_weighted = pippenger_weighted_coeffs(column, i as int + 1);
+            let ghost weighted = pippenger_weighted_coeffs(column, i as int);
+
+            /* ORIGINAL CODE: buckets_intermediate_sum += buckets[i]; */
+            buckets_intermediate_sum = &buckets_intermediate_sum + &buckets[i];
+            proof {
+                assert forall|k: int| 0 <= k < n implies #[trigger] intermediate[k]
+                    == prev_intermediate[k] + bucket[k] by {}
+                lemma_sum_of_signed_scalar_muls_add(pts, prev_intermediate, bucket, intermediate, n);
+            }
 
-                if digit_index == 0 {
-                    break ;
-                }
-                digit_index = digit_index - 1;
+            /* ORIGINAL CODE: buckets_sum += buckets_intermediate_sum; */
+            buckets_sum = &buckets_sum + &buckets_intermediate_sum;
+            proof {
+                assert forall|k: int| 0 <= k < n implies #[trigger] weighted[k] == prev_weighted[k]
+                    + intermediate[k] by {}
+                lemma_sum_of_signed_scalar_muls_add(pts, prev_weighted, intermediate, weighted, n);
             }
-        }  /* </REFACTORED CODE> */
-        // PROOF BYPASS: Assume postconditions (requires full loop invariant proofs)
-        // At this point, we reached the end without returning None, so all points were Some
+        }
 
         proof {
-            assume(all_points_some(spec_points));
-            assume(is_well_formed_edwards_point(total));
-            assume(edwards_point_as_affine(total) == sum_of_scalar_muls(
-                spec_scalars,
-                unwrap_points(spec_points),
-            ));
+            // The weighted coefficients from bucket 0 on are the digits
+            assert(pippenger_weighted_coeffs(column, 0) =~= column);
         }
 
-        Some(total)
+        buckets_sum
     }
 }
 
@@ -331,3 +331,13 @@ fn test_edwards_multiscalar_dispatcher_original_vs_verus() {
         total_comparisons
     );
 }
+
+/// The hard-coded `ED25519_BASEPOINT_TABLE` matches `EdwardsBasepointTable::create`
+/// entry by entry, which is what `axiom_ed25519_basepoint_table_valid` assumes.
+#[cfg(feature = "precomputed-tables")]
+#[test]
+fn test_ed25519_basepoint_table_matches_create() {
+    assert!(
+        constants::ED25519_BASEPOINT_TABLE.is_valid_table_for(&constants::ED25519_BASEPOINT_POINT)
+    );
+}
@@ -27,14 +27,31 @@ use crate::window::NafLookupTable5;
 
 use vstd::prelude::*;
 
+#[cfg(verus_keep_ghost)]
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
 #[cfg(verus_keep_ghost)]
 use crate::specs::edwards_specs::*;
+#[cfg(verus_keep_ghost)]
+use crate::specs::field_specs::{fe51_limbs_bounded, sum_of_limbs_bounded};
+#[cfg(verus_keep_ghost)]
+use crate::specs::scalar_specs::{
+    is_valid_naf, radix_16_all_bounded, radix_16_digit_bounded, reconstruct, reconstruct_radix_16,
+    scalar_to_nat,
+};
+#[cfg(verus_keep_ghost)]
+use crate::specs::window_specs::{
+    is_valid_lookup_table_projective, is_valid_naf_lookup_table5_projective,
+    lookup_table_projective_limbs_bounded, naf_lookup_table5_projective_limbs_bounded,
+};
+#[cfg(verus_keep_ghost)]
+use vstd::arithmetic::power2::{lemma2_to64, pow2};
 
 // Import spec functions from scalar_mul_specs (ghost only)
 #[cfg(verus_keep_ghost)]
 use crate::specs::scalar_mul_specs::{
-    all_points_some, spec_optional_points_from_iter, spec_points_from_iter, spec_scalars_from_iter,
-    sum_of_scalar_muls, unwrap_points,
+    all_points_some, naf_column, naf_suffix_values, points_as_affine, radix_16_column,
+    radix_16_suffix_values, spec_optional_points_from_iter, spec_points_from_iter,
+    spec_scalars_from_iter, sum_of_scalar_muls, sum_of_signed_scalar_muls, unwrap_points,
 };
 
 // Import runtime helpers from scalar_mul_specs
@@ -297,6 +314,7 @@ impl Straus {
         /* Ghost vars to capture spec values before iterator consumption */
         let ghost spec_scalars = spec_scalars_from_iter::<S, I>(scalars);
         let ghost spec_points = spec_optional_points_from_iter::<J>(points);
+        let ghost n = spec_points.len();
 
         /* <ORIGINAL CODE>
     let nafs: Vec<_> = scalars
@@ -314,11 +332,17 @@ impl Straus {
         let mut nafs: Vec<[i8; 256]> = Vec::new();
         let mut idx: usize = 0;
         while idx < scalars_vec.len()
+            invariant
+                idx <= scalars_vec.len(),
+                scalars_vec@ == spec_scalars,
+                nafs.len() == idx,
+                // nafs[k] is a width-5 NAF of scalar k
+                forall|k: int|
+                    #![trigger nafs@[k]]
+                    0 <= k < idx ==> is_valid_naf(nafs@[k]@, 5) && reconstruct(nafs@[k]@)
+                        == scalar_to_nat(&spec_scalars[k]),
             decreases scalars_vec.len() - idx,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
             nafs.push(scalars_vec[idx].non_adjacent_form(5));
             idx = idx + 1;
         }
@@ -337,19 +361,28 @@ impl Straus {
         let mut lookup_tables: Vec<NafLookupTable5<ProjectiveNielsPoint>> = Vec::new();
         idx = 0;
         while idx < points_vec.len()
+            invariant
+                idx <= points_vec.len(),
+                points_vec@ == spec_points,
+                lookup_tables.len() == idx,
+                forall|k: int| 0 <= k < idx ==> (#[trigger] spec_points[k]).is_some(),
+                // lookup_tables[k] holds the odd multiples of point k
+                forall|k: int|
+                    #![trigger lookup_tables@[k]]
+                    0 <= k < idx ==> is_valid_naf_lookup_table5_projective(
+                        lookup_tables@[k].0,
+                        spec_points[k].unwrap(),
+                    ) && naf_lookup_table5_projective_limbs_bounded(lookup_tables@[k].0),
             decreases points_vec.len() - idx,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
             match points_vec[idx] {
                 Some(P) => {
                     lookup_tables.push(NafLookupTable5::<ProjectiveNielsPoint>::from(&P));
                 },
                 None => {
-                    // PROOF BYPASS: Found a None point, so not all_points_some
+                    // Point idx is None, so not all points are Some
                     proof {
-                        assume(!all_points_some(spec_points));
+                        assert(spec_points[idx as int].is_none());
                     }
                     return None;
                 },
@@ -358,9 +391,33 @@ impl Straus {
         }
         /* </REFACTORED CODE> */
 
+        // Every point is Some; pts are their affine forms
+        let ghost upts = unwrap_points(spec_points);
+        let ghost pts = points_as_affine(upts);
+        proof {
+            assert(all_points_some(spec_points));
+            assert forall|k: int|
+                #![trigger pts[k]]
+                0 <= k < n implies math_on_edwards_curve(pts[k].0, pts[k].1) by {
+                assert(spec_points[k].is_some());
+                assert(is_well_formed_edwards_point(upts[k]));
+            }
+        }
+
         /* UNCHANGED FROM ORIGINAL */
         let mut r = ProjectivePoint::identity();
 
+        proof {
+            // r = (0 : 1 : 1) = O, and with no digits processed every coefficient is 0
+            lemma_identity_projective_point_affine();
+            assert(1u64 < (1u64 << 54u64)) by (bit_vector);
+            let top = naf_suffix_values(nafs@, 256);
+            assert forall|k: int| 0 <= k < n implies #[trigger] top[k] == 0 * top[k] by {
+                assert(nafs@[k]@.skip(256).len() == 0);
+            }
+            lemma_sum_of_signed_scalar_muls_scale(pts, top, top, 0, n);
+        }
+
         /* <ORIGINAL CODE>
     for i in (0..256).rev() {
         let mut t: CompletedPoint = r.double();
@@ -384,21 +441,59 @@ impl Straus {
          *   1. Double the accumulator r
          *   2. For each (scalar, point) pair, add/sub the appropriate table entry
          *      based on the NAF digit at position i
+         *
+         * Before position i - 1, r = Σ_k [naf_{k,i} + 2·naf_{k,i+1} + ... + 2^(255-i)·naf_{k,255}]P_k.
          */
         let mut i: usize = 256;
-        loop
+        while i > 0
+            invariant
+                i <= 256,
+                n == spec_points.len(),
+                nafs.len() == n,
+                lookup_tables.len() == n,
+                forall|k: int|
+                    #![trigger nafs@[k]]
+                    0 <= k < n ==> is_valid_naf(nafs@[k]@, 5) && reconstruct(nafs@[k]@)
+                        == scalar_to_nat(&spec_scalars[k]),
+                forall|k: int|
+                    #![trigger lookup_tables@[k]]
+                    0 <= k < n ==> is_valid_naf_lookup_table5_projective(
+                        lookup_tables@[k].0,
+                        upts[k],
+                    ) && naf_lookup_table5_projective_limbs_bounded(lookup_tables@[k].0),
+                pts == points_as_affine(upts),
+                forall|k: int|
+                    #![trigger pts[k]]
+                    0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1),
+                is_valid_projective_point(r),
+                fe51_limbs_bounded(&r.X, 54),
+                fe51_limbs_bounded(&r.Y, 54),
+                fe51_limbs_bounded(&r.Z, 54),
+                sum_of_limbs_bounded(&r.X, &r.Y, u64::MAX),
+                projective_point_as_affine_edwards(r) == sum_of_signed_scalar_muls(
+                    pts,
+                    naf_suffix_values(nafs@, i as int),
+                    n,
+                ),
             decreases i,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
-            if i == 0 {
-                break ;
-            }
             i = i - 1;
+            let ghost prev = naf_suffix_values(nafs@, (i + 1) as int);
+            let ghost doubled = prev.map(|_k, c: int| 2 * c);
+            let ghost column = naf_column(nafs@, i as int);
 
             let mut t: CompletedPoint = r.double();
 
+            proof {
+                // t = 2·Σ [c_k]P_k = Σ [2·c_k]P_k
+                lemma_sum_of_signed_scalar_muls_add(pts, prev, prev, doubled, n);
+                // ... = Σ [2·c_k]P_k + O
+                lemma_sum_of_signed_scalar_muls_on_curve(pts, doubled, n);
+                let sd = sum_of_signed_scalar_muls(pts, doubled, n);
+                lemma_edwards_add_identity_right(sd.0, sd.1);
+                lemma2_to64();
+            }
+
             // Inner loop: iterate over nafs and lookup_tables
             let mut j: usize = 0;
             let min_len = if nafs.len() < lookup_tables.len() {
@@ -407,41 +502,144 @@ impl Straus {
                 lookup_tables.len()
             };
             while j < min_len
+                invariant
+                    i < 256,
+                    j <= min_len,
+                    min_len == n,
+                    nafs.len() == n,
+                    lookup_tables.len() == n,
+                    forall|k: int|
+                        #![trigger nafs@[k]]
+                        0 <= k < n ==> is_valid_naf(nafs@[k]@, 5),
+                    forall|k: int|
+                        #![trigger lookup_tables@[k]]
+                        0 <= k < n ==> is_valid_naf_lookup_table5_projective(
+                            lookup_tables@[k].0,
+                            upts[k],
+                        ) && naf_lookup_table5_projective_limbs_bounded(lookup_tables@[k].0),
+                    pts == points_as_affine(upts),
+                    forall|k: int|
+                        #![trigger pts[k]]
+                        0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1),
+                    column == naf_column(nafs@, i as int),
+                    pow2(4) == 16,
+                    is_valid_completed_point(t),
+                    fe51_limbs_bounded(&t.X, 54),
+                    fe51_limbs_bounded(&t.Y, 54),
+                    fe51_limbs_bounded(&t.Z, 54),
+                    fe51_limbs_bounded(&t.T, 54),
+                    // t = Σ [2·c_k]P_k + Σ_{k < j} [naf_{k,i}]P_k
+                    ({
+                        let sd = sum_of_signed_scalar_muls(pts, doubled, n);
+                        let sc = sum_of_signed_scalar_muls(pts, column, j as nat);
+                        completed_point_as_affine_edwards(t) == edwards_add(sd.0, sd.1, sc.0, sc.1)
+                    }),
                 decreases min_len - j,
             {
-                proof {
-                    assume(false);
-                }  // PROOF BYPASS
                 let naf = &nafs[j];
                 let lookup_table = &lookup_tables[j];
+                let ghost digit = naf[i as int] as int;
+
+                proof {
+                    // Adding [naf_{j,i}]P_j to t advances the column sum by one term
+                    let sd = sum_of_signed_scalar_muls(pts, doubled, n);
+                    let sc = sum_of_signed_scalar_muls(pts, column, j as nat);
+                    let term = edwards_scalar_mul_signed(pts[j as int], digit);
+                    assert(math_on_edwards_curve(pts[j as int].0, pts[j as int].1));
+                    assert(column[j as int] == digit);
+                    lemma_sum_of_signed_scalar_muls_on_curve(pts, doubled, n);
+                    lemma_sum_of_signed_scalar_muls_on_curve(pts, column, j as nat);
+                    lemma_edwards_scalar_mul_signed_on_curve(pts[j as int], digit);
+                    axiom_edwards_add_associative(sd.0, sd.1, sc.0, sc.1, term.0, term.1);
+                    // A non-zero width-5 NAF digit is odd with |digit| < 16
+                    assert(is_valid_naf(naf@, 5));
+                    assert(digit == 0 || (digit % 2 != 0 && -16 < digit && digit < 16));
+                }
 
                 match naf[i].cmp(&0) {
                     Ordering::Greater => {
-                        t = &t.as_extended() + &lookup_table.select(naf[i] as usize);
+                        /* ORIGINAL CODE: t = &t.as_extended() + &lookup_table.select(naf[i] as usize); */
+                        let x = naf[i] as usize;
+                        proof {
+                            assert(x % 2 == 1);
+                            assert(x & 1 == 1) by (bit_vector)
+                                requires
+                                    x % 2 == 1,
+                            ;
+                        }
+                        let t_ext = t.as_extended();
+                        let selected = lookup_table.select(x);
+                        t = &t_ext + &selected;
+                        proof {
+                            lemma_select_naf_projective_niels_is_multiple(
+                                lookup_table.0,
+                                upts[j as int],
+                                x,
+                                selected,
+                            );
+                        }
                     },
                     Ordering::Less => {
-                        t = &t.as_extended() - &lookup_table.select((-naf[i]) as usize);
+                        /* ORIGINAL CODE: t = &t.as_extended() - &lookup_table.select((-naf[i]) as usize); */
+                        let x = (-naf[i]) as usize;
+                        proof {
+                            assert(x % 2 == 1);
+                            assert(x & 1 == 1) by (bit_vector)
+                                requires
+                                    x % 2 == 1,
+                            ;
+                        }
+                        let t_ext = t.as_extended();
+                        let selected = lookup_table.select(x);
+                        t = &t_ext - &selected;
+                        proof {
+                            // t_ext - [-d]P_j = t_ext + [d]P_j for d < 0
+                            lemma_select_naf_projective_niels_is_multiple(
+                                lookup_table.0,
+                                upts[j as int],
+                                x,
+                                selected,
+                            );
+                        }
+                    },
+                    Ordering::Equal => {
+                        proof {
+                            // [0]P_j = O
+                            let sc = sum_of_signed_scalar_muls(pts, column, j as nat);
+                            lemma_edwards_add_identity_right(sc.0, sc.1);
+                        }
                     },
-                    Ordering::Equal => {},
                 }
                 j = j + 1;
             }
 
             r = t.as_projective();
+
+            proof {
+                // naf_k from position i on = naf_{k,i} + 2·(naf_k from i+1 on)
+                let cur = naf_suffix_values(nafs@, i as int);
+                assert forall|k: int| 0 <= k < n implies #[trigger] cur[k] == doubled[k]
+                    + column[k] by {
+                    lemma_reconstruct_naf_suffix(nafs@[k]@, i as int);
+                }
+                lemma_sum_of_signed_scalar_muls_add(pts, doubled, column, cur, n);
+            }
         }
         /* </REFACTORED CODE> */
 
-        assume(false);  // PROOF BYPASS: as_extended precondition requires loop invariants
         let result = r.as_extended();
 
-        // PROOF BYPASS: Assert postconditions for verification goal
         proof {
-            assume(all_points_some(spec_points));
-            assume(is_well_formed_edwards_point(result));
-            assume(edwards_point_as_affine(result) == sum_of_scalar_muls(
-                spec_scalars,
-                unwrap_points(spec_points),
-            ));
+            // After position 0 each coefficient is the full NAF value, i.e. the scalar
+            let coeffs = naf_suffix_values(nafs@, 0);
+            assert forall|k: int| 0 <= k < n implies #[trigger] coeffs[k] == scalar_to_nat(
+                &spec_scalars[k],
+            ) by {
+                assert(nafs@[k]@.skip(0) =~= nafs@[k]@);
+            }
+            lemma_sum_of_scalar_muls_as_signed(spec_scalars, upts, coeffs, n);
+            assert(spec_scalars.subrange(0, n as int) =~= spec_scalars);
+            assert(upts.subrange(0, n as int) =~= upts);
         }
 
         Some(result)
@@ -468,6 +666,10 @@ impl Straus {
                     ==> is_well_formed_edwards_point(
                     #[trigger] spec_points_from_iter::<P, J>(points)[i],
                 ),
+            // as_radix_16 requires bit 255 of each scalar to be clear
+            forall|i: int|
+                0 <= i < spec_scalars_from_iter::<S, I>(scalars).len() ==> (
+                #[trigger] spec_scalars_from_iter::<S, I>(scalars)[i]).bytes[31] <= 127,
         ensures
     // Result is a well-formed Edwards point
 
@@ -483,6 +685,8 @@ impl Straus {
         /* Ghost vars to capture spec values before iterator consumption */
         let ghost spec_scalars = spec_scalars_from_iter::<S, I>(scalars);
         let ghost spec_points = spec_points_from_iter::<P, J>(points);
+        let ghost n = spec_points.len();
+        let ghost pts = points_as_affine(spec_points);
 
         /* <ORIGINAL CODE>
         let lookup_tables: Vec<_> = points
@@ -496,14 +700,30 @@ impl Straus {
          */
         let scalars_vec = collect_scalars_from_iter(scalars);
         let points_vec = collect_points_from_iter(points);
+        proof {
+            assert forall|k: int|
+                #![trigger pts[k]]
+                0 <= k < n implies math_on_edwards_curve(pts[k].0, pts[k].1) by {
+                assert(is_well_formed_edwards_point(points_vec@[k]));
+            }
+        }
         let mut lookup_tables: Vec<LookupTable<ProjectiveNielsPoint>> = Vec::new();
         let mut idx: usize = 0;
         while idx < points_vec.len()
+            invariant
+                idx <= points_vec.len(),
+                points_vec@ == spec_points,
+                lookup_tables.len() == idx,
+                // lookup_tables[k] holds [P_k, 2P_k, ..., 8P_k]
+                forall|k: int|
+                    #![trigger lookup_tables@[k]]
+                    0 <= k < idx ==> is_valid_lookup_table_projective(
+                        lookup_tables@[k].0,
+                        spec_points[k],
+                        8 as nat,
+                    ) && lookup_table_projective_limbs_bounded(lookup_tables@[k].0),
             decreases points_vec.len() - idx,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
             lookup_tables.push(LookupTable::<ProjectiveNielsPoint>::from(&points_vec[idx]));
             idx = idx + 1;
         }
@@ -521,11 +741,20 @@ impl Straus {
         let mut scalar_digits: Vec<[i8; 64]> = Vec::new();
         idx = 0;
         while idx < scalars_vec.len()
+            invariant
+                idx <= scalars_vec.len(),
+                scalars_vec@ == spec_scalars,
+                forall|k: int|
+                    0 <= k < spec_scalars.len() ==> (#[trigger] spec_scalars[k]).bytes[31] <= 127,
+                scalar_digits.len() == idx,
+                // scalar_digits[k] are the radix-16 digits of scalar k
+                forall|k: int|
+                    #![trigger scalar_digits@[k]]
+                    0 <= k < idx ==> radix_16_all_bounded(&scalar_digits@[k]) && reconstruct_radix_16(
+                        scalar_digits@[k]@,
+                    ) == scalar_to_nat(&spec_scalars[k]),
             decreases scalars_vec.len() - idx,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
             scalar_digits.push(scalars_vec[idx].as_radix_16());
             idx = idx + 1;
         }
@@ -534,6 +763,16 @@ impl Straus {
         /* UNCHANGED FROM ORIGINAL */
         let mut Q = EdwardsPoint::identity();
 
+        proof {
+            // Q = O, and with no digits processed every coefficient is 0
+            lemma_identity_edwards_point_affine(Q);
+            let top = radix_16_suffix_values(scalar_digits@, 64);
+            assert forall|k: int| 0 <= k < n implies #[trigger] top[k] == 0 * top[k] by {
+                assert(scalar_digits@[k]@.skip(64).len() == 0);
+            }
+            lemma_sum_of_signed_scalar_muls_scale(pts, top, top, 0, n);
+        }
+
         /* <ORIGINAL CODE>
         for j in (0..64).rev() {
             Q = Q.mul_by_pow_2(4);
@@ -549,21 +788,57 @@ impl Straus {
          * For each position j:
          *   1. Multiply accumulator Q by 16 (= 2^4)
          *   2. For each (scalar, point) pair, add s_j * P_i from lookup table
+         *
+         * Before position j - 1, Q = Σ_i [s_{i,j} + 16·s_{i,j+1} + ... + 16^(63-j)·s_{i,63}]P_i.
          */
         let mut j: usize = 64;
-        loop
+        while j > 0
+            invariant
+                j <= 64,
+                n == spec_points.len(),
+                scalar_digits.len() == n,
+                lookup_tables.len() == n,
+                forall|k: int|
+                    #![trigger scalar_digits@[k]]
+                    0 <= k < n ==> radix_16_all_bounded(&scalar_digits@[k]) && reconstruct_radix_16(
+                        scalar_digits@[k]@,
+                    ) == scalar_to_nat(&spec_scalars[k]),
+                forall|k: int|
+                    #![trigger lookup_tables@[k]]
+                    0 <= k < n ==> is_valid_lookup_table_projective(
+                        lookup_tables@[k].0,
+                        spec_points[k],
+                        8 as nat,
+                    ) && lookup_table_projective_limbs_bounded(lookup_tables@[k].0),
+                pts == points_as_affine(spec_points),
+                forall|k: int|
+                    #![trigger pts[k]]
+                    0 <= k < n ==> math_on_edwards_curve(pts[k].0, pts[k].1),
+                is_well_formed_edwards_point(Q),
+                edwards_point_as_affine(Q) == sum_of_signed_scalar_muls(
+                    pts,
+                    radix_16_suffix_values(scalar_digits@, j as int),
+                    n,
+                ),
             decreases j,
         {
-            proof {
-                assume(false);
-            }  // PROOF BYPASS
-            if j == 0 {
-                break ;
-            }
             j = j - 1;
+            let ghost prev = radix_16_suffix_values(scalar_digits@, (j + 1) as int);
+            let ghost shifted = prev.map(|_k, c: int| 16 * c);
+            let ghost column = radix_16_column(scalar_digits@, j as int);
 
             Q = Q.mul_by_pow_2(4);
 
+            proof {
+                // Q = 16·Σ [c_k]P_k = Σ [16·c_k]P_k
+                lemma2_to64();
+                lemma_sum_of_signed_scalar_muls_scale(pts, prev, shifted, 16, n);
+                // ... = Σ [16·c_k]P_k + O
+                lemma_sum_of_signed_scalar_muls_on_curve(pts, shifted, n);
+                let ss = sum_of_signed_scalar_muls(pts, shifted, n);
+                lemma_edwards_add_identity_right(ss.0, ss.1);
+            }
+
             // Inner loop: iterate over scalar_digits and lookup_tables
             let mut k: usize = 0;
             let min_len = if scalar_digits.len() < lookup_tables.len() {
@@ -572,24 +847,89 @@ impl Straus {
                 lookup_tables.len()
             };
             while k < min_len
+                invariant
+                    j < 64,
+                    k <= min_len,
+                    min_len == n,
+                    scalar_digits.len() == n,
+                    lookup_tables.len() == n,
+                    forall|m: int|
+                        #![trigger scalar_digits@[m]]
+                        0 <= m < n ==> radix_16_all_bounded(&scalar_digits@[m]),
+                    forall|m: int|
+                        #![trigger lookup_tables@[m]]
+                        0 <= m < n ==> is_valid_lookup_table_projective(
+                            lookup_tables@[m].0,
+                            spec_points[m],
+                            8 as nat,
+                        ) && lookup_table_projective_limbs_bounded(lookup_tables@[m].0),
+                    pts == points_as_affine(spec_points),
+                    forall|m: int|
+                        #![trigger pts[m]]
+                        0 <= m < n ==> math_on_edwards_curve(pts[m].0, pts[m].1),
+                    column == radix_16_column(scalar_digits@, j as int),
+                    is_well_formed_edwards_point(Q),
+                    // Q = Σ [16·c_m]P_m + Σ_{m < k} [s_{m,j}]P_m
+                    ({
+                        let ss = sum_of_signed_scalar_muls(pts, shifted, n);
+                        let sc = sum_of_signed_scalar_muls(pts, column, k as nat);
+                        edwards_point_as_affine(Q) == edwards_add(ss.0, ss.1, sc.0, sc.1)
+                    }),
                 decreases min_len - k,
             {
-                proof {
-                    assume(false);
-                }  // PROOF BYPASS
                 let s_i = &scalar_digits[k];
                 let lookup_table_i = &lookup_tables[k];
+                proof {
+                    assert(radix_16_all_bounded(s_i));
+                    assert(radix_16_digit_bounded(s_i[j as int]));
+                }
                 let R_i = lookup_table_i.select(s_i[j]);
-                Q = (&Q + &R_i).as_extended();
+                /* ORIGINAL CODE: Q = (&Q + &R_i).as_extended(); */
+                let sum = &Q + &R_i;
+                proof {
+                    // R_i = [s_{k,j}]P_k, and adding it advances the column sum by one term
+                    lemma_select_projective_niels_is_signed_multiple(
+                        lookup_table_i.0,
+                        spec_points[k as int],
+                        s_i[j as int],
+                        R_i,
+                    );
+                    let ss = sum_of_signed_scalar_muls(pts, shifted, n);
+                    let sc = sum_of_signed_scalar_muls(pts, column, k as nat);
+                    let term = edwards_scalar_mul_signed(pts[k as int], column[k as int]);
+                    assert(math_on_edwards_curve(pts[k as int].0, pts[k as int].1));
+                    lemma_sum_of_signed_scalar_muls_on_curve(pts, shifted, n);
+                    lemma_sum_of_signed_scalar_muls_on_curve(pts, column, k as nat);
+                    lemma_edwards_scalar_mul_signed_on_curve(pts[k as int], column[k as int]);
+                    axiom_edwards_add_associative(ss.0, ss.1, sc.0, sc.1, term.0, term.1);
+                }
+                Q = sum.as_extended();
                 k = k + 1;
             }
+
+            proof {
+                // s_i from position j on = s_{i,j} + 16·(s_i from j+1 on)
+                let cur = radix_16_suffix_values(scalar_digits@, j as int);
+                assert forall|m: int| 0 <= m < n implies #[trigger] cur[m] == shifted[m]
+                    + column[m] by {
+                    lemma_reconstruct_radix_16_suffix(scalar_digits@[m]@, j as int);
+                }
+                lemma_sum_of_signed_scalar_muls_add(pts, shifted, column, cur, n);
+            }
         }
         /* </REFACTORED CODE> */
 
-        // PROOF BYPASS: Assume postconditions (requires full loop invariant proofs)
         proof {
-            assume(is_well_formed_edwards_point(Q));
-            assume(edwards_point_as_affine(Q) == sum_of_scalar_muls(spec_scalars, spec_points));
+            // After position 0 each coefficient is the full radix-16 value, i.e. the scalar
+            let coeffs = radix_16_suffix_values(scalar_digits@, 0);
+            assert forall|k: int| 0 <= k < n implies #[trigger] coeffs[k] == scalar_to_nat(
+                &spec_scalars[k],
+            ) by {
+                assert(scalar_digits@[k]@.skip(0) =~= scalar_digits@[k]@);
+            }
+            lemma_sum_of_scalar_muls_as_signed(spec_scalars, spec_points, coeffs, n);
+            assert(spec_scalars.subrange(0, n as int) =~= spec_scalars);
+            assert(spec_points.subrange(0, n as int) =~= spec_points);
         }
 
         Q
@@ -6,17 +6,28 @@ use crate::scalar::Scalar;
 use crate::traits::Identity;
 use crate::window::LookupTable;
 
+#[cfg(verus_keep_ghost)]
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::{
+    lemma_edwards_scalar_mul_signed_additive, lemma_edwards_scalar_mul_signed_double,
+    lemma_identity_edwards_point_affine, lemma_reconstruct_radix_16_suffix,
+    lemma_select_projective_niels_is_signed_multiple,
+};
 #[cfg(verus_keep_ghost)]
 use crate::specs::edwards_specs::{
-    edwards_point_as_affine, edwards_scalar_mul, is_valid_completed_point,
-    is_well_formed_edwards_point,
+    completed_point_as_affine_edwards, edwards_point_as_affine, edwards_scalar_mul,
+    edwards_scalar_mul_signed, is_valid_completed_point, is_well_formed_edwards_point,
+    math_on_edwards_curve,
 };
 #[cfg(verus_keep_ghost)]
 use crate::specs::field_specs::{fe51_limbs_bounded, sum_of_limbs_bounded};
 #[cfg(verus_keep_ghost)]
-use crate::specs::scalar_specs::{radix_16_all_bounded, radix_16_digit_bounded, spec_scalar};
+use crate::specs::scalar_specs::{
+    radix_16_all_bounded, radix_16_digit_bounded, reconstruct_radix_16, scalar_to_nat,
+};
 #[cfg(verus_keep_ghost)]
-use crate::specs::window_specs::lookup_table_projective_limbs_bounded;
+use crate::specs::window_specs::{
+    is_valid_lookup_table_projective, lookup_table_projective_limbs_bounded,
+};
 
 use vstd::prelude::*;
 
@@ -24,7 +35,9 @@ verus! {
 
 /// Perform constant-time, variable-base scalar multiplication.
 /// Computes scalar * point on the Ed25519 curve.
-// VERIFICATION NOTE: PROOF BYPASS - assumes used for intermediate preconditions
+///
+/// The result is \\([s]P\\) for the integer s encoded by `scalar.bytes`, which is not
+/// reduced mod ℓ: for a point with a torsion component, [s]P and [s mod ℓ]P differ.
 #[rustfmt::skip]  // keep alignment of explanatory comments
 pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoint)
     requires
@@ -40,9 +53,10 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoin
         // Functional correctness: result represents scalar * point
         edwards_point_as_affine(result) == edwards_scalar_mul(
             edwards_point_as_affine(*point),
-            spec_scalar(scalar),
+            scalar_to_nat(scalar),
         ),
 {
+    let ghost P = edwards_point_as_affine(*point);
     // Construct a lookup table of [P,2P,3P,4P,5P,6P,7P,8P]
     let lookup_table = LookupTable::<ProjectiveNielsPoint>::from(point);
     // Setting s = scalar, compute
@@ -70,9 +84,25 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoin
         assert(radix_16_all_bounded(&scalar_digits));
         assert(radix_16_digit_bounded(scalar_digits[63]));  // instantiate for index 63
     }
-    let mut tmp1 = &tmp3 + &lookup_table.select(scalar_digits[63]);
+    /* ORIGINAL CODE: let mut tmp1 = &tmp3 + &lookup_table.select(scalar_digits[63]); */
+    let selected = lookup_table.select(scalar_digits[63]);
+    let mut tmp1 = &tmp3 + &selected;
 
     // Now tmp1 = s_63*P in P1xP1 coords
+    proof {
+        // tmp1 = O + [s_63]P = [0 + s_63]P
+        lemma_identity_edwards_point_affine(tmp3);
+        lemma_select_projective_niels_is_signed_multiple(
+            lookup_table.0,
+            *point,
+            scalar_digits[63],
+            selected,
+        );
+        lemma_edwards_scalar_mul_signed_additive(P, 0, scalar_digits[63] as int);
+        // The digits from index 63 on are just s_63
+        lemma_reconstruct_radix_16_suffix(scalar_digits@, 63);
+        assert(scalar_digits@.skip(64).len() == 0);
+    }
     /* ORIGINAL CODE:
     for i in (0..63).rev() {
     */
@@ -84,6 +114,10 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoin
             radix_16_all_bounded(&scalar_digits),
             // lookup_table has bounded limbs (from from() postcondition)
             lookup_table_projective_limbs_bounded(lookup_table.0),
+            // lookup_table holds [P, 2P, ..., 8P] (from from() postcondition)
+            is_valid_lookup_table_projective(lookup_table.0, *point, 8 as nat),
+            P == edwards_point_as_affine(*point),
+            math_on_edwards_curve(P.0, P.1),
             // tmp1 is always a valid completed point (from Add postcondition)
             is_valid_completed_point(tmp1),
             // tmp1 limb bounds (from Add postcondition, preserved through loop)
@@ -91,19 +125,41 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoin
             fe51_limbs_bounded(&tmp1.Y, 54),
             fe51_limbs_bounded(&tmp1.Z, 54),
             fe51_limbs_bounded(&tmp1.T, 54),
+            // tmp1 = [s_{63-j} + 16*s_{64-j} + ... + 16^j*s_63]P
+            completed_point_as_affine_edwards(tmp1) == edwards_scalar_mul_signed(
+                P,
+                reconstruct_radix_16(scalar_digits@.skip(63 - j)),
+            ),
     {
         let i = 62 - j;  // i goes from 62 down to 0
+        let ghost prev = reconstruct_radix_16(scalar_digits@.skip(i + 1));
         tmp2 = tmp1.as_projective();  // tmp2 =    (prev) in P2 coords
         tmp1 = tmp2.double();  // tmp1 =  2*(prev) in P1xP1 coords
+        proof { lemma_edwards_scalar_mul_signed_double(P, prev); }
         tmp2 = tmp1.as_projective();  // tmp2 =  2*(prev) in P2 coords
         tmp1 = tmp2.double();  // tmp1 =  4*(prev) in P1xP1 coords
+        proof { lemma_edwards_scalar_mul_signed_double(P, 2 * prev); }
         tmp2 = tmp1.as_projective();  // tmp2 =  4*(prev) in P2 coords
         tmp1 = tmp2.double();  // tmp1 =  8*(prev) in P1xP1 coords
+        proof { lemma_edwards_scalar_mul_signed_double(P, 4 * prev); }
         tmp2 = tmp1.as_projective();  // tmp2 =  8*(prev) in P2 coords
         tmp1 = tmp2.double();  // tmp1 = 16*(prev) in P1xP1 coords
+        proof { lemma_edwards_scalar_mul_signed_double(P, 8 * prev); }
         tmp3 = tmp1.as_extended();  // tmp3 = 16*(prev) in P3 coords
-        tmp1 = &tmp3 + &lookup_table.select(scalar_digits[i]);
+        /* ORIGINAL CODE: tmp1 = &tmp3 + &lookup_table.select(scalar_digits[i]); */
+        let selected = lookup_table.select(scalar_digits[i]);
+        tmp1 = &tmp3 + &selected;
         // Now tmp1 = s_i*P + 16*(prev) in P1xP1 coords
+        proof {
+            lemma_select_projective_niels_is_signed_multiple(
+                lookup_table.0,
+                *point,
+                scalar_digits[i as int],
+                selected,
+            );
+            lemma_edwards_scalar_mul_signed_additive(P, 16 * prev, scalar_digits[i as int] as int);
+            lemma_reconstruct_radix_16_suffix(scalar_digits@, i as int);
+        }
     }
     proof {
         // From loop invariant
@@ -116,11 +172,8 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> (result: EdwardsPoin
     }
     let result = tmp1.as_extended();
     proof {
-        // postconditions
-        assume(edwards_point_as_affine(result) == edwards_scalar_mul(
-            edwards_point_as_affine(*point),
-            spec_scalar(scalar),
-        ));
+        // After the last iteration tmp1 = [s_0 + 16*s_1 + ... + 16^63*s_63]P = [s]P
+        assert(scalar_digits@.skip(0) =~= scalar_digits@);
     }
     result
 }
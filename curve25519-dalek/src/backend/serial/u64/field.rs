@@ -55,12 +55,18 @@ use crate::lemmas::field_lemmas::as_bytes_lemmas::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::compute_q_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::from_bytes_lemmas::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::limbs_to_bytes_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::load8_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::mul_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::negate_lemmas::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::pow2_51_lemmas::*;
@@ -73,6 +79,8 @@ use crate::lemmas::field_lemmas::to_bytes_reduction_lemmas::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::u64_5_as_nat_lemmas::*;
 #[allow(unused_imports)]
+use crate::core_assumes::seq_from32;
+#[allow(unused_imports)]
 use crate::specs::core_specs::*;
 
 #[allow(unused_imports)]
@@ -176,6 +184,11 @@ impl<'a> AddAssign<&'a FieldElement51> for FieldElement51 {
                 spec_field_element(old(self)),
                 spec_field_element(_rhs),
             ),
+            // Bound propagation: tighter inputs give tighter output
+            fe51_limbs_bounded(old(self), 51) && fe51_limbs_bounded(_rhs, 51) ==> fe51_limbs_bounded(
+                self,
+                52,
+            ),
     {
         let ghost original_limbs = self.limbs;
         for i in 0..5
@@ -196,6 +209,9 @@ impl<'a> AddAssign<&'a FieldElement51> for FieldElement51 {
             assert(self.limbs =~= spec_add_fe51_limbs(old(self), _rhs).limbs);
             // Discharge the rest of the ensures
             lemma_field51_add(old(self), _rhs);
+
+            // 51-bit inputs → 52-bit output
+            assert((1u64 << 51) + (1u64 << 51) == (1u64 << 52)) by (bit_vector);
         }
 
     }
@@ -278,16 +294,11 @@ impl<'a> Add<&'a FieldElement51> for &FieldElement51 {
 }
 
 impl<'a> SubAssign<&'a FieldElement51> for FieldElement51 {
-    fn sub_assign(
-        &mut self,
-        _rhs: &'a FieldElement51,
-    )
-    // VERIFICATION NOTE: PROOF BYPASS
-
+    fn sub_assign(&mut self, _rhs: &'a FieldElement51)
         requires
             fe51_limbs_bounded(old(self), 54) && fe51_limbs_bounded(_rhs, 54),
         ensures
-            forall|i: int| 0 <= i < 5 ==> #[trigger] self.limbs[i] < (1u64 << 52),
+            fe51_limbs_bounded(self, 52),
             *self == spec_sub_limbs(old(self), _rhs),
             spec_field_element(self) == math_field_sub(
                 spec_field_element(old(self)),
@@ -306,12 +317,9 @@ impl<'a> SubAssign<&'a FieldElement51> for FieldElement51 {
             assert(result == spec_sub_limbs(old(self), _rhs));
             // Therefore self.limbs equals spec_sub_limbs(old(self), _rhs).limbs
             assert(self.limbs =~= spec_sub_limbs(old(self), _rhs).limbs);
+            // Same limbs, so the value and the 52-bit bound carry over from result
+            assert(self.limbs == result.limbs);
         }
-        assume(spec_field_element(self) == math_field_sub(
-            spec_field_element(old(self)),
-            spec_field_element(_rhs),
-        ));
-        assume(forall|i: int| 0 <= i < 5 ==> self.limbs[i] < (1u64 << 52))
     }
 }
 
@@ -336,16 +344,15 @@ impl vstd::std_specs::ops::SubSpecImpl<&FieldElement51> for &FieldElement51 {
 impl<'a> Sub<&'a FieldElement51> for &FieldElement51 {
     type Output = FieldElement51;
 
-    fn sub(self, _rhs: &'a FieldElement51) -> (output:
-        FieldElement51)
-    // VERIFICATION NOTE: PROOF BYPASS
-
+    fn sub(self, _rhs: &'a FieldElement51) -> (output: FieldElement51)
         ensures
             output == spec_sub_limbs(self, _rhs),
             spec_field_element(&output) == math_field_sub(
                 spec_field_element(self),
                 spec_field_element(_rhs),
             ),
+            // Actual bound from the final reduce
+            fe51_limbs_bounded(&output, 52),
             fe51_limbs_bounded(&output, 54),
     {
         assert(fe51_limbs_bounded(self, 54) && fe51_limbs_bounded(_rhs, 54));
@@ -391,8 +398,7 @@ impl<'a> Sub<&'a FieldElement51> for &FieldElement51 {
         );
 
         proof {
-            assert(1u64 << 52 < 1u64 << 54) by (compute);
-            assert(fe51_limbs_bounded(&output, 54));
+            lemma_fe51_limbs_bounded_weaken(&output, 52, 54);
 
             // Glue the raw subtraction back to the spec subtraction using reduction lemmas.
             lemma_u64_5_as_nat_add(self.limbs, const_vec);
@@ -449,6 +455,7 @@ impl<'a> MulAssign<&'a FieldElement51> for FieldElement51 {
                 spec_field_element(old(self)),
                 spec_field_element(_rhs),
             ),
+            fe51_limbs_bounded(self, 52),
             fe51_limbs_bounded(self, 54),
     {
         let result = &*self * _rhs;
@@ -482,11 +489,7 @@ impl<'a> Mul<&'a FieldElement51> for &FieldElement51 {
 
     #[rustfmt::skip]  // keep alignment of c* calculations
     fn mul(self, _rhs: &'a FieldElement51) -> (output:
-        FieldElement51)/*  VERIFICATION NOTE:
-    - PROOF BYPASS
-    - REVIEW SPEC WHILE DOING THE PROOF
-    */
-
+        FieldElement51)
         ensures
             spec_field_element(&output) == math_field_mul(
                 spec_field_element(self),
@@ -519,13 +522,17 @@ impl<'a> Mul<&'a FieldElement51> for &FieldElement51 {
         // Since 51 + b + lg(19) < 51 + 4.25 + b
         //                       = 55.25 + b,
         // this fits if b < 8.75.
-        assume(false);  // PROOF BYPASS for arithmetic overflow
+        proof {
+            // No overflow in the b[i] * 19 precomputations, the c[i] sums and the carry chain;
+            // the returned limbs are congruent to a * b (mod p)
+            lemma_mul_boundary(*a, *b);
+            lemma_mul_value(*a, *b);
+        }
         let b1_19 = b[1] * 19;
         let b2_19 = b[2] * 19;
         let b3_19 = b[3] * 19;
         let b4_19 = b[4] * 19;
 
-        assume(false);  // PROOF BYPASS for arithmetic overflow
         // Multiply to get 128-bit coefficients of output
         let c0: u128 = m(a[0], b[0]) + m(a[4], b1_19) + m(a[3], b2_19) + m(a[2], b3_19) + m(
             a[1],
@@ -618,6 +625,12 @@ impl<'a> Mul<&'a FieldElement51> for &FieldElement51 {
         out[0] &= LOW_51_BIT_MASK;
 
         // Now out[i] < 2^(51 + epsilon) for all i.
+        proof {
+            assert(u64_5_as_nat(out) == u64_5_as_nat(mul_return(*a, *b)));
+            // (a * b) % p == ((a % p) * (b % p)) % p
+            lemma_mul_mod_noop(u64_5_as_nat(*a) as int, u64_5_as_nat(*b) as int, p() as int);
+            assert((1u64 << 52) < (1u64 << 54)) by (bit_vector);
+        }
         FieldElement51 { limbs: out }
     }
 }
@@ -632,7 +645,7 @@ impl vstd::std_specs::ops::NegSpecImpl for &FieldElement51 {
 
     // Pre-condition of neg
     open spec fn neg_req(self) -> bool {
-        fe51_limbs_bounded(self, 51)
+        fe51_limbs_bounded(self, 54)
     }
 
     // Postcondition of neg
@@ -648,7 +661,8 @@ impl Neg for &FieldElement51 {
     fn neg(self) -> (output: FieldElement51)
         ensures
             spec_field_element(&output) == math_field_neg(spec_field_element(self)),
-            forall|i: int| 0 <= i < 5 ==> output.limbs[i] < (1u64 << 52),
+            fe51_limbs_bounded(&output, 52),
+            output.limbs == spec_negate(self.limbs),
     {
         let mut output = *self;
         output.negate();
@@ -793,19 +807,15 @@ impl FieldElement51 {
     ///
     /// # Implementation Note on Limb Bounds
     ///
-    /// The implementation adds 16*p (constants around 2^55) then subtracts and reduces.
-    /// The math shows it can actually handle larger inputs without underflow:
-    /// - For 52-bit limbs (< 2^52): 2^55 - 2^52 = 7*2^52 > 0 ✓ (no underflow)
-    /// - For 54-bit limbs (< 2^54): 2^55 - 2^54 = 2^54 > 0 ✓ (no underflow)
-    ///
-    /// The verified spec conservatively requires 51-bit input, but the implementation
-    /// safely handles up to 54-bit. Wrappers like `negate_field_element` and
-    /// `conditional_negate_field_element` in `subtle_assumes.rs` use relaxed 52-bit bounds.
+    /// The implementation adds 16*p (constants around 2^55) then subtracts and reduces,
+    /// so it handles 54-bit limbs without underflow: 2^55 - 2^54 = 2^54 > 0 ✓.
+    /// This matches the output bound of `add`/`sub` and lets `negate_field_element` and
+    /// `conditional_negate_field_element` run on unreduced inputs.
     pub fn negate(&mut self)
         requires
-            forall|i: int| 0 <= i < 5 ==> old(self).limbs[i] < (1u64 << 51),
+            fe51_limbs_bounded(old(self), 54),
         ensures
-            forall|i: int| 0 <= i < 5 ==> self.limbs[i] < (1u64 << 52),
+            fe51_limbs_bounded(self, 52),
             // Assume we start with l = (l0, l1, l2, l3, l4).
             // Using c0 = 2^51 - 19 and c = 2^51 - 1, we can see that
             // ( 36028797018963664u64 - l0,
@@ -820,7 +830,7 @@ impl FieldElement51 {
             // This gives us the identity
             // u64_5_as_nat(negate(l)) = u64_5_as_nat(reduce(16 * (c0, c, c, c, c) - l))
             //                   = 16p - u64_5_as_nat(l) - p * ((16c - l4) >> 51)
-            // Note that (16c - l4) >> 51 is either 14 or 15, in either case < 16.
+            // Note that (16c - l4) >> 51 is between 7 and 15 for l4 < 2^54, in either case < 16.
             u64_5_as_nat(self.limbs) == 16 * p() - u64_5_as_nat(old(self).limbs) - p() * ((
             36028797018963952u64 - old(self).limbs[4]) as u64 >> 51),
             (u64_5_as_nat(self.limbs) + u64_5_as_nat(old(self).limbs)) % p() == 0,
@@ -1009,6 +1019,10 @@ impl FieldElement51 {
     // Canonical encoding: bytes represent the field element value
 
             bytes32_to_nat(&r) == spec_field_element(&self),
+            // Canonicity: never one of the 19 encodings of values in [p, 2^255)
+            bytes32_to_nat(&r) < p(),
+            // Same bytes as the spec-level encoding
+            seq_from32(&r) == spec_fe51_to_bytes(&self),
     {
         proof {
             // No overflows
@@ -1040,6 +1054,10 @@ impl FieldElement51 {
 
             // Step 4: Prove that packing limbs into bytes preserves the value
             lemma_limbs_to_bytes(final_limbs, s);
+
+            // Step 5: The encoded value is a residue mod p, hence canonical
+            pow255_gt_19();
+            lemma_mod_bound(u64_5_as_nat(self.limbs) as int, p() as int);
         }
 
         // Let h = limbs[0] + limbs[1]*2^51 + ... + limbs[4]*2^204.
@@ -1122,6 +1140,10 @@ impl FieldElement51 {
         #[cfg(not(verus_keep_ghost))]
         debug_assert!((s[31] & 0b1000_0000u8) == 0u8);
 
+        proof {
+            lemma_as_bytes_equals_spec_fe51_to_bytes(&self, &s);
+        }
+
         s
     }
 
@@ -1130,17 +1152,15 @@ impl FieldElement51 {
     pub fn pow2k(&self, mut k: u32) -> (r: FieldElement51)
         requires
             k > 0,  // debug_assert!( k > 0 );
-            forall|i: int|
-                0 <= i < 5 ==> self.limbs[i] < 1u64 << 54  // 51 + b for b = 3
-            ,
+            fe51_limbs_bounded(self, 54),  // 51 + b for b = 3
         ensures/*  VERIFICATION NOTE: spec updated
          - spec needs cleanup
          - proof needs completed: one assume left */
     // Actual bound: 2^51 + 2^13 < 2^52 (from carry propagation in reduction)
 
-            forall|i: int| 0 <= i < 5 ==> r.limbs[i] < 1u64 << 52,
+            fe51_limbs_bounded(&r, 52),
             // 52-bit implies 54-bit (for compatibility with callers)
-            forall|i: int| 0 <= i < 5 ==> r.limbs[i] < 1u64 << 54,
+            fe51_limbs_bounded(&r, 54),
             u64_5_as_nat(r.limbs) % p() == pow(
                 u64_5_as_nat(self.limbs) as int,
                 pow2(k as nat),
@@ -1304,13 +1324,13 @@ impl FieldElement51 {
         requires
     // The precondition in pow2k loop propagates to here
 
-            forall|i: int| 0 <= i < 5 ==> self.limbs[i] < 1u64 << 54,
+            fe51_limbs_bounded(self, 54),
         ensures
     // Actual bound: 2^51 + 2^13 < 2^52 (from carry propagation)
 
-            forall|i: int| 0 <= i < 5 ==> r.limbs[i] < 1u64 << 52,
+            fe51_limbs_bounded(&r, 52),
             // 52-bit implies 54-bit (for compatibility with callers)
-            forall|i: int| 0 <= i < 5 ==> r.limbs[i] < 1u64 << 54,
+            fe51_limbs_bounded(&r, 54),
             u64_5_as_nat(r.limbs) % p() == pow(u64_5_as_nat(self.limbs) as int, 2) as nat % p(),
     {
         proof {
@@ -1325,10 +1345,16 @@ impl FieldElement51 {
         requires
     // The precondition in pow2k loop propagates to here
 
-            forall|i: int| 0 <= i < 5 ==> self.limbs[i] < 1u64 << 54,
+            fe51_limbs_bounded(self, 54),
         ensures
             u64_5_as_nat(r.limbs) % p() == (2 * pow(u64_5_as_nat(self.limbs) as int, 2)) as nat
                 % p(),
+            // Same result at the field level: square2(x) = 2 · square(x)
+            spec_field_element(&r) == math_field_mul(2, math_field_square(spec_field_element(self))),
+            // Doubling the 52-bit output of square adds one bit
+            fe51_limbs_bounded(&r, 53),
+            // 53-bit implies 54-bit (for compatibility with callers)
+            fe51_limbs_bounded(&r, 54),
     {
         let mut square = self.pow2k(1);
 
@@ -1400,8 +1426,114 @@ impl FieldElement51 {
             square.limbs[i] *= 2;
         }
 
+        proof {
+            assert(2 * (1u64 << 52) == (1u64 << 53)) by (compute);
+            lemma_fe51_limbs_bounded_weaken(&square, 53, 54);
+            lemma_square2_matches_math_field_square(
+                u64_5_as_nat(self.limbs),
+                u64_5_as_nat(square.limbs),
+            );
+        }
+
         square
     }
 }
 
 } // verus!
+#[cfg(all(test, feature = "differential"))]
+mod differential_test {
+    use super::*;
+    use crate::specs::exec_specs as exec;
+    use num_bigint::BigUint;
+    use num_traits::One;
+    use proptest::prelude::*;
+
+    // Differential tests: the field operations are run against the BigUint model of their
+    // specs in `exec_specs`, on random inputs and on the limb values where carries and
+    // borrows change.
+
+    /// Limb values next to the carry and borrow edges of the 51-bit radix, up to the
+    /// 54-bit bound of the operation preconditions
+    const EDGE_LIMBS: [u64; 8] = [
+        0,
+        1,
+        (1u64 << 51) - 19,
+        (1u64 << 51) - 1,
+        1u64 << 51,
+        (1u64 << 52) - 1,
+        (1u64 << 53) - 1,
+        (1u64 << 54) - 1,
+    ];
+
+    /// Generate a FieldElement51 with limbs < 2^bit_limit, mixing edge and random limbs
+    fn arb_fe51(bit_limit: u64) -> impl Strategy<Value = FieldElement51> {
+        let mask = (1u64 << bit_limit) - 1;
+        let limb = prop_oneof![
+            prop::sample::select(EDGE_LIMBS.to_vec()).prop_map(move |x| x & mask),
+            0u64..(1u64 << bit_limit),
+        ];
+        prop::array::uniform5(limb).prop_map(|limbs| FieldElement51 { limbs })
+    }
+
+    /// Byte strings just below and above p and 2^255, the top string, and random ones
+    fn arb_bytes32() -> impl Strategy<Value = [u8; 32]> {
+        let mut edges: Vec<[u8; 32]> = vec![[0u8; 32], [0xffu8; 32]];
+        for base in [exec::p(), BigUint::one() << 255] {
+            for k in [0u32, 1, 19] {
+                for v in [&base - k, &base + k] {
+                    let mut bytes = [0u8; 32];
+                    let le = v.to_bytes_le();
+                    bytes[..le.len()].copy_from_slice(&le);
+                    edges.push(bytes);
+                }
+            }
+        }
+        prop_oneof![
+            prop::sample::select(edges),
+            prop::array::uniform32(any::<u8>())
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(proptest::test_runner::Config::with_cases(100000))]
+
+        /// add: limbwise sum, no reduction
+        #[test]
+        fn prop_add(a in arb_fe51(54), b in arb_fe51(54)) {
+            let r = &a + &b;
+            for i in 0..5 {
+                prop_assert_eq!(r.limbs[i], a.limbs[i] + b.limbs[i]);
+            }
+        }
+
+        #[test]
+        fn prop_sub(a in arb_fe51(54), b in arb_fe51(54)) {
+            exec::check_field_sub(&a, &b);
+        }
+
+        #[test]
+        fn prop_mul(a in arb_fe51(54), b in arb_fe51(54)) {
+            exec::check_field_mul(&a, &b);
+        }
+
+        #[test]
+        fn prop_square(a in arb_fe51(54)) {
+            exec::check_field_square(&a);
+        }
+
+        #[test]
+        fn prop_negate(a in arb_fe51(54)) {
+            exec::check_field_neg(&a);
+        }
+
+        #[test]
+        fn prop_bytes_roundtrip(bytes in arb_bytes32()) {
+            exec::check_field_bytes(&bytes);
+        }
+
+        #[test]
+        fn prop_as_bytes(a in arb_fe51(54)) {
+            exec::check_field_as_bytes(&a);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Exhaustive checks at the carry and borrow edges of the limb arithmetic
+//!
+//! The proptests next to the implementations sample random limbs, which almost never
+//! hit the values where a carry or borrow changes. These harnesses instead enumerate
+//! every combination of edge limbs (0, 1, the radix minus one, the precondition bound
+//! minus one, ...) and check each result against the executable specs. They complement
+//! the Verus proofs with bit-level coverage, in particular for `Scalar::as_radix_16`,
+//! whose recentering loop is still a PROOF BYPASS.
+//!
+//! The enumerations take a while in debug builds, so they only run with the
+//! `harnesses` feature:
+//!
+//! ```sh
+//! cargo test --release --features harnesses harnesses
+//! ```
+use super::field::FieldElement51;
+use super::scalar::Scalar52;
+use crate::scalar::Scalar;
+use crate::specs::exec_specs as exec;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+/// Edge limbs for `FieldElement51::sub`: around 0 and 2^51, and the 2^54 bound of its
+/// precondition, where the added multiple of p no longer covers the borrow by much
+const FIELD_SUB_EDGES: [u64; 4] = [0, (1u64 << 51) - 1, 1u64 << 51, (1u64 << 54) - 1];
+
+/// Edge limbs for `Scalar52::sub`: around 0 and the 2^52 radix
+const SCALAR_SUB_EDGES: [u64; 3] = [0, 1, (1u64 << 52) - 1];
+
+/// All arrays of 5 limbs drawn from `edges`
+fn edge_limbs(edges: &[u64]) -> Vec<[u64; 5]> {
+    let mut all = vec![[0u64; 5]];
+    for i in 0..5 {
+        all = all
+            .into_iter()
+            .flat_map(|limbs| {
+                edges.iter().map(move |&e| {
+                    let mut next = limbs;
+                    next[i] = e;
+                    next
+                })
+            })
+            .collect();
+    }
+    all
+}
+
+/// The 52-bit limbs of n < 2^260
+fn scalar52_from_nat(n: &BigUint) -> Scalar52 {
+    let mut limbs = [0u64; 5];
+    let mask = BigUint::from((1u64 << 52) - 1);
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        *limb = ((n >> (52 * i)) & &mask).try_into().unwrap();
+    }
+    Scalar52 { limbs }
+}
+
+#[test]
+fn field_sub_borrow_edges() {
+    let p = exec::p();
+    let all = edge_limbs(&FIELD_SUB_EDGES);
+    for a in &all {
+        let a = FieldElement51 { limbs: *a };
+        let a_nat = exec::u64_5_as_nat(a.limbs);
+        for b in &all {
+            let b = FieldElement51 { limbs: *b };
+            let r = &a - &b;
+            let expected = (&a_nat + &p * 16u32 - exec::u64_5_as_nat(b.limbs)) % &p;
+            assert_eq!(
+                exec::spec_field_element(&r),
+                expected,
+                "{:?} - {:?}",
+                a.limbs,
+                b.limbs
+            );
+            assert!(
+                exec::fe51_limbs_bounded(&r, 52),
+                "{:?} - {:?}",
+                a.limbs,
+                b.limbs
+            );
+        }
+    }
+}
+
+#[test]
+fn scalar52_sub_borrow_edges() {
+    let l = exec::group_order();
+    let mut all: Vec<Scalar52> = edge_limbs(&SCALAR_SUB_EDGES)
+        .into_iter()
+        .map(|limbs| Scalar52 { limbs })
+        .collect();
+    for n in [
+        BigUint::one(),
+        &l - 1u32,
+        l.clone(),
+        &l + 1u32,
+        BigUint::one() << 252,
+    ] {
+        all.push(scalar52_from_nat(&n));
+    }
+    let l_int = BigInt::from(l.clone());
+    for a in &all {
+        let a_int = BigInt::from(exec::scalar52_to_nat(a));
+        for b in &all {
+            let diff = &a_int - BigInt::from(exec::scalar52_to_nat(b));
+            // Precondition: -ℓ <= a - b < ℓ
+            if diff < -&l_int || diff >= l_int {
+                continue;
+            }
+            let s = Scalar52::sub(a, b);
+            let expected = ((diff % &l_int) + &l_int) % &l_int;
+            assert_eq!(
+                BigInt::from(exec::scalar52_to_nat(&s)),
+                expected,
+                "{:?} - {:?}",
+                a.limbs,
+                b.limbs
+            );
+            assert!(exec::limbs_bounded(&s), "{:?} - {:?}", a.limbs, b.limbs);
+        }
+    }
+}
+
+/// Check the postconditions of `as_radix_16` on `bytes`
+fn check_as_radix_16(bytes: [u8; 32]) {
+    let digits = Scalar { bytes }.as_radix_16();
+    // is_valid_radix_16 and radix_16_all_bounded
+    for (i, &d) in digits.iter().enumerate() {
+        if i < 63 {
+            assert!((-8..8).contains(&d), "digit {} = {} for {:?}", i, d, bytes);
+        } else {
+            assert!((-8..=8).contains(&d), "digit {} = {} for {:?}", i, d, bytes);
+        }
+    }
+    // reconstruct_radix_16(digits) == scalar_to_nat(self)
+    let mut value = BigInt::zero();
+    for &d in digits.iter().rev() {
+        value = (value << 4) + BigInt::from(d);
+    }
+    assert_eq!(
+        value,
+        BigInt::from(BigUint::from_bytes_le(&bytes)),
+        "{:?}",
+        bytes
+    );
+}
+
+#[test]
+fn as_radix_16_carry_edges() {
+    // Every byte value at every position, over backgrounds with no carries (0x00, 0x77),
+    // a carry out of every digit (0x88) and all ones; byte 31 keeps bit 255 clear
+    for background in [0x00u8, 0x77, 0x88, 0xff] {
+        for i in 0..32 {
+            for v in 0..=255u8 {
+                let mut bytes = [background; 32];
+                bytes[i] = v;
+                bytes[31] &= 0x7f;
+                check_as_radix_16(bytes);
+            }
+        }
+    }
+}
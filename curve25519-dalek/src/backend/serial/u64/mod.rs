@@ -26,5 +26,8 @@ pub mod constants;
 
 pub mod subtle_assumes;
 
+#[cfg(all(test, feature = "harnesses"))]
+mod harnesses;
+
 // #[cfg(feature = "serde")]
 // pub use crate::serde_assumes;
@@ -104,7 +104,7 @@ impl Zeroize for Scalar52 {
     </VERIFICATION NOTE> */
     fn zeroize(&mut self)
         ensures
-            forall|i: int| 0 <= i < 5 ==> self.limbs[i] == 0,
+            is_zeroized_scalar52(self),
     {
         /* ORIGINAL CODE: self.limbs.zeroize(); */
         crate::core_assumes::zeroize_limbs5(&mut self.limbs);
@@ -1197,8 +1197,13 @@ impl Scalar52 {
             (scalar52_to_nat(&result) * montgomery_radix()) % group_order() == (scalar52_to_nat(
                 self,
             ) * scalar52_to_nat(self)) % group_order(),
+            is_montgomery_square_chain(scalar52_to_nat(&result), scalar52_to_nat(self), 1),
     {
-        Scalar52::montgomery_reduce(&Scalar52::square_internal(self))
+        let result = Scalar52::montgomery_reduce(&Scalar52::square_internal(self));
+        proof {
+            lemma_montgomery_square_chain_one(scalar52_to_nat(&result), scalar52_to_nat(self));
+        }
+        result
     }
 
     /// Puts a Scalar52 in to Montgomery form, i.e. computes `a*R (mod l)`
@@ -1270,55 +1275,10 @@ impl Scalar52 {
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::specs::exec_specs as exec;
     use num_bigint::BigUint;
-    use num_traits::{One, Zero};
     use proptest::prelude::*;
 
-    // Executable versions of spec functions to match the spec as closely as possible
-
-    /// Convert 5 limbs (52-bit each) to a BigUint
-    /// Matches the spec: scalar52_to_nat(&[u64; 5])
-    pub fn to_nat_exec(limbs: &[u64; 5]) -> BigUint {
-        let mut result = BigUint::zero();
-        let radix = BigUint::from(1u128 << 52);
-        for i in (0..5).rev() {
-            result = result * &radix + BigUint::from(limbs[i]);
-        }
-        result
-    }
-
-    /// Convert 9 u128 limbs (52-bit each) to a BigUint
-    /// Matches the spec: slice128_to_nat(&[u128; 9])
-    pub fn slice128_to_nat_exec(limbs: &[u128; 9]) -> BigUint {
-        let mut result = BigUint::zero();
-        let radix = BigUint::from(1u128 << 52);
-        for i in (0..9).rev() {
-            result = result * &radix + BigUint::from(limbs[i]);
-        }
-        result
-    }
-
-    /// The group order L
-    /// Matches the spec: group_order()
-    pub fn group_order_exec() -> BigUint {
-        // L = 2^252 + 27742317777372353535851937790883648493
-        let base = BigUint::one() << 252;
-        let offset = BigUint::parse_bytes(b"27742317777372353535851937790883648493", 10).unwrap();
-        base + offset
-    }
-
-    /// Montgomery radix R = 2^260
-    /// Matches the spec: montgomery_radix()
-    pub fn montgomery_radix_exec() -> BigUint {
-        BigUint::one() << 260
-    }
-
-    /// Check if all limbs are bounded by 2^52
-    /// Matches the spec: limbs_bounded(&Scalar52)
-    pub fn limbs_bounded_exec(s: &Scalar52) -> bool {
-        s.limbs.iter().all(|&limb| limb < (1u64 << 52))
-    }
-
     // Property-based test generators
 
     /// Generate a valid Scalar52 with bounded limbs (each limb < 2^52)
@@ -1331,7 +1291,7 @@ pub mod test {
     fn arb_canonical_scalar52() -> impl Strategy<Value = Scalar52> {
         // Generate random bytes and interpret as BigUint, then reduce mod L
         proptest::collection::vec(any::<u8>(), 32..=64).prop_map(|bytes| {
-            let l = group_order_exec();
+            let l = exec::group_order();
             let value = BigUint::from_bytes_le(&bytes) % &l;
 
             // Convert BigUint to limbs in base 2^52
@@ -1384,10 +1344,10 @@ pub mod test {
 
         let result = Scalar52::montgomery_reduce(&limbs);
 
-        let result_nat = to_nat_exec(&result.limbs);
-        let limbs_nat = slice128_to_nat_exec(&limbs);
-        let l = group_order_exec();
-        let r = montgomery_radix_exec();
+        let result_nat = exec::scalar52_to_nat(&result);
+        let limbs_nat = exec::slice128_to_nat(&limbs);
+        let l = exec::group_order();
+        let r = exec::montgomery_radix();
 
         // The Montgomery property should still hold
         assert_eq!(
@@ -1398,7 +1358,7 @@ pub mod test {
 
         // The result should be limbs_bounded
         assert!(
-            limbs_bounded_exec(&result),
+            exec::limbs_bounded(&result),
             "Result limbs not bounded by 2^52"
         );
 
@@ -1419,7 +1379,7 @@ pub mod test {
         use proptest::test_runner::{Config, TestRunner};
 
         let mut runner = TestRunner::new(Config::default());
-        let l = group_order_exec();
+        let l = exec::group_order();
 
         println!("Testing canonical scalar generator round-trip:");
         for i in 0..10 {
@@ -1429,7 +1389,7 @@ pub mod test {
                 .current();
 
             // Convert to nat
-            let value = to_nat_exec(&scalar.limbs);
+            let value = exec::scalar52_to_nat(&scalar);
 
             // Check it's canonical
             assert!(value < l, "Generated value should be < L");
@@ -1464,7 +1424,7 @@ pub mod test {
             );
 
             // Convert back to nat and verify
-            let value_check = to_nat_exec(&limbs_check);
+            let value_check = exec::scalar52_to_nat(&Scalar52 { limbs: limbs_check });
             assert_eq!(
                 value,
                 value_check,
@@ -1493,17 +1453,6 @@ pub mod test {
             .prop_map(|(a, b)| Scalar52::mul_internal(&a, &b))
     }
 
-    /// Convert a 32-byte array to a BigUint
-    /// Matches the spec: bytes32_to_nat(&[u8; 32])
-    pub fn bytes32_to_nat_exec(bytes: &[u8; 32]) -> BigUint {
-        let mut result = BigUint::zero();
-        let radix = BigUint::from(256u32);
-        for i in (0..32).rev() {
-            result = result * &radix + BigUint::from(bytes[i]);
-        }
-        result
-    }
-
     /// Test case demonstrating that from_bytes does NOT ensure canonicality.
     /// i.e. the postcondition `scalar52_to_nat(&s) < group_order()` may not hold
     ///
@@ -1518,8 +1467,8 @@ pub mod test {
 
         let s = Scalar52::from_bytes(&bytes);
 
-        let result_nat = to_nat_exec(&s.limbs);
-        let l = group_order_exec();
+        let result_nat = exec::scalar52_to_nat(&s);
+        let l = exec::group_order();
 
         // OLD Postcondition 3: scalar52_to_nat(&s) < group_order() - DOES NOT HOLD
         assert!(
@@ -1542,15 +1491,15 @@ pub mod test {
             let s = Scalar52::from_bytes(&bytes);
 
             // Convert to BigUint using executable spec functions
-            let bytes_nat = bytes32_to_nat_exec(&bytes);
-            let result_nat = to_nat_exec(&s.limbs);
+            let bytes_nat = exec::bytes32_to_nat(&bytes);
+            let result_nat = exec::scalar52_to_nat(&s);
 
             // Postcondition 1: bytes32_to_nat(bytes) == scalar52_to_nat(&s)
             prop_assert_eq!(bytes_nat, result_nat,
                 "from_bytes spec violated: bytes32_to_nat(bytes) != scalar52_to_nat(&s)");
 
             // Postcondition 2: limbs_bounded(&s)
-            prop_assert!(limbs_bounded_exec(&s),
+            prop_assert!(exec::limbs_bounded(&s),
                 "from_bytes spec violated: result limbs not bounded by 2^52");
         }
 
@@ -1563,10 +1512,10 @@ pub mod test {
             let result = Scalar52::montgomery_reduce(&limbs);
 
             // Convert to BigUint using executable spec functions
-            let result_nat = to_nat_exec(&result.limbs);
-            let limbs_nat = slice128_to_nat_exec(&limbs);
-            let l = group_order_exec();
-            let r = montgomery_radix_exec();
+            let result_nat = exec::scalar52_to_nat(&result);
+            let limbs_nat = exec::slice128_to_nat(&limbs);
+            let l = exec::group_order();
+            let r = exec::montgomery_radix();
 
             // Postcondition 1: Montgomery property (should hold for product of two bounded)
             let lhs = (&result_nat * &r) % &l;
@@ -1575,7 +1524,7 @@ pub mod test {
                 "Montgomery reduce spec violated: (result * R) mod L != limbs mod L");
 
             // Postcondition 2: limbs_bounded (should hold for product of two bounded)
-            prop_assert!(limbs_bounded_exec(&result),
+            prop_assert!(exec::limbs_bounded(&result),
                 "Result limbs not bounded by 2^52");
 
             // Postcondition 3: Canonicality is NOT guaranteed for product of two bounded scalars
@@ -1590,10 +1539,10 @@ pub mod test {
             let result = Scalar52::montgomery_reduce(&limbs);
 
             // Convert to BigUint using executable spec functions
-            let result_nat = to_nat_exec(&result.limbs);
-            let limbs_nat = slice128_to_nat_exec(&limbs);
-            let l = group_order_exec();
-            let r = montgomery_radix_exec();
+            let result_nat = exec::scalar52_to_nat(&result);
+            let limbs_nat = exec::slice128_to_nat(&limbs);
+            let l = exec::group_order();
+            let r = exec::montgomery_radix();
 
             // Postcondition 1: Montgomery property (holds by first part of spec)
             let lhs = (&result_nat * &r) % &l;
@@ -1602,7 +1551,7 @@ pub mod test {
                 "Montgomery reduce spec violated: (result * R) mod L != limbs mod L");
 
             // Postcondition 2: limbs_bounded (holds by first part of spec)
-            prop_assert!(limbs_bounded_exec(&result),
+            prop_assert!(exec::limbs_bounded(&result),
                 "Result limbs not bounded by 2^52");
 
             // Postcondition 3: Canonicality - SHOULD hold by second part of spec
@@ -1,8 +1,16 @@
-//! Tell Verus what Choice and CtOption do
+//! Tell Verus what Choice and CtOption do, and model the `ConditionallySelectable` and
+//! `ConditionallyNegatable` operations on integers, field elements and points
 use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+use crate::backend::serial::curve_models::{AffineNielsPoint, ProjectiveNielsPoint};
 use crate::backend::serial::u64::field::FieldElement51;
 #[cfg(verus_keep_ghost)]
+use crate::specs::constant_time_specs::{edwards_swap_trace, montgomery_swap_trace, Observation};
+#[cfg(verus_keep_ghost)]
+use crate::specs::edwards_specs::{
+    edwards_point_as_affine, is_valid_edwards_point, negate_affine_niels, negate_projective_niels,
+};
+#[cfg(verus_keep_ghost)]
 use crate::specs::field_specs::{fe51_limbs_bounded, math_field_neg, spec_field_element};
 
 use vstd::prelude::*;
@@ -51,24 +59,121 @@ pub assume_specification[ Choice::unwrap_u8 ](c: &Choice) -> (u: u8)
 // VERIFICATION NOTE: For other external functions, we use wrapper functions because:
 // - Generic functions don't work well with assume_specification
 // - Trait implementations on arrays have issues with assume_specification
+/// Constant-time mask for a Choice on u64: all ones if true, all zeros if false.
+/// Same computation as subtle's `conditional_select` for u64.
+pub fn choice_mask_u64(c: Choice) -> (mask: u64)
+    ensures
+        !choice_is_true(c) ==> mask == 0u64,
+        choice_is_true(c) ==> mask == u64::MAX,
+{
+    let bit = c.unwrap_u8();
+    // if bit = 0, mask = 0x00..0
+    // if bit = 1, mask = 0xff..f
+    let mask = (-(bit as i64)) as u64;
+    proof {
+        assert((-(1i64)) as u64 == u64::MAX) by (compute_only);
+    }
+    mask
+}
+
 /// Wrapper for conditional_select on u64
-#[verifier::external_body]
+///
+/// Verified against subtle's mask arithmetic: `a ^ (mask & (a ^ b))`.
 pub fn select(a: &u64, b: &u64, c: Choice) -> (res: u64)
     ensures
         !choice_is_true(c) ==> res == *a,
         choice_is_true(c) ==> res == *b,
 {
-    u64::conditional_select(a, b, c)
+    let mask = choice_mask_u64(c);
+    let x = *a;
+    let y = *b;
+    proof {
+        assert(x ^ (0u64 & (x ^ y)) == x) by (bit_vector);
+        assert(x ^ (u64::MAX & (x ^ y)) == y) by (bit_vector);
+    }
+    x ^ (mask & (x ^ y))
 }
 
 /// Wrapper for conditional_select on u8
-#[verifier::external_body]
+///
+/// Verified against subtle's mask arithmetic: `a ^ (mask & (a ^ b))`.
 pub fn select_u8(a: &u8, b: &u8, c: Choice) -> (res: u8)
     ensures
         !choice_is_true(c) ==> res == *a,
         choice_is_true(c) ==> res == *b,
 {
-    u8::conditional_select(a, b, c)
+    let mask = choice_mask_u8(c);
+    let x = *a;
+    let y = *b;
+    proof {
+        assert(x ^ (0u8 & (x ^ y)) == x) by (bit_vector);
+        assert(x ^ (u8::MAX & (x ^ y)) == y) by (bit_vector);
+    }
+    x ^ (mask & (x ^ y))
+}
+
+/// Constant-time mask for a Choice on u8: all ones if true, all zeros if false.
+/// Same computation as subtle's `conditional_select` for u8.
+pub fn choice_mask_u8(c: Choice) -> (mask: u8)
+    ensures
+        !choice_is_true(c) ==> mask == 0u8,
+        choice_is_true(c) ==> mask == u8::MAX,
+{
+    let bit = c.unwrap_u8();
+    let mask = (-(bit as i8)) as u8;
+    proof {
+        assert((-(1i8)) as u8 == u8::MAX) by (compute_only);
+    }
+    mask
+}
+
+/// Wrapper for conditional_swap on u8
+///
+/// Verified against subtle's mask arithmetic: `t = mask & (a ^ b); a ^= t; b ^= t`.
+pub fn conditional_swap_u8(a: &mut u8, b: &mut u8, choice: Choice)
+    ensures
+        !choice_is_true(choice) ==> (*a == *old(a) && *b == *old(b)),
+        choice_is_true(choice) ==> (*a == *old(b) && *b == *old(a)),
+{
+    let mask = choice_mask_u8(choice);
+    let x = *a;
+    let y = *b;
+    let t = mask & (x ^ y);
+    proof {
+        assert((x ^ (0u8 & (x ^ y))) == x && (y ^ (0u8 & (x ^ y))) == y) by (bit_vector);
+        assert((x ^ (u8::MAX & (x ^ y))) == y && (y ^ (u8::MAX & (x ^ y))) == x) by (bit_vector);
+    }
+    *a = x ^ t;
+    *b = y ^ t;
+}
+
+/// Wrapper for conditional_assign on u8
+pub fn conditional_assign_u8(a: &mut u8, b: &u8, choice: Choice)
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == *b,
+{
+    *a = select_u8(a, b, choice);
+}
+
+/// Wrapper for conditional_negate on i8
+///
+/// Same body as subtle's provided `conditional_negate` (negate, then select), on the
+/// two's complement bits. Requires x ≠ -128, whose negation overflows.
+pub fn conditional_negate_i8(a: &mut i8, choice: Choice)
+    requires
+        *old(a) != i8::MIN,
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == -*old(a),
+{
+    let x = *a;
+    let neg_x = -x;
+    let selected = select_u8(&(x as u8), &(neg_x as u8), choice);
+    proof {
+        assert(((x as u8) as i8) == x && ((neg_x as u8) as i8) == neg_x) by (bit_vector);
+    }
+    *a = selected as i8;
 }
 
 /// Wrapper for ct_eq on byte arrays
@@ -218,7 +323,6 @@ pub assume_specification[ <u64 as ConditionallySelectable>::conditional_assign ]
 ;
 
 /// Wrapper for conditional_select on u64
-#[verifier::external_body]
 pub fn conditional_select_u64(a: &u64, b: &u64, choice: Choice) -> (res: u64)
     ensures
         !choice_is_true(choice) ==> res == *a,
@@ -228,51 +332,52 @@ pub fn conditional_select_u64(a: &u64, b: &u64, choice: Choice) -> (res: u64)
 }
 
 /// Wrapper for conditional_swap on u64
-#[verifier::external_body]
+///
+/// Verified against subtle's mask arithmetic: `t = mask & (a ^ b); a ^= t; b ^= t`.
 pub fn conditional_swap_u64(a: &mut u64, b: &mut u64, choice: Choice)
     ensures
         !choice_is_true(choice) ==> (*a == *old(a) && *b == *old(b)),
         choice_is_true(choice) ==> (*a == *old(b) && *b == *old(a)),
 {
-    u64::conditional_swap(a, b, choice)
+    let mask = choice_mask_u64(choice);
+    let x = *a;
+    let y = *b;
+    let t = mask & (x ^ y);
+    proof {
+        assert((x ^ (0u64 & (x ^ y))) == x && (y ^ (0u64 & (x ^ y))) == y) by (bit_vector);
+        assert((x ^ (u64::MAX & (x ^ y))) == y && (y ^ (u64::MAX & (x ^ y))) == x)
+            by (bit_vector);
+    }
+    *a = x ^ t;
+    *b = y ^ t;
 }
 
 /// Wrapper for conditional_assign on u64
-#[verifier::external_body]
 pub fn conditional_assign_u64(a: &mut u64, b: &u64, choice: Choice)
     ensures
         !choice_is_true(choice) ==> *a == *old(a),
         choice_is_true(choice) ==> *a == *b,
 {
-    a.conditional_assign(b, choice)
-}
-
-/// Generic wrapper for conditional_negate on types implementing ConditionallyNegatable
-/// Used for: ProjectiveNielsPoint, AffineNielsPoint in window.rs
-/// For FieldElement51 with proper specs, we use conditional_negate_field_element instead.
-#[verifier::external_body]
-pub fn conditional_negate_generic<T>(a: &mut T, choice: Choice) where
-    T: subtle::ConditionallyNegatable,
- {
-    a.conditional_negate(choice);
+    *a = select(a, b, choice);
 }
 
 /// Specialized wrapper for conditional_negate on FieldElement51 with proper specs.
 /// Use this when you need verified limb bounds and functional correctness guarantees.
 ///
-/// Note: The implementation internally uses field negation which calls reduce(),
-/// so 52-bit bounded input is safe (reduce handles up to 64-bit limbs).
-/// The output is always 52-bit bounded from the reduce operation.
-#[verifier::external_body]
+/// Verified against the same body as subtle's provided `conditional_negate`
+/// (negate, then `conditional_assign`), so only the `u64` select model is trusted.
+/// The negation reduces, and a 52-bit input stays 52-bit when not selected.
+/// `negate` accepts 54-bit limbs, so unreduced sums (e.g. from `add`) are accepted too.
 pub fn conditional_negate_field_element(a: &mut FieldElement51, choice: Choice)
     requires
         fe51_limbs_bounded(
             old(a),
-            52,
-        ),  // Relaxed from 51 to 52 to match mul/square output
+            54,
+        ),  // Relaxed from 51 to 54 to match add/sub output
 
     ensures
-        fe51_limbs_bounded(a, 52),
+        fe51_limbs_bounded(old(a), 52) ==> fe51_limbs_bounded(a, 52),
+        fe51_limbs_bounded(a, 54),
         spec_field_element(a) == if choice_is_true(choice) {
             math_field_neg(spec_field_element(old(a)))
         } else {
@@ -282,18 +387,46 @@ pub fn conditional_negate_field_element(a: &mut FieldElement51, choice: Choice)
     a.conditional_negate(choice);
 }
 
-/// Generic wrapper for ConditionallySelectable::conditional_assign()
-#[verifier::external_body]
-pub fn conditional_assign_generic<T>(a: &mut T, b: &T, choice: Choice) where
-    T: subtle::ConditionallySelectable,
- {
-    a.conditional_assign(b, choice)
+/// Wrapper for conditional_negate on ProjectiveNielsPoint
+///
+/// Same body as subtle's provided `conditional_negate` (negate, then
+/// `conditional_assign`), using the verified `Neg` and `ConditionallySelectable`.
+pub fn conditional_negate_projective_niels(a: &mut ProjectiveNielsPoint, choice: Choice)
+    requires
+        fe51_limbs_bounded(&old(a).T2d, 54),
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == negate_projective_niels(*old(a)),
+        choice_is_true(choice) ==> fe51_limbs_bounded(&a.T2d, 52),
+{
+    use core::ops::Neg;
+    let a_neg = Neg::neg(&*a);
+    a.conditional_assign(&a_neg, choice);
+}
+
+/// Wrapper for conditional_negate on AffineNielsPoint
+///
+/// Same body as subtle's provided `conditional_negate` (negate, then
+/// `conditional_assign`), using the verified `Neg` and `ConditionallySelectable`.
+pub fn conditional_negate_affine_niels(a: &mut AffineNielsPoint, choice: Choice)
+    requires
+        fe51_limbs_bounded(&old(a).xy2d, 54),
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == negate_affine_niels(*old(a)),
+        choice_is_true(choice) ==> fe51_limbs_bounded(&a.xy2d, 52),
+{
+    use core::ops::Neg;
+    let a_neg = Neg::neg(&*a);
+    a.conditional_assign(&a_neg, choice);
 }
 
 /*** ConditionallySelectable specification for FieldElement51 ***/
 
 /// Wrapper for conditional_select on FieldElement51
-#[verifier::external_body]
+///
+/// Lifts the limb-wise ensures of `ConditionallySelectable for FieldElement51`
+/// to whole-element equality.
 pub fn conditional_select_field_element(
     a: &FieldElement51,
     b: &FieldElement51,
@@ -303,14 +436,129 @@ pub fn conditional_select_field_element(
         !choice_is_true(choice) ==> result == *a,
         choice_is_true(choice) ==> result == *b,
 {
-    FieldElement51::conditional_select(a, b, choice)
+    let result = FieldElement51::conditional_select(a, b, choice);
+    proof {
+        if choice_is_true(choice) {
+            assert(result.limbs =~= b.limbs);
+        } else {
+            assert(result.limbs =~= a.limbs);
+        }
+    }
+    result
+}
+
+/// Wrapper for conditional_swap on FieldElement51
+pub fn conditional_swap_field_element(a: &mut FieldElement51, b: &mut FieldElement51, choice: Choice)
+    ensures
+        !choice_is_true(choice) ==> (*a == *old(a) && *b == *old(b)),
+        choice_is_true(choice) ==> (*a == *old(b) && *b == *old(a)),
+{
+    FieldElement51::conditional_swap(a, b, choice);
+    proof {
+        if choice_is_true(choice) {
+            assert(a.limbs =~= old(b).limbs);
+            assert(b.limbs =~= old(a).limbs);
+        } else {
+            assert(a.limbs =~= old(a).limbs);
+            assert(b.limbs =~= old(b).limbs);
+        }
+    }
+}
+
+/// Wrapper for conditional_assign on FieldElement51
+pub fn conditional_assign_field_element(a: &mut FieldElement51, b: &FieldElement51, choice: Choice)
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == *b,
+{
+    a.conditional_assign(b, choice);
+    proof {
+        if choice_is_true(choice) {
+            assert(a.limbs =~= b.limbs);
+        } else {
+            assert(a.limbs =~= old(a).limbs);
+        }
+    }
+}
+
+/*** ConditionallySelectable specification for EdwardsPoint ***/
+
+/// Wrapper for conditional_assign on EdwardsPoint
+///
+/// Same body as subtle's provided `conditional_assign`, which assume_specification
+/// cannot reach: `*self = Self::conditional_select(self, other, choice)`.
+pub fn conditional_assign_edwards_point(
+    a: &mut crate::edwards::EdwardsPoint,
+    b: &crate::edwards::EdwardsPoint,
+    choice: Choice,
+)
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        choice_is_true(choice) ==> *a == *b,
+{
+    *a = crate::edwards::EdwardsPoint::conditional_select(a, b, choice);
+}
+
+/// Wrapper for conditional_swap on EdwardsPoint
+///
+/// Same body as subtle's provided `conditional_swap`. Both assignments go through the
+/// masked `FieldElement51` select on every coordinate, so there is no branch or memory
+/// access depending on `choice` (see `constant_time_specs::edwards_swap_trace`).
+pub fn conditional_swap_edwards_point(
+    a: &mut crate::edwards::EdwardsPoint,
+    b: &mut crate::edwards::EdwardsPoint,
+    choice: Choice,
+)
+    ensures
+        !choice_is_true(choice) ==> (*a == *old(a) && *b == *old(b)),
+        choice_is_true(choice) ==> (*a == *old(b) && *b == *old(a)),
+        // Swapping preserves validity and the represented points
+        is_valid_edwards_point(*old(a)) && is_valid_edwards_point(*old(b)) ==> (
+        is_valid_edwards_point(*a) && is_valid_edwards_point(*b)),
+        choice_is_true(choice) ==> (edwards_point_as_affine(*a) == edwards_point_as_affine(*old(b))
+            && edwards_point_as_affine(*b) == edwards_point_as_affine(*old(a))),
+{
+    let ghost trace: Seq<Observation> = Seq::empty();
+    let t = *a;
+    // Constant-time primitives: no observations
+    conditional_assign_edwards_point(a, b, choice);
+    conditional_assign_edwards_point(b, &t, choice);
+    proof {
+        assert(trace == edwards_swap_trace());
+    }
+}
+
+/// Wrapper for conditional_negate on EdwardsPoint
+///
+/// Same body as subtle's provided `conditional_negate` (negate, then
+/// `conditional_assign`), using the verified `Neg`.
+pub fn conditional_negate_edwards_point(a: &mut crate::edwards::EdwardsPoint, choice: Choice)
+    requires
+        fe51_limbs_bounded(&old(a).X, 51),
+        fe51_limbs_bounded(&old(a).T, 51),
+    ensures
+        !choice_is_true(choice) ==> *a == *old(a),
+        is_valid_edwards_point(*old(a)) ==> is_valid_edwards_point(*a),
+        // -P represents (-x, y)
+        choice_is_true(choice) && is_valid_edwards_point(*old(a)) ==> edwards_point_as_affine(*a)
+            == (
+            math_field_neg(edwards_point_as_affine(*old(a)).0),
+            edwards_point_as_affine(*old(a)).1,
+        ),
+{
+    use core::ops::Neg;
+    let a_neg = Neg::neg(&*a);
+    conditional_assign_edwards_point(a, &a_neg, choice);
 }
 
 /*** ConditionallySelectable specification for ProjectivePoint ***/
 
 /// Wrapper for conditional_swap on Montgomery ProjectivePoint
 /// This is needed because assume_specification doesn't work on provided trait methods
-#[verifier::external_body]
+///
+/// Swaps U and W with the masked `FieldElement51` swap, which has the same effect as the
+/// provided `conditional_swap` and involves no branch or memory access depending on `choice`
+/// (see `constant_time_specs::montgomery_swap_trace`).
 pub fn conditional_swap_montgomery_projective(
     a: &mut crate::montgomery::ProjectivePoint,
     b: &mut crate::montgomery::ProjectivePoint,
@@ -332,8 +580,26 @@ pub fn conditional_swap_montgomery_projective(
             &&& b.U == old(a).U
             &&& b.W == old(a).W
         },
+        // As whole points
+        !choice_is_true(choice) ==> (*a == *old(a) && *b == *old(b)),
+        choice_is_true(choice) ==> (*a == *old(b) && *b == *old(a)),
 {
+    /* <ORIGINAL CODE>
     crate::montgomery::ProjectivePoint::conditional_swap(a, b, choice)
+    </ORIGINAL CODE> */
+    let ghost trace: Seq<Observation> = Seq::empty();
+    let mut a_u = a.U;
+    let mut a_w = a.W;
+    let mut b_u = b.U;
+    let mut b_w = b.W;
+    // Constant-time primitives: no observations
+    conditional_swap_field_element(&mut a_u, &mut b_u, choice);
+    conditional_swap_field_element(&mut a_w, &mut b_w, choice);
+    *a = crate::montgomery::ProjectivePoint { U: a_u, W: a_w };
+    *b = crate::montgomery::ProjectivePoint { U: b_u, W: b_w };
+    proof {
+        assert(trace == montgomery_swap_trace());
+    }
 }
 
 } // verus!
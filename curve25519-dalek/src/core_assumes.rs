@@ -4,6 +4,10 @@
 use core::array::TryFromSliceError;
 use core::convert::TryInto;
 
+#[allow(unused_imports)]
+use crate::backend::serial::u64::field::FieldElement51;
+#[allow(unused_imports)]
+use crate::backend::serial::u64::scalar::Scalar52;
 #[allow(unused_imports)]
 use crate::montgomery::MontgomeryPoint;
 #[allow(unused_imports)]
@@ -13,9 +17,13 @@ use crate::specs::field_specs::*;
 #[allow(unused_imports)]
 use crate::specs::scalar52_specs::*;
 #[allow(unused_imports)]
+use crate::specs::scalar_specs::*;
+#[allow(unused_imports)]
 use crate::Scalar;
 use vstd::prelude::*;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "rand_core")]
 use rand_core::RngCore;
 
@@ -149,13 +157,21 @@ pub fn u128_from_le_bytes(bytes: [u8; 16]) -> (x: u128)
     u128::from_le_bytes(bytes)
 }
 
-/// Wrapper for FieldElement negation to avoid Verus internal error
-#[verifier::external_body]
-pub fn negate_field<T>(a: &T) -> (result: T) where for <'a>&'a T: core::ops::Neg<Output = T> {
+/// Wrapper for FieldElement51 negation with specs.
+///
+/// Kept as a named function for callers that negate unreduced (54-bit) values;
+/// it simply forwards to the verified `Neg for &FieldElement51`.
+pub fn negate_field_element(a: &FieldElement51) -> (result: FieldElement51)
+    requires
+        fe51_limbs_bounded(a, 54),
+    ensures
+        spec_field_element(&result) == math_field_neg(spec_field_element(a)),
+        fe51_limbs_bounded(&result, 52),
+{
     -a
 }
 
-// annotations for random values
+// annotations for random values; see `specs::rng_specs` for the model behind them
 pub uninterp spec fn is_random(x: u8) -> bool;
 
 pub uninterp spec fn is_random_bytes(bytes: &[u8]) -> bool;
@@ -164,7 +180,16 @@ pub uninterp spec fn is_random_scalar(scalar: &Scalar) -> bool;
 
 #[cfg(feature = "rand_core")]
 #[verifier::external_body]
-pub fn fill_bytes<R: RngCore>(rng: &mut R, bytes: &mut [u8; 64])
+pub fn fill_bytes<R: RngCore + ?Sized>(rng: &mut R, bytes: &mut [u8; 64])
+    ensures
+        is_random_bytes(bytes),
+{
+    rng.fill_bytes(bytes)
+}
+
+#[cfg(feature = "rand_core")]
+#[verifier::external_body]
+pub fn fill_bytes32<R: RngCore + ?Sized>(rng: &mut R, bytes: &mut [u8; 32])
     ensures
         is_random_bytes(bytes),
 {
@@ -303,6 +328,32 @@ pub fn zeroize_limbs5(limbs: &mut [u64; 5])
     limbs.zeroize();
 }
 
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+// Wrapper for zeroize on the elements of a Vec<Scalar52> (used by Scalar::batch_invert)
+// Every element is overwritten in place with volatile writes; the length is kept
+#[verifier::external_body]
+pub fn zeroize_scalar52_vec(v: &mut Vec<Scalar52>)
+    ensures
+        v.len() == old(v).len(),
+        forall|i: int| 0 <= i < v.len() ==> #[trigger] is_zeroized_scalar52(&v[i]),
+{
+    use zeroize::Zeroize;
+    v.iter_mut().zeroize();
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+// Wrapper for zeroize on the elements of a Vec<Scalar> (used by Scalar::try_batch_invert)
+// Every element is overwritten in place with volatile writes; the length is kept
+#[verifier::external_body]
+pub fn zeroize_scalar_vec(v: &mut Vec<Scalar>)
+    ensures
+        v.len() == old(v).len(),
+        forall|i: int| 0 <= i < v.len() ==> #[trigger] is_zeroized_scalar(&v[i]),
+{
+    use zeroize::Zeroize;
+    v.iter_mut().zeroize();
+}
+
 #[cfg(feature = "zeroize")]
 // Wrapper for zeroize on bool values
 // After zeroizing, the bool should be false
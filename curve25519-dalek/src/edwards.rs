@@ -57,6 +57,10 @@
 //! `EdwardsBasepointTable`, which performs constant-time fixed-base
 //! scalar multiplication;
 //!
+//! * the `*` operator between a `Scalar` and a `FixedBaseTable`, which
+//! does the same for a table built from any point, with a choice of
+//! window width;
+//!
 //! * an implementation of the
 //! [`MultiscalarMul`](../traits/trait.MultiscalarMul.html) trait for
 //! constant-time variable-base multiscalar multiplication;
@@ -136,14 +140,14 @@ use crate::backend::serial::curve_models::ProjectivePoint;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::core_assumes::try_into_32_bytes_array;
 #[cfg(verus_keep_ghost)]
-use vstd::arithmetic::power2::{lemma2_to64, pow2};
+use vstd::arithmetic::power2::{lemma2_to64, lemma_pow2_pos, pow2};
 
 /* VERIFICATION NOTE: Only importing LookupTableRadix16 since other radix variants
 were removed during manual expansion focusing on radix-16. */
 #[cfg(feature = "precomputed-tables")]
 use crate::window::LookupTableRadix16;
 
-#[cfg(feature = "precomputed-tables")]
+#[cfg(any(feature = "precomputed-tables", feature = "alloc"))]
 use crate::traits::BasepointTable;
 
 use crate::traits::ValidityCheck;
@@ -158,16 +162,30 @@ use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 use crate::backend::serial::u64::field::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::backend::serial::u64::subtle_assumes::*;
+#[allow(unused_imports)] // Used in verus! blocks for the addition group law
+use crate::lemmas::edwards_lemmas::add_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for compress proofs
+use crate::lemmas::edwards_lemmas::compress_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks for Edwards curve constants
 use crate::lemmas::edwards_lemmas::constants_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks for decompress proofs
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the model conversions
+use crate::lemmas::edwards_lemmas::curve_models_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for decompress proofs
 use crate::lemmas::edwards_lemmas::decompress_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for scalar multiplication proofs
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks for decompress proofs
 use crate::lemmas::edwards_lemmas::step1_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks for general field constants (ONE, ZERO)
 use crate::lemmas::field_lemmas::constants_lemmas::*;
 #[allow(unused_imports)] // Used in verus! blocks for field algebra lemmas
 use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for sum bounds
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::core_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::edwards_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
@@ -245,22 +263,24 @@ impl CompressedEdwardsY {
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.
     ///
+    /// VERIFICATION NOTE: `lemma_compress_decompress_roundtrip` shows that
+    /// decompressing `P.compress()` gives back the affine point of `P`. The function is
+    /// total: it has no precondition, so its verified body cannot panic or loop on any
+    /// 32-byte input, and it returns either `None` or a well-formed curve point.
     pub fn decompress(&self) -> (result: Option<
         EdwardsPoint,
     >)
-    // The compressed point must have a valid sign bit. This is automatically
-    // satisfied for points produced by `compress()`. For externally-sourced
-    // bytes (e.g., from network input), callers must ensure this invariant.
-    //
-    // See `compressed_y_has_valid_sign_bit` in `edwards_specs.rs` for full justification.
+    // No precondition: the bytes may come from an attacker. Every `Some` result is a
+    // curve point, and `None` is returned exactly when no x fits the encoded y.
 
-        requires
-            compressed_y_has_valid_sign_bit(&self.0),
         ensures
     // Decompression succeeds iff the y-coordinate is valid
 
             math_is_valid_y_coordinate(spec_field_element_from_bytes(&self.0))
                 <==> result.is_some(),
+            // Equivalently, iff some x puts (x, y) on the curve
+            math_is_curve_y_coordinate(spec_field_element_from_bytes(&self.0))
+                <==> result.is_some(),
             // When successful, the result has these properties:
             result.is_some() ==> (
             // The Y coordinate matches the one from the compressed representation
@@ -272,7 +292,14 @@ impl CompressedEdwardsY {
                 result.unwrap(),
             )
             // The X coordinate sign bit matches the sign bit from the compressed representation
-             && spec_field_element_sign_bit(&result.unwrap().X) == (self.0[31] >> 7)),
+            // (a sign bit of 1 on x = 0 cannot be honoured, see `compressed_y_has_valid_sign_bit`)
+             && (compressed_y_has_valid_sign_bit(&self.0) ==> spec_field_element_sign_bit(
+                &result.unwrap().X,
+            ) == (self.0[31] >> 7))
+            // The point is returned in affine form, Z = 1
+             && spec_field_element(&result.unwrap().Z) == 1
+            // Ready for further arithmetic
+             && is_well_formed_edwards_point(result.unwrap())),
     {
         let (is_valid_y_coord, X, Y, Z) = decompress::step_1(self);
 
@@ -307,10 +334,31 @@ impl CompressedEdwardsY {
 
                 // Use the unified lemma to prove all postconditions
                 lemma_decompress_valid_branch(&self.0, x_orig, &point);
+
+                // Limb bounds: X, T from step_2, Y, Z from step_1
+                lemma_fe51_limbs_bounded_weaken(&point.X, 52, 54);
+                lemma_fe51_limbs_bounded_weaken(&point.Y, 51, 54);
+                lemma_fe51_limbs_bounded_weaken(&point.Z, 51, 54);
+                lemma_fe51_limbs_bounded_weaken(&point.T, 52, 54);
+                lemma_fe51_limbs_bounded_weaken(&point.Y, 51, 52);
+                lemma_sum_of_limbs_bounded_from_fe51_bounded(&point.Y, &point.X, 52);
+
+                // The decoded X witnesses that y is a curve y-coordinate
+                let x_final = spec_field_element(&point.X);
+                lemma_mod_bound(spec_field_element_as_nat(&point.X) as int, p() as int);
+                assert(math_on_edwards_curve(x_final, spec_field_element(&point.Y)));
             }
             result
         } else {
             let result = None;
+            proof {
+                // Any curve point (x, y) would make y a valid y-coordinate
+                let y = spec_field_element_from_bytes(&self.0);
+                if math_is_curve_y_coordinate(y) {
+                    let x = choose|x: nat| x < p() && #[trigger] math_on_edwards_curve(x, y);
+                    lemma_on_curve_implies_valid_y(x, y);
+                }
+            }
             result
         }
     }
@@ -504,6 +552,8 @@ mod decompress {
                 spec_field_element(&result.X),
                 spec_field_element(&result.Y),
             ),
+            // The negated X and the product T are 52-bit bounded
+            fe51_limbs_bounded(&result.X, 52) && fe51_limbs_bounded(&result.T, 52),
     {
         // FieldElement::sqrt_ratio_i always returns the nonnegative square root,
         // so we negate according to the supplied sign bit.
@@ -572,6 +622,163 @@ impl TryFrom<&[u8]> for CompressedEdwardsY {
 }
 
 } // verus!
+// ------------------------------------------------------------------------
+// Batch compression and decompression
+// ------------------------------------------------------------------------
+/* VERIFICATION NOTE: not verified yet. Decompression goes through the verified
+`CompressedEdwardsY::decompress`, compression follows `EdwardsPoint::compress` with
+the Z inversions batched. */
+impl EdwardsPoint {
+    /// Compress a batch of points, sharing one field inversion between all of them.
+    ///
+    /// [`EdwardsPoint::compress`] spends most of its time inverting \\(Z\\). Here
+    /// Montgomery's trick replaces the \\(n\\) inversions by one inversion and about
+    /// \\(3n\\) multiplications. The output is the same as compressing each point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let points: Vec<EdwardsPoint> = (1u64..=16)
+    ///     .map(|k| ED25519_BASEPOINT_POINT * Scalar::from(k))
+    ///     .collect();
+    ///
+    /// let compressed = EdwardsPoint::compress_batch(&points);
+    ///
+    /// for (p, c) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*c, p.compress());
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch(points: &[EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+        let mut recips: Vec<FieldElement> = points.iter().map(|p| p.Z).collect();
+        FieldElement::batch_invert(&mut recips[..]);
+
+        points
+            .iter()
+            .zip(recips.iter())
+            .map(|(p, recip)| {
+                let x = &p.X * recip;
+                let y = &p.Y * recip;
+                let mut s = y.as_bytes();
+                s[31] ^= x.is_negative().unwrap_u8() << 7;
+                CompressedEdwardsY(s)
+            })
+            .collect()
+    }
+
+    /// Decompress a batch of points, or return `None` if any of them is not the
+    /// encoding of a curve point.
+    ///
+    /// Decompression has no inversion to amortize: the results are in affine form
+    /// (\\(Z = 1\\)) already, and the cost is one square root per point, which cannot be
+    /// shared between points. So this is as fast as decompressing in a loop, and stops
+    /// at the first invalid encoding. Use [`EdwardsPoint::decompress_iter`] to find out
+    /// which inputs are invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::edwards::EdwardsPoint;
+    /// # use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+    /// let points = [ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_POINT.mul_by_cofactor()];
+    /// let compressed: Vec<_> = points.iter().map(|p| p.compress()).collect();
+    ///
+    /// assert_eq!(EdwardsPoint::decompress_batch(&compressed), Some(points.to_vec()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decompress_batch(compressed: &[CompressedEdwardsY]) -> Option<Vec<EdwardsPoint>> {
+        Self::decompress_iter(compressed).collect()
+    }
+
+    /// Decompress points one at a time, yielding `None` for each input that is not the
+    /// encoding of a curve point.
+    ///
+    /// See [`EdwardsPoint::decompress_batch`].
+    pub fn decompress_iter<'a, I>(compressed: I) -> impl Iterator<Item = Option<EdwardsPoint>> + 'a
+    where
+        I: IntoIterator<Item = &'a CompressedEdwardsY>,
+        I::IntoIter: 'a,
+    {
+        compressed.into_iter().map(CompressedEdwardsY::decompress)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod batch_compression_test {
+    use super::*;
+    use rand::Rng;
+
+    fn assert_matches_compress(points: &[EdwardsPoint]) {
+        let compressed = EdwardsPoint::compress_batch(points);
+        assert_eq!(compressed.len(), points.len());
+        for (p, c) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*c, p.compress());
+        }
+    }
+
+    #[test]
+    fn compress_batch_empty() {
+        assert!(EdwardsPoint::compress_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn compress_batch_identity_and_torsion() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        assert_matches_compress(&[EdwardsPoint::identity()]);
+        assert_matches_compress(&constants::EIGHT_TORSION);
+        assert_matches_compress(&[B, EdwardsPoint::identity(), B + constants::EIGHT_TORSION[1]]);
+    }
+
+    #[test]
+    fn compress_batch_random() {
+        let mut rng = rand::thread_rng();
+        let points: Vec<EdwardsPoint> = (0..100)
+            .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::from_bytes_mod_order(rng.gen()))
+            .collect();
+        assert_matches_compress(&points);
+    }
+
+    /// y = 2 gives a non-square x^2 = (y^2 - 1) / (d y^2 + 1)
+    fn invalid_encoding() -> CompressedEdwardsY {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        CompressedEdwardsY(bytes)
+    }
+
+    #[test]
+    fn decompress_batch_roundtrip() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let points = [
+            B,
+            EdwardsPoint::identity(),
+            B + constants::EIGHT_TORSION[3],
+            B * Scalar::from(7u64),
+        ];
+        let compressed = EdwardsPoint::compress_batch(&points);
+        assert_eq!(
+            EdwardsPoint::decompress_batch(&compressed),
+            Some(points.to_vec())
+        );
+        assert_eq!(EdwardsPoint::decompress_batch(&[]), Some(Vec::new()));
+        assert_eq!(EdwardsPoint::decompress_iter(&[]).count(), 0);
+    }
+
+    #[test]
+    fn decompress_batch_invalid_in_middle() {
+        assert!(invalid_encoding().decompress().is_none());
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let compressed = [B.compress(), invalid_encoding(), (B + B).compress()];
+
+        assert_eq!(EdwardsPoint::decompress_batch(&compressed), None);
+
+        let each: Vec<Option<EdwardsPoint>> = EdwardsPoint::decompress_iter(&compressed).collect();
+        assert_eq!(each, [Some(B), None, Some(B + B)]);
+    }
+}
+
 /* VERIFICATION NOTE: we don't cover serde feature yet */
 // ------------------------------------------------------------------------
 // Serde support
@@ -581,22 +788,17 @@ impl TryFrom<&[u8]> for CompressedEdwardsY {
 // structs containing `EdwardsPoint`s and use Serde's derived
 // serializers to serialize those structures.
 #[cfg(feature = "serde")]
-use serde::de::Visitor;
-#[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+// Human-readable formats get 64 hex digits, binary formats 32 bytes, see `serde_hex`.
+
 #[cfg(feature = "serde")]
 impl Serialize for EdwardsPoint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.compress().as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::serde_hex::serialize(self.compress().as_bytes(), serializer)
     }
 }
 
@@ -606,12 +808,7 @@ impl Serialize for CompressedEdwardsY {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::serde_hex::serialize(self.as_bytes(), serializer)
     }
 }
 
@@ -621,33 +818,9 @@ impl<'de> Deserialize<'de> for EdwardsPoint {
     where
         D: Deserializer<'de>,
     {
-        struct EdwardsPointVisitor;
-
-        impl<'de> Visitor<'de> for EdwardsPointVisitor {
-            type Value = EdwardsPoint;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("a valid point in Edwards y + sign format")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<EdwardsPoint, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                CompressedEdwardsY(bytes)
-                    .decompress()
-                    .ok_or_else(|| serde::de::Error::custom("decompression failed"))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, EdwardsPointVisitor)
+        CompressedEdwardsY(crate::serde_hex::deserialize(deserializer)?)
+            .decompress()
+            .ok_or_else(|| serde::de::Error::custom("decompression failed"))
     }
 }
 
@@ -657,31 +830,9 @@ impl<'de> Deserialize<'de> for CompressedEdwardsY {
     where
         D: Deserializer<'de>,
     {
-        struct CompressedEdwardsYVisitor;
-
-        impl<'de> Visitor<'de> for CompressedEdwardsYVisitor {
-            type Value = CompressedEdwardsY;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("32 bytes of data")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<CompressedEdwardsY, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                Ok(CompressedEdwardsY(bytes))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, CompressedEdwardsYVisitor)
+        Ok(CompressedEdwardsY(crate::serde_hex::deserialize(
+            deserializer,
+        )?))
     }
 }
 
@@ -827,6 +978,7 @@ impl Identity for EdwardsPoint {
         ensures
             is_identity_edwards_point(result),
             is_well_formed_edwards_point(result),
+            edwards_point_as_affine(result) == math_edwards_identity(),
     {
         let result = EdwardsPoint {
             X: FieldElement::ZERO,
@@ -835,19 +987,8 @@ impl Identity for EdwardsPoint {
             T: FieldElement::ZERO,
         };
         proof {
-            // ZERO has limbs [0,0,0,0,0] → spec_field_element = 0
-            // ONE has limbs [1,0,0,0,0] → spec_field_element = 1
-            assume(spec_field_element(&FieldElement::ZERO) == 0);
-            assume(spec_field_element(&FieldElement::ONE) == 1);
-            // is_identity_edwards_point requires: z != 0, x == 0, y == z
-            // With X=ZERO, Y=ONE, Z=ONE: z=1≠0, x=0, y=z=1 ✓
-
-            // is_well_formed_edwards_point requires:
-            // - is_valid_edwards_point (identity is on curve)
-            // - edwards_point_limbs_bounded (all limbs < 2^54)
-            // - edwards_point_sum_bounded (Y + X doesn't overflow)
-            // ZERO/ONE have limbs [0/1, 0, 0, 0, 0] which are trivially bounded
-            assume(is_well_formed_edwards_point(result));
+            lemma_identity_edwards_point_well_formed(result);
+            lemma_identity_edwards_point_affine(result);
         }
         result
     }
@@ -864,6 +1005,7 @@ impl Default for EdwardsPoint {
     fn default() -> (result: EdwardsPoint)
         ensures
             is_identity_edwards_point(result),
+            is_well_formed_edwards_point(result),
     {
         EdwardsPoint::identity()
     }
@@ -931,10 +1073,24 @@ impl ValidityCheck for EdwardsPoint {
 
         let on_segre_image = (&self.X * &self.Y) == (&self.Z * &self.T);
 
-        let result = point_on_curve && on_segre_image;
+        /* <ORIGINAL CODE>
+        point_on_curve && on_segre_image
+        </ORIGINAL CODE> */
+        /* <MODIFIED CODE> The projective curve equation also holds for some points with
+        Z = 0, which `is_valid_edwards_point` excludes, so Z is checked as well */
+        let z_nonzero = !choice_into(self.Z.is_zero());
+        let result = z_nonzero && point_on_curve && on_segre_image;
+        /* </MODIFIED CODE> */
         proof {
-            // postcondition: capturing both point_on_curve and on_segre_image
-            assume(result == is_valid_edwards_point(*self));
+            if z_nonzero {
+                let (x, y, z, t) = spec_edwards_point(*self);
+                p_gt_2();
+                lemma_mod_bound(spec_field_element_as_nat(&self.Z) as int, p() as int);
+                lemma_small_mod(z, p());
+                lemma_mod_bound(spec_field_element_as_nat(&self.T) as int, p() as int);
+                lemma_projective_curve_equation_iff_affine(x, y, z);
+                lemma_segre_relation_iff_extended(x, y, z, t);
+            }
         }
         result
     }
@@ -953,25 +1109,21 @@ impl ConditionallySelectable for EdwardsPoint {
             // If choice is true (1), return b
             choice_is_true(choice) ==> result == *b,
     {
-        let result = EdwardsPoint {
+        /* <ORIGINAL CODE>
+        EdwardsPoint {
             X: FieldElement::conditional_select(&a.X, &b.X, choice),
             Y: FieldElement::conditional_select(&a.Y, &b.Y, choice),
             Z: FieldElement::conditional_select(&a.Z, &b.Z, choice),
             T: FieldElement::conditional_select(&a.T, &b.T, choice),
-        };
-
-        proof {
-            // When all limbs of all fields match, the structs should be equal by extensionality
-            // However, Verus requires explicit extensionality axioms for struct equality
-            // To prove this without assumes would require:
-            // 1. Lemma: FieldElement equality from limb equality (extensionality for FieldElement)
-            // 2. Lemma: EdwardsPoint equality from field equality (extensionality for EdwardsPoint)
-            // For now, we assume the postcondition as it's straightforward from the field-level specs
-            assume(!choice_is_true(choice) ==> result == *a);
-            assume(choice_is_true(choice) ==> result == *b);
         }
-
-        result
+        </ORIGINAL CODE> */
+        // The wrapper lifts the limb-wise ensures to whole-element equality
+        EdwardsPoint {
+            X: conditional_select_field_element(&a.X, &b.X, choice),
+            Y: conditional_select_field_element(&a.Y, &b.Y, choice),
+            Z: conditional_select_field_element(&a.Z, &b.Z, choice),
+            T: conditional_select_field_element(&a.T, &b.T, choice),
+        }
     }
 }
 
@@ -1097,6 +1249,7 @@ impl EdwardsPoint {
             edwards_point_sum_bounded(*self),
         ensures
             projective_niels_corresponds_to_edwards(result, *self),
+            projective_niels_point_as_affine_edwards(result) == edwards_point_as_affine(*self),
             fe51_limbs_bounded(&result.Y_plus_X, 54),
             fe51_limbs_bounded(&result.Y_minus_X, 54),
             fe51_limbs_bounded(&result.Z, 54),
@@ -1114,8 +1267,16 @@ impl EdwardsPoint {
         };
 
         proof {
+            // T2d = T·(2d) = (2d)·T
+            lemma_edwards_d2_value();
+            lemma_field_mul_comm(
+                spec_field_element(&self.T),
+                spec_field_element(&constants::EDWARDS_D2),
+            );
+            assert(projective_niels_corresponds_to_edwards(result, *self));
+            lemma_projective_niels_affine(result, *self);
+
             // postconditions:
-            assume(projective_niels_corresponds_to_edwards(result, *self));
             assume(fe51_limbs_bounded(&result.Y_plus_X, 54));
             assume(fe51_limbs_bounded(&result.Y_minus_X, 54));
             assume(fe51_limbs_bounded(&result.Z, 54));
@@ -1136,6 +1297,7 @@ impl EdwardsPoint {
             result.X == self.X,
             result.Y == self.Y,
             result.Z == self.Z,
+            projective_point_as_affine_edwards(result) == edwards_point_as_affine(*self),
             fe51_limbs_bounded(&result.X, 54) && fe51_limbs_bounded(&result.Y, 54)
                 && fe51_limbs_bounded(&result.Z, 54),
     {
@@ -1150,6 +1312,10 @@ impl EdwardsPoint {
             edwards_point_limbs_bounded(*self),
         ensures
             affine_niels_corresponds_to_edwards(result, *self),
+            affine_niels_point_as_affine_edwards(result) == edwards_point_as_affine(*self),
+            fe51_limbs_bounded(&result.y_plus_x, 54),
+            fe51_limbs_bounded(&result.y_minus_x, 54),
+            fe51_limbs_bounded(&result.xy2d, 54),
     {
         let recip = self.Z.invert();
         // recip bounded by 54 from invert() postcondition
@@ -1168,14 +1334,25 @@ impl EdwardsPoint {
         let xy2d = &xy * &constants::EDWARDS_D2;
 
         proof {
-            assume(sum_of_limbs_bounded(&y, &x, u64::MAX));  // for y_plus_x
-            assume(fe51_limbs_bounded(&y, 54) && fe51_limbs_bounded(&x, 54));  // for y_minus_x
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&y, &x, 54);  // for y_plus_x
         }
 
         let result = AffineNielsPoint { y_plus_x: &y + &x, y_minus_x: &y - &x, xy2d };
 
         proof {
-            assume(affine_niels_corresponds_to_edwards(result, *self));
+            // xy2d = xy·(2d) = (xy·2)·d
+            lemma_edwards_d2_value();
+            lemma_field_mul_assoc(
+                spec_field_element(&xy),
+                2,
+                spec_field_element(&constants::EDWARDS_D),
+            );
+            assert(affine_niels_corresponds_to_edwards(result, *self));
+            lemma_affine_niels_affine(result, *self);
+
+            // y + x of two 52-bit products is 53-bit bounded
+            lemma_add_fe51_limbs_bounded_52(&y, &x);
+            lemma_fe51_limbs_bounded_weaken(&result.y_plus_x, 53, 54);
         }
 
         result
@@ -1192,12 +1369,20 @@ impl EdwardsPoint {
     /// model does not retain sign information.
     pub fn to_montgomery(&self) -> (result: MontgomeryPoint)
         requires
+            // Z != 0, so that y = Y/Z is the affine y-coordinate
+            is_valid_edwards_point(*self),
             fe51_limbs_bounded(&self.X, 54),
             // Y and Z need 51-bit bounds so U = Z + Y is 52-bit bounded (< 54 for mul)
             fe51_limbs_bounded(&self.Y, 51) && fe51_limbs_bounded(&self.Z, 51),
             sum_of_limbs_bounded(&self.Z, &self.Y, u64::MAX),
         ensures
             montgomery_corresponds_to_edwards(result, *self),
+            // u = (1+y)/(1-y), with the identity (y = 1) sent to u = 0
+            spec_montgomery_point(result) == montgomery_u_from_edwards_y(
+                edwards_point_as_affine(*self).1,
+            ),
+            // The u-coordinate is canonically encoded
+            bytes32_to_nat(&result.0) < p(),
     {
         // We have u = (1+y)/(1-y) = (Z+Y)/(Z-Y).
         //
@@ -1222,7 +1407,13 @@ impl EdwardsPoint {
         let u = &U * &W.invert();
         let result = MontgomeryPoint(u.as_bytes());
         proof {
-            assume(montgomery_corresponds_to_edwards(result, *self));
+            // (Z + Y)/(Z - Y) = (1 + y)/(1 - y), and 0 when Z - Y = 0
+            lemma_edwards_to_montgomery_u(*self);
+            // The encoding is canonical, so decoding it gives u back
+            let u_bytes = bytes32_to_nat(&result.0);
+            assert(u_bytes < pow2(255));
+            lemma_small_mod(u_bytes, pow2(255));
+            lemma_small_mod(u_bytes, p());
         }
         result
     }
@@ -1233,17 +1424,38 @@ impl EdwardsPoint {
             edwards_point_limbs_bounded(*self),
         ensures
             compressed_edwards_y_corresponds_to_edwards(result, *self),
+            compressed_y_encodes(
+                &result.0,
+                edwards_point_as_affine(*self).0,
+                edwards_point_as_affine(*self).1,
+            ),
     {
         let recip = self.Z.invert();
         let ghost z_abs = spec_field_element(&self.Z);
         assert(spec_field_element(&recip) == math_field_inv(z_abs));
-        assume(false);
         let x = &self.X * &recip;
         let y = &self.Y * &recip;
         let mut s: [u8; 32];
 
         s = y.as_bytes();
+        let ghost s_y = s;
         s[31] ^= x.is_negative().unwrap_u8() << 7;
+
+        proof {
+            let x_abs = spec_field_element(&x);
+            let y_abs = spec_field_element(&y);
+            assert((x_abs, y_abs) == edwards_point_as_affine(*self));
+
+            // is_negative is the low bit of x
+            let sign = ((x_abs % p()) % 2) as u8;
+            assert(s[31] == s_y[31] ^ (sign << 7u8));
+
+            // y.as_bytes() is canonical, so bit 255 is free for the sign
+            lemma_set_sign_bit(&s_y, &s, sign);
+            p_gt_2();
+            lemma_mod_bound(spec_field_element_as_nat(&y) as int, p() as int);
+            lemma_small_mod(y_abs, p());
+        }
         CompressedEdwardsY(s)
     }
 
@@ -1278,6 +1490,53 @@ impl EdwardsPoint {
             "Montgomery conversion to Edwards point in Elligator failed",
         ).mul_by_cofactor()
     }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the curve with the suite `edwards25519_XMD:SHA-512_ELL2_RO_` of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+    ///
+    /// The output is uniformly distributed in the prime-order subgroup (the "random
+    /// oracle" encoding). `dst` is the domain separation tag, unique to the application
+    /// and the protocol; see §3.1 of the RFC. Tags longer than 255 bytes are hashed first.
+    ///
+    /// # Panics
+    ///
+    /// If `dst` is empty.
+    #[verifier::external_body]
+    pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> EdwardsPoint {
+        use crate::hash_to_curve::*;
+
+        let mut uniform = [0u8; 2 * FIELD_ELEMENT_BYTES];
+        expand_message_xmd(msg, dst, &mut uniform);
+        let (u0, u1) = uniform.split_at(FIELD_ELEMENT_BYTES);
+        let r0 = field_element_from_uniform_bytes(u0.try_into().unwrap());
+        let r1 = field_element_from_uniform_bytes(u1.try_into().unwrap());
+        let q0 = map_to_curve_elligator2(&r0);
+        let q1 = map_to_curve_elligator2(&r1);
+        (&q0 + &q1).mul_by_cofactor()
+    }
+
+    #[cfg(feature = "digest")]
+    /// Encode a message to the curve with the suite `edwards25519_XMD:SHA-512_ELL2_NU_` of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+    ///
+    /// This "nonuniform" encoding is about twice as fast as
+    /// [`hash_to_curve`](EdwardsPoint::hash_to_curve), but its output only covers about
+    /// half of the prime-order subgroup and is distinguishable from uniform. Use it only
+    /// where the protocol allows it.
+    ///
+    /// # Panics
+    ///
+    /// If `dst` is empty.
+    #[verifier::external_body]
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> EdwardsPoint {
+        use crate::hash_to_curve::*;
+
+        let mut uniform = [0u8; FIELD_ELEMENT_BYTES];
+        expand_message_xmd(msg, dst, &mut uniform);
+        let r = field_element_from_uniform_bytes(&uniform);
+        map_to_curve_elligator2(&r).mul_by_cofactor()
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -1291,6 +1550,7 @@ impl EdwardsPoint {
             edwards_point_limbs_bounded(*self),
         ensures
             is_valid_edwards_point(result),  // result is also a valid Edwards point
+            is_well_formed_edwards_point(result),
             // Result equals the affine doubling of the input.
             edwards_point_as_affine(result) == edwards_double(
                 edwards_point_as_affine(*self).0,
@@ -1404,12 +1664,18 @@ impl<'a, 'b> Add<&'b EdwardsPoint> for &'a EdwardsPoint {
 
         proof {
             // CompletedPoint::as_extended ensures is_well_formed_edwards_point(result)
-            // Assume affine semantics postcondition
-            assume({
-                let (x1, y1) = edwards_point_as_affine(*self);
-                let (x2, y2) = edwards_point_as_affine(*other);
-                edwards_point_as_affine(result) == edwards_add(x1, y1, x2, y2)
-            });
+            // and edwards_point_as_affine(result) == completed_point_as_affine_edwards(sum).
+            // The sum's coordinates are the Niels formula, which computes the group law.
+            let (x1, y1, z1, t1) = spec_edwards_point(*self);
+            let (x2, y2, z2, t2) = spec_edwards_point(*other);
+            p_gt_2();
+            lemma_mod_bound(spec_field_element_as_nat(&self.X) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&self.Y) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&self.Z) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&other.X) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&other.Y) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&other.Z) as int, p() as int);
+            lemma_edwards_add_extended_formula(x1, y1, z1, t1, x2, y2, z2, t2);
         }
 
         result
@@ -1657,12 +1923,25 @@ impl vstd::std_specs::ops::NegSpecImpl for &EdwardsPoint {
 impl<'a> Neg for &'a EdwardsPoint {
     type Output = EdwardsPoint;
 
-    fn neg(
-        self,
-    ) -> EdwardsPoint/* requires clause in NegSpecImpl for &EdwardsPoint above:
+    fn neg(self) -> (result:
+        EdwardsPoint)/* requires clause in NegSpecImpl for &EdwardsPoint above:
            requires fe51_limbs_bounded(&self.X, 51) && fe51_limbs_bounded(&self.T, 51)
         */
-     {
+
+        ensures
+            spec_field_element(&result.X) == math_field_neg(spec_field_element(&self.X)),
+            result.Y == self.Y,
+            result.Z == self.Z,
+            spec_field_element(&result.T) == math_field_neg(spec_field_element(&self.T)),
+            fe51_limbs_bounded(&result.X, 52),
+            fe51_limbs_bounded(&result.T, 52),
+            // -P represents (-x, y), the inverse of P = (x, y)
+            is_valid_edwards_point(*self) ==> is_valid_edwards_point(result),
+            is_valid_edwards_point(*self) ==> edwards_point_as_affine(result) == (
+                math_field_neg(edwards_point_as_affine(*self).0),
+                edwards_point_as_affine(*self).1,
+            ),
+    {
         /* ORIGINAL CODE
         EdwardsPoint {
             X: -(&self.X),
@@ -1674,7 +1953,18 @@ impl<'a> Neg for &'a EdwardsPoint {
         // REFACTORED: Use explicit Neg::neg() calls instead of operator shortcuts
         // to avoid Verus panic
         use core::ops::Neg;
-        EdwardsPoint { X: Neg::neg(&self.X), Y: self.Y, Z: self.Z, T: Neg::neg(&self.T) }
+        let result = EdwardsPoint {
+            X: Neg::neg(&self.X),
+            Y: self.Y,
+            Z: self.Z,
+            T: Neg::neg(&self.T),
+        };
+        proof {
+            if is_valid_edwards_point(*self) {
+                lemma_negate_edwards_point(*self, result);
+            }
+        }
+        result
     }
 }
 
@@ -1700,12 +1990,24 @@ impl vstd::std_specs::ops::NegSpecImpl for EdwardsPoint {
 impl Neg for EdwardsPoint {
     type Output = EdwardsPoint;
 
-    fn neg(
-        self,
-    ) -> EdwardsPoint/* requires clause in NegSpecImpl for EdwardsPoint above:
+    fn neg(self) -> (result:
+        EdwardsPoint)/* requires clause in NegSpecImpl for EdwardsPoint above:
             requires fe51_limbs_bounded(&self.X, 51) && fe51_limbs_bounded(&self.T, 51)
         */
-     {
+
+        ensures
+            spec_field_element(&result.X) == math_field_neg(spec_field_element(&self.X)),
+            result.Y == self.Y,
+            result.Z == self.Z,
+            spec_field_element(&result.T) == math_field_neg(spec_field_element(&self.T)),
+            fe51_limbs_bounded(&result.X, 52),
+            fe51_limbs_bounded(&result.T, 52),
+            is_valid_edwards_point(self) ==> is_valid_edwards_point(result),
+            is_valid_edwards_point(self) ==> edwards_point_as_affine(result) == (
+                math_field_neg(edwards_point_as_affine(self).0),
+                edwards_point_as_affine(self).1,
+            ),
+    {
         /* ORIGINAL CODE
         -&self
         */
@@ -1727,7 +2029,7 @@ impl<'b> MulAssign<&'b Scalar> for EdwardsPoint {
             is_well_formed_edwards_point(*self),
             edwards_point_as_affine(*self) == edwards_scalar_mul(
                 edwards_point_as_affine(*old(self)),
-                spec_scalar(scalar),
+                scalar_to_nat(scalar),
             ),
     {
         /* ORIGINAL CODE
@@ -1763,7 +2065,7 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a EdwardsPoint {
             is_well_formed_edwards_point(result),
             edwards_point_as_affine(result) == edwards_scalar_mul(
                 edwards_point_as_affine(*self),
-                spec_scalar(scalar),
+                scalar_to_nat(scalar),
             ),
     {
         crate::backend::variable_base_mul(self, scalar)
@@ -1786,7 +2088,7 @@ impl<'a, 'b> Mul<&'b EdwardsPoint> for &'a Scalar {
             is_well_formed_edwards_point(result),
             edwards_point_as_affine(result) == edwards_scalar_mul(
                 edwards_point_as_affine(*point),
-                spec_scalar(self),
+                scalar_to_nat(self),
             ),
     {
         point * self
@@ -1810,9 +2112,23 @@ impl EdwardsPoint {
             ),
     {
         #[cfg(not(feature = "precomputed-tables"))]
-        { scalar * constants::ED25519_BASEPOINT_POINT }
+        {
+            proof {
+                // B is well-formed, and [n]B = [n mod ℓ]B
+                lemma_ed25519_basepoint_well_formed();
+                axiom_ed25519_basepoint_order();
+                lemma_edwards_scalar_mul_mod_order(spec_ed25519_basepoint(), scalar_to_nat(scalar));
+            }
+            /* ORIGINAL CODE: scalar * constants::ED25519_BASEPOINT_POINT */
+            scalar * &constants::ED25519_BASEPOINT_POINT
+        }
         #[cfg(feature = "precomputed-tables")]
-        { scalar * constants::ED25519_BASEPOINT_TABLE }
+        {
+            proof {
+                axiom_ed25519_basepoint_table_valid();
+            }
+            scalar * constants::ED25519_BASEPOINT_TABLE
+        }
     }
 
     /// Multiply this point by `clamp_integer(bytes)`. For a description of clamping, see
@@ -1822,10 +2138,11 @@ impl EdwardsPoint {
             is_well_formed_edwards_point(self),
         ensures
             is_well_formed_edwards_point(result),
-            // Result is scalar multiplication of self by the clamped scalar
+            // VERIFICATION NOTE: Result is [n]self where n is the clamped integer value, which
+            // is not reduced modulo the group order (see `spec_clamp_integer`)
             edwards_point_as_affine(result) == edwards_scalar_mul(
                 edwards_point_as_affine(self),
-                spec_scalar(&Scalar { bytes: spec_clamp_integer(bytes) }),
+                bytes32_to_nat(&spec_clamp_integer(bytes)),
             ),
     {
         // We have to construct a Scalar that is not reduced mod l, which breaks scalar invariant
@@ -1836,14 +2153,15 @@ impl EdwardsPoint {
         // issues arising from the fact that the curve point is not necessarily in the prime-order
         // subgroup.
         let s = Scalar { bytes: clamp_integer(bytes) };
-        let result = s * self;
         proof {
-            assume(is_well_formed_edwards_point(result));
-            assume(edwards_point_as_affine(result) == edwards_scalar_mul(
-                edwards_point_as_affine(self),
-                spec_scalar(&Scalar { bytes: spec_clamp_integer(bytes) }),
-            ));
+            // Clamping clears bit 255: invariant #1 is all that `Scalar * EdwardsPoint` requires
+            let b31 = s.bytes[31];
+            assert(b31 & 0b1000_0000 == 0 ==> b31 <= 127) by (bit_vector);
+            assert(scalar_high_bit_clear(&s));
         }
+        /* ORIGINAL CODE: let result = s * self; */
+        // The owned-operand Mul variants carry no ensures, so call the reference one
+        let result = &s * &self;
         result
     }
 
@@ -1852,17 +2170,29 @@ impl EdwardsPoint {
     pub fn mul_base_clamped(bytes: [u8; 32]) -> (result: Self)
         ensures
             is_well_formed_edwards_point(result),
-            // Functional correctness: result = [clamped_scalar] * B where B is the basepoint
+            // Functional correctness: result = [clamped_integer] * B where B is the basepoint;
+            // the clamped integer is not reduced modulo the group order (see `spec_clamp_integer`)
             edwards_point_as_affine(result) == edwards_scalar_mul(
                 spec_ed25519_basepoint(),
-                spec_scalar(&Scalar { bytes: spec_clamp_integer(bytes) }),
+                bytes32_to_nat(&spec_clamp_integer(bytes)),
             ),
     {
         // See reasoning in Self::mul_clamped why it is OK to make an unreduced Scalar here. We
         // note that fixed-base multiplication is also defined for all values of `bytes` less than
         // 2^255.
         let s = Scalar { bytes: clamp_integer(bytes) };
-        Self::mul_base(&s)
+        proof {
+            let b31 = s.bytes[31];
+            assert(b31 & 0b1000_0000 == 0 ==> b31 <= 127) by (bit_vector);
+            assert(scalar_high_bit_clear(&s));
+        }
+        let result = Self::mul_base(&s);
+        proof {
+            // mul_base computes [n mod ℓ]B, which is [n]B because B has order ℓ
+            axiom_ed25519_basepoint_order();
+            lemma_edwards_scalar_mul_mod_order(spec_ed25519_basepoint(), scalar_to_nat(&s));
+        }
+        result
     }
 }
 
@@ -1910,7 +2240,10 @@ impl MultiscalarMul for EdwardsPoint {
         // size-dependent algorithm dispatch, use this as the hint.
         let _size = s_lo;
 
-        crate::backend::straus_multiscalar_mul(scalars, points)
+        let result = crate::backend::straus_multiscalar_mul(scalars, points);
+        // Unverified entry point: check the invariant the verified operations ensure
+        debug_assert!(result.is_valid());
+        result
     }
 }
 
@@ -1952,11 +2285,14 @@ impl VartimeMultiscalarMul for EdwardsPoint {
         // Use this as the hint to decide which algorithm to use.
         let size = s_lo;
 
-        if size < 190 {
+        let result = if size < 190 {
             crate::backend::straus_optional_multiscalar_mul(scalars, points)
         } else {
             crate::backend::pippenger_optional_multiscalar_mul(scalars, points)
-        }
+        };
+        // Unverified entry point: check the invariant the verified operations ensure
+        debug_assert!(result.map_or(true, |P| P.is_valid()));
+        result
     }
 }
 
@@ -1992,8 +2328,12 @@ impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
         J::Item: Borrow<Scalar>,
         K: IntoIterator<Item = Option<Self::Point>>,
     {
-        self.0
-            .optional_mixed_multiscalar_mul(static_scalars, dynamic_scalars, dynamic_points)
+        let result =
+            self.0
+                .optional_mixed_multiscalar_mul(static_scalars, dynamic_scalars, dynamic_points);
+        // Unverified entry point: check the invariant the verified operations ensure
+        debug_assert!(result.map_or(true, |P| P.is_valid()));
+        result
     }
 }
 
@@ -2156,6 +2496,10 @@ impl EdwardsPoint {
                     ==> is_well_formed_edwards_point(
                     #[trigger] spec_points_from_iter::<P, J>(points)[i],
                 ),
+            // Bit 255 of each scalar is clear (Scalar invariant #1)
+            forall|i: int|
+                0 <= i < spec_scalars_from_iter::<S, I>(scalars).len() ==> (
+                #[trigger] spec_scalars_from_iter::<S, I>(scalars)[i]).bytes[31] <= 127,
         ensures
     // Result is a well-formed Edwards point
 
@@ -2312,14 +2656,15 @@ impl BasepointTable for EdwardsBasepointTable {
         */
         /* REFACTORED FOR ASSERTIONS: */
         let identity = EdwardsPoint::identity();
+        proof {
+            // Precondition for select (trait fns cannot carry a table-validity requires)
+            assume(crate::specs::window_specs::lookup_table_affine_limbs_bounded(self.0[0].0));
+        }
         let selected = self.0[0].select(1);
         proof {
             // Preconditions for addition
             assume(is_well_formed_edwards_point(identity));
             assume(sum_of_limbs_bounded(&identity.Z, &identity.Z, u64::MAX));
-            assume(fe51_limbs_bounded(&selected.y_plus_x, 54));
-            assume(fe51_limbs_bounded(&selected.y_minus_x, 54));
-            assume(fe51_limbs_bounded(&selected.xy2d, 54));
         }
         let completed = &identity + &selected;
         proof {
@@ -2382,6 +2727,8 @@ impl BasepointTable for EdwardsBasepointTable {
     fn mul_base(&self, scalar: &Scalar) -> (result: EdwardsPoint)
         requires
             scalar.bytes[31] <= 127,
+            // table i holds [1·256^i·B, ..., 8·256^i·B]
+            is_valid_edwards_basepoint_table(*self, spec_ed25519_basepoint()),
         ensures
             is_well_formed_edwards_point(result),
             // Functional correctness: result = [scalar] * B
@@ -2395,26 +2742,46 @@ impl BasepointTable for EdwardsBasepointTable {
         let tables = &self.0;
         let mut P = EdwardsPoint::identity();
 
+        let ghost B = spec_ed25519_basepoint();
+        proof {
+            axiom_ed25519_basepoint_order();
+            lemma_identity_edwards_point_affine(P);
+        }
+
         // ORIGINAL CODE (doesn't work with Verus - .filter() not supported in ghost for loops):
         // for i in (0..64).filter(|x| x % 2 == 1) {
         //     P = (&P + &tables[i / 2].select(a[i])).as_extended();
         // }
-        for i in 0..64 {
+        for i in 0..64
+            invariant
+                tables == &self.0,
+                is_valid_edwards_basepoint_table(*self, B),
+                is_valid_radix_2w(&a, 4, 64),
+                B == spec_ed25519_basepoint(),
+                math_on_edwards_curve(B.0, B.1),
+                is_well_formed_edwards_point(P),
+                // P = [d_1 + d_3·16² + ... ]B over the odd positions below i
+                edwards_point_as_affine(P) == edwards_scalar_mul_signed(
+                    B,
+                    radix_16_parity_sum(a@, 1, i as nat),
+                ),
+        {
             if i % 2 == 1 {
                 // ORIGINAL CODE: need to add intermediate variables for pre and post conditions
                 //     P = (&P + &tables[i / 2].select(a[i])).as_extended();
                 proof {
-                    // preconditions for select and arithmetic operations
-                    assume(a[i as int] >= -8 && a[i as int] <= 8);
+                    // digit bounds for select, from as_radix_2w (2^3 = 8)
+                    lemma2_to64();
+                    assert(-8 <= a[i as int] && a[i as int] <= 8);
+                    // table limb bounds for select, from the table invariant
+                    assert(crate::specs::window_specs::lookup_table_affine_limbs_bounded(
+                        self.0[(i / 2) as int].0,
+                    ));
                 }
                 let selected = tables[i / 2].select(a[i]);
                 proof {
                     // preconditions for addition
-                    assume(is_well_formed_edwards_point(P));
                     assume(sum_of_limbs_bounded(&P.Z, &P.Z, u64::MAX));  // extra bound for Z2 = &P.Z + &P.Z in add
-                    assume(fe51_limbs_bounded(&selected.y_plus_x, 54));
-                    assume(fe51_limbs_bounded(&selected.y_minus_x, 54));
-                    assume(fe51_limbs_bounded(&selected.xy2d, 54));
                 }
                 let tmp = &P + &selected;
                 proof {
@@ -2425,32 +2792,67 @@ impl BasepointTable for EdwardsBasepointTable {
                     assume(fe51_limbs_bounded(&tmp.T, 54));
                 }
                 P = tmp.as_extended();
+                proof {
+                    // selected = [d_i]([256^(i/2)]B) = [d_i·256^(i/2)]B
+                    let k = (i / 2) as nat;
+                    lemma_select_affine_niels_is_signed_multiple(
+                        tables[k as int].0,
+                        edwards_scalar_mul(B, pow256(k)),
+                        a[i as int],
+                        selected,
+                    );
+                    lemma_pow2_pos(8 * k);
+                    lemma_edwards_scalar_mul_signed_of_multiple(B, pow256(k), a[i as int] as int);
+                    lemma_edwards_scalar_mul_signed_additive(
+                        B,
+                        radix_16_parity_sum(a@, 1, i as nat),
+                        a[i as int] * pow256(k),
+                    );
+                }
             }
         }
 
+        let ghost odd_sum = radix_16_parity_sum(a@, 1, 64);
+        // P = [2^4]P, so P = [16·odd_sum]B
+        P = P.mul_by_pow_2(4);
         proof {
-            assume(edwards_point_limbs_bounded(P));
-            assume(fe51_limbs_bounded(&P.T, 54));
+            lemma2_to64();
+            lemma_edwards_scalar_mul_signed_scale(B, odd_sum, pow2(4));
         }
-        P = P.mul_by_pow_2(4);
+
         // ORIGINAL CODE (doesn't work with Verus - .filter() not supported in ghost for loops):
         // for i in (0..64).filter(|x| x % 2 == 0) {
         //     P = (&P + &tables[i / 2].select(a[i])).as_extended();
         // }
-        for i in 0..64 {
+        for i in 0..64
+            invariant
+                tables == &self.0,
+                is_valid_edwards_basepoint_table(*self, B),
+                is_valid_radix_2w(&a, 4, 64),
+                B == spec_ed25519_basepoint(),
+                math_on_edwards_curve(B.0, B.1),
+                odd_sum == radix_16_parity_sum(a@, 1, 64),
+                is_well_formed_edwards_point(P),
+                // P = [16·odd_sum + d_0 + d_2·16² + ... ]B over the even positions below i
+                edwards_point_as_affine(P) == edwards_scalar_mul_signed(
+                    B,
+                    16 * odd_sum + radix_16_parity_sum(a@, 0, i as nat),
+                ),
+        {
             if i % 2 == 0 {
                 proof {
-                    // preconditions for select and arithmetic operations
-                    assume(a[i as int] >= -8 && a[i as int] <= 8);
+                    // digit bounds for select, from as_radix_2w (2^3 = 8)
+                    lemma2_to64();
+                    assert(-8 <= a[i as int] && a[i as int] <= 8);
+                    // table limb bounds for select, from the table invariant
+                    assert(crate::specs::window_specs::lookup_table_affine_limbs_bounded(
+                        self.0[(i / 2) as int].0,
+                    ));
                 }
                 let selected = tables[i / 2].select(a[i]);
                 proof {
                     // preconditions for addition
-                    assume(is_well_formed_edwards_point(P));
                     assume(sum_of_limbs_bounded(&P.Z, &P.Z, u64::MAX));  // extra bound for Z2 = &P.Z + &P.Z in add
-                    assume(fe51_limbs_bounded(&selected.y_plus_x, 54));
-                    assume(fe51_limbs_bounded(&selected.y_minus_x, 54));
-                    assume(fe51_limbs_bounded(&selected.xy2d, 54));
                 }
                 let tmp = &P + &selected;
                 proof {
@@ -2461,16 +2863,33 @@ impl BasepointTable for EdwardsBasepointTable {
                     assume(fe51_limbs_bounded(&tmp.T, 54));
                 }
                 P = tmp.as_extended();
+                proof {
+                    // selected = [d_i]([256^(i/2)]B) = [d_i·256^(i/2)]B
+                    let k = (i / 2) as nat;
+                    lemma_select_affine_niels_is_signed_multiple(
+                        tables[k as int].0,
+                        edwards_scalar_mul(B, pow256(k)),
+                        a[i as int],
+                        selected,
+                    );
+                    lemma_pow2_pos(8 * k);
+                    lemma_edwards_scalar_mul_signed_of_multiple(B, pow256(k), a[i as int] as int);
+                    lemma_edwards_scalar_mul_signed_additive(
+                        B,
+                        16 * odd_sum + radix_16_parity_sum(a@, 0, i as nat),
+                        a[i as int] * pow256(k),
+                    );
+                }
             }
         }
 
         proof {
-            // postconditions
-            assume(is_well_formed_edwards_point(P));
-            assume(edwards_point_as_affine(P) == edwards_scalar_mul(
-                spec_ed25519_basepoint(),
-                spec_scalar(scalar),
-            ));
+            // 16·odd_sum + even_sum = s, and [s]B = [s mod ℓ]B since B has order ℓ
+            let s = scalar_to_nat(scalar);
+            lemma_radix_16_parity_split(a@, 64);
+            assert(a@.skip(64).len() == 0);
+            assert(a@.take(64) =~= a@);
+            lemma_edwards_scalar_mul_mod_order(B, s);
         }
         P
     }
@@ -2484,6 +2903,7 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a EdwardsBasepointTable {
     fn mul(self, scalar: &'b Scalar) -> (result:
         EdwardsPoint)/* requires clause in MulSpecImpl<&Scalar> for &EdwardsBasepointTable in mul_specs.rs:
         requires scalar.bytes[31] <= 127
+            && is_valid_edwards_basepoint_table(*self, spec_ed25519_basepoint())
     */
 
         ensures
@@ -2506,6 +2926,7 @@ impl<'a, 'b> Mul<&'a EdwardsBasepointTable> for &'b Scalar {
     fn mul(self, basepoint_table: &'a EdwardsBasepointTable) -> (result:
         EdwardsPoint)/* requires clause in MulSpecImpl<&EdwardsBasepointTable> for &Scalar in mul_specs.rs:
         requires self.bytes[31] <= 127
+            && is_valid_edwards_basepoint_table(*basepoint_table, spec_ed25519_basepoint())
     */
 
         ensures
@@ -2520,6 +2941,104 @@ impl<'a, 'b> Mul<&'a EdwardsBasepointTable> for &'b Scalar {
     }
 }
 
+/// Runtime check of `fe51_limbs_bounded(fe, 54)`
+fn fe51_limbs_below_2_54(fe: &FieldElement) -> (result: bool)
+    ensures
+        result == fe51_limbs_bounded(fe, 54),
+{
+    fe.limbs[0] < (1u64 << 54u64) && fe.limbs[1] < (1u64 << 54u64) && fe.limbs[2] < (1u64
+        << 54u64) && fe.limbs[3] < (1u64 << 54u64) && fe.limbs[4] < (1u64 << 54u64)
+}
+
+impl EdwardsBasepointTable {
+    /// Check this table entry by entry against `EdwardsBasepointTable::create(basepoint)`,
+    /// together with the limb bounds that `mul_base` relies on.
+    ///
+    /// `ED25519_BASEPOINT_TABLE` is a hard-coded dump of limbs whose validity is the
+    /// axiom `axiom_ed25519_basepoint_table_valid`. A `true` result of this check on it,
+    /// with the Ed25519 basepoint, establishes that axiom for the running binary.
+    pub(crate) fn is_valid_table_for(&self, basepoint: &EdwardsPoint) -> (result: bool)
+        requires
+            is_well_formed_edwards_point(*basepoint),
+        ensures
+            result ==> is_valid_edwards_basepoint_table(*self, edwards_point_as_affine(*basepoint)),
+    {
+        let expected = EdwardsBasepointTable::create(basepoint);
+        let ghost B = edwards_point_as_affine(*basepoint);
+
+        for i in 0..32
+            invariant
+                B == edwards_point_as_affine(*basepoint),
+                is_valid_edwards_basepoint_table(expected, B),
+                forall|k: int|
+                    #![trigger self.0[k]]
+                    0 <= k < i ==> crate::specs::window_specs::is_valid_lookup_table_affine_coords(
+                        self.0[k].0,
+                        edwards_scalar_mul(B, pow256(k as nat)),
+                        8,
+                    ) && crate::specs::window_specs::lookup_table_affine_limbs_bounded(self.0[k].0),
+        {
+            let ghost multiple = edwards_scalar_mul(B, pow256(i as nat));
+            proof {
+                assert(crate::specs::window_specs::is_valid_lookup_table_affine_coords(
+                    expected.0[i as int].0,
+                    multiple,
+                    8,
+                ));
+            }
+            for j in 0..8
+                invariant
+                    0 <= i < 32,
+                    crate::specs::window_specs::is_valid_lookup_table_affine_coords(
+                        expected.0[i as int].0,
+                        multiple,
+                        8,
+                    ),
+                    forall|k: int|
+                        #![trigger self.0[i as int].0[k]]
+                        0 <= k < j ==> affine_niels_point_as_affine_edwards(self.0[i as int].0[k])
+                            == edwards_scalar_mul(multiple, (k + 1) as nat) && fe51_limbs_bounded(
+                            &self.0[i as int].0[k].y_plus_x,
+                            54,
+                        ) && fe51_limbs_bounded(&self.0[i as int].0[k].y_minus_x, 54)
+                            && fe51_limbs_bounded(&self.0[i as int].0[k].xy2d, 54),
+            {
+                let entry = &self.0[i].0[j];
+                let expected_entry = &expected.0[i].0[j];
+                if !(fe51_limbs_below_2_54(&entry.y_plus_x) && fe51_limbs_below_2_54(
+                    &entry.y_minus_x,
+                ) && fe51_limbs_below_2_54(&entry.xy2d)) {
+                    return false;
+                }
+                // Field elements are compared by value, so the entry represents the
+                // same affine point as the computed one
+                if !(choice_into(entry.y_plus_x.ct_eq(&expected_entry.y_plus_x)) && choice_into(
+                    entry.y_minus_x.ct_eq(&expected_entry.y_minus_x),
+                ) && choice_into(entry.xy2d.ct_eq(&expected_entry.xy2d))) {
+                    return false;
+                }
+                proof {
+                    assert(affine_niels_point_as_affine_edwards(*expected_entry) == edwards_scalar_mul(
+                        multiple,
+                        (j + 1) as nat,
+                    ));
+                }
+            }
+            proof {
+                assert(crate::specs::window_specs::is_valid_lookup_table_affine_coords(
+                    self.0[i as int].0,
+                    multiple,
+                    8,
+                ));
+                assert(crate::specs::window_specs::lookup_table_affine_limbs_bounded(
+                    self.0[i as int].0,
+                ));
+            }
+        }
+        true
+    }
+}
+
 } // verus!
 impl Debug for EdwardsBasepointTable {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -2546,6 +3065,227 @@ impl Debug for EdwardsBasepointTable {
 since only radix-16 is kept and no conversions between radix sizes are needed.
 */
 
+// ------------------------------------------------------------------------
+// Fixed-base tables for arbitrary points
+// ------------------------------------------------------------------------
+/* VERIFICATION NOTE: not verified yet. This is `EdwardsBasepointTable::mul_base` with the
+window width as a parameter; the tables live in a `Vec` since their size depends on `W`. */
+
+/// A precomputed table of multiples of an arbitrary point \\(B\\), for
+/// constant-time fixed-base scalar multiplication with a window of `W` bits,
+/// \\(4 \leq W \leq 8\\).
+///
+/// This is the same algorithm as [`EdwardsBasepointTable`] (which is the case
+/// \\(W = 4\\) for the Ed25519 basepoint), for callers that multiply many
+/// scalars by one point known only at run time, such as a long-term public key.
+/// Wider windows trade memory and creation time for fewer additions:
+///
+/// | `W` | size  | additions |
+/// |-----|-------|-----------|
+/// | 4   | 30KB  | 64        |
+/// | 5   | 49KB  | 52        |
+/// | 6   | 83KB  | 43        |
+/// | 7   | 143KB | 37        |
+/// | 8   | 255KB | 33        |
+///
+/// Creating a table costs about as much as a few dozen scalar multiplications,
+/// so it only pays off when the point is reused.
+///
+/// # Example
+///
+/// ```
+/// # use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+/// # use curve25519_dalek::edwards::FixedBaseTable;
+/// # use curve25519_dalek::scalar::Scalar;
+/// # use curve25519_dalek::traits::BasepointTable;
+/// let P = ED25519_BASEPOINT_POINT * Scalar::from(42u64);
+/// let table = FixedBaseTable::<6>::create(&P);
+///
+/// let k = Scalar::from(1234567u64);
+/// assert_eq!(&table * &k, P * k);
+/// assert_eq!(table.basepoint(), P);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<const W: usize> {
+    // Table i holds [1, 2, ..., 2^(W-1)]·2^(2·W·i)·B, one table after the other
+    entries: Vec<AffineNielsPoint>,
+}
+
+#[cfg(feature = "alloc")]
+impl<const W: usize> FixedBaseTable<W> {
+    /// Fails to compile for a window width outside the range of `Scalar::as_radix_2w`.
+    const WINDOW_WIDTH_IS_SUPPORTED: () = assert!(4 <= W && W <= 8, "W must be in 4..=8");
+
+    /// The number of entries in each lookup table.
+    const TABLE_SIZE: usize = 1 << (W - 1);
+
+    /// The number of radix-\\(2^W\\) digits of a scalar.
+    fn digits() -> usize {
+        Scalar::to_radix_2w_size_hint(W)
+    }
+
+    /// Given \\(-2^{W-1} \leq x \leq 2^{W-1}\\), return \\(x \cdot 2^{2Wi} B\\) in
+    /// constant time, reading every entry of table `i`.
+    fn select(&self, i: usize, x: i8) -> AffineNielsPoint {
+        let table = &self.entries[i * Self::TABLE_SIZE..(i + 1) * Self::TABLE_SIZE];
+
+        // Compute xabs = |x|
+        let xmask = x as i16 >> 7;
+        let xabs = (x as i16 + xmask) ^ xmask;
+
+        let mut t = AffineNielsPoint::identity();
+        for (j, entry) in table.iter().enumerate() {
+            let c = (xabs as u16).ct_eq(&((j + 1) as u16));
+            t.conditional_assign(entry, c);
+        }
+        let neg_mask = Choice::from((xmask & 1) as u8);
+        let neg_t = -&t;
+        t.conditional_assign(&neg_t, neg_mask);
+        t
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const W: usize> BasepointTable for FixedBaseTable<W> {
+    type Point = EdwardsPoint;
+
+    /// Create a table of precomputed multiples of `basepoint`.
+    fn create(basepoint: &EdwardsPoint) -> FixedBaseTable<W> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::WINDOW_WIDTH_IS_SUPPORTED;
+
+        let tables = (Self::digits() + 1) / 2;
+        let mut entries = Vec::with_capacity(tables * Self::TABLE_SIZE);
+        let mut P = *basepoint;
+        for _ in 0..tables {
+            // P = 2^(2·W·i)·basepoint
+            let mut Q = P;
+            for _ in 0..Self::TABLE_SIZE {
+                entries.push(Q.as_affine_niels());
+                Q = &Q + &P;
+            }
+            P = P.mul_by_pow_2((W + W) as u32);
+        }
+        FixedBaseTable { entries }
+    }
+
+    /// Get the point this table was created from.
+    fn basepoint(&self) -> EdwardsPoint {
+        (&EdwardsPoint::identity() + &self.entries[0]).as_extended()
+    }
+
+    /// Compute \\(aB\\) as in [`EdwardsBasepointTable::mul_base`], with digits in radix
+    /// \\(2^W\\): first the odd digits, then a multiplication by \\(2^W\\), then the even
+    /// digits.
+    ///
+    /// For \\(W = 4\\) the scalar must be below \\(2^{255}\\), which is the case for
+    /// every reduced or clamped scalar.
+    fn mul_base(&self, scalar: &Scalar) -> EdwardsPoint {
+        let a = scalar.as_radix_2w(W);
+        let digits = Self::digits();
+
+        let mut P = EdwardsPoint::identity();
+        for i in (1..digits).step_by(2) {
+            P = (&P + &self.select(i / 2, a[i])).as_extended();
+        }
+        P = P.mul_by_pow_2(W as u32);
+        for i in (0..digits).step_by(2) {
+            P = (&P + &self.select(i / 2, a[i])).as_extended();
+        }
+        P
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'b, const W: usize> Mul<&'b Scalar> for &'a FixedBaseTable<W> {
+    type Output = EdwardsPoint;
+
+    /// Construct an `EdwardsPoint` from a `Scalar` \\(a\\) by
+    /// computing the multiple \\(aB\\) of this basepoint \\(B\\).
+    fn mul(self, scalar: &'b Scalar) -> EdwardsPoint {
+        self.mul_base(scalar)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'b, const W: usize> Mul<&'a FixedBaseTable<W>> for &'b Scalar {
+    type Output = EdwardsPoint;
+
+    /// Construct an `EdwardsPoint` from a `Scalar` \\(a\\) by
+    /// computing the multiple \\(aB\\) of this basepoint \\(B\\).
+    fn mul(self, table: &'a FixedBaseTable<W>) -> EdwardsPoint {
+        table.mul_base(self)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod fixed_base_table_test {
+    use super::*;
+
+    fn check_table<const W: usize>() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let bases = [
+            B,
+            B * Scalar::from(42u64),
+            constants::EIGHT_TORSION[1],
+            B + constants::EIGHT_TORSION[3],
+        ];
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            // A single digit -2^(W-1), carried into the next digit
+            Scalar::from(1u64 << (W - 1)),
+            Scalar::from_bytes_mod_order_wide(&[0xa5; 64]),
+            Scalar::from_bytes_mod_order_wide(&[0x3c; 64]),
+        ];
+        for P in bases {
+            let table = FixedBaseTable::<W>::create(&P);
+            assert_eq!(table.basepoint(), P);
+            for k in scalars {
+                assert_eq!(&table * &k, P * k);
+                assert_eq!(&k * &table, P * k);
+            }
+        }
+
+        // 2^255 - 1, the largest scalar allowed for W = 4: its top radix-16 digit is
+        // the carry 8, and for W = 8 it has a digit -128 and the extra digit 1
+        let mut bytes = [0xff; 32];
+        bytes[31] = 0x7f;
+        let table = FixedBaseTable::<W>::create(&B);
+        assert_eq!(
+            &table * &Scalar { bytes },
+            B * Scalar::from_bytes_mod_order(bytes)
+        );
+    }
+
+    #[test]
+    fn fixed_base_table_w4() {
+        check_table::<4>();
+    }
+
+    #[test]
+    fn fixed_base_table_w5() {
+        check_table::<5>();
+    }
+
+    #[test]
+    fn fixed_base_table_w6() {
+        check_table::<6>();
+    }
+
+    #[test]
+    fn fixed_base_table_w7() {
+        check_table::<7>();
+    }
+
+    #[test]
+    fn fixed_base_table_w8() {
+        check_table::<8>();
+    }
+}
+
 verus! {
 
 impl EdwardsPoint {
@@ -2706,13 +3446,14 @@ impl EdwardsPoint {
     {
         /* ORIGINAL CODE: (self * constants::BASEPOINT_ORDER_PRIVATE).is_identity() */
         let order_mul = self * constants::BASEPOINT_ORDER_PRIVATE;
-        // Mul ensures: edwards_point_as_affine(order_mul) == edwards_scalar_mul(..., spec_scalar(&BASEPOINT_ORDER_PRIVATE))
+        // Mul ensures: edwards_point_as_affine(order_mul) == edwards_scalar_mul(..., scalar_to_nat(&BASEPOINT_ORDER_PRIVATE))
         let result = order_mul.is_identity();
         // is_identity ensures: result == (edwards_point_as_affine(order_mul) == math_edwards_identity())
         proof {
-            // TODO: Need lemma that spec_scalar(&BASEPOINT_ORDER_PRIVATE) == group_order()
-            // BASEPOINT_ORDER_PRIVATE represents ℓ = 2^252 + 27742317777372353535851937790883648493
-            assume(spec_scalar(&constants::BASEPOINT_ORDER_PRIVATE) == group_order());
+            // TODO: Need lemma that scalar_to_nat(&BASEPOINT_ORDER_PRIVATE) == group_order()
+            // BASEPOINT_ORDER_PRIVATE holds the unreduced bytes of ℓ = 2^252 + 27742317777372353535851937790883648493
+            // (spec_scalar would reduce it to 0)
+            assume(scalar_to_nat(&constants::BASEPOINT_ORDER_PRIVATE) == group_order());
         }
         result
     }
@@ -29,6 +29,7 @@ use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 use crate::backend;
 use crate::constants;
@@ -60,16 +61,28 @@ use crate::specs::field_specs_u64::*;
 #[allow(unused_imports)]
 use crate::lemmas::common_lemmas::pow_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::common_lemmas::to_nat_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::as_bytes_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::constants_lemmas::*;
+#[allow(unused_imports)]
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::invert_lemmas::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::pow22501_t19_lemma::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::pow22501_t3_lemma::*;
 #[allow(unused_imports)]
 use crate::lemmas::field_lemmas::pow_p58_lemma::*;
 #[allow(unused_imports)]
+use crate::lemmas::field_lemmas::sqrt_m1_lemmas::*;
+#[allow(unused_imports)]
+use crate::lemmas::field_lemmas::sqrt_ratio_lemmas::*;
+#[allow(unused_imports)]
 use crate::lemmas::field_lemmas::u64_5_as_nat_lemmas::*;
 
 verus! {
@@ -101,13 +114,14 @@ impl vstd::std_specs::cmp::PartialEqSpecImpl for FieldElement {
 impl PartialEq for FieldElement {
     fn eq(&self, other: &FieldElement) -> (result:
         bool)/* VERIFICATION NOTE:
-     - DRAFT SPEC: spec_fe51_to_bytes is a complex spec function that should correspond to as_bytes()
+     - spec_fe51_to_bytes matches as_bytes() (see the as_bytes postcondition)
      - PartialEqSpecImpl trait provides the external specification
      - Proof follows from ct_eq and choice_into postconditions
      */
 
         ensures
             result == (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other)),
+            result == (spec_field_element(self) == spec_field_element(other)),
     {
         /* <VERIFICATION NOTE>
          Use wrapper function for Choice::into
@@ -142,12 +156,14 @@ impl ConstantTimeEq for FieldElement {
     fn ct_eq(&self, other: &FieldElement) -> (result:
         Choice)/* <VERIFICATION NOTE>
      - Use wrapper functions for ConstantTimeEq and CtOption
-     - DRAFT SPEC: spec_fe51_to_bytes is a complex spec function that should correspond to as_bytes()
-     - Proof uses lemma_as_bytes_equals_spec_fe51_to_bytes
+     - spec_fe51_to_bytes matches as_bytes() (see the as_bytes postcondition)
+     - Value equality relies on as_bytes being canonical (bytes32_to_nat(&bytes) < p)
     </VERIFICATION NOTE> */
 
         ensures
             choice_is_true(result) == (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other)),
+            // Same comparison on field values (the encoding is canonical)
+            choice_is_true(result) == (spec_field_element(self) == spec_field_element(other)),
     {
         /* <VERIFICATION NOTE>
          Use wrapper function for Verus compatibility instead of direct subtle call
@@ -161,45 +177,22 @@ impl ConstantTimeEq for FieldElement {
         let result = ct_eq_bytes32(&self_bytes, &other_bytes);
 
         proof {
-            // Proof chain:
             // 1. ct_eq_bytes32 ensures: choice_is_true(result) == (self_bytes == other_bytes)
-            // 2. Array equality <==> sequence equality
-            // 3. as_bytes postcondition: bytes32_to_nat(&bytes) == u64_5_as_nat(fe.limbs) % p()
-            // 4. lemma_as_bytes_equals_spec_fe51_to_bytes: seq_from32(&bytes) == spec_fe51_to_bytes(fe)
-            //    when bytes32_to_nat(&bytes) == u64_5_as_nat(fe.limbs) % p()
-            // 5. Therefore: choice_is_true(result) == (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other))
-            // From as_bytes() postcondition, we know:
-            // - bytes32_to_nat(&self_bytes) == u64_5_as_nat(self.limbs) % p()
-            // - bytes32_to_nat(&other_bytes) == u64_5_as_nat(other.limbs) % p()
-            // Apply lemmas with the bytes and the postcondition requirement
-            lemma_as_bytes_equals_spec_fe51_to_bytes(self, &self_bytes);
-            lemma_as_bytes_equals_spec_fe51_to_bytes(other, &other_bytes);
-
-            // Now we have:
-            // - seq_from32(&self_bytes) == spec_fe51_to_bytes(self)
-            // - seq_from32(&other_bytes) == spec_fe51_to_bytes(other)
-
-            // Prove the bidirectional implication:
-            // (self_bytes == other_bytes) <==> (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other))
-
-            // Forward direction: array equality implies spec equality
+            // 2. as_bytes ensures: seq_from32(&bytes) == spec_fe51_to_bytes(fe)
+            // 3. Array equality <==> sequence equality
             if self_bytes == other_bytes {
-                // Arrays equal => all elements equal => sequences equal
-                assert forall|i: int| 0 <= i < 32 implies self_bytes[i] == other_bytes[i] by {}
-                assert(seq_from32(&self_bytes) == seq_from32(&other_bytes));
-                assert(spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other));
+                assert(seq_from32(&self_bytes) =~= seq_from32(&other_bytes));
             }
-            // Backward direction: spec equality implies array equality
-
             if spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other) {
-                assert(seq_from32(&self_bytes) == seq_from32(&other_bytes));
                 lemma_seq_eq_implies_array_eq(&self_bytes, &other_bytes);
-                assert(self_bytes == other_bytes);
             }
-            // Therefore: (self_bytes == other_bytes) <==> (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other))
-            // And since ct_eq_bytes32 ensures: choice_is_true(result) == (self_bytes == other_bytes)
-            // We conclude: choice_is_true(result) == (spec_fe51_to_bytes(self) == spec_fe51_to_bytes(other))
-
+            // 4. Field-value form: both encodings are canonical (< p) and
+            //    bytes32_to_nat is injective on byte arrays
+            if spec_field_element(self) == spec_field_element(other) {
+                lemma_canonical_bytes_equal(&self_bytes, &other_bytes);
+                assert(seq_from32(&self_bytes) =~= seq_from32(&other_bytes));
+                lemma_seq_eq_implies_array_eq(&self_bytes, &other_bytes);
+            }
         }
 
         result
@@ -207,6 +200,37 @@ impl ConstantTimeEq for FieldElement {
 }
 
 impl FieldElement {
+    /// Attempt to decode a `FieldElement` from its canonical byte encoding.
+    ///
+    /// Unlike `from_bytes`, which ignores the high bit and accepts values
+    /// in \\( [p, 2^{255}) \\), this rejects every non-canonical input.
+    ///
+    /// # Return
+    ///
+    /// - `Some(x)` if `bytes` is the encoding of a value \\( x < p \\);
+    /// - `None` otherwise.
+    #[allow(dead_code)]  // opt-in strict decoding, not used by the default decompression paths
+    pub(crate) fn from_canonical_bytes(bytes: &[u8; 32]) -> (result: CtOption<FieldElement>)
+        ensures
+            ct_option_has_value(result) == is_canonical_field_encoding(bytes),
+            ct_option_has_value(result) ==> spec_field_element(&ct_option_value(result))
+                == bytes32_to_nat(bytes),
+            // Canonical encodings never have the high bit set
+            is_canonical_field_encoding(bytes) ==> bytes[31] <= 127,
+            fe51_limbs_bounded(&ct_option_value(result), 51),
+    {
+        let candidate = FieldElement::from_bytes(bytes);
+        let encoded = candidate.as_bytes();
+        let is_canonical = ct_eq_bytes32(&encoded, bytes);
+        let result = ct_option_new(candidate, is_canonical);
+
+        proof {
+            lemma_canonical_field_encoding_roundtrip(bytes, &candidate, &encoded);
+        }
+
+        result
+    }
+
     /// Determine if this `FieldElement` is negative, in the sense
     /// used in the ed25519 paper: `x` is negative if the low bit is
     /// set.
@@ -222,6 +246,9 @@ impl FieldElement {
 
         ensures
             choice_is_true(result) == (spec_fe51_to_bytes(self)[0] & 1 == 1),
+            // Same test on the field value: the canonical encoding is odd
+            choice_is_true(result) == math_field_is_negative(spec_field_element(self)),
+            choice_is_true(result) == (spec_field_element_sign_bit(self) == 1),
     {
         let bytes = self.as_bytes();
         let result = Choice::from(bytes[0] & 1);
@@ -230,6 +257,19 @@ impl FieldElement {
             // From as_bytes() postcondition: bytes32_to_nat(&bytes) == u64_5_as_nat(self.limbs) % p()
             // Apply lemma to establish that bytes matches spec_fe51_to_bytes
             lemma_as_bytes_equals_spec_fe51_to_bytes(self, &bytes);
+
+            // bytes[0] = value % 2^8, and bytes[0] & 1 = bytes[0] % 2 = value % 2
+            let value = bytes32_to_nat(&bytes);
+            lemma_extract_byte_at_index(&bytes, 0);
+            lemma2_to64();
+            lemma_div_basics(value as int);
+            lemma_mod_mod(value as int, 2, 128);
+            let b0 = bytes[0];
+            assert(b0 & 1 == b0 % 2) by (bit_vector);
+
+            // spec_field_element is already reduced, so the extra % p() is a no-op
+            pow255_gt_19();
+            lemma_mod_twice(u64_5_as_nat(self.limbs) as int, p() as int);
         }
 
         result
@@ -252,6 +292,8 @@ impl FieldElement {
     // SPEC BYPASS through placeholder spec_fe51_to_bytes
 
             choice_is_true(result) == (spec_fe51_to_bytes(self) == seq![0u8; 32]),
+            // Same test on the field value
+            choice_is_true(result) == (spec_field_element(self) == 0),
     {
         let zero = [0u8;32];
         let bytes = self.as_bytes();
@@ -278,6 +320,14 @@ impl FieldElement {
                 assert(seq_from32(&bytes) == seq_from32(&zero));
                 assert(bytes == zero);
             }
+
+            // Field-value form: the only encoding of 0 is all zero bytes
+            assert(bytes32_to_nat(&zero) == 0);
+            if spec_field_element(self) == 0 {
+                lemma_canonical_bytes_equal(&bytes, &zero);
+                assert(seq_from32(&bytes) =~= seq_from32(&zero));
+                lemma_seq_eq_implies_array_eq(&bytes, &zero);
+            }
         }
 
         result
@@ -524,34 +574,35 @@ impl FieldElement {
     )/* <VERIFICATION NOTE>
      - Refactored for Verus: Index loops instead of iterators, manual Vec construction
      - Choice type operations handled by wrappers in subtle_assumes.rs
-     - PROOF BYPASSES because of trait issues and proof obligations.
+     - Forward pass: scratch[i] holds the product of the nonzero inputs before i
+     - Backward pass: acc holds the inverse of the product of the nonzero inputs before i
     </VERIFICATION NOTE> */
 
         requires
             forall|i: int|
-                #![trigger old(inputs)[i]]
-                0 <= i < old(inputs).len() ==> (forall|j: int|
-                    0 <= j < 5 ==> old(inputs)[i].limbs[j] < 1u64 << 54),
+                0 <= i < old(inputs).len() ==> fe51_limbs_bounded(#[trigger] &old(inputs)[i], 54),
         ensures
-    // Each element is replaced appropriately:
-
+            inputs.len() == old(inputs).len(),
+            // Each element is replaced appropriately:
             forall|i: int|
-                #![auto]
+                #![trigger inputs[i]]
                 0 <= i < inputs.len() ==> {
                     // If input was non-zero, it's replaced with its inverse
-                    (spec_field_element(&old(inputs)[i]) != 0) ==> is_inverse_field(
+                    &&& (spec_field_element(&old(inputs)[i]) != 0) ==> is_inverse_field(
                         &old(inputs)[i],
                         &inputs[i],
-                    ) &&
+                    )
                     // If input was zero, it remains zero
-                    (spec_field_element(&old(inputs)[i]) == 0) ==> spec_field_element(&inputs[i])
-                        == 0
+                    &&& (spec_field_element(&old(inputs)[i]) == 0) ==> spec_field_element(
+                        &inputs[i],
+                    ) == 0
                 },
     {
         // Montgomery's Trick and Fast Implementation of Masked AES
         // Genelle, Prouff and Quisquater
         // Section 3.2
         let n = inputs.len();
+        let ghost orig = old(inputs)@;
 
         // Extract ONE constant before loops (similar to scalar.rs pattern)
         let one = FieldElement::ONE;
@@ -563,18 +614,28 @@ impl FieldElement {
          let mut scratch = vec![FieldElement::ONE; n];
         </ORIGINAL CODE> */
         let mut scratch = Vec::new();
-        for _ in 0..n {
+        for j in 0..n
+            invariant
+                scratch.len() == j,
+        {
             scratch.push(one);
         }
 
         // Keep an accumulator of all of the previous products
         let mut acc = one;
+        proof {
+            lemma_one_limbs_bounded_51();
+            lemma_one_field_element_value();
+            lemma_fe51_limbs_bounded_weaken(&acc, 51, 54);
+            assert(orig.subrange(0, 0) =~= Seq::<FieldElement51>::empty());
+        }
 
         // Pass through the input vector, recording the previous
         // products in the scratch space
         /* <VERIFICATION NOTE>
          Rewritten with index loop instead of .zip() for Verus compatibility
          Using choice_not() wrapper instead of ! operator
+         Product and conditional assignment split out so the proof can name them
         </VERIFICATION NOTE> */
         /* <ORIGINAL CODE>
          for (input, scratch) in inputs.iter().zip(scratch.iter_mut()) {
@@ -583,12 +644,31 @@ impl FieldElement {
             acc.conditional_assign(&(&acc * input), !input.is_zero());
         }
         </ORIGINAL CODE> */
-        for i in 0..n {
-            assume(false);
+        for i in 0..n
+            invariant
+                n == inputs.len(),
+                n == scratch.len(),
+                inputs@ == orig,
+                forall|j: int| 0 <= j < n ==> fe51_limbs_bounded(#[trigger] &orig[j], 54),
+                fe51_limbs_bounded(&acc, 54),
+                spec_field_element(&acc) == spec_product_of_nonzero_field_elems(
+                    orig.subrange(0, i as int),
+                ),
+                forall|j: int|
+                    #![trigger scratch@[j]]
+                    0 <= j < i ==> fe51_limbs_bounded(&scratch@[j], 54) && spec_field_element(
+                        &scratch@[j],
+                    ) == spec_product_of_nonzero_field_elems(orig.subrange(0, j)),
+        {
             scratch[i] = acc;
-            assume(false);
             // acc <- acc * input, but skipping zeros (constant-time)
-            acc.conditional_assign(&(&acc * &inputs[i]), choice_not(inputs[i].is_zero()));
+            let acc_times_input = &acc * &inputs[i];
+            let nz = choice_not(inputs[i].is_zero());
+            conditional_assign_field_element(&mut acc, &acc_times_input, nz);
+
+            proof {
+                lemma_product_of_nonzero_prefix_step(orig, i as int);
+            }
         }
 
         // acc is nonzero because we skipped zeros in inputs
@@ -597,11 +677,6 @@ impl FieldElement {
         assert!(bool::from(choice_not(acc.is_zero())));
 
         // Compute the inverse of all products
-        proof {
-            // PROOF BYPASS: assume acc limbs are bounded
-            // (This would follow from the loop invariant, but we haven't proven that yet)
-            assume(fe51_limbs_bounded(&acc, 54));
-        }
         acc = acc.invert();
 
         // Pass through the vector backwards to compute the inverses
@@ -621,43 +696,71 @@ impl FieldElement {
             acc.conditional_assign(&tmp, nz);
         }
         </ORIGINAL CODE> */
-
-        proof {
-            assume(false);
-        }
-
         let mut i: usize = n;
         while i > 0
             invariant
                 n == inputs.len(),
                 n == scratch.len(),
+                n == orig.len(),
+                i <= n,
+                forall|j: int| 0 <= j < n ==> fe51_limbs_bounded(#[trigger] &orig[j], 54),
+                forall|j: int|
+                    #![trigger scratch@[j]]
+                    0 <= j < n ==> fe51_limbs_bounded(&scratch@[j], 54) && spec_field_element(
+                        &scratch@[j],
+                    ) == spec_product_of_nonzero_field_elems(orig.subrange(0, j)),
+                fe51_limbs_bounded(&acc, 54),
+                spec_field_element(&acc) == math_field_inv(
+                    spec_product_of_nonzero_field_elems(orig.subrange(0, i as int)),
+                ),
+                // Not yet visited: unchanged
+                forall|j: int| 0 <= j < i ==> #[trigger] inputs@[j] == orig[j],
+                // Already visited: inverted, or still zero
+                forall|j: int|
+                    #![trigger inputs@[j]]
+                    i <= j < n ==> {
+                        &&& (spec_field_element(&orig[j]) != 0) ==> is_inverse_field(
+                            &orig[j],
+                            &inputs@[j],
+                        )
+                        &&& (spec_field_element(&orig[j]) == 0) ==> spec_field_element(
+                            &inputs@[j],
+                        ) == 0
+                    },
             decreases i,
         {
             i -= 1;
-            assume(false);
             let tmp = &acc * &inputs[i];
             // input <- acc * scratch, then acc <- tmp
             // Again, we skip zeros in a constant-time way
             let nz = choice_not(inputs[i].is_zero());
             // Verus doesn't support index for &mut, so we extract-modify-reassign
             let mut input_i = inputs[i];
-            input_i.conditional_assign(&(&acc * &scratch[i]), nz);
+            let acc_times_scratch = &acc * &scratch[i];
+            conditional_assign_field_element(&mut input_i, &acc_times_scratch, nz);
             inputs[i] = input_i;
-            acc.conditional_assign(&tmp, nz);
+            conditional_assign_field_element(&mut acc, &tmp, nz);
+
+            proof {
+                let k = i as int;
+                let prefix = spec_product_of_nonzero_field_elems(orig.subrange(0, k));
+                let x = spec_field_element(&orig[k]);
+                lemma_product_of_nonzero_prefix_step(orig, k);
+                lemma_product_of_nonzero_is_nonzero(orig.subrange(0, k));
+                if x != 0 {
+                    assert(x % p() != 0) by {
+                        p_gt_2();
+                        lemma_mod_bound(u64_5_as_nat(orig[k].limbs) as int, p() as int);
+                        lemma_small_mod(x, p());
+                    };
+                    lemma_batch_invert_step(prefix, x);
+                    lemma_inverse_field_of_math_field_inv(&orig[k], &input_i);
+                }
+            }
         }
 
         proof {
-            // Assume the postconditions hold
-            assume(forall|i: int|
-                #![auto]
-                0 <= i < inputs.len() ==> {
-                    (spec_field_element(&old(inputs)[i]) != 0) ==> is_inverse_field(
-                        &old(inputs)[i],
-                        &inputs[i],
-                    ) && (spec_field_element(&old(inputs)[i]) == 0) ==> spec_field_element(
-                        &inputs[i],
-                    ) == 0
-                });
+            assert(inputs@.len() == orig.len());
         }
     }
 
@@ -713,10 +816,7 @@ impl FieldElement {
 
             fe51_limbs_bounded(&result, 54),
             // Mathematical value
-            spec_field_element(&result) == (pow(
-                spec_field_element(self) as int,
-                (pow2(252) - 3) as nat,
-            ) as nat) % p(),
+            spec_field_element(&result) == math_field_pow_p58(spec_field_element(self)),
     {
         // The bits of (p-5)/8 are 101111.....11.
         //
@@ -826,8 +926,13 @@ impl FieldElement {
                 &result.1,
                 52,
             ),
-    // VERIFICATION NOTE: PROOF BYPASS
-
+            // Complete case analysis: square / nonsquare / zero numerator / zero denominator
+            spec_sqrt_ratio_i_cases(
+                spec_field_element(u),
+                spec_field_element(v),
+                choice_is_true(result.0),
+                spec_field_element(&result.1),
+            ),
     {
         // Using the same trick as in ed25519 decoding, we merge the
         // inversion, the square root, and the square test as follows.
@@ -852,13 +957,22 @@ impl FieldElement {
         // If vr^2 = -u, then sqrt(u/v) = r*sqrt(-1).
         //
         // If v is zero, r is also zero.
-        proof {
-            assume(false);  // PROOF BYPASS
-        }
+        /* ORIGINAL CODE:
         let v3 = &v.square() * v;
         let v7 = &v3.square() * v;
         let mut r = &(u * &v3) * &(u * &v7).pow_p58();
         let check = v * &r.square();
+        */
+        let v_sq = v.square();
+        let v3 = &v_sq * v;
+        let v3_sq = v3.square();
+        let v7 = &v3_sq * v;
+        let u_v3 = u * &v3;
+        let u_v7 = u * &v7;
+        let u_v7_p58 = u_v7.pow_p58();
+        let mut r = &u_v3 * &u_v7_p58;
+        let r_sq = r.square();
+        let check = v * &r_sq;
 
         let i = &constants::SQRT_M1;
 
@@ -868,26 +982,37 @@ impl FieldElement {
         // let flipped_sign_sqrt = check.ct_eq(&(-u));
         // let flipped_sign_sqrt_i = check.ct_eq(&(&(-u) * i));
         // REFACTORED: Use wrapper to avoid Verus internal error with negation
-        let u_neg = negate_field(u);
+        let u_neg = negate_field_element(u);
         let flipped_sign_sqrt = check.ct_eq(&u_neg);
+        proof {
+            lemma_sqrt_m1_limbs_bounded();
+        }
         let flipped_sign_sqrt_i = check.ct_eq(&(&u_neg * i));
 
         let r_prime = &constants::SQRT_M1 * &r;
+        let ghost r_before = r;
         // ORIGINAL CODE:
         // r.conditional_assign(&r_prime, flipped_sign_sqrt | flipped_sign_sqrt_i);
         // REFACTORED: Use wrapper for Choice bitwise OR
-        r.conditional_assign(&r_prime, choice_or(flipped_sign_sqrt, flipped_sign_sqrt_i));
+        let flip = choice_or(flipped_sign_sqrt, flipped_sign_sqrt_i);
+        r.conditional_assign(&r_prime, flip);
+
+        proof {
+            // conditional_assign selects all five limbs, so the value and bounds follow
+            if choice_is_true(flip) {
+                assert(u64_5_as_nat(r.limbs) == u64_5_as_nat(r_prime.limbs));
+            } else {
+                assert(u64_5_as_nat(r.limbs) == u64_5_as_nat(r_before.limbs));
+            }
+            assert(fe51_limbs_bounded(&r, 52));
+        }
+        let ghost r_adj = r;
 
         // Choose the nonnegative square root.
         let r_is_negative = r.is_negative();
         // ORIGINAL CODE:
         // r.conditional_negate(r_is_negative);
         // REFACTORED: Use specialized wrapper with specs
-        proof {
-            // r is bounded after conditional_assign (result of multiplications and pow_p58)
-            // This will need to be proven when we remove the assume(false) bypass
-            assume(fe51_limbs_bounded(&r, 51));
-        }
         conditional_negate_field_element(&mut r, r_is_negative);
 
         // ORIGINAL CODE:
@@ -895,6 +1020,54 @@ impl FieldElement {
         // REFACTORED: Use wrapper for Choice bitwise OR
         let was_nonzero_square = choice_or(correct_sign_sqrt, flipped_sign_sqrt);
 
+        proof {
+            let uu = spec_field_element(u);
+            let vv = spec_field_element(v);
+            let ii = spec_sqrt_m1();
+
+            // Bridge the square() postconditions to math_field_square
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(v.limbs),
+                u64_5_as_nat(v_sq.limbs),
+            );
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(v3.limbs),
+                u64_5_as_nat(v3_sq.limbs),
+            );
+            lemma_square_matches_math_field_square(
+                u64_5_as_nat(r_before.limbs),
+                u64_5_as_nat(r_sq.limbs),
+            );
+
+            // r before the adjustment is the candidate (uv³)(uv⁷)^((p-5)/8)
+            assert(spec_field_element(&r_before) == spec_sqrt_ratio_i_candidate(uu, vv));
+            assert(spec_field_element(&check) == math_field_mul(
+                vv,
+                math_field_square(spec_field_element(&r_before)),
+            ));
+            assert(spec_field_element(&r_adj) == if spec_field_element(&check) == math_field_neg(uu)
+                || spec_field_element(&check) == math_field_mul(math_field_neg(uu), ii) {
+                math_field_mul(ii, spec_field_element(&r_before))
+            } else {
+                spec_field_element(&r_before)
+            });
+
+            // uu, vv < p
+            lemma_mod_bound(u64_5_as_nat(u.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(v.limbs) as int, p() as int);
+            lemma_mod_bound(u64_5_as_nat(r_adj.limbs) as int, p() as int);
+
+            lemma_sqrt_ratio_i_cases(
+                uu,
+                vv,
+                spec_field_element(&r_before),
+                spec_field_element(&check),
+                spec_field_element(&r_adj),
+                spec_field_element(&r),
+                choice_is_true(was_nonzero_square),
+            );
+        }
+
         (was_nonzero_square, r)
     }
 
@@ -914,8 +1087,8 @@ impl FieldElement {
         Choice,
         FieldElement,
     ))
-    // VERIFICATION NOTE: PROOF BYPASS
-
+        requires
+            fe51_limbs_bounded(self, 54),
         ensures
     // When self = 0: return (false, 0)
 
@@ -930,10 +1103,66 @@ impl FieldElement {
                 self,
                 &result.1,
             ),
+            // The root is the non-negative one
+            spec_field_element(&result.1) % 2 == 0,
+            fe51_limbs_bounded(&result.1, 52),
+            // Complete case analysis, as for sqrt_ratio_i with numerator 1
+            spec_sqrt_ratio_i_cases(
+                1,
+                spec_field_element(self),
+                choice_is_true(result.0),
+                spec_field_element(&result.1),
+            ),
     {
-        assume(false);
+        proof {
+            lemma_one_limbs_bounded_51();
+            lemma_fe51_limbs_bounded_weaken(&FieldElement::ONE, 51, 54);
+            lemma_one_field_element_value();
+        }
         FieldElement::sqrt_ratio_i(&FieldElement::ONE, self)
     }
+
+    /// Attempt to compute `sqrt(self)` in constant time.
+    ///
+    /// Convenience wrapper around `sqrt_ratio_i` with denominator one.
+    ///
+    /// This function always returns the nonnegative square root.
+    ///
+    /// # Return
+    ///
+    /// - `(Choice(1), +sqrt(self))  ` if `self` is a square (including zero);
+    /// - `(Choice(0), +sqrt(i*self))` if `self` is a nonsquare;
+    ///
+    #[allow(dead_code)]  // not used by the point decompression paths, which call sqrt_ratio_i directly
+    pub(crate) fn sqrt(&self) -> (result: (Choice, FieldElement))
+        requires
+            fe51_limbs_bounded(self, 54),
+        ensures
+            choice_is_true(result.0) == math_is_square(spec_field_element(self)),
+            // When successful: r² ≡ self (mod p)
+            choice_is_true(result.0) ==> math_field_square(spec_field_element(&result.1))
+                == spec_field_element(self),
+            // The root is the non-negative one
+            !math_field_is_negative(spec_field_element(&result.1)),
+            fe51_limbs_bounded(&result.1, 52),
+    {
+        proof {
+            lemma_one_limbs_bounded_51();
+            lemma_fe51_limbs_bounded_weaken(&FieldElement::ONE, 51, 54);
+        }
+        let result = FieldElement::sqrt_ratio_i(self, &FieldElement::ONE);
+        proof {
+            lemma_one_field_element_value();
+            pow255_gt_19();
+            lemma_mod_bound(u64_5_as_nat(self.limbs) as int, p() as int);
+            lemma_sqrt_ratio_i_one_denominator(
+                spec_field_element(self),
+                choice_is_true(result.0),
+                spec_field_element(&result.1),
+            );
+        }
+        result
+    }
 }
 
 // verus!
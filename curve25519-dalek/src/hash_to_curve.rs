@@ -0,0 +1,330 @@
+//! Hashing to edwards25519 and ristretto255 as in
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html)
+//!
+//! These are the building blocks of the suites `edwards25519_XMD:SHA-512_ELL2_RO_` and
+//! `edwards25519_XMD:SHA-512_ELL2_NU_`, exposed as
+//! [`EdwardsPoint::hash_to_curve`](crate::edwards::EdwardsPoint::hash_to_curve) and
+//! [`EdwardsPoint::encode_to_curve`](crate::edwards::EdwardsPoint::encode_to_curve), and of
+//! [`RistrettoPoint::hash_to_group`](crate::ristretto::RistrettoPoint::hash_to_group):
+//!
+//! - `expand_message_xmd`: uniform bytes from a message and a domain separation tag (§5.3.1)
+//! - `expand_message_xof`: the same with an extendable-output function (§5.3.2)
+//! - `field_element_from_uniform_bytes`: one field element from 48 of those bytes (§5.2)
+//! - `map_to_curve_elligator2`: Elligator 2 to Curve25519, then the rational map to
+//!   edwards25519 (§6.7.1, §6.8.2 and Appendix D.1)
+//!
+//! Cofactor clearing is left to the callers.
+/* VERIFICATION NOTE: not verified yet. It only calls field arithmetic that is verified,
+but the Elligator 2 map and the rational map have no specs. */
+use digest::{ExtendableOutput, Update};
+use sha2::{Digest, Sha512};
+use subtle::{ConditionallyNegatable, ConditionallySelectable};
+
+use crate::constants;
+use crate::edwards::EdwardsPoint;
+use crate::field::FieldElement;
+
+/// Number of uniform bytes per field element, L = ceil((ceil(log2(p)) + 128) / 8)
+pub(crate) const FIELD_ELEMENT_BYTES: usize = 48;
+
+/// `expand_message_xmd` with SHA-512: fills `out` with bytes derived from `msg` and `dst`
+///
+/// Tags longer than 255 bytes are first hashed, as in §5.3.3.
+///
+/// # Panics
+///
+/// If `dst` is empty, or if more than 255 · 64 bytes are requested.
+pub(crate) fn expand_message_xmd(msg: &[u8], dst: &[u8], out: &mut [u8]) {
+    assert!(
+        !dst.is_empty(),
+        "the domain separation tag must not be empty"
+    );
+    let ell = (out.len() + 63) / 64;
+    assert!(ell <= 255, "expand_message_xmd: too many bytes requested");
+
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = Sha512::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = Sha512::new()
+        .chain_update([0u8; 128])
+        .chain_update(msg)
+        .chain_update((out.len() as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    let mut b_i = Sha512::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+    for (i, chunk) in out.chunks_mut(64).enumerate() {
+        if i > 0 {
+            let mut xored = [0u8; 64];
+            for ((x, a), b) in xored.iter_mut().zip(b_0.iter()).zip(b_i.iter()) {
+                *x = a ^ b;
+            }
+            b_i = Sha512::new()
+                .chain_update(xored)
+                .chain_update([(i + 1) as u8])
+                .chain_update(dst)
+                .chain_update(dst_len)
+                .finalize();
+        }
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+/// `expand_message_xof`: fills `out` with bytes derived from `msg` and `dst` by an
+/// extendable-output function such as SHAKE128
+///
+/// Tags longer than 255 bytes are first hashed to 32 bytes, as in §5.3.3 for the 128-bit
+/// security level of edwards25519 and ristretto255.
+///
+/// # Panics
+///
+/// If `dst` is empty, or if more than 65535 bytes are requested.
+pub(crate) fn expand_message_xof<X>(msg: &[u8], dst: &[u8], out: &mut [u8])
+where
+    X: Default + Update + ExtendableOutput,
+{
+    assert!(
+        !dst.is_empty(),
+        "the domain separation tag must not be empty"
+    );
+    assert!(
+        out.len() <= 0xffff,
+        "expand_message_xof: too many bytes requested"
+    );
+
+    let mut oversize_dst = [0u8; 32];
+    let dst = if dst.len() > 255 {
+        let mut h = X::default();
+        h.update(b"H2C-OVERSIZE-DST-");
+        h.update(dst);
+        h.finalize_xof_into(&mut oversize_dst);
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+
+    // H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+    let mut h = X::default();
+    h.update(msg);
+    h.update(&(out.len() as u16).to_be_bytes());
+    h.update(dst);
+    h.update(&[dst.len() as u8]);
+    h.finalize_xof_into(out);
+}
+
+/// The field element OS2IP(bytes) mod p, for 48 big-endian bytes
+pub(crate) fn field_element_from_uniform_bytes(bytes: &[u8; FIELD_ELEMENT_BYTES]) -> FieldElement {
+    // bytes = c2 || c1 || c0 in 16-byte chunks, so the value is c0 + 2^128·(c1 + 2^128·c2)
+    let chunk = |k: usize| {
+        let mut le = [0u8; 32];
+        for (j, byte) in le.iter_mut().take(16).enumerate() {
+            *byte = bytes[FIELD_ELEMENT_BYTES - 1 - 16 * k - j];
+        }
+        FieldElement::from_bytes(&le)
+    };
+    let mut two_128 = [0u8; 32];
+    two_128[16] = 1;
+    let two_128 = FieldElement::from_bytes(&two_128);
+
+    let x = &chunk(0) + &(&two_128 * &(&chunk(1) + &(&two_128 * &chunk(2))));
+    // Back to 51-bit limbs
+    FieldElement::from_bytes(&x.as_bytes())
+}
+
+/// Elligator 2 from the field element r to edwards25519
+///
+/// The point is not in the prime-order subgroup: callers clear the cofactor.
+pub(crate) fn map_to_curve_elligator2(r: &FieldElement) -> EdwardsPoint {
+    let one = FieldElement::ONE;
+    // Curve25519 is v² = g(u) = u³ + A·u² + u
+    let g = |u: &FieldElement| u * &(&(u * &(u + &constants::MONTGOMERY_A)) + &one);
+
+    // x1 = -A / (1 + 2·r²), where 1 + 2·r² is never zero since -1/2 is not a square
+    let x1 = &constants::MONTGOMERY_A_NEG * &(&one + &r.square2()).invert();
+    // x2 = -x1 - A, and g(x1)·g(x2) is not a square unless one of them is zero
+    let x2 = &(-&x1) - &constants::MONTGOMERY_A;
+    let (gx1_is_square, y1) = FieldElement::sqrt_ratio_i(&g(&x1), &one);
+    let (_, y2) = FieldElement::sqrt_ratio_i(&g(&x2), &one);
+
+    let s = FieldElement::conditional_select(&x2, &x1, gx1_is_square);
+    let mut t = FieldElement::conditional_select(&y2, &y1, gx1_is_square);
+    // sqrt_ratio_i returns the nonnegative root; the RFC wants sgn0(t) = 1 exactly for x1
+    t.conditional_negate(gx1_is_square);
+
+    // Rational map (s, t) -> (c1·s/t, (s - 1)/(s + 1)), sending the points with
+    // t·(s + 1) = 0 to the identity
+    let mut xn = &constants::ED25519_SQRTAM2 * &s;
+    let mut xd = t;
+    let mut yn = &s - &one;
+    let mut yd = &s + &one;
+    let exceptional = (&xd * &yd).is_zero();
+    xn.conditional_assign(&FieldElement::ZERO, exceptional);
+    xd.conditional_assign(&one, exceptional);
+    yn.conditional_assign(&one, exceptional);
+    yd.conditional_assign(&one, exceptional);
+
+    EdwardsPoint {
+        X: &xn * &yd,
+        Y: &xd * &yn,
+        Z: &xd * &yd,
+        T: &xn * &yn,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::edwards::CompressedEdwardsY;
+
+    const DST_RO: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+    const DST_NU: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+
+    /// The compressed point with the given big-endian affine coordinates
+    fn compressed(x: &str, y: &str) -> CompressedEdwardsY {
+        let x = hex::decode(x).unwrap();
+        let mut y = hex::decode(y).unwrap();
+        y.reverse();
+        y[31] |= (x[31] & 1) << 7;
+        CompressedEdwardsY(y.try_into().unwrap())
+    }
+
+    /// RFC 9380, Appendix J.5.1: (msg, x, y) for the RO suite
+    const RO_VECTORS: &[(&[u8], &str, &str)] = &[
+        (
+            b"",
+            "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+            "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+        ),
+        (
+            b"abc",
+            "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+            "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+        ),
+        (
+            b"abcdef0123456789",
+            "6d7fabf47a2dc03fe7d47f7dddd21082c5fb8f86743cd020f3fb147d57161472",
+            "53060a3d140e7fbcda641ed3cf42c88a75411e648a1add71217f70ea8ec561a6",
+        ),
+    ];
+
+    /// RFC 9380, Appendix J.5.2: (msg, x, y) for the NU suite
+    const NU_VECTORS: &[(&[u8], &str, &str)] = &[
+        (
+            b"",
+            "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+            "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+        ),
+        (
+            b"abc",
+            "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+            "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+        ),
+        (
+            b"abcdef0123456789",
+            "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+            "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+        ),
+    ];
+
+    #[test]
+    fn hash_to_field_vector() {
+        let mut uniform = [0u8; FIELD_ELEMENT_BYTES];
+        expand_message_xmd(b"", DST_NU, &mut uniform);
+        let mut expected =
+            hex::decode("7f3e7fb9428103ad7f52db32f9df32505d7b427d894c5093f7a0f0374a30641d")
+                .unwrap();
+        expected.reverse();
+        assert_eq!(
+            field_element_from_uniform_bytes(&uniform).as_bytes()[..],
+            expected[..]
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_vectors() {
+        for (msg, x, y) in RO_VECTORS {
+            let p = EdwardsPoint::hash_to_curve(msg, DST_RO);
+            assert_eq!(p.compress(), compressed(x, y));
+        }
+    }
+
+    #[test]
+    fn encode_to_curve_vectors() {
+        for (msg, x, y) in NU_VECTORS {
+            let p = EdwardsPoint::encode_to_curve(msg, DST_NU);
+            assert_eq!(p.compress(), compressed(x, y));
+        }
+    }
+
+    #[test]
+    fn expand_message_xof_vectors() {
+        // RFC 9380, Appendix K.6: (msg, uniform_bytes) with len_in_bytes = 0x20
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        let vectors: &[(&[u8], &str)] = &[
+            (
+                b"",
+                "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+            ),
+            (
+                b"abc",
+                "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+            ),
+        ];
+        for (msg, expected) in vectors {
+            let mut out = [0u8; 32];
+            expand_message_xof::<sha3::Shake128>(msg, dst, &mut out);
+            assert_eq!(hex::encode(out), *expected);
+        }
+    }
+
+    #[test]
+    fn hash_to_group_is_the_one_way_map() {
+        use crate::ristretto::RistrettoPoint;
+
+        let dst = b"ristretto255-test-application";
+        let mut uniform = [0u8; 64];
+        expand_message_xmd(b"abc", dst, &mut uniform);
+        assert_eq!(
+            RistrettoPoint::hash_to_group(b"abc", dst),
+            RistrettoPoint::from_uniform_bytes(&uniform)
+        );
+        assert_ne!(
+            RistrettoPoint::hash_to_group(b"abc", dst),
+            RistrettoPoint::hash_to_group(b"abc", b"another-application")
+        );
+
+        expand_message_xof::<sha3::Shake256>(b"abc", dst, &mut uniform);
+        assert_eq!(
+            RistrettoPoint::hash_to_group_xof::<sha3::Shake256>(b"abc", dst),
+            RistrettoPoint::from_uniform_bytes(&uniform)
+        );
+    }
+
+    #[test]
+    fn exceptional_inputs_map_to_identity() {
+        use crate::traits::Identity;
+        assert_eq!(
+            map_to_curve_elligator2(&FieldElement::ZERO).compress(),
+            EdwardsPoint::identity().compress()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Lemmas about `count_range`, the number of integers in a range satisfying a predicate
+//!
+//! - `lemma_count_range_split`: counts over adjacent ranges add up
+//! - `lemma_count_range_single`: exactly one r < n equals v < n
+//! - `lemma_count_range_below`: exactly l of the r < n are below l ≤ n
+//! - `lemma_count_range_periodic`: a predicate of x % n is counted k times over [0, k·n)
+#![allow(unused)]
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::prelude::*;
+
+use crate::specs::rng_specs::*;
+
+verus! {
+
+/// Lemma: count(lo, a + b) = count(lo, a) + count(lo + a, b)
+pub proof fn lemma_count_range_split(lo: nat, a: nat, b: nat, p: spec_fn(nat) -> bool)
+    ensures
+        count_range(lo, a + b, p) == count_range(lo, a, p) + count_range(lo + a, b, p),
+    decreases b,
+{
+    if b > 0 {
+        lemma_count_range_split(lo, a, (b - 1) as nat, p);
+        assert((a + b - 1) as nat == a + (b - 1) as nat);
+    }
+}
+
+/// Lemma: if q(r) holds exactly for r = v, then q holds for exactly one r < n when v < n
+/// (and none when v ≥ n)
+pub proof fn lemma_count_range_single(n: nat, v: nat, q: spec_fn(nat) -> bool)
+    requires
+        forall|r: nat| #[trigger] q(r) == (r == v),
+    ensures
+        count_range(0, n, q) == if v < n {
+            1nat
+        } else {
+            0nat
+        },
+    decreases n,
+{
+    if n > 0 {
+        lemma_count_range_single((n - 1) as nat, v, q);
+        assert(q((n - 1) as nat) == ((n - 1) as nat == v));
+    }
+}
+
+/// Lemma: if q(r) holds exactly for r < l, then q holds for min(n, l) of the r < n
+pub proof fn lemma_count_range_below(n: nat, l: nat, q: spec_fn(nat) -> bool)
+    requires
+        forall|r: nat| #[trigger] q(r) == (r < l),
+    ensures
+        count_range(0, n, q) == if n < l {
+            n
+        } else {
+            l
+        },
+    decreases n,
+{
+    if n > 0 {
+        lemma_count_range_below((n - 1) as nat, l, q);
+        assert(q((n - 1) as nat) == ((n - 1) as nat) < l);
+    }
+}
+
+/// Lemma: if p(x) only depends on x % n, a block [k·n, k·n + m) with m ≤ n is counted
+/// like [0, m)
+proof fn lemma_count_range_periodic_block(
+    k: nat,
+    n: nat,
+    m: nat,
+    p: spec_fn(nat) -> bool,
+    q: spec_fn(nat) -> bool,
+)
+    requires
+        0 < n,
+        m <= n,
+        forall|x: nat| #[trigger] p(x) == q(x % n),
+    ensures
+        count_range(k * n, m, p) == count_range(0, m, q),
+    decreases m,
+{
+    if m > 0 {
+        lemma_count_range_periodic_block(k, n, (m - 1) as nat, p, q);
+        let x = (k * n + m - 1) as nat;
+        assert(x % n == (m - 1) as nat) by {
+            lemma_mul_is_commutative(k as int, n as int);
+            lemma_mod_multiples_vanish(k as int, (m - 1) as int, n as int);
+            lemma_small_mod((m - 1) as nat, n);
+        };
+        assert(p(x) == q((m - 1) as nat));
+    }
+}
+
+/// Lemma: if p(x) only depends on x % n, as q(x % n), then p holds for k times as many
+/// x < k·n as q holds for r < n
+///
+/// ## Mathematical Proof
+/// By induction on k, splitting [0, k·n) into [0, (k-1)·n) and the block
+/// [(k-1)·n, k·n), on which x % n runs through 0, ..., n-1 once.
+pub proof fn lemma_count_range_periodic(
+    k: nat,
+    n: nat,
+    p: spec_fn(nat) -> bool,
+    q: spec_fn(nat) -> bool,
+)
+    requires
+        0 < n,
+        forall|x: nat| #[trigger] p(x) == q(x % n),
+    ensures
+        count_range(0, k * n, p) == k * count_range(0, n, q),
+    decreases k,
+{
+    if k > 0 {
+        let j = (k - 1) as nat;
+        lemma_count_range_periodic(j, n, p, q);
+        lemma_count_range_split(0, j * n, n, p);
+        lemma_count_range_periodic_block(j, n, n, p, q);
+        assert(j * n + n == k * n) by {
+            lemma_mul_is_distributive_add_other_way(n as int, j as int, 1);
+        };
+        assert(j * count_range(0, n, q) + count_range(0, n, q) == k * count_range(0, n, q)) by {
+            lemma_mul_is_distributive_add_other_way(count_range(0, n, q) as int, j as int, 1);
+        };
+    }
+}
+
+} // verus!
@@ -6,6 +6,7 @@ use vstd::prelude::*;
 
 use super::pow_lemmas::*;
 use super::shift_lemmas::*;
+use super::u128_lemmas::*;
 
 // Proofs of when masking a value fits into the number of bits used by the mask.
 macro_rules! lemma_masked_lt {
@@ -65,8 +66,13 @@ lemma_masked_lt!(
     lemma_u64_shl_is_mul,
     u64
 );
-// TODO: missing VSTD lemmas for u128
-// lemma_masked_lt!(lemma_u128_masked_lt, lemma_u128_low_bits_mask_is_mod, lemma_u128_pow2_le_max, lemma_u128_shl_is_mul, u128);
+lemma_masked_lt!(
+    lemma_u128_masked_lt,
+    lemma_u128_low_bits_mask_is_mod,
+    lemma_u128_pow2_le_max,
+    lemma_u128_shl_is_mul,
+    u128
+);
 
 // Proofs of when k <= N => 2^k - 1 <= uN::MAX = 2^N - 1
 macro_rules! lemma_low_bits_masks_fit {
@@ -94,8 +100,7 @@ lemma_low_bits_masks_fit!(lemma_u8_low_bits_masks_fit, u8);
 lemma_low_bits_masks_fit!(lemma_u16_low_bits_masks_fit, u16);
 lemma_low_bits_masks_fit!(lemma_u32_low_bits_masks_fit, u32);
 lemma_low_bits_masks_fit!(lemma_u64_low_bits_masks_fit, u64);
-// TODO
-// lemma_low_bits_masks_fit!(lemma_u128_low_bits_masks_fit, u128);
+lemma_low_bits_masks_fit!(lemma_u128_low_bits_masks_fit, u128);
 
 verus! {
 
@@ -123,4 +128,34 @@ pub proof fn lemma_low_bits_mask_increases(a: nat, b: nat)
 
 }
 
+/// The branch-free absolute value `xabs = (x + xmask) ^ xmask`, with the sign
+/// mask `xmask = x >> 7` computed in i16 (as in `LookupTable::select`)
+///
+/// xmask is all ones for negative x and zero otherwise, so the XOR either
+/// undoes the two's complement `x - 1` or leaves x unchanged.
+pub proof fn lemma_i8_abs_by_sign_mask(x: i8, xmask: i16, xabs: i16)
+    requires
+        xmask == (x as i16) >> 7,
+        xabs == ((x as i16) + xmask) ^ xmask,
+    ensures
+        x < 0 ==> xmask == -1 && xabs == -x && (xmask & 1) == 1,
+        x >= 0 ==> xmask == 0 && xabs == x && (xmask & 1) == 0,
+{
+    let x16 = x as i16;
+    assert(x16 < 0 ==> x16 >> 7 == -1i16) by (bit_vector)
+        requires
+            -128i16 <= x16 <= 127i16,
+    ;
+    assert(x16 >= 0 ==> x16 >> 7 == 0i16) by (bit_vector)
+        requires
+            -128i16 <= x16 <= 127i16,
+    ;
+    assert(x16 < 0 ==> (x16 + -1i16) ^ -1i16 == -x16) by (bit_vector)
+        requires
+            -128i16 <= x16 <= 127i16,
+    ;
+    assert((x16 + 0i16) ^ 0i16 == x16) by (bit_vector);
+    assert(((-1i16) & 1i16) == 1i16 && (0i16 & 1i16) == 0i16) by (bit_vector);
+}
+
 } // verus!
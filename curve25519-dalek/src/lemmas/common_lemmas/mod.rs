@@ -1,5 +1,7 @@
 pub mod bit_lemmas;
 
+pub mod counting_lemmas;
+
 pub mod div_mod_lemmas;
 
 pub mod mask_lemmas;
@@ -15,3 +17,5 @@ pub mod shift_lemmas;
 pub mod sum_lemmas;
 
 pub mod to_nat_lemmas;
+
+pub mod u128_lemmas;
@@ -7,6 +7,7 @@ use vstd::prelude::*;
 
 use super::mul_lemmas::*;
 use super::pow_lemmas::*;
+use super::u128_lemmas::*;
 
 verus! {
 
@@ -42,8 +43,8 @@ lemma_shift_is_pow2!(lemma_u32_shift_is_pow2, lemma_u32_pow2_le_max, lemma_u32_s
 
 lemma_shift_is_pow2!(lemma_u64_shift_is_pow2, lemma_u64_pow2_le_max, lemma_u64_shl_is_mul, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_shift_is_pow2!(lemma_u128_shift_is_pow2, lemma_u128_pow2_le_max, lemma_u128_shl_is_mul, u128);
+lemma_shift_is_pow2!(lemma_u128_shift_is_pow2, lemma_u128_pow2_le_max, lemma_u128_shl_is_mul, u128);
+
 // Proofs that left-shift by 0 is a no-op
 macro_rules! lemma_shl_zero_is_id {
     ($name:ident, $uN:ty) => {
@@ -123,8 +124,8 @@ lemma_shl_by_sum!(lemma_u32_shl_by_sum, lemma_u32_shl_is_mul, u32);
 
 lemma_shl_by_sum!(lemma_u64_shl_by_sum, lemma_u64_shl_is_mul, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_shl_by_sum!(lemma_u128_shl_by_sum, lemma_u128_shl_is_mul, u128);
+lemma_shl_by_sum!(lemma_u128_shl_by_sum, lemma_u128_shl_is_mul, u128);
+
 // Proofs that [<<] preserves [<=]
 macro_rules! lemma_shl_le {
     ($name:ident, $shl_is_mul:ident, $uN:ty) => {
@@ -157,8 +158,8 @@ lemma_shl_le!(lemma_u32_shl_le, lemma_u32_shl_is_mul, u32);
 
 lemma_shl_le!(lemma_u64_shl_le, lemma_u64_shl_is_mul, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_shl_le!(lemma_u128_shl_le, lemma_u128_shl_is_mul, u128);
+lemma_shl_le!(lemma_u128_shl_le, lemma_u128_shl_is_mul, u128);
+
 // Proofs that if a <= b then v << a <= v << b (up to overflow)
 macro_rules! lemma_shl_nondecreasing {
     ($name:ident, $shl_by_sum:ident, $shl_is_mul:ident, $shl_le:ident, $uN:ty) => {
@@ -231,8 +232,8 @@ lemma_shl_nondecreasing!(lemma_u32_shl_nondecreasing, lemma_u32_shl_by_sum, lemm
 
 lemma_shl_nondecreasing!(lemma_u64_shl_nondecreasing, lemma_u64_shl_by_sum, lemma_u64_shl_is_mul, lemma_u64_shl_le, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_shl_nondecreasing!(lemma_u128_shl_nondecreasing, lemma_u128_shl_by_sum, lemma_u128_shl_is_mul, lemma_u128_shl_le, u128);
+lemma_shl_nondecreasing!(lemma_u128_shl_nondecreasing, lemma_u128_shl_by_sum, lemma_u128_shl_is_mul, lemma_u128_shl_le, u128);
+
 // Proofs that right-shift by 0 is a no-op
 macro_rules! lemma_shr_zero_is_id {
     ($name:ident, $uN:ty) => {
@@ -530,16 +531,16 @@ lemma_max_shifting!(lemma_u64_max_shifting,
     u64
 );
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_max_shifting!(lemma_u128_max_shifting,
-//     lemma_u128_shr_by_sum,
-//     lemma_u128_pow2_le_max, // MISSING
-//     lemma_u128_shl_by_sum, // MISSING
-//     lemma_u128_shr_is_div,
-//     lemma_u128_shift_is_pow2, // MISSING
-//     lemma_u128_shl_is_mul, // MISSING
-//     u128
-// );
+lemma_max_shifting!(lemma_u128_max_shifting,
+    lemma_u128_shr_by_sum,
+    lemma_u128_pow2_le_max,
+    lemma_u128_shl_by_sum,
+    lemma_u128_shr_is_div,
+    lemma_u128_shift_is_pow2,
+    lemma_u128_shl_is_mul,
+    u128
+);
+
 // Corollary of lemma_max_shifting, since for any
 // v: uN it holds that v <= uN::MAX and >> preserves [<=]
 macro_rules! lemma_shifted_lt {
@@ -575,8 +576,8 @@ lemma_shifted_lt!(lemma_u32_shifted_lt, lemma_u32_shl_zero_is_id, lemma_u32_shr_
 
 lemma_shifted_lt!(lemma_u64_shifted_lt, lemma_u64_shl_zero_is_id, lemma_u64_shr_le, lemma_u64_max_shifting, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_shifted_lt!(lemma_u128_shifted_lt, lemma_u128_shl_zero_is_id, lemma_u128_shr_le, lemma_u128_max_shifting, u128);
+lemma_shifted_lt!(lemma_u128_shifted_lt, lemma_u128_shl_zero_is_id, lemma_u128_shr_le, lemma_u128_max_shifting, u128);
+
 // Proofs that shifting left then right is the same as shifting left once by the difference.
 macro_rules! lemma_left_right_shift {
     ($name:ident, $shl_is_mul:ident, $shr_is_div:ident, $uN:ty) => {
@@ -634,6 +635,6 @@ lemma_left_right_shift!(lemma_u32_left_right_shift, lemma_u32_shl_is_mul, lemma_
 
 lemma_left_right_shift!(lemma_u64_left_right_shift, lemma_u64_shl_is_mul, lemma_u64_shr_is_div, u64);
 
-// TODO: missing lemma_u128_shl_is_mul from vstd
-// lemma_left_right_shift!(lemma_u128_left_right_shift, lemma_u128_shl_is_mul, lemma_u128_shr_is_div, u128);
+lemma_left_right_shift!(lemma_u128_left_right_shift, lemma_u128_shl_is_mul, lemma_u128_shr_is_div, u128);
+
 } // verus!
@@ -5,6 +5,7 @@
 //! - Connecting Horner form (`bytes_seq_to_nat`) to direct sum form (`bytes_to_nat_prefix`)
 //! - Byte extraction and injectivity of `bytes32_to_nat`
 //! - Word-to-nat conversions for 64-bit words
+//! - Little-endian vs. big-endian bit arrays (`bits_to_nat`, `bits_be_to_nat`)
 #![allow(unused)]
 use vstd::arithmetic::div_mod::*;
 use vstd::arithmetic::mul::*;
@@ -122,6 +123,38 @@ pub proof fn lemma_bytes_seq_to_nat_equals_prefix(seq: Seq<u8>)
     }
 }
 
+/// Lemma: the value of a concatenation is the value of the low part plus the
+/// high part shifted past it.
+///
+/// Proves: bytes_seq_to_nat(lo + hi) == bytes_seq_to_nat(lo) + 2^(8·|lo|) * bytes_seq_to_nat(hi)
+///
+/// Used to split a long input into 32-byte chunks for Horner evaluation.
+pub proof fn lemma_bytes_seq_to_nat_concat(lo: Seq<u8>, hi: Seq<u8>)
+    ensures
+        bytes_seq_to_nat(lo + hi) == bytes_seq_to_nat(lo) + pow2((lo.len() * 8) as nat)
+            * bytes_seq_to_nat(hi),
+    decreases lo.len(),
+{
+    if lo.len() == 0 {
+        assert(lo + hi =~= hi);
+        lemma2_to64();
+    } else {
+        let tail = lo.skip(1);
+        assert((lo + hi).skip(1) =~= tail + hi);
+        assert((lo + hi)[0] == lo[0]);
+        lemma_bytes_seq_to_nat_concat(tail, hi);
+
+        // 2^(8·|lo|) = 2^8 · 2^(8·|tail|)
+        let p = pow2((tail.len() * 8) as nat);
+        lemma_pow2_adds(8, (tail.len() * 8) as nat);
+        assert(8 + tail.len() * 8 == lo.len() * 8);
+
+        let a = bytes_seq_to_nat(tail);
+        let b = bytes_seq_to_nat(hi);
+        assert(pow2(8) * (a + p * b) == pow2(8) * a + (pow2(8) * p) * b) by (nonlinear_arith);
+    }
+}
+
 /// Shows one step of the Horner-to-prefix conversion.
 ///
 /// Proves: seq[0] + 256 * prefix(tail, k) == prefix(seq, k+1)
@@ -665,6 +698,29 @@ proof fn lemma_bytes32_to_nat_rec_bound(bytes: &[u8; 32], start: usize, target:
     }
 }
 
+/// The bytes from index n on are all zero iff bytes32_to_nat is below 2^(n*8).
+/// Used to decide whether a scalar fits in a u64 or u128.
+pub proof fn lemma_bytes32_high_bytes_zero_iff_lt(bytes: &[u8; 32], n: nat)
+    requires
+        n <= 32,
+    ensures
+        (forall|i: int| n <= i < 32 ==> bytes[i] == 0) <==> bytes32_to_nat(bytes) < pow2(n * 8),
+{
+    if forall|i: int| n <= i < 32 ==> bytes[i] == 0 {
+        lemma_bytes32_to_nat_with_trailing_zeros(bytes, n);
+        lemma_bytes_to_nat_prefix_bounded(bytes@, n);
+    } else {
+        // Some byte j >= n is non-zero, so the value is at least 2^(j*8) >= 2^(n*8)
+        let j = choose|j: int| n <= j < 32 && bytes[j] != 0;
+        lemma_bytes32_to_nat_lower_bound(bytes, j as usize);
+        lemma_pow2_pos((j * 8) as nat);
+        lemma_mul_increases(bytes[j] as int, pow2((j * 8) as nat) as int);
+        if n < j {
+            lemma_pow2_strictly_increases(n * 8, (j * 8) as nat);
+        }
+    }
+}
+
 // ============================================================================
 // Bridge lemmas: connecting different byte-to-nat representations
 // ============================================================================
@@ -816,4 +872,61 @@ pub proof fn lemma_words64_from_bytes_to_nat_wide(bytes: &[u8; 64])
     };
 }
 
+// ============================================================================
+// Bit array to nat conversion
+// ============================================================================
+/// A set top bit contributes its full weight: bits[255] ==> bits_to_nat_rec(bits, i) >= 2^255
+pub proof fn lemma_bits_to_nat_rec_top_bit(bits: &[bool; 256], index: int)
+    requires
+        0 <= index <= 255,
+    ensures
+        bits[255] ==> bits_to_nat_rec(bits, index) >= pow2(255),
+    decreases 255 - index,
+{
+    if index < 255 {
+        lemma_bits_to_nat_rec_top_bit(bits, index + 1);
+    } else {
+        assert(bits_to_nat_rec(bits, 256) == 0);
+    }
+}
+
+/// Reading the low 255 little-endian bits backwards as big-endian gives the same value
+///
+/// With be[i] = le[254 - i] and le[255] clear, for 0 <= len <= 255:
+/// ```text
+/// bits_to_nat_rec(le, 255 - len) = 2^(255 - len) · bits_be_to_nat(be, len)
+/// ```
+/// so len = 255 gives bits_to_nat(le) = bits_be_to_nat(be, 255).
+pub proof fn lemma_bits_be_reversed_to_nat(le: &[bool; 256], be: &[bool], len: int)
+    requires
+        0 <= len <= 255,
+        be.len() == 255,
+        !le[255],
+        forall|i: int| 0 <= i < 255 ==> #[trigger] be[i] == le[254 - i],
+    ensures
+        bits_to_nat_rec(le, 255 - len) == pow2((255 - len) as nat) * bits_be_to_nat(be, len),
+    decreases len,
+{
+    if len == 0 {
+        assert(bits_to_nat_rec(le, 256) == 0);
+        assert(bits_to_nat_rec(le, 255) == 0);
+    } else {
+        // The bit added at the bottom of the big-endian prefix is le[k]
+        let k = 255 - len;
+        lemma_bits_be_reversed_to_nat(le, be, len - 1);
+        assert(be[len - 1] == le[k]);
+        let b: nat = if le[k] {
+            1
+        } else {
+            0
+        };
+        let rest = bits_be_to_nat(be, len - 1);
+        // rec(k) = b·2^k + 2^(k+1)·rest = 2^k·(b + 2·rest)
+        lemma2_to64();
+        lemma_pow2_adds(k as nat, 1);
+        let pk = pow2(k as nat);
+        assert(pk * (b + 2 * rest) == b * pk + (pk * 2) * rest) by (nonlinear_arith);
+    }
+}
+
 } // verus!
@@ -0,0 +1,72 @@
+//! u128 counterparts of the vstd::bits lemmas that only exist for u8..u64.
+//!
+//! The field and scalar multiplications accumulate limb products in u128, so
+//! the carry chains need the same shift/mask <-> arithmetic bridges as the u64
+//! code. With these two in place, the u128 instances of the generic macros in
+//! shift_lemmas, mask_lemmas and div_mod_lemmas are available as well
+//! (`lemma_u128_shl_by_sum`, `lemma_u128_masked_lt`, `lemma_u128_div_and_mod`, ...).
+#![allow(unused)]
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power2::*;
+use vstd::bits::*;
+use vstd::prelude::*;
+
+use super::pow_lemmas::*;
+
+verus! {
+
+/// Left shift is multiplication by a power of two, as long as nothing overflows
+pub broadcast proof fn lemma_u128_shl_is_mul(x: u128, shift: u128)
+    requires
+        0 <= shift < <u128>::BITS,
+        x * pow2(shift as nat) <= <u128>::MAX,
+    ensures
+        #[trigger] (x << shift) == x * pow2(shift as nat),
+    decreases shift,
+{
+    if shift == 0 {
+        assert(x << 0 == x) by (bit_vector);
+        assert(x * pow2(0) == x) by {
+            lemma2_to64();
+        }
+    } else {
+        let s1 = (shift - 1) as u128;
+        assert(x << shift == mul(x << s1, 2)) by (bit_vector)
+            requires
+                0 < shift < 128,
+                s1 == shift - 1,
+        ;
+        // x * 2^shift = (x * 2^(shift-1)) * 2
+        assert(x * pow2(shift as nat) == (x * pow2(s1 as nat)) * 2) by {
+            lemma_pow2_unfold(shift as nat);
+            lemma_mul_is_associative(x as int, pow2(s1 as nat) as int, 2);
+            lemma_mul_is_commutative(pow2(s1 as nat) as int, 2);
+        }
+        lemma_u128_shl_is_mul(x, s1);
+    }
+}
+
+/// Masking with the low `n` bits is reduction modulo 2^n
+pub proof fn lemma_u128_low_bits_mask_is_mod(x: u128, n: nat)
+    requires
+        n < <u128>::BITS,
+    ensures
+        x & (low_bits_mask(n) as u128) == x % (pow2(n) as u128),
+{
+    let s = n as u128;
+    assert(0 < pow2(n) <= <u128>::MAX) by {
+        lemma_pow2_pos(n);
+        lemma_u128_pow2_le_max(n);
+    }
+    assert(1u128 << s == pow2(n)) by {
+        lemma_mul_basics_4(pow2(n) as int);
+        lemma_u128_shl_is_mul(1u128, s);
+    }
+    assert(x & (sub(1u128 << s, 1u128)) == x % (1u128 << s)) by (bit_vector)
+        requires
+            s < 128,
+    ;
+}
+
+} // verus!
@@ -0,0 +1,700 @@
+//! Lemmas for Edwards point addition and doubling
+//!
+//! `EdwardsPoint + EdwardsPoint` converts the right operand to a `ProjectiveNielsPoint`
+//! (Y+X, Y-X, Z, 2d·T) and evaluates the unified formula of [HWCD2008] Section 3.1:
+//!
+//! ```text
+//! PP = (Y1+X1)·(Y2+X2)      MM = (Y1-X1)·(Y2-X2)
+//! TT2d = T1·2d·T2           ZZ2 = 2·Z1·Z2
+//! (X3 : Y3 : Z3 : T3) = (PP - MM : PP + MM : ZZ2 + TT2d : ZZ2 - TT2d)
+//! ```
+//!
+//! The result is a `CompletedPoint` ((X3:Z3), (Y3:T3)). This module proves that it
+//! represents `edwards_add` of the two affine points.
+//!
+//! ## Mathematical Proof Sketch
+//!
+//! With affine coordinates xᵢ = Xᵢ/Zᵢ, yᵢ = Yᵢ/Zᵢ and Tᵢ = Xᵢ·Yᵢ/Zᵢ = xᵢ·yᵢ·Zᵢ:
+//! ```text
+//! PP - MM = 2·(X1·Y2 + Y1·X2) = 2·Z1·Z2·(x1·y2 + y1·x2)
+//! PP + MM = 2·(Y1·Y2 + X1·X2) = 2·Z1·Z2·(y1·y2 + x1·x2)
+//! ZZ2 ± TT2d = 2·Z1·Z2 ± 2d·T1·T2 = 2·Z1·Z2·(1 ± d·x1·x2·y1·y2)
+//! ```
+//! The common factor k = 2·Z1·Z2 is non-zero and cancels in X3/Z3 and Y3/T3.
+//! Because inv(0) = 0 on both sides, no completeness assumption is needed here.
+//!
+//! ## Doubling
+//!
+//! `ProjectivePoint::double` evaluates the a = -1 doubling formula of [BBJLP2008]:
+//! ```text
+//! (X3 : Y3 : Z3 : T3) = ((X+Y)² - (Y²+X²) : Y²+X² : Y²-X² : 2Z² - (Y²-X²))
+//! ```
+//! Here 2XY/(Y²-X²) and (Y²+X²)/(2Z²-Y²+X²) only match `edwards_add(P, P)` after
+//! rewriting y² - x² = 1 + d·x²·y² with the curve equation, so the input must be on
+//! the curve.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_edwards_add_extended_formula`: the keystone group-law lemma
+//! - `lemma_projective_double_formula`: the doubling formula computes `edwards_double`
+//! - `lemma_double_is_self_add`: `double(P)` and `P + P` represent the same point
+//! - `lemma_niels_sum_difference`: PP ∓ MM = 2·(X1·Y2 ± ...)
+//! - `lemma_affine_times_z`: (X/Z)·Z = X
+//! - `lemma_field_cancel_factor`: (Z·W)/Z = W for Z ≠ 0
+//! - `lemma_field_mul_rearrange_4`: (a·b)·(c·e) = (b·e)·(a·c)
+//! - `lemma_field_common_factor`: (k·n)/(k·e) = n/e for k ≠ 0
+//! - `lemma_field_mul_neg`, `lemma_field_neg_mul`, `lemma_field_neg_add`: negation
+//!   commutes with products and sums
+//! - `lemma_field_sub_antisymmetric`: a - b = -(b - a)
+#![allow(unused_imports)]
+use crate::backend::serial::u64::constants::EDWARDS_D;
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::edwards::EdwardsPoint;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::lemmas::field_lemmas::field_theory_lemmas::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Field helpers
+// =============================================================================
+/// Lemma: math_field_sub as an integer residue
+pub proof fn lemma_field_sub_as_int(a: nat, b: nat)
+    ensures
+        math_field_sub(a, b) as int == (a as int - b as int) % (p() as int),
+{
+    let p = p() as int;
+    p_gt_2();
+    lemma_mod_add_multiples_vanish((a % p()) as int - (b % p()) as int, p);
+    lemma_sub_mod_noop(a as int, b as int, p);
+}
+
+/// Lemma: math_field_neg as an integer residue
+pub proof fn lemma_field_neg_as_int(a: nat)
+    ensures
+        math_field_neg(a) as int == (-(a as int)) % (p() as int),
+{
+    let p = p() as int;
+    p_gt_2();
+    lemma_mod_bound(a as int, p);
+    lemma_mod_add_multiples_vanish(-((a % p()) as int), p);
+    lemma_sub_mod_noop(0, a as int, p);
+    lemma_small_mod(0, p());
+}
+
+/// Lemma: a·(-b) = -(a·b) in the field
+pub proof fn lemma_field_mul_neg(a: nat, b: nat)
+    ensures
+        math_field_mul(a, math_field_neg(b)) == math_field_neg(math_field_mul(a, b)),
+{
+    let p = p() as int;
+    p_gt_2();
+    let (ai, bi) = (a as int, b as int);
+    lemma_field_neg_as_int(b);
+    lemma_field_neg_as_int(math_field_mul(a, b));
+    // a·((-b) mod p) ≡ a·(-b) = -(a·b) ≡ -((a·b) mod p)
+    lemma_mul_mod_noop_right(ai, -bi, p);
+    lemma_mul_unary_negation(ai, bi);
+    lemma_sub_mod_noop(0, ai * bi, p);
+    lemma_small_mod(0, p());
+}
+
+/// Lemma: (-a)·b = -(a·b) in the field
+pub proof fn lemma_field_neg_mul(a: nat, b: nat)
+    ensures
+        math_field_mul(math_field_neg(a), b) == math_field_neg(math_field_mul(a, b)),
+{
+    lemma_field_mul_comm(math_field_neg(a), b);
+    lemma_field_mul_neg(b, a);
+    lemma_field_mul_comm(b, a);
+}
+
+/// Lemma: -(-a) = a for reduced a
+pub proof fn lemma_field_neg_neg(a: nat)
+    requires
+        a < p(),
+    ensures
+        math_field_neg(math_field_neg(a)) == a,
+{
+    p_gt_2();
+    lemma_field_neg_as_int(a);
+    lemma_field_neg_as_int(math_field_neg(a));
+    lemma_sub_mod_noop(0, -(a as int), p() as int);
+    lemma_small_mod(0, p());
+    lemma_small_mod(a, p());
+}
+
+/// Lemma: (-a) + (-b) = -(a + b) in the field
+pub proof fn lemma_field_neg_add(a: nat, b: nat)
+    ensures
+        math_field_add(math_field_neg(a), math_field_neg(b)) == math_field_neg(
+            math_field_add(a, b),
+        ),
+{
+    let p = p() as int;
+    p_gt_2();
+    lemma_field_neg_as_int(a);
+    lemma_field_neg_as_int(b);
+    lemma_field_neg_as_int(math_field_add(a, b));
+    lemma_add_mod_noop(-(a as int), -(b as int), p);
+    lemma_sub_mod_noop(0, a as int + b as int, p);
+    lemma_small_mod(0, p());
+}
+
+/// Lemma: a - b = -(b - a) in the field
+pub proof fn lemma_field_sub_antisymmetric(a: nat, b: nat)
+    ensures
+        math_field_sub(a, b) == math_field_neg(math_field_sub(b, a)),
+{
+    let p = p() as int;
+    p_gt_2();
+    lemma_field_sub_as_int(a, b);
+    lemma_field_sub_as_int(b, a);
+    lemma_field_neg_as_int(math_field_sub(b, a));
+    lemma_sub_mod_noop(0, b as int - a as int, p);
+    lemma_small_mod(0, p());
+}
+
+/// Lemma: (a·b)·(c·e) = (b·e)·(a·c) in the field
+pub proof fn lemma_field_mul_rearrange_4(a: nat, b: nat, c: nat, e: nat)
+    ensures
+        math_field_mul(math_field_mul(a, b), math_field_mul(c, e)) == math_field_mul(
+            math_field_mul(b, e),
+            math_field_mul(a, c),
+        ),
+{
+    let p = p() as int;
+    p_gt_2();
+    let (a, b, c, e) = (a as int, b as int, c as int, e as int);
+    // Both sides reduce to the raw product of the four factors
+    lemma_mul_mod_noop(a * b, c * e, p);
+    lemma_mul_mod_noop(b * e, a * c, p);
+    assert((a * b) * (c * e) == (b * e) * (a * c)) by (nonlinear_arith);
+}
+
+/// Lemma: (X/Z)·Z = X for reduced X and Z ≢ 0
+pub proof fn lemma_affine_times_z(x: nat, z: nat)
+    requires
+        x < p(),
+        z % p() != 0,
+    ensures
+        math_field_mul(math_field_mul(x, math_field_inv(z)), z) == x,
+{
+    p_gt_2();
+    let z_inv = math_field_inv(z);
+    // (x · inv(z)) · z = x · (inv(z) · z) = x · 1 = x
+    lemma_field_mul_assoc(x, z_inv, z);
+    lemma_field_mul_comm(z_inv, z);
+    lemma_field_mul_inv(z);
+    lemma_small_mod(x, p());
+}
+
+/// Lemma: (Z·W)/Z = W for Z ≢ 0
+pub proof fn lemma_field_cancel_factor(z: nat, w: nat)
+    requires
+        z % p() != 0,
+    ensures
+        math_field_mul(math_field_mul(z, w), math_field_inv(z)) == w % p(),
+{
+    p_gt_2();
+    let z_inv = math_field_inv(z);
+    // (z·w)·inv(z) = (w·z)·inv(z) = w·(z·inv(z)) = w·1
+    lemma_field_mul_comm(z, w);
+    lemma_field_mul_assoc(w, z, z_inv);
+    lemma_field_mul_inv(z);
+}
+
+/// Lemma: (k·n) / (k·e) = n / e for k ≢ 0
+///
+/// ## Mathematical Proof
+/// ```text
+/// (k·n) · inv(k·e) = (k·n) · (inv(k)·inv(e))   [lemma_inv_of_product]
+///                  = (n·inv(e)) · (k·inv(k))   [rearrangement]
+///                  = n·inv(e)
+/// ```
+pub proof fn lemma_field_common_factor(k: nat, n: nat, e: nat)
+    requires
+        k % p() != 0,
+    ensures
+        math_field_mul(math_field_mul(k, n), math_field_inv(math_field_mul(k, e)))
+            == math_field_mul(n, math_field_inv(e)),
+{
+    let p = p();
+    p_gt_2();
+    let k_inv = math_field_inv(k);
+    let e_inv = math_field_inv(e);
+    let q = math_field_mul(n, e_inv);
+
+    lemma_inv_of_product(k, e);
+    lemma_field_mul_rearrange_4(k, n, k_inv, e_inv);
+    lemma_field_mul_inv(k);
+
+    // q · 1 = q since q is already reduced
+    assert(math_field_mul(q, 1) == q) by {
+        lemma_mod_bound((n * e_inv) as int, p as int);
+        lemma_small_mod(q, p);
+    };
+}
+
+// =============================================================================
+// Niels products
+// =============================================================================
+/// Lemma: the PP/MM products of the Niels addition formula
+///
+/// ## Mathematical Proof
+/// ```text
+/// (y1+x1)(y2+x2) - (y1-x1)(y2-x2) = 2·(x1·y2 + y1·x2)
+/// (y1+x1)(y2+x2) + (y1-x1)(y2-x2) = 2·(y1·y2 + x1·x2)
+/// ```
+/// Each field operation is congruent to its integer counterpart, so the
+/// integer identities carry over mod p.
+pub proof fn lemma_niels_sum_difference(x1: nat, y1: nat, x2: nat, y2: nat)
+    ensures
+        ({
+            let pp = math_field_mul(math_field_add(y1, x1), math_field_add(y2, x2));
+            let mm = math_field_mul(math_field_sub(y1, x1), math_field_sub(y2, x2));
+            &&& math_field_sub(pp, mm) == math_field_mul(
+                2,
+                math_field_add(math_field_mul(x1, y2), math_field_mul(y1, x2)),
+            )
+            &&& math_field_add(pp, mm) == math_field_mul(
+                2,
+                math_field_add(math_field_mul(y1, y2), math_field_mul(x1, x2)),
+            )
+        }),
+{
+    let p = p() as int;
+    p_gt_2();
+    let pp = math_field_mul(math_field_add(y1, x1), math_field_add(y2, x2));
+    let mm = math_field_mul(math_field_sub(y1, x1), math_field_sub(y2, x2));
+    let (x1i, y1i, x2i, y2i) = (x1 as int, y1 as int, x2 as int, y2 as int);
+    let big_p = (y1i + x1i) * (y2i + x2i);
+    let big_m = (y1i - x1i) * (y2i - x2i);
+    let cross = x1i * y2i + y1i * x2i;
+    let dot = y1i * y2i + x1i * x2i;
+
+    // pp ≡ (y1+x1)(y2+x2)
+    assert(pp as int == big_p % p) by {
+        lemma_mul_mod_noop(y1i + x1i, y2i + x2i, p);
+    };
+
+    // mm ≡ (y1-x1)(y2-x2)
+    assert(mm as int == big_m % p) by {
+        lemma_field_sub_as_int(y1, x1);
+        lemma_field_sub_as_int(y2, x2);
+        lemma_mul_mod_noop(y1i - x1i, y2i - x2i, p);
+    };
+
+    assert(big_p - big_m == 2 * cross && big_p + big_m == 2 * dot) by (nonlinear_arith)
+        requires
+            big_p == (y1i + x1i) * (y2i + x2i),
+            big_m == (y1i - x1i) * (y2i - x2i),
+            cross == x1i * y2i + y1i * x2i,
+            dot == y1i * y2i + x1i * x2i,
+    ;
+
+    // Difference: (pp - mm) % p = (big_p - big_m) % p
+    assert(math_field_sub(pp, mm) as int == (2 * cross) % p) by {
+        lemma_field_sub_as_int(pp, mm);
+        lemma_sub_mod_noop(big_p, big_m, p);
+    };
+    assert(math_field_mul(2, math_field_add(math_field_mul(x1, y2), math_field_mul(y1, x2))) as int
+        == (2 * cross) % p) by {
+        lemma_add_mod_noop(x1i * y2i, y1i * x2i, p);
+        lemma_mul_mod_noop_right(2, cross, p);
+    };
+
+    // Sum: (pp + mm) % p = (big_p + big_m) % p
+    assert(math_field_add(pp, mm) as int == (2 * dot) % p) by {
+        lemma_add_mod_noop(big_p, big_m, p);
+    };
+    assert(math_field_mul(2, math_field_add(math_field_mul(y1, y2), math_field_mul(x1, x2))) as int
+        == (2 * dot) % p) by {
+        lemma_add_mod_noop(y1i * y2i, x1i * x2i, p);
+        lemma_mul_mod_noop_right(2, dot, p);
+    };
+}
+
+// =============================================================================
+// Group law
+// =============================================================================
+/// Lemma: the extended-coordinate addition formula computes the Edwards group law
+///
+/// P1 = (X1:Y1:Z1:T1) and P2 = (X2:Y2:Z2:T2) are valid extended points; P2 enters
+/// through its Niels form (Y2+X2, Y2-X2, Z2, 2d·T2). The completed point
+/// ((X3:Z3), (Y3:T3)) computed by `&EdwardsPoint + &ProjectiveNielsPoint` then
+/// represents `edwards_add` of the affine points (X1/Z1, Y1/Z1) and (X2/Z2, Y2/Z2).
+///
+/// See the module documentation for the proof sketch.
+pub proof fn lemma_edwards_add_extended_formula(
+    x1: nat,
+    y1: nat,
+    z1: nat,
+    t1: nat,
+    x2: nat,
+    y2: nat,
+    z2: nat,
+    t2: nat,
+)
+    requires
+        x1 < p(),
+        y1 < p(),
+        z1 < p(),
+        x2 < p(),
+        y2 < p(),
+        z2 < p(),
+        math_is_valid_extended_edwards_point(x1, y1, z1, t1),
+        math_is_valid_extended_edwards_point(x2, y2, z2, t2),
+    ensures
+        ({
+            let d = spec_field_element(&EDWARDS_D);
+            let pp = math_field_mul(math_field_add(y1, x1), math_field_add(y2, x2));
+            let mm = math_field_mul(math_field_sub(y1, x1), math_field_sub(y2, x2));
+            let tt2d = math_field_mul(t1, math_field_mul(math_field_mul(2, d), t2));
+            let zz = math_field_mul(z1, z2);
+            let zz2 = math_field_add(zz, zz);
+            let x3 = math_field_sub(pp, mm);
+            let y3 = math_field_add(pp, mm);
+            let z3 = math_field_add(zz2, tt2d);
+            let t3 = math_field_sub(zz2, tt2d);
+            (math_field_mul(x3, math_field_inv(z3)), math_field_mul(y3, math_field_inv(t3)))
+                == edwards_add(
+                math_field_mul(x1, math_field_inv(z1)),
+                math_field_mul(y1, math_field_inv(z1)),
+                math_field_mul(x2, math_field_inv(z2)),
+                math_field_mul(y2, math_field_inv(z2)),
+            )
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+
+    // Affine coordinates
+    let a1 = math_field_mul(x1, math_field_inv(z1));
+    let b1 = math_field_mul(y1, math_field_inv(z1));
+    let a2 = math_field_mul(x2, math_field_inv(z2));
+    let b2 = math_field_mul(y2, math_field_inv(z2));
+
+    // Formula intermediates
+    let pp = math_field_mul(math_field_add(y1, x1), math_field_add(y2, x2));
+    let mm = math_field_mul(math_field_sub(y1, x1), math_field_sub(y2, x2));
+    let tt2d = math_field_mul(t1, math_field_mul(math_field_mul(2, d), t2));
+    let zz = math_field_mul(z1, z2);
+    let zz2 = math_field_add(zz, zz);
+    let x3 = math_field_sub(pp, mm);
+    let y3 = math_field_add(pp, mm);
+    let z3 = math_field_add(zz2, tt2d);
+    let t3 = math_field_sub(zz2, tt2d);
+
+    // edwards_add intermediates
+    let nx = math_field_add(math_field_mul(a1, b2), math_field_mul(b1, a2));
+    let ny = math_field_add(math_field_mul(b1, b2), math_field_mul(a1, a2));
+    let t = math_field_mul(d, math_field_mul(math_field_mul(a1, a2), math_field_mul(b1, b2)));
+
+    // Common factor k = 2·Z1·Z2 ≠ 0
+    let k = math_field_mul(2, zz);
+    assert(k % p != 0) by {
+        lemma_small_mod(z1, p);
+        lemma_small_mod(z2, p);
+        lemma_field_mul_nonzero(z1, z2);
+        lemma_small_mod(2, p);
+        lemma_field_mul_nonzero(2, zz);
+    };
+
+    // Step 1: Xi = xi·Zi and Yi = yi·Zi
+    assert(math_field_mul(a1, z1) == x1 && math_field_mul(b1, z1) == y1) by {
+        lemma_small_mod(z1, p);
+        lemma_affine_times_z(x1, z1);
+        lemma_affine_times_z(y1, z1);
+    };
+    assert(math_field_mul(a2, z2) == x2 && math_field_mul(b2, z2) == y2) by {
+        lemma_small_mod(z2, p);
+        lemma_affine_times_z(x2, z2);
+        lemma_affine_times_z(y2, z2);
+    };
+
+    // Step 2: X3 = k·nx and Y3 = k·ny
+    assert(x3 == math_field_mul(k, nx) && y3 == math_field_mul(k, ny)) by {
+        lemma_niels_sum_difference(x1, y1, x2, y2);
+
+        // Each cross product picks up the factor Z1·Z2
+        lemma_field_mul_rearrange_4(a1, z1, b2, z2);
+        lemma_field_mul_rearrange_4(b1, z1, a2, z2);
+        lemma_field_mul_rearrange_4(b1, z1, b2, z2);
+        lemma_field_mul_rearrange_4(a1, z1, a2, z2);
+        assert(math_field_mul(x1, y2) == math_field_mul(zz, math_field_mul(a1, b2)));
+        assert(math_field_mul(y1, x2) == math_field_mul(zz, math_field_mul(b1, a2)));
+        assert(math_field_mul(y1, y2) == math_field_mul(zz, math_field_mul(b1, b2)));
+        assert(math_field_mul(x1, x2) == math_field_mul(zz, math_field_mul(a1, a2)));
+
+        // Factor Z1·Z2 out of the sums, then merge with the 2
+        lemma_field_mul_distributes_over_add(zz, math_field_mul(a1, b2), math_field_mul(b1, a2));
+        lemma_field_mul_distributes_over_add(zz, math_field_mul(b1, b2), math_field_mul(a1, a2));
+        lemma_field_mul_assoc(2, zz, nx);
+        lemma_field_mul_assoc(2, zz, ny);
+    };
+
+    // Step 3: Ti = xi·yi·Zi
+    let ab1 = math_field_mul(a1, b1);
+    let ab2 = math_field_mul(a2, b2);
+    assert(t1 == math_field_mul(z1, ab1)) by {
+        // X1·Y1 = (x1·Z1)·(y1·Z1) = Z1·(Z1·x1·y1)
+        lemma_field_mul_rearrange_4(a1, z1, b1, z1);
+        lemma_field_mul_assoc(z1, z1, ab1);
+        // T1 = X1·Y1/Z1
+        lemma_small_mod(z1, p);
+        lemma_field_cancel_factor(z1, math_field_mul(z1, ab1));
+        lemma_mod_bound((z1 * ab1) as int, p as int);
+        lemma_small_mod(math_field_mul(z1, ab1), p);
+    };
+    assert(t2 == math_field_mul(z2, ab2)) by {
+        lemma_field_mul_rearrange_4(a2, z2, b2, z2);
+        lemma_field_mul_assoc(z2, z2, ab2);
+        lemma_small_mod(z2, p);
+        lemma_field_cancel_factor(z2, math_field_mul(z2, ab2));
+        lemma_mod_bound((z2 * ab2) as int, p as int);
+        lemma_small_mod(math_field_mul(z2, ab2), p);
+    };
+
+    // Step 4: TT2d = k·t
+    assert(tt2d == math_field_mul(k, t)) by {
+        let pi = p as int;
+        let (z1, z2, a1, a2, b1, b2, d) = (
+            z1 as int,
+            z2 as int,
+            a1 as int,
+            a2 as int,
+            b1 as int,
+            b2 as int,
+            d as int,
+        );
+        // LHS ≡ (Z1·x1·y1)·((2·d)·(Z2·x2·y2))
+        lemma_mul_mod_noop_right(z1, a1 * b1, pi);
+        lemma_mul_mod_noop_right(z2, a2 * b2, pi);
+        lemma_mul_mod_noop(2 * d, z2 * (a2 * b2), pi);
+        lemma_mul_mod_noop(z1 * (a1 * b1), (2 * d) * (z2 * (a2 * b2)), pi);
+
+        // RHS ≡ (2·Z1·Z2)·(d·(x1·x2)·(y1·y2))
+        lemma_mul_mod_noop_right(2, z1 * z2, pi);
+        lemma_mul_mod_noop(a1 * a2, b1 * b2, pi);
+        lemma_mul_mod_noop_right(d, (a1 * a2) * (b1 * b2), pi);
+        lemma_mul_mod_noop(2 * (z1 * z2), d * ((a1 * a2) * (b1 * b2)), pi);
+
+        assert((z1 * (a1 * b1)) * ((2 * d) * (z2 * (a2 * b2))) == (2 * (z1 * z2)) * (d * ((a1
+            * a2) * (b1 * b2)))) by (nonlinear_arith);
+    };
+
+    // Step 5: Z3 = k·(1 + t) and T3 = k·(1 - t)
+    assert(zz2 == k);
+    assert(math_field_mul(k, 1) == k) by {
+        lemma_mod_bound((2 * zz) as int, p as int);
+        lemma_small_mod(k, p);
+    };
+    assert(z3 == math_field_mul(k, math_field_add(1, t))) by {
+        lemma_field_mul_distributes_over_add(k, 1, t);
+    };
+    assert(t3 == math_field_mul(k, math_field_sub(1, t))) by {
+        lemma_field_mul_distributes_over_sub(k, 1, t);
+    };
+
+    // Step 6: cancel k in both quotients
+    lemma_field_common_factor(k, nx, math_field_add(1, t));
+    lemma_field_common_factor(k, ny, math_field_sub(1, t));
+}
+
+// =============================================================================
+// Doubling
+// =============================================================================
+/// Lemma: (x+y)² - (y² + x²) = 2·x·y in the field
+pub proof fn lemma_double_cross_term(x: nat, y: nat)
+    ensures
+        math_field_sub(
+            math_field_square(math_field_add(x, y)),
+            math_field_add(math_field_square(y), math_field_square(x)),
+        ) == math_field_mul(2, math_field_mul(x, y)),
+{
+    let p = p() as int;
+    p_gt_2();
+    let (xi, yi) = (x as int, y as int);
+    let s = (xi + yi) * (xi + yi);
+    let q = yi * yi + xi * xi;
+    let sq = math_field_square(math_field_add(x, y));
+    let sum = math_field_add(math_field_square(y), math_field_square(x));
+
+    assert(sq as int == s % p) by {
+        lemma_mul_mod_noop(xi + yi, xi + yi, p);
+    };
+    assert(sum as int == q % p) by {
+        lemma_add_mod_noop(yi * yi, xi * xi, p);
+    };
+    assert(s - q == 2 * (xi * yi)) by (nonlinear_arith)
+        requires
+            s == (xi + yi) * (xi + yi),
+            q == yi * yi + xi * xi,
+    ;
+    assert(math_field_sub(sq, sum) as int == (2 * (xi * yi)) % p) by {
+        lemma_field_sub_as_int(sq, sum);
+        lemma_sub_mod_noop(s, q, p);
+    };
+    lemma_mul_mod_noop_right(2, xi * yi, p);
+}
+
+/// Lemma: the projective doubling formula computes `edwards_double`
+///
+/// For a projective point (X:Y:Z) whose affine point (x, y) = (X/Z, Y/Z) is on the
+/// curve, the completed point ((X3:Z3), (Y3:T3)) computed by `ProjectivePoint::double`
+/// represents `edwards_double(x, y) = edwards_add(x, y, x, y)`.
+///
+/// ## Mathematical Proof
+/// ```text
+/// X3 = 2XY          = Z²·2xy
+/// Y3 = Y² + X²      = Z²·(y² + x²)
+/// Z3 = Y² - X²      = Z²·(y² - x²) = Z²·(1 + d·x²y²)        [curve equation]
+/// T3 = 2Z² - Z3     = Z²·(2 - (1 + d·x²y²)) = Z²·(1 - d·x²y²)
+/// ```
+/// The factor Z² ≠ 0 cancels in X3/Z3 and Y3/T3.
+pub proof fn lemma_projective_double_formula(x: nat, y: nat, z: nat)
+    requires
+        x < p(),
+        y < p(),
+        z < p(),
+        z != 0,
+        math_on_edwards_curve(
+            math_field_mul(x, math_field_inv(z)),
+            math_field_mul(y, math_field_inv(z)),
+        ),
+    ensures
+        ({
+            let xx = math_field_square(x);
+            let yy = math_field_square(y);
+            let zz2 = math_field_mul(2, math_field_square(z));
+            let yy_plus_xx = math_field_add(yy, xx);
+            let yy_minus_xx = math_field_sub(yy, xx);
+            let x3 = math_field_sub(math_field_square(math_field_add(x, y)), yy_plus_xx);
+            let t3 = math_field_sub(zz2, yy_minus_xx);
+            (
+                math_field_mul(x3, math_field_inv(yy_minus_xx)),
+                math_field_mul(yy_plus_xx, math_field_inv(t3)),
+            ) == edwards_double(
+                math_field_mul(x, math_field_inv(z)),
+                math_field_mul(y, math_field_inv(z)),
+            )
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+
+    // Affine coordinates and their products
+    let a = math_field_mul(x, math_field_inv(z));
+    let b = math_field_mul(y, math_field_inv(z));
+    let aa = math_field_mul(a, a);
+    let bb = math_field_mul(b, b);
+    let ab = math_field_mul(a, b);
+    let t = math_field_mul(d, math_field_mul(aa, bb));
+
+    // Formula intermediates
+    let zz = math_field_mul(z, z);
+    let xx = math_field_square(x);
+    let yy = math_field_square(y);
+    let zz2 = math_field_mul(2, math_field_square(z));
+    let yy_plus_xx = math_field_add(yy, xx);
+    let yy_minus_xx = math_field_sub(yy, xx);
+    let x3 = math_field_sub(math_field_square(math_field_add(x, y)), yy_plus_xx);
+    let t3 = math_field_sub(zz2, yy_minus_xx);
+
+    // Common factor Z² ≠ 0
+    assert(zz % p != 0) by {
+        lemma_small_mod(z, p);
+        lemma_field_mul_nonzero(z, z);
+    };
+
+    // Step 1: X = x·Z and Y = y·Z, so X² = Z²·x², Y² = Z²·y², XY = Z²·xy
+    assert(math_field_mul(a, z) == x && math_field_mul(b, z) == y) by {
+        lemma_small_mod(z, p);
+        lemma_affine_times_z(x, z);
+        lemma_affine_times_z(y, z);
+    };
+    assert(xx == math_field_mul(zz, aa)) by {
+        lemma_field_mul_rearrange_4(a, z, a, z);
+    };
+    assert(yy == math_field_mul(zz, bb)) by {
+        lemma_field_mul_rearrange_4(b, z, b, z);
+    };
+    assert(math_field_mul(x, y) == math_field_mul(zz, ab)) by {
+        lemma_field_mul_rearrange_4(a, z, b, z);
+    };
+
+    // Step 2: X3 = Z²·2xy
+    assert(x3 == math_field_mul(zz, math_field_mul(2, ab))) by {
+        lemma_double_cross_term(x, y);
+        lemma_field_mul_assoc(2, zz, ab);
+        lemma_field_mul_comm(2, zz);
+        lemma_field_mul_assoc(zz, 2, ab);
+    };
+
+    // Step 3: Y3 = Z²·(y² + x²) and Z3 = Z²·(y² - x²)
+    assert(yy_plus_xx == math_field_mul(zz, math_field_add(bb, aa))) by {
+        lemma_field_mul_distributes_over_add(zz, bb, aa);
+    };
+    assert(yy_minus_xx == math_field_mul(zz, math_field_sub(bb, aa))) by {
+        lemma_field_mul_distributes_over_sub(zz, bb, aa);
+    };
+
+    // Step 4: T3 = Z²·(2 - (y² - x²))
+    assert(t3 == math_field_mul(zz, math_field_sub(2, math_field_sub(bb, aa)))) by {
+        lemma_field_mul_comm(2, zz);
+        lemma_field_mul_distributes_over_sub(zz, 2, math_field_sub(bb, aa));
+    };
+
+    // Step 5: the curve equation gives y² - x² = 1 + t, hence 2 - (y² - x²) = 1 - t
+    assert(math_field_sub(bb, aa) == math_field_add(1, t));
+    assert(math_field_sub(2, math_field_add(1, t)) == math_field_sub(1, t)) by {
+        let ti = t as int;
+        lemma_field_sub_as_int(2, math_field_add(1, t));
+        lemma_small_mod(2, p);
+        lemma_sub_mod_noop(2, 1 + ti, p as int);
+        lemma_field_sub_as_int(1, t);
+    };
+
+    // Step 6: the cross terms of edwards_add(P, P) combine to 2xy
+    assert(math_field_add(ab, math_field_mul(b, a)) == math_field_mul(2, ab)) by {
+        lemma_field_mul_comm(b, a);
+    };
+
+    // Step 7: cancel Z² in both quotients
+    lemma_field_common_factor(zz, math_field_mul(2, ab), math_field_sub(bb, aa));
+    lemma_field_common_factor(
+        zz,
+        math_field_add(bb, aa),
+        math_field_sub(2, math_field_sub(bb, aa)),
+    );
+}
+
+/// Lemma: `double(P)` and `P + P` represent the same affine point
+///
+/// `EdwardsPoint::double` is specified with `edwards_double` and `EdwardsPoint + EdwardsPoint`
+/// with `edwards_add`; this lets scalar multiplication proofs use either one.
+pub proof fn lemma_double_is_self_add(point: EdwardsPoint, doubled: EdwardsPoint, sum: EdwardsPoint)
+    requires
+        edwards_point_as_affine(doubled) == edwards_double(
+            edwards_point_as_affine(point).0,
+            edwards_point_as_affine(point).1,
+        ),
+        ({
+            let (x, y) = edwards_point_as_affine(point);
+            edwards_point_as_affine(sum) == edwards_add(x, y, x, y)
+        }),
+    ensures
+        edwards_point_as_affine(doubled) == edwards_point_as_affine(sum),
+{
+}
+
+} // verus!
@@ -0,0 +1,305 @@
+//! Lemmas for Edwards point compression and the compress/decompress roundtrip
+//!
+//! ## compress Function
+//!
+//! ```text
+//! fn compress(&self: EdwardsPoint) -> CompressedEdwardsY
+//! ```
+//!
+//! Computes the affine point (x, y) = (X/Z, Y/Z), writes the canonical bytes of y,
+//! and stores the sign (low bit) of x in bit 255.
+//!
+//! ## Key Properties Proven
+//!
+//! 1. **Sign bit storage**: setting bit 255 of a canonical encoding leaves the decoded y unchanged
+//! 2. **Compressed points decode**: a compressed valid point has a valid sign bit and a valid
+//!    y-coordinate, so `decompress` returns `Some`
+//! 3. **Roundtrip**: the point `decompress` returns has the affine coordinates of the original
+//! 4. **Negation**: -P compresses to the same y with the opposite sign bit (for x ≠ 0)
+//! 5. **Uniqueness**: exactly one byte string encodes a given affine point, so the output of
+//!    `compress` is fixed by the `compressed_y_encodes` postcondition alone
+#![allow(unused_imports)]
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::lemmas::common_lemmas::to_nat_lemmas::*;
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::*;
+use crate::core_assumes::seq_from32;
+use crate::lemmas::field_lemmas::as_bytes_lemmas::{
+    lemma_from_bytes_clears_high_bit, lemma_seq_eq_implies_array_eq,
+};
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::specs::core_specs::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Byte-level encoding
+// =============================================================================
+/// Lemma: XOR-ing a sign into bit 255 of a canonical encoding stores the sign
+/// and leaves the low 255 bits unchanged
+///
+/// ## Mathematical Proof
+/// ```text
+/// s < p < 2^255  ⟹  s[31] ≤ 127, i.e. bit 255 of s is clear
+/// t[31] = s[31] ^ (sign << 7)  ⟹  t[31] & 127 = s[31] and t[31] >> 7 = sign
+/// t mod 2^255 = lo + (t[31] & 127)·2^248 = lo + s[31]·2^248 = s   [lemma_from_bytes_clears_high_bit]
+/// ```
+pub proof fn lemma_set_sign_bit(s: &[u8; 32], t: &[u8; 32], sign: u8)
+    requires
+        bytes32_to_nat(s) < p(),
+        sign <= 1,
+        t[31] == s[31] ^ (sign << 7u8),
+        forall|i: int| 0 <= i < 31 ==> t[i] == s[i],
+    ensures
+        bytes32_to_nat(t) % pow2(255) == bytes32_to_nat(s),
+        t[31] >> 7 == sign,
+{
+    let s31 = s[31];
+    let t31 = t[31];
+
+    // bit 255 of s is clear, since s < 2^255
+    assert(s31 <= 127) by {
+        pow255_gt_19();
+        if s31 >= 128 {
+            lemma_bytes32_to_nat_lower_bound(s, 31);
+            lemma_pow2_adds(7, 248);
+            lemma2_to64();
+            lemma_mul_inequality(128, s31 as int, pow2(248) as int);
+        }
+    };
+
+    assert(t31 & 127u8 == s31 && t31 >> 7u8 == sign && s31 & 127u8 == s31) by (bit_vector)
+        requires
+            s31 <= 127,
+            sign <= 1,
+            t31 == s31 ^ (sign << 7u8),
+    ;
+
+    // Only the top byte differs, and only in bit 7
+    lemma_from_bytes_clears_high_bit(s);
+    lemma_from_bytes_clears_high_bit(t);
+    lemma_prefix_equal_when_bytes_match(s@, t@, 31);
+}
+
+/// Lemma: an encoding is its low 255 bits plus bit 255, the top bit of byte 31
+///
+/// ## Mathematical Proof
+/// ```text
+/// b = lo + b[31]·2^248                     with lo < 2^248 the first 31 bytes
+/// b[31] = (b[31] & 127) + 128·(b[31] >> 7)
+/// b mod 2^255 = lo + (b[31] & 127)·2^248   [lemma_from_bytes_clears_high_bit]
+/// ⟹ b = b mod 2^255 + (b[31] >> 7)·2^255
+/// ```
+pub proof fn lemma_bytes32_to_nat_split_bit_255(bytes: &[u8; 32])
+    ensures
+        bytes32_to_nat(bytes) == bytes32_to_nat(bytes) % pow2(255) + ((bytes[31] >> 7) as nat)
+            * pow2(255),
+{
+    let hi = bytes[31];
+    let lo = bytes_to_nat_prefix(bytes@, 31);
+    lemma_from_bytes_clears_high_bit(bytes);
+    assert(bytes32_to_nat(bytes) == lo + (hi as nat) * pow2(248)) by {
+        lemma_bytes32_to_nat_equals_rec(bytes);
+        lemma_decomposition_prefix_rec(bytes, 31);
+        reveal_with_fuel(bytes32_to_nat_rec, 2);
+    };
+    assert(hi == (hi & 127u8) + 128 * (hi >> 7u8)) by (bit_vector);
+    lemma2_to64();
+    lemma_pow2_adds(7, 248);
+    assert(bytes32_to_nat(bytes) == lo + ((hi & 127u8) as nat) * pow2(248) + ((hi >> 7u8) as nat)
+        * pow2(255)) by (nonlinear_arith)
+        requires
+            bytes32_to_nat(bytes) == lo + (hi as nat) * pow2(248),
+            hi as nat == (hi & 127u8) as nat + 128 * ((hi >> 7u8) as nat),
+            pow2(255) == 128 * pow2(248),
+    ;
+}
+
+/// Lemma: two byte strings that encode the same affine point are equal
+///
+/// This is what makes the wire format independent of the field backend: the
+/// `compressed_y_encodes` postcondition of `EdwardsPoint::compress` mentions only the
+/// affine point, so any implementation meeting it returns these exact bytes.
+///
+/// ## Mathematical Proof
+/// ```text
+/// b1 mod 2^255 = y mod p = b2 mod 2^255
+/// b1[31] >> 7 = x mod p mod 2 = b2[31] >> 7
+/// ⟹ b1 = b2 as integers                    [lemma_bytes32_to_nat_split_bit_255]
+/// ⟹ b1 = b2 byte for byte                  [lemma_canonical_bytes_equal]
+/// ```
+pub proof fn lemma_compressed_y_encodes_unique(b1: &[u8; 32], b2: &[u8; 32], x: nat, y: nat)
+    requires
+        compressed_y_encodes(b1, x, y),
+        compressed_y_encodes(b2, x, y),
+    ensures
+        *b1 == *b2,
+{
+    lemma_bytes32_to_nat_split_bit_255(b1);
+    lemma_bytes32_to_nat_split_bit_255(b2);
+    lemma_canonical_bytes_equal(b1, b2);
+    assert(seq_from32(b1) =~= seq_from32(b2));
+    lemma_seq_eq_implies_array_eq(b1, b2);
+}
+
+/// Lemma: points with the same affine coordinates compress to the same bytes,
+/// whatever their projective representation
+pub proof fn lemma_compress_depends_only_on_affine(
+    p1: EdwardsPoint,
+    p2: EdwardsPoint,
+    c1: CompressedEdwardsY,
+    c2: CompressedEdwardsY,
+)
+    requires
+        edwards_point_as_affine(p1) == edwards_point_as_affine(p2),
+        compressed_y_encodes(&c1.0, edwards_point_as_affine(p1).0, edwards_point_as_affine(p1).1),
+        compressed_y_encodes(&c2.0, edwards_point_as_affine(p2).0, edwards_point_as_affine(p2).1),
+    ensures
+        c1.0 == c2.0,
+{
+    let (x, y) = edwards_point_as_affine(p1);
+    lemma_compressed_y_encodes_unique(&c1.0, &c2.0, x, y);
+}
+
+// =============================================================================
+// Roundtrip
+// =============================================================================
+/// Lemma: the compression of a valid point is accepted by `decompress`
+///
+/// Its sign bit is valid (y² = 1 forces x = 0, whose sign is 0), and its
+/// y-coordinate is valid (the original x is a square root of u/v).
+pub proof fn lemma_compressed_point_decodes(point: EdwardsPoint, compressed: CompressedEdwardsY)
+    requires
+        is_valid_edwards_point(point),
+        compressed_edwards_y_corresponds_to_edwards(compressed, point),
+    ensures
+        compressed_y_has_valid_sign_bit(&compressed.0),
+        math_is_valid_y_coordinate(spec_field_element_from_bytes(&compressed.0)),
+{
+    let p = p();
+    p_gt_2();
+    let (x, y) = edwards_point_as_affine(point);
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+
+    lemma_on_curve_implies_valid_y(x, y);
+    lemma_small_mod(0, p);
+}
+
+/// Lemma: decompress(compress(P)) represents P
+///
+/// `decoded` is any point satisfying the `Some` postconditions of
+/// `CompressedEdwardsY::decompress` on the compression of `point`.
+///
+/// ## Mathematical Proof
+/// ```text
+/// decoded has Z = 1, so its affine point is (X, Y) with Y = y
+/// (X, y) and (x, y) are both on the curve, and X, x have the same sign bit
+/// ⟹ X = x                                  [lemma_on_curve_x_determined_by_sign]
+/// ```
+pub proof fn lemma_compress_decompress_roundtrip(
+    point: EdwardsPoint,
+    compressed: CompressedEdwardsY,
+    decoded: EdwardsPoint,
+)
+    requires
+        is_valid_edwards_point(point),
+        compressed_edwards_y_corresponds_to_edwards(compressed, point),
+        // decompress postconditions for Some(decoded)
+        is_valid_edwards_point(decoded),
+        spec_field_element(&decoded.Y) == spec_field_element_from_bytes(&compressed.0),
+        spec_field_element(&decoded.Z) == 1,
+        spec_field_element_sign_bit(&decoded.X) == (compressed.0[31] >> 7),
+    ensures
+        edwards_point_as_affine(decoded) == edwards_point_as_affine(point),
+{
+    let p = p();
+    p_gt_2();
+    let (x, y) = edwards_point_as_affine(point);
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+    lemma_mod_bound((spec_field_element(&point.Y) * z_inv) as int, p as int);
+    lemma_small_mod(x, p);
+
+    // With Z = 1 the affine point of decoded is (X, Y)
+    let xd = spec_field_element(&decoded.X);
+    let yd = spec_field_element(&decoded.Y);
+    lemma_mod_bound(spec_field_element_as_nat(&decoded.X) as int, p as int);
+    lemma_mod_bound(spec_field_element_as_nat(&decoded.Y) as int, p as int);
+    lemma_field_inv_one();
+    lemma_mul_basics(xd as int);
+    lemma_mul_basics(yd as int);
+    lemma_small_mod(xd, p);
+    lemma_small_mod(yd, p);
+    assert(edwards_point_as_affine(decoded) == (xd, yd));
+    assert(yd == y);
+
+    // Both x-coordinates solve the curve equation for y, with the same sign
+    assert(xd % 2 == x % 2) by {
+        assert((xd % 2) as u8 == (x % 2) as u8);
+    };
+    lemma_on_curve_x_determined_by_sign(xd, x, y);
+}
+
+// =============================================================================
+// Negation
+// =============================================================================
+/// Lemma: compressing -P keeps the y-bytes of P and flips the sign bit,
+/// unless x = 0, where both sign bits are 0
+///
+/// ## Mathematical Proof
+/// ```text
+/// -P = (-x, y), so both encode the same y
+/// x = 0:  -x = 0, whose sign is 0
+/// x ≠ 0:  sign(-x) = 1 - sign(x)               [lemma_neg_flips_sign]
+/// ```
+pub proof fn lemma_compress_negation(
+    point: EdwardsPoint,
+    neg: EdwardsPoint,
+    compressed: CompressedEdwardsY,
+    compressed_neg: CompressedEdwardsY,
+)
+    requires
+        is_valid_edwards_point(point),
+        edwards_point_as_affine(neg) == (
+            math_field_neg(edwards_point_as_affine(point).0),
+            edwards_point_as_affine(point).1,
+        ),
+        compressed_edwards_y_corresponds_to_edwards(compressed, point),
+        compressed_edwards_y_corresponds_to_edwards(compressed_neg, neg),
+    ensures
+        spec_field_element_from_bytes(&compressed_neg.0) == spec_field_element_from_bytes(
+            &compressed.0,
+        ),
+        ({
+            let x = edwards_point_as_affine(point).0;
+            let sign = compressed.0[31] >> 7;
+            let sign_neg = compressed_neg.0[31] >> 7;
+            &&& x == 0 ==> sign == 0 && sign_neg == 0
+            &&& x != 0 ==> sign + sign_neg == 1
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let x = edwards_point_as_affine(point).0;
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+    lemma_small_mod(x, p);
+
+    let neg_x = math_field_neg(x);
+    lemma_mod_bound((p - x) as int, p as int);
+    lemma_small_mod(neg_x, p);
+    if x == 0 {
+        lemma_mod_self_0(p as int);
+    } else {
+        lemma_neg_flips_sign(x);
+    }
+}
+
+} // verus!
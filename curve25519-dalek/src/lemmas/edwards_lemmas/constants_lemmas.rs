@@ -1,4 +1,4 @@
-//! Lemmas about Edwards curve constants (EDWARDS_D)
+//! Lemmas about Edwards curve constants (EDWARDS_D and the basepoint)
 //!
 //! This module contains proofs about the properties of Edwards curve constants.
 //! These are the curve parameters used in the twisted Edwards curve equation.
@@ -15,14 +15,37 @@
 //! d = limbs[0] + 2^51·limbs[1] + 2^102·limbs[2] + 2^153·limbs[3] + 2^204·limbs[4]
 //! ```
 //!
-//! ## Note
+//! ## Derived constants
 //!
-//! - EDWARDS_D2 (= 2·d) lemmas are in `unused_constants_lemmas.rs` (currently unused).
+//! The remaining curve constants are raw limb dumps of values derived from `d`
+//! (with `a = -1`). Each one is checked against its defining equation by evaluating
+//! the 255-bit arithmetic directly (`by (compute)`):
+//! - `MINUS_ONE`: -1
+//! - `EDWARDS_D`: d · 121666 = -121665, and d + 1 ≠ 0
+//! - `EDWARDS_D2`: 2 · d
+//! - `ONE_MINUS_EDWARDS_D_SQUARED`: (1 - d)²
+//! - `EDWARDS_D_MINUS_ONE_SQUARED`: (d - 1)²
+//! - `SQRT_AD_MINUS_ONE`: s² = a·d - 1
+//! - `INVSQRT_A_MINUS_D`: x² · (a - d) = 1
+//!
+//! ## The Ed25519 basepoint
+//!
+//! `ED25519_BASEPOINT_POINT` is checked the same way: its coordinates satisfy the
+//! curve equation, y = 4/5, Z = 1 and T = x·y. That B has order ℓ is not checked by
+//! evaluation; it is `axiom_ed25519_basepoint_order` in `scalar_mul_lemmas`. Only
+//! the u64 backend's limbs are present in this tree.
 #![allow(unused_imports)]
-use crate::backend::serial::u64::constants::EDWARDS_D;
+use crate::backend::serial::u64::constants::{
+    ED25519_BASEPOINT_POINT, EDWARDS_D, EDWARDS_D2, EDWARDS_D_MINUS_ONE_SQUARED, INVSQRT_A_MINUS_D,
+    MINUS_ONE, ONE_MINUS_EDWARDS_D_SQUARED, SQRT_AD_MINUS_ONE,
+};
 use crate::backend::serial::u64::field::FieldElement51;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::lemma_field_inv_one;
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::lemma_sum_of_limbs_bounded_from_fe51_bounded;
+use crate::specs::edwards_specs::*;
 use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
+use vstd::arithmetic::div_mod::*;
 use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
 
@@ -69,4 +92,262 @@ pub(crate) proof fn lemma_edwards_d_limbs_bounded_54()
     };
 }
 
+/// INVSQRT_A_MINUS_D has 51-bit bounded limbs (hence 54-bit)
+pub(crate) proof fn lemma_invsqrt_a_minus_d_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&INVSQRT_A_MINUS_D, 51),
+        fe51_limbs_bounded(&INVSQRT_A_MINUS_D, 54),
+{
+    assert(fe51_limbs_bounded(&INVSQRT_A_MINUS_D, 51)) by {
+        assert(278908739862762u64 < (1u64 << 51)) by (bit_vector);
+        assert(821645201101625u64 < (1u64 << 51)) by (bit_vector);
+        assert(8113234426968u64 < (1u64 << 51)) by (bit_vector);
+        assert(1777959178193151u64 < (1u64 << 51)) by (bit_vector);
+        assert(2118520810568447u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert((1u64 << 51) < (1u64 << 54)) by (bit_vector);
+}
+
+/// The constants of the Ristretto Elligator map have 51-bit bounded limbs (hence 54-bit)
+pub(crate) proof fn lemma_elligator_constants_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&MINUS_ONE, 51),
+        fe51_limbs_bounded(&MINUS_ONE, 54),
+        fe51_limbs_bounded(&ONE_MINUS_EDWARDS_D_SQUARED, 51),
+        fe51_limbs_bounded(&ONE_MINUS_EDWARDS_D_SQUARED, 54),
+        fe51_limbs_bounded(&EDWARDS_D_MINUS_ONE_SQUARED, 51),
+        fe51_limbs_bounded(&EDWARDS_D_MINUS_ONE_SQUARED, 54),
+        fe51_limbs_bounded(&SQRT_AD_MINUS_ONE, 51),
+        fe51_limbs_bounded(&SQRT_AD_MINUS_ONE, 54),
+{
+    assert(fe51_limbs_bounded(&MINUS_ONE, 51)) by {
+        assert(2251799813685228u64 < (1u64 << 51)) by (bit_vector);
+        assert(2251799813685247u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&ONE_MINUS_EDWARDS_D_SQUARED, 51)) by {
+        assert(1136626929484150u64 < (1u64 << 51)) by (bit_vector);
+        assert(1998550399581263u64 < (1u64 << 51)) by (bit_vector);
+        assert(496427632559748u64 < (1u64 << 51)) by (bit_vector);
+        assert(118527312129759u64 < (1u64 << 51)) by (bit_vector);
+        assert(45110755273534u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&EDWARDS_D_MINUS_ONE_SQUARED, 51)) by {
+        assert(1507062230895904u64 < (1u64 << 51)) by (bit_vector);
+        assert(1572317787530805u64 < (1u64 << 51)) by (bit_vector);
+        assert(683053064812840u64 < (1u64 << 51)) by (bit_vector);
+        assert(317374165784489u64 < (1u64 << 51)) by (bit_vector);
+        assert(1572899562415810u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&SQRT_AD_MINUS_ONE, 51)) by {
+        assert(2241493124984347u64 < (1u64 << 51)) by (bit_vector);
+        assert(425987919032274u64 < (1u64 << 51)) by (bit_vector);
+        assert(2207028919301688u64 < (1u64 << 51)) by (bit_vector);
+        assert(1220490630685848u64 < (1u64 << 51)) by (bit_vector);
+        assert(974799131293748u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert((1u64 << 51) < (1u64 << 54)) by (bit_vector);
+}
+
+// =============================================================================
+// Defining equations of the curve constants
+// =============================================================================
+/// MINUS_ONE is -1
+pub(crate) proof fn lemma_minus_one_value()
+    ensures
+        spec_field_element(&MINUS_ONE) == math_field_neg(1),
+{
+    assert(spec_field_element(&MINUS_ONE) == math_field_neg(1)) by (compute);
+}
+
+/// EDWARDS_D is -121665/121666, i.e. d · 121666 ≡ -121665 (mod p)
+pub(crate) proof fn lemma_edwards_d_value()
+    ensures
+        math_field_mul(spec_field_element(&EDWARDS_D), 121666) == math_field_neg(121665),
+{
+    assert(math_field_mul(spec_field_element(&EDWARDS_D), 121666) == math_field_neg(121665))
+        by (compute);
+}
+
+/// d + 1 ≠ 0, so y² = 1 on the curve forces x = 0
+pub(crate) proof fn lemma_edwards_d_plus_one_nonzero()
+    ensures
+        math_field_add(spec_field_element(&EDWARDS_D), 1) != 0,
+{
+    assert(math_field_add(spec_field_element(&EDWARDS_D), 1) != 0) by (compute);
+}
+
+/// EDWARDS_D2 is 2·d
+pub(crate) proof fn lemma_edwards_d2_value()
+    ensures
+        spec_field_element(&EDWARDS_D2) == math_field_mul(2, spec_field_element(&EDWARDS_D)),
+{
+    assert(spec_field_element(&EDWARDS_D2) == math_field_mul(2, spec_field_element(&EDWARDS_D)))
+        by (compute);
+}
+
+/// ONE_MINUS_EDWARDS_D_SQUARED is (1 - d)²
+pub(crate) proof fn lemma_one_minus_edwards_d_squared_value()
+    ensures
+        spec_field_element(&ONE_MINUS_EDWARDS_D_SQUARED) == math_field_square(
+            math_field_sub(1, spec_field_element(&EDWARDS_D)),
+        ),
+{
+    assert(spec_field_element(&ONE_MINUS_EDWARDS_D_SQUARED) == math_field_square(
+        math_field_sub(1, spec_field_element(&EDWARDS_D)),
+    )) by (compute);
+}
+
+/// EDWARDS_D_MINUS_ONE_SQUARED is (d - 1)²
+pub(crate) proof fn lemma_edwards_d_minus_one_squared_value()
+    ensures
+        spec_field_element(&EDWARDS_D_MINUS_ONE_SQUARED) == math_field_square(
+            math_field_sub(spec_field_element(&EDWARDS_D), 1),
+        ),
+{
+    assert(spec_field_element(&EDWARDS_D_MINUS_ONE_SQUARED) == math_field_square(
+        math_field_sub(spec_field_element(&EDWARDS_D), 1),
+    )) by (compute);
+}
+
+/// SQRT_AD_MINUS_ONE is a square root of a·d - 1, with a = -1
+pub(crate) proof fn lemma_sqrt_ad_minus_one_value()
+    ensures
+        math_field_square(spec_field_element(&SQRT_AD_MINUS_ONE)) == math_field_sub(
+            math_field_mul(math_field_neg(1), spec_field_element(&EDWARDS_D)),
+            1,
+        ),
+{
+    assert(math_field_square(spec_field_element(&SQRT_AD_MINUS_ONE)) == math_field_sub(
+        math_field_mul(math_field_neg(1), spec_field_element(&EDWARDS_D)),
+        1,
+    )) by (compute);
+}
+
+/// INVSQRT_A_MINUS_D is an inverse square root of a - d, with a = -1:
+/// its square times (a - d) is 1
+pub(crate) proof fn lemma_invsqrt_a_minus_d_value()
+    ensures
+        math_field_mul(
+            math_field_square(spec_field_element(&INVSQRT_A_MINUS_D)),
+            math_field_sub(math_field_neg(1), spec_field_element(&EDWARDS_D)),
+        ) == 1,
+{
+    assert(math_field_mul(
+        math_field_square(spec_field_element(&INVSQRT_A_MINUS_D)),
+        math_field_sub(math_field_neg(1), spec_field_element(&EDWARDS_D)),
+    ) == 1) by (compute);
+}
+
+// =============================================================================
+// The Ed25519 basepoint
+// =============================================================================
+/// ED25519_BASEPOINT_POINT has 51-bit bounded limbs (hence 54-bit), so it
+/// satisfies the limb and sum bounds of a well-formed EdwardsPoint
+pub(crate) proof fn lemma_ed25519_basepoint_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.X, 51),
+        fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.Y, 51),
+        fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.Z, 51),
+        fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.T, 51),
+        edwards_point_limbs_bounded(ED25519_BASEPOINT_POINT),
+        edwards_point_sum_bounded(ED25519_BASEPOINT_POINT),
+{
+    assert(fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.X, 51)) by {
+        assert(1738742601995546u64 < (1u64 << 51)) by (bit_vector);
+        assert(1146398526822698u64 < (1u64 << 51)) by (bit_vector);
+        assert(2070867633025821u64 < (1u64 << 51)) by (bit_vector);
+        assert(562264141797630u64 < (1u64 << 51)) by (bit_vector);
+        assert(587772402128613u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.Y, 51)) by {
+        assert(1801439850948184u64 < (1u64 << 51)) by (bit_vector);
+        assert(1351079888211148u64 < (1u64 << 51)) by (bit_vector);
+        assert(450359962737049u64 < (1u64 << 51)) by (bit_vector);
+        assert(900719925474099u64 < (1u64 << 51)) by (bit_vector);
+        assert(1801439850948198u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.Z, 51)) by {
+        assert(1u64 < (1u64 << 51)) by (bit_vector);
+        assert(0u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&ED25519_BASEPOINT_POINT.T, 51)) by {
+        assert(1841354044333475u64 < (1u64 << 51)) by (bit_vector);
+        assert(16398895984059u64 < (1u64 << 51)) by (bit_vector);
+        assert(755974180946558u64 < (1u64 << 51)) by (bit_vector);
+        assert(900171276175154u64 < (1u64 << 51)) by (bit_vector);
+        assert(1821297809914039u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert((1u64 << 51) < (1u64 << 54)) by (bit_vector);
+    lemma_sum_of_limbs_bounded_from_fe51_bounded(
+        &ED25519_BASEPOINT_POINT.Y,
+        &ED25519_BASEPOINT_POINT.X,
+        51,
+    );
+}
+
+/// The coordinates of ED25519_BASEPOINT_POINT: Z = 1, X and Y are the reduced
+/// affine coordinates of B = (x, y), y = 4/5, and T = x·y
+pub(crate) proof fn lemma_ed25519_basepoint_value()
+    ensures
+        spec_ed25519_basepoint().0 < p(),
+        spec_ed25519_basepoint().1 < p(),
+        spec_field_element(&ED25519_BASEPOINT_POINT.X) == spec_ed25519_basepoint().0,
+        spec_field_element(&ED25519_BASEPOINT_POINT.Y) == spec_ed25519_basepoint().1,
+        spec_field_element(&ED25519_BASEPOINT_POINT.Z) == 1,
+        math_field_mul(5, spec_ed25519_basepoint().1) == 4,
+        spec_field_element(&ED25519_BASEPOINT_POINT.T) == math_field_mul(
+            spec_ed25519_basepoint().0,
+            spec_ed25519_basepoint().1,
+        ),
+{
+    assert(spec_ed25519_basepoint().0 < p()) by (compute);
+    assert(spec_ed25519_basepoint().1 < p()) by (compute);
+    lemma_small_mod(spec_ed25519_basepoint().0, p());
+    lemma_small_mod(spec_ed25519_basepoint().1, p());
+    assert(spec_field_element(&ED25519_BASEPOINT_POINT.Z) == 1) by (compute);
+    assert(math_field_mul(5, spec_ed25519_basepoint().1) == 4) by (compute);
+    assert(spec_field_element(&ED25519_BASEPOINT_POINT.T) == math_field_mul(
+        spec_ed25519_basepoint().0,
+        spec_ed25519_basepoint().1,
+    )) by (compute);
+}
+
+/// The basepoint B satisfies the curve equation -x² + y² = 1 + d·x²·y²
+pub(crate) proof fn lemma_ed25519_basepoint_on_curve()
+    ensures
+        math_on_edwards_curve(spec_ed25519_basepoint().0, spec_ed25519_basepoint().1),
+{
+    assert(math_on_edwards_curve(spec_ed25519_basepoint().0, spec_ed25519_basepoint().1))
+        by (compute);
+}
+
+/// ED25519_BASEPOINT_POINT is a well-formed EdwardsPoint whose affine point is B
+///
+/// ## Mathematical Proof
+/// ```text
+/// Z = 1, so 1/Z = 1 and (X/Z, Y/Z) = (x, y), which is on the curve
+/// T = x·y = (X·Y)/Z
+/// ```
+pub(crate) proof fn lemma_ed25519_basepoint_well_formed()
+    ensures
+        is_well_formed_edwards_point(ED25519_BASEPOINT_POINT),
+        edwards_point_as_affine(ED25519_BASEPOINT_POINT) == spec_ed25519_basepoint(),
+{
+    let (x, y) = spec_ed25519_basepoint();
+    lemma_ed25519_basepoint_limbs_bounded();
+    lemma_ed25519_basepoint_value();
+    lemma_ed25519_basepoint_on_curve();
+    lemma_field_inv_one();
+
+    p_gt_2();
+    lemma_small_mod(x, p());
+    lemma_small_mod(y, p());
+    assert(math_field_mul(x, 1) == x);
+    assert(math_field_mul(y, 1) == y);
+    let xy = math_field_mul(x, y);
+    lemma_mod_bound((x * y) as int, p() as int);
+    lemma_small_mod(xy, p());
+    assert(math_field_mul(xy, 1) == xy);
+}
+
 } // verus!
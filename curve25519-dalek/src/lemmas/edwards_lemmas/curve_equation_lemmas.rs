@@ -9,10 +9,18 @@
 //! 1. **Negation preserves curve**: (-x, y) is on the curve if (x, y) is (since x² = (-x)²)
 //! 2. **Affine to extended validity**: (x, y, 1, xy) is a valid extended point when (x, y) is on curve
 //! 3. **x=0 implies y²=1**: If x ≡ 0 and (x, y) is on curve, then y² = 1
+//! 4. **x² from y**: x²·(d·y² + 1) = y² - 1, with d·y² + 1 ≠ 0
+//! 5. **x from y and sign**: two curve points with the same y and the same sign of x are equal
+//! 6. **Valid y**: the y-coordinate of a curve point passes `math_is_valid_y_coordinate`
+//! 7. **Projective equation**: for Z ≠ 0, (X:Y:Z) satisfies the homogenized equation iff (X/Z, Y/Z) is on the curve
+//! 8. **Segre relation**: for Z ≠ 0, X·Y = Z·T iff T = X·Y/Z
 #![allow(unused_imports)]
 use crate::backend::serial::u64::constants::EDWARDS_D;
 use crate::backend::serial::u64::field::FieldElement51;
 use crate::lemmas::common_lemmas::number_theory_lemmas::*;
+use crate::lemmas::edwards_lemmas::add_lemmas::lemma_field_sub_as_int;
+use crate::lemmas::edwards_lemmas::constants_lemmas::*;
+use crate::lemmas::edwards_lemmas::step1_lemmas::lemma_u_zero_implies_identity_point;
 use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
 use crate::specs::edwards_specs::*;
 use crate::specs::field_specs::*;
@@ -209,4 +217,339 @@ pub proof fn lemma_x_zero_implies_y_squared_one(x: nat, y: nat)
     assert(y2 == 1);
 }
 
+// =============================================================================
+// Recovering x from y
+// =============================================================================
+/// Lemma: a curve point satisfies x²·(d·y² + 1) = y² - 1
+///
+/// This is the converse of `lemma_sqrt_ratio_implies_on_curve`.
+///
+/// ## Mathematical Proof
+/// ```text
+/// y² - x² = 1 + d·x²·y²                 [curve equation]
+/// x²·(d·y² + 1) = d·x²·y² + x² = y² - 1
+/// ```
+pub proof fn lemma_on_curve_implies_x2_ratio(x: nat, y: nat)
+    requires
+        math_on_edwards_curve(x, y),
+    ensures
+        ({
+            let d = spec_field_element(&EDWARDS_D);
+            let y2 = math_field_square(y);
+            let u = math_field_sub(y2, 1);
+            let v = math_field_add(math_field_mul(d, y2), 1);
+            math_field_mul(math_field_square(x), v) == u
+        }),
+{
+    let p = p();
+    let pi = p as int;
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let x2 = math_field_square(x);
+    let y2 = math_field_square(y);
+    let dy2 = math_field_mul(d, y2);
+    let d_x2y2 = math_field_mul(d, math_field_mul(x2, y2));
+
+    // x²·(d·y² + 1) = x²·(d·y²) + x²
+    lemma_field_mul_distributes_over_add(x2, dy2, 1);
+    assert(math_field_mul(x2, 1) == x2) by {
+        lemma_mul_basics(x2 as int);
+        lemma_mod_bound((x * x) as int, pi);
+        lemma_small_mod(x2, p);
+    };
+
+    // x²·(d·y²) = d·(x²·y²)
+    assert(math_field_mul(x2, dy2) == d_x2y2) by {
+        lemma_field_mul_assoc(x2, d, y2);
+        lemma_field_mul_comm(x2, d);
+        lemma_field_mul_assoc(d, x2, y2);
+    };
+
+    // y² - x² ≡ 1 + d·x²·y², so d·x²·y² + x² ≡ y² - 1
+    let e = d_x2y2 as int;
+    lemma_field_sub_as_int(y2, x2);
+    lemma_field_sub_as_int(y2, 1);
+    lemma_add_mod_noop(1 + e, x2 as int - 1, pi);
+    lemma_add_mod_noop(y2 as int - x2 as int, x2 as int - 1, pi);
+}
+
+/// Lemma: d·y² + 1 ≠ 0 for every curve point
+///
+/// ## Mathematical Proof
+/// ```text
+/// d·y² + 1 = 0  ⟹  y² - 1 = x²·0 = 0  ⟹  y² = 1  ⟹  d + 1 = 0,
+/// but d + 1 ≠ 0                         [lemma_edwards_d_plus_one_nonzero]
+/// ```
+pub proof fn lemma_on_curve_v_nonzero(x: nat, y: nat)
+    requires
+        math_on_edwards_curve(x, y),
+    ensures
+        math_field_add(
+            math_field_mul(spec_field_element(&EDWARDS_D), math_field_square(y)),
+            1,
+        ) != 0,
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let y2 = math_field_square(y);
+    let v = math_field_add(math_field_mul(d, y2), 1);
+
+    if v == 0 {
+        // y² - 1 = x²·0 = 0
+        lemma_on_curve_implies_x2_ratio(x, y);
+        lemma_mul_basics(math_field_square(x) as int);
+        lemma_small_mod(0, p);
+        lemma_u_zero_implies_identity_point(y);
+
+        // so v = d·1 + 1 = d + 1 ≠ 0
+        lemma_mul_basics(d as int);
+        lemma_mod_bound(spec_field_element_as_nat(&EDWARDS_D) as int, p as int);
+        lemma_small_mod(d, p);
+        lemma_edwards_d_plus_one_nonzero();
+    }
+}
+
+/// Lemma: a curve point is determined by its y-coordinate and the sign of x
+///
+/// ## Mathematical Proof
+/// ```text
+/// x₁²·v = y² - 1 = x₂²·v with v = d·y² + 1 ≠ 0  ⟹  x₁² = x₂²  ⟹  x₁ = ±x₂
+/// x₁ = -x₂ ≠ x₂ means x₂ ≠ 0, and then x₁ and x₂ have different parity  [p odd]
+/// ```
+pub proof fn lemma_on_curve_x_determined_by_sign(x1: nat, x2: nat, y: nat)
+    requires
+        x1 < p(),
+        x2 < p(),
+        math_on_edwards_curve(x1, y),
+        math_on_edwards_curve(x2, y),
+        x1 % 2 == x2 % 2,
+    ensures
+        x1 == x2,
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let v = math_field_add(math_field_mul(d, math_field_square(y)), 1);
+    let sq1 = math_field_square(x1);
+    let sq2 = math_field_square(x2);
+
+    // x₁² = x₂², cancelling v ≠ 0
+    lemma_on_curve_implies_x2_ratio(x1, y);
+    lemma_on_curve_implies_x2_ratio(x2, y);
+    lemma_on_curve_v_nonzero(x1, y);
+    lemma_mod_bound((math_field_mul(d, math_field_square(y)) + 1) as int, p as int);
+    lemma_small_mod(v, p);
+    lemma_mod_bound((x1 * x1) as int, p as int);
+    lemma_mod_bound((x2 * x2) as int, p as int);
+    lemma_field_mul_comm(sq1, v);
+    lemma_field_mul_comm(sq2, v);
+    lemma_field_mul_left_cancel(v, sq1, sq2);
+
+    // x₁ = ±x₂, and -x₂ has the other sign unless x₂ = 0
+    lemma_equal_squares_equal_or_neg(x1, x2);
+    if x1 != x2 {
+        lemma_small_mod(x1, p);
+        lemma_small_mod(x2, p);
+        if x2 == 0 {
+            assert(math_field_neg(0) == 0) by {
+                lemma_small_mod(0, p);
+                lemma_mod_self_0(p as int);
+            };
+        } else {
+            lemma_neg_flips_sign(x2);
+        }
+    }
+}
+
+/// Lemma: the y-coordinate of a curve point is a valid y-coordinate, and y² = 1 forces x = 0
+///
+/// `x` itself witnesses `math_is_valid_y_coordinate`: x²·v = u.
+pub proof fn lemma_on_curve_implies_valid_y(x: nat, y: nat)
+    requires
+        x < p(),
+        math_on_edwards_curve(x, y),
+    ensures
+        math_is_valid_y_coordinate(y),
+        math_field_square(y) == 1 ==> x == 0,
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let y2 = math_field_square(y);
+    let u = math_field_sub(y2, 1);
+    let v = math_field_add(math_field_mul(d, y2), 1);
+    let x2 = math_field_square(x);
+
+    lemma_on_curve_implies_x2_ratio(x, y);
+    lemma_on_curve_v_nonzero(x, y);
+    lemma_mod_bound((math_field_mul(d, y2) + 1) as int, p as int);
+    lemma_small_mod(v, p);
+    lemma_field_sub_as_int(y2, 1);
+    lemma_mod_bound(y2 as int - 1, p as int);
+    lemma_small_mod(u, p);
+
+    // r = x solves r²·v = u
+    if u % p != 0 {
+        assert(math_field_mul(math_field_square(x), v) == u % p);
+    }
+
+    // y² = 1 gives x²·v = 0 with v ≠ 0, so x = 0
+    if y2 == 1 {
+        assert(u == 0) by {
+            lemma_small_mod(1, p);
+            lemma_mod_self_0(p as int);
+        };
+        if x != 0 {
+            lemma_small_mod(x, p);
+            lemma_field_mul_nonzero(x, x);
+            lemma_mod_bound((x * x) as int, p as int);
+            lemma_small_mod(x2, p);
+            lemma_field_mul_nonzero(x2, v);
+        }
+    }
+}
+
+// =============================================================================
+// Projective and extended coordinates
+// =============================================================================
+/// Lemma: for Z ≠ 0, (X : Y : Z) satisfies the homogenized curve equation exactly
+/// when (X/Z, Y/Z) is on the curve
+///
+/// ## Mathematical Proof
+/// ```text
+/// With w = 1/Z, u = X·w and v = Y·w:
+///   v² - u²      = (Y² - X²)·w²    = ((Y² - X²)·Z²)·w⁴
+///   1 + d·u²·v²  = 1 + d·X²·Y²·w⁴  = (Z⁴ + d·X²·Y²)·w⁴
+/// ```
+/// and multiplying by w⁴ ≠ 0 is injective.
+pub proof fn lemma_projective_curve_equation_iff_affine(x: nat, y: nat, z: nat)
+    requires
+        z % p() != 0,
+    ensures
+        math_on_edwards_curve_projective(x, y, z) == math_on_edwards_curve(
+            math_field_mul(x, math_field_inv(z)),
+            math_field_mul(y, math_field_inv(z)),
+        ),
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let w = math_field_inv(z);
+    let x2 = math_field_square(x);
+    let y2 = math_field_square(y);
+    let z2 = math_field_square(z);
+    let z4 = math_field_square(z2);
+    let w2 = math_field_square(w);
+    let w4 = math_field_square(w2);
+    let u2 = math_field_square(math_field_mul(x, w));
+    let v2 = math_field_square(math_field_mul(y, w));
+
+    // w·z = 1, hence w²·z² = 1 and w⁴·z⁴ = 1
+    field_inv_property(z);
+    lemma_mul_mod_noop_left(z as int, w as int, p as int);
+    lemma_field_mul_comm(w, z);
+    assert(math_field_mul(w, z) == 1);
+    lemma_small_mod(1, p);
+    assert(math_field_square(1) == 1);
+    lemma_product_of_squares_eq_square_of_product(w, z);
+    assert(math_field_mul(w2, z2) == 1);
+    lemma_product_of_squares_eq_square_of_product(w2, z2);
+    assert(math_field_mul(w4, z4) == 1);
+    lemma_field_mul_comm(w4, z4);
+
+    // w⁴·z² = w²·(w²·z²) = w²
+    assert(math_field_mul(w4, z2) == w2) by {
+        lemma_field_mul_assoc(w2, w2, z2);
+        lemma_mod_bound((w * w) as int, p as int);
+        lemma_small_mod(w2, p);
+    };
+
+    // u² = x²·w² and v² = y²·w²
+    lemma_product_of_squares_eq_square_of_product(x, w);
+    lemma_product_of_squares_eq_square_of_product(y, w);
+
+    // v² - u² = ((y² - x²)·z²)·w⁴
+    let s = math_field_sub(y2, x2);
+    let lhs = math_field_mul(s, z2);
+    assert(math_field_sub(v2, u2) == math_field_mul(lhs, w4)) by {
+        lemma_field_mul_distributes_over_sub(w2, y2, x2);
+        lemma_field_mul_comm(w2, y2);
+        lemma_field_mul_comm(w2, x2);
+        lemma_field_mul_comm(w2, s);
+        lemma_field_mul_assoc(s, z2, w4);
+        lemma_field_mul_comm(z2, w4);
+    };
+
+    // 1 + d·u²·v² = (z⁴ + d·x²·y²)·w⁴
+    let xy2 = math_field_mul(x2, y2);
+    let dxy2 = math_field_mul(d, xy2);
+    let rhs = math_field_add(z4, dxy2);
+    assert(math_field_add(1, math_field_mul(d, math_field_mul(u2, v2))) == math_field_mul(
+        rhs,
+        w4,
+    )) by {
+        lemma_field_mul_pair_rearrange(x2, y2, w2, w2);
+        lemma_field_mul_assoc(d, xy2, w4);
+        lemma_field_mul_distributes_over_add(w4, z4, dxy2);
+        lemma_field_mul_comm(w4, rhs);
+        lemma_field_mul_comm(w4, dxy2);
+    };
+
+    // Cancel w⁴ ≠ 0
+    if math_on_edwards_curve(math_field_mul(x, w), math_field_mul(y, w)) {
+        assert(w4 % p != 0) by {
+            lemma_mod_bound((w2 * w2) as int, p as int);
+            lemma_small_mod(w4, p);
+            if w4 == 0 {
+                lemma_field_mul_zero_left(w4, z4);
+            }
+        };
+        lemma_mod_bound((s * z2) as int, p as int);
+        lemma_mod_bound((z4 + dxy2) as int, p as int);
+        lemma_field_mul_comm(lhs, w4);
+        lemma_field_mul_comm(rhs, w4);
+        lemma_field_mul_left_cancel(w4, lhs, rhs);
+    }
+}
+
+/// Lemma: for Z ≠ 0, the extended coordinate satisfies X·Y = Z·T exactly when
+/// T = X·Y/Z
+///
+/// ## Mathematical Proof
+/// ```text
+/// Z·T = X·Y   ⟹  T = (X·Y)/Z             [solve for T]
+/// T = (X·Y)/Z ⟹  Z·T = (X·Y)·(Z/Z) = X·Y
+/// ```
+pub proof fn lemma_segre_relation_iff_extended(x: nat, y: nat, z: nat, t: nat)
+    requires
+        z % p() != 0,
+        t < p(),
+    ensures
+        (math_field_mul(x, y) == math_field_mul(z, t)) == (t == math_field_mul(
+            math_field_mul(x, y),
+            math_field_inv(z),
+        )),
+{
+    let p = p();
+    p_gt_2();
+    let xy = math_field_mul(x, y);
+    let w = math_field_inv(z);
+    lemma_mod_bound((x * y) as int, p as int);
+    lemma_small_mod(xy, p);
+    lemma_small_mod(t, p);
+    if xy == math_field_mul(z, t) {
+        lemma_field_mul_comm(z, t);
+        lemma_solve_for_left_factor(t, z, xy);
+    }
+    if t == math_field_mul(xy, w) {
+        // z·(xy·w) = xy·(w·z) = xy
+        field_inv_property(z);
+        lemma_mul_mod_noop_left(z as int, w as int, p as int);
+        lemma_field_mul_comm(w, z);
+        lemma_field_mul_comm(z, t);
+        lemma_field_mul_assoc(xy, w, z);
+    }
+}
+
 } // verus!
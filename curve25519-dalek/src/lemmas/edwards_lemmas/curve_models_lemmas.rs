@@ -0,0 +1,433 @@
+//! Lemmas for conversions between the curve models
+//!
+//! Each model carries its own representation spec in `edwards_specs`:
+//!
+//! | Model                  | Coordinates         | Affine point               |
+//! |------------------------|---------------------|----------------------------|
+//! | `ProjectivePoint`      | (X:Y:Z)             | (X/Z, Y/Z)                 |
+//! | `CompletedPoint`       | ((X:Z), (Y:T))      | (X/Z, Y/T)                 |
+//! | `EdwardsPoint`         | (X:Y:Z:T), T = XY/Z | (X/Z, Y/Z)                 |
+//! | `ProjectiveNielsPoint` | (Y+X, Y-X, Z, 2dT)  | recover X, Y; divide by Z  |
+//! | `AffineNielsPoint`     | (y+x, y-x, 2dxy)    | ((y+x)-(y-x))/2, (..+..)/2 |
+//!
+//! The lemmas here show every conversion preserves the represented affine point,
+//! and that the conversions into `EdwardsPoint` establish T = XY/Z.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_completed_to_extended`: ((X:Z), (Y:T)) ↦ (XT : YZ : ZT : XY)
+//! - `lemma_projective_to_extended`: (X:Y:Z) ↦ (XZ : YZ : Z² : XY)
+//! - `lemma_niels_recover_coordinates`: ((y+x) ∓ (y-x))/2 recovers x and y
+//! - `lemma_projective_niels_affine`: a ProjectiveNielsPoint has its Edwards point's affine form
+//! - `lemma_affine_niels_affine`: an AffineNielsPoint has its Edwards point's affine form
+//! - `lemma_identity_projective_niels_affine`: the Niels identity (1, 1, 1, 0) is (0, 1)
+//! - `lemma_identity_projective_niels_limbs_bounded`: the Niels identity has 54-bit limbs
+//! - `lemma_negate_projective_niels_affine`: swapping Y+X and Y-X negates x
+//! - `lemma_identity_affine_niels_affine`: the affine Niels identity (1, 1, 0) is (0, 1)
+//! - `lemma_identity_affine_niels_limbs_bounded`: the affine Niels identity has 54-bit limbs
+//! - `lemma_negate_affine_niels_affine`: swapping y+x and y-x negates x
+//! - `lemma_negate_edwards_point`: negating X and T negates x and keeps the point valid
+//! - `lemma_edwards_to_montgomery_u`: (Z+Y)/(Z-Y) is the Montgomery u = (1+y)/(1-y)
+#![allow(unused_imports)]
+use crate::backend::serial::curve_models::{AffineNielsPoint, ProjectiveNielsPoint};
+use crate::edwards::EdwardsPoint;
+use crate::lemmas::edwards_lemmas::add_lemmas::*;
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::lemma_negation_preserves_curve;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use crate::specs::montgomery_specs::montgomery_u_from_edwards_y;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Into extended coordinates
+// =============================================================================
+/// Lemma: the Segre map ((X:Z), (Y:T)) ↦ (X·T : Y·Z : Z·T : X·Y) preserves the affine point
+///
+/// Also covers `CompletedPoint::as_projective`, which computes the first three coordinates.
+///
+/// ## Mathematical Proof
+/// ```text
+/// (X·T)/(Z·T) = X/Z,  (Y·Z)/(Z·T) = Y/T               [cancel T resp. Z]
+/// (X·T)(Y·Z)/(Z·T) = X·Y                              [cancel Z·T]
+/// ```
+pub proof fn lemma_completed_to_extended(x: nat, y: nat, z: nat, t: nat)
+    requires
+        z < p(),
+        t < p(),
+        z != 0,
+        t != 0,
+    ensures
+        ({
+            let x3 = math_field_mul(x, t);
+            let y3 = math_field_mul(y, z);
+            let z3 = math_field_mul(z, t);
+            let t3 = math_field_mul(x, y);
+            &&& z3 != 0
+            &&& math_field_mul(x3, math_field_inv(z3)) == math_field_mul(x, math_field_inv(z))
+            &&& math_field_mul(y3, math_field_inv(z3)) == math_field_mul(y, math_field_inv(t))
+            &&& t3 == math_field_mul(math_field_mul(x3, y3), math_field_inv(z3))
+        }),
+{
+    let p = p();
+    p_gt_2();
+    lemma_small_mod(z, p);
+    lemma_small_mod(t, p);
+    let zt = math_field_mul(z, t);
+    let xy = math_field_mul(x, y);
+    lemma_field_mul_nonzero(z, t);
+
+    // X·T / Z·T = X / Z
+    lemma_field_mul_comm(x, t);
+    lemma_field_mul_comm(z, t);
+    lemma_field_common_factor(t, x, z);
+
+    // Y·Z / Z·T = Y / T
+    lemma_field_mul_comm(y, z);
+    lemma_field_common_factor(z, y, t);
+
+    // (X·T)(Y·Z) = (Z·T)(X·Y), then cancel Z·T
+    lemma_field_mul_rearrange_4(x, t, y, z);
+    lemma_field_cancel_factor(math_field_mul(t, z), xy);
+    lemma_mod_bound((x * y) as int, p as int);
+    lemma_small_mod(xy, p);
+}
+
+/// Lemma: (X:Y:Z) ↦ (X·Z : Y·Z : Z² : X·Y) preserves the affine point and gives T = XY/Z
+pub proof fn lemma_projective_to_extended(x: nat, y: nat, z: nat)
+    requires
+        z < p(),
+        z != 0,
+    ensures
+        ({
+            let x3 = math_field_mul(x, z);
+            let y3 = math_field_mul(y, z);
+            let z3 = math_field_square(z);
+            let t3 = math_field_mul(x, y);
+            &&& z3 != 0
+            &&& math_field_mul(x3, math_field_inv(z3)) == math_field_mul(x, math_field_inv(z))
+            &&& math_field_mul(y3, math_field_inv(z3)) == math_field_mul(y, math_field_inv(z))
+            &&& t3 == math_field_mul(math_field_mul(x3, y3), math_field_inv(z3))
+        }),
+{
+    let p = p();
+    p_gt_2();
+    lemma_small_mod(z, p);
+    let xy = math_field_mul(x, y);
+    lemma_field_mul_nonzero(z, z);
+
+    // X·Z / Z·Z = X / Z and Y·Z / Z·Z = Y / Z
+    lemma_field_mul_comm(x, z);
+    lemma_field_mul_comm(y, z);
+    lemma_field_common_factor(z, x, z);
+    lemma_field_common_factor(z, y, z);
+
+    // (X·Z)(Y·Z) = (Z·Z)(X·Y), then cancel Z·Z
+    lemma_field_mul_rearrange_4(x, z, y, z);
+    lemma_field_cancel_factor(math_field_mul(z, z), xy);
+    lemma_mod_bound((x * y) as int, p as int);
+    lemma_small_mod(xy, p);
+}
+
+// =============================================================================
+// Niels forms
+// =============================================================================
+/// Lemma: ((y+x) - (y-x))/2 = x and ((y+x) + (y-x))/2 = y
+pub proof fn lemma_niels_recover_coordinates(x: nat, y: nat)
+    requires
+        x < p(),
+        y < p(),
+    ensures
+        ({
+            let y_plus_x = math_field_add(y, x);
+            let y_minus_x = math_field_sub(y, x);
+            &&& math_field_mul(math_field_sub(y_plus_x, y_minus_x), math_field_inv(2)) == x
+            &&& math_field_mul(math_field_add(y_plus_x, y_minus_x), math_field_inv(2)) == y
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let pi = p as int;
+    let (xi, yi) = (x as int, y as int);
+    let y_plus_x = math_field_add(y, x);
+    let y_minus_x = math_field_sub(y, x);
+    lemma_field_sub_as_int(y, x);
+    lemma_small_mod(2, p);
+
+    // (y+x) - (y-x) ≡ 2x
+    assert(math_field_sub(y_plus_x, y_minus_x) == math_field_mul(2, x)) by {
+        lemma_field_sub_as_int(y_plus_x, y_minus_x);
+        lemma_sub_mod_noop(yi + xi, yi - xi, pi);
+    };
+    // (y+x) + (y-x) ≡ 2y
+    assert(math_field_add(y_plus_x, y_minus_x) == math_field_mul(2, y)) by {
+        lemma_add_mod_noop(yi + xi, yi - xi, pi);
+    };
+
+    // 2x / 2 = x and 2y / 2 = y
+    lemma_field_cancel_factor(2, x);
+    lemma_field_cancel_factor(2, y);
+    lemma_small_mod(x, p);
+    lemma_small_mod(y, p);
+}
+
+/// Lemma: a ProjectiveNielsPoint represents the same affine point as its EdwardsPoint
+pub proof fn lemma_projective_niels_affine(niels: ProjectiveNielsPoint, point: EdwardsPoint)
+    requires
+        projective_niels_corresponds_to_edwards(niels, point),
+    ensures
+        projective_niels_point_as_affine_edwards(niels) == edwards_point_as_affine(point),
+{
+    p_gt_2();
+    lemma_mod_bound(spec_field_element_as_nat(&point.X) as int, p() as int);
+    lemma_mod_bound(spec_field_element_as_nat(&point.Y) as int, p() as int);
+    lemma_niels_recover_coordinates(
+        spec_field_element(&point.X),
+        spec_field_element(&point.Y),
+    );
+}
+
+/// Lemma: an AffineNielsPoint represents the same affine point as its EdwardsPoint
+pub proof fn lemma_affine_niels_affine(niels: AffineNielsPoint, point: EdwardsPoint)
+    requires
+        affine_niels_corresponds_to_edwards(niels, point),
+    ensures
+        affine_niels_point_as_affine_edwards(niels) == edwards_point_as_affine(point),
+{
+    let p = p();
+    p_gt_2();
+    let (x, y) = edwards_point_as_affine(point);
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+    lemma_mod_bound((spec_field_element(&point.Y) * z_inv) as int, p as int);
+    lemma_niels_recover_coordinates(x, y);
+}
+
+/// Lemma: the ProjectiveNielsPoint identity (Y+X, Y-X, Z, T2d) = (1, 1, 1, 0) represents (0, 1)
+pub proof fn lemma_identity_projective_niels_affine()
+    ensures
+        projective_niels_point_as_affine_edwards(identity_projective_niels())
+            == math_edwards_identity(),
+{
+    let p = p();
+    p_gt_2();
+    let id = identity_projective_niels();
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    lemma_small_mod(2, p);
+    assert(u64_5_as_nat(id.Y_plus_X.limbs) == 1);
+    assert(u64_5_as_nat(id.Y_minus_X.limbs) == 1);
+    assert(u64_5_as_nat(id.Z.limbs) == 1);
+
+    // (1 - 1)/2 = 0 and (1 + 1)/2 = 1
+    let inv2 = math_field_inv(2);
+    assert(math_field_sub(1, 1) == 0) by {
+        lemma_mod_self_0(p as int);
+    };
+    assert(math_field_mul(0, inv2) == 0);
+    assert(math_field_mul(math_field_add(1, 1), inv2) == 1) by {
+        field_inv_property(2);
+    };
+
+    // Dividing by Z = 1 changes nothing
+    lemma_field_inv_one();
+    assert(math_field_mul(0, 1) == 0);
+    assert(math_field_mul(1, 1) == 1);
+}
+
+/// Lemma: the ProjectiveNielsPoint identity has 54-bit bounded limbs
+pub proof fn lemma_identity_projective_niels_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&identity_projective_niels().Y_plus_X, 54),
+        fe51_limbs_bounded(&identity_projective_niels().Y_minus_X, 54),
+        fe51_limbs_bounded(&identity_projective_niels().Z, 54),
+        fe51_limbs_bounded(&identity_projective_niels().T2d, 54),
+{
+    assert(0u64 < (1u64 << 54) && 1u64 < (1u64 << 54)) by (bit_vector);
+}
+
+/// Lemma: negating a ProjectiveNielsPoint negates the affine x-coordinate
+///
+/// ## Mathematical Proof
+/// ```text
+/// negate swaps Y+X and Y-X and keeps Z:
+///   ((Y-X) - (Y+X))/2 = -((Y+X) - (Y-X))/2 = -X    [lemma_field_sub_antisymmetric]
+///   ((Y-X) + (Y+X))/2 = Y
+/// ```
+pub proof fn lemma_negate_projective_niels_affine(niels: ProjectiveNielsPoint)
+    ensures
+        ({
+            let (x, y) = projective_niels_point_as_affine_edwards(niels);
+            projective_niels_point_as_affine_edwards(negate_projective_niels(niels)) == (
+                math_field_neg(x),
+                y,
+            )
+        }),
+{
+    let y_plus_x = spec_field_element(&niels.Y_plus_X);
+    let y_minus_x = spec_field_element(&niels.Y_minus_X);
+    let inv2 = math_field_inv(2);
+    let z_inv = math_field_inv(spec_field_element(&niels.Z));
+    let x_proj = math_field_mul(math_field_sub(y_plus_x, y_minus_x), inv2);
+
+    lemma_field_sub_antisymmetric(y_minus_x, y_plus_x);
+    lemma_field_neg_mul(math_field_sub(y_plus_x, y_minus_x), inv2);
+    lemma_field_neg_mul(x_proj, z_inv);
+}
+
+/// Lemma: the AffineNielsPoint identity (y+x, y-x, xy2d) = (1, 1, 0) represents (0, 1)
+pub proof fn lemma_identity_affine_niels_affine()
+    ensures
+        affine_niels_point_as_affine_edwards(identity_affine_niels()) == math_edwards_identity(),
+{
+    let p = p();
+    p_gt_2();
+    let id = identity_affine_niels();
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    lemma_small_mod(2, p);
+    assert(u64_5_as_nat(id.y_plus_x.limbs) == 1);
+    assert(u64_5_as_nat(id.y_minus_x.limbs) == 1);
+
+    // (1 - 1)/2 = 0 and (1 + 1)/2 = 1
+    let inv2 = math_field_inv(2);
+    assert(math_field_sub(1, 1) == 0) by {
+        lemma_mod_self_0(p as int);
+    };
+    assert(math_field_mul(0, inv2) == 0);
+    assert(math_field_mul(math_field_add(1, 1), inv2) == 1) by {
+        field_inv_property(2);
+    };
+}
+
+/// Lemma: the AffineNielsPoint identity has 54-bit bounded limbs
+pub proof fn lemma_identity_affine_niels_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&identity_affine_niels().y_plus_x, 54),
+        fe51_limbs_bounded(&identity_affine_niels().y_minus_x, 54),
+        fe51_limbs_bounded(&identity_affine_niels().xy2d, 54),
+{
+    assert(0u64 < (1u64 << 54) && 1u64 < (1u64 << 54)) by (bit_vector);
+}
+
+/// Lemma: negating an AffineNielsPoint negates the affine x-coordinate
+///
+/// Same argument as `lemma_negate_projective_niels_affine`, without the division by Z.
+pub proof fn lemma_negate_affine_niels_affine(niels: AffineNielsPoint)
+    ensures
+        ({
+            let (x, y) = affine_niels_point_as_affine_edwards(niels);
+            affine_niels_point_as_affine_edwards(negate_affine_niels(niels)) == (
+                math_field_neg(x),
+                y,
+            )
+        }),
+{
+    let y_plus_x = spec_field_element(&niels.y_plus_x);
+    let y_minus_x = spec_field_element(&niels.y_minus_x);
+    let inv2 = math_field_inv(2);
+
+    lemma_field_sub_antisymmetric(y_minus_x, y_plus_x);
+    lemma_field_neg_mul(math_field_sub(y_plus_x, y_minus_x), inv2);
+}
+
+/// Lemma: negating X and T of a valid EdwardsPoint negates the affine x-coordinate
+/// and keeps the point valid
+///
+/// ## Mathematical Proof
+/// ```text
+/// (-X)/Z = -(X/Z)                               [lemma_field_neg_mul]
+/// (-X)·Y/Z = -(X·Y/Z) = -T
+/// (-x, y) is on the curve                       [lemma_negation_preserves_curve]
+/// ```
+pub proof fn lemma_negate_edwards_point(point: EdwardsPoint, neg: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+        spec_field_element(&neg.X) == math_field_neg(spec_field_element(&point.X)),
+        spec_field_element(&neg.Y) == spec_field_element(&point.Y),
+        spec_field_element(&neg.Z) == spec_field_element(&point.Z),
+        spec_field_element(&neg.T) == math_field_neg(spec_field_element(&point.T)),
+    ensures
+        is_valid_edwards_point(neg),
+        ({
+            let (x, y) = edwards_point_as_affine(point);
+            edwards_point_as_affine(neg) == (math_field_neg(x), y)
+        }),
+{
+    let x = spec_field_element(&point.X);
+    let y = spec_field_element(&point.Y);
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+
+    lemma_field_neg_mul(x, z_inv);
+    lemma_field_neg_mul(x, y);
+    lemma_field_neg_mul(math_field_mul(x, y), z_inv);
+    lemma_negation_preserves_curve(math_field_mul(x, z_inv), math_field_mul(y, z_inv));
+}
+
+/// Lemma: (Z + Y)/(Z - Y) is the Montgomery u-coordinate (1 + y)/(1 - y) of a
+/// valid EdwardsPoint, including the exceptional case y = 1 where both are 0
+///
+/// ## Mathematical Proof
+/// With y = Y/Z and Z ≠ 0:
+/// ```text
+/// Z·(1 + y) = Z + Y,   Z·(1 - y) = Z - Y
+/// 1 - y = 0:  Z - Y = 0, so (Z + Y)·inv(0) = 0
+/// 1 - y ≠ 0:  (Z·(1 + y))·inv(Z·(1 - y)) = (1 + y)·(Z·inv(Z·(1 - y))) = (1 + y)·inv(1 - y)
+/// ```
+pub proof fn lemma_edwards_to_montgomery_u(point: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+    ensures
+        ({
+            let y = spec_field_element(&point.Y);
+            let z = spec_field_element(&point.Z);
+            math_field_mul(math_field_add(z, y), math_field_inv(math_field_sub(z, y)))
+                == montgomery_u_from_edwards_y(edwards_point_as_affine(point).1)
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let y = spec_field_element(&point.Y);
+    let z = spec_field_element(&point.Z);
+    let z_inv = math_field_inv(z);
+    let y_aff = math_field_mul(y, z_inv);
+    lemma_mod_bound(spec_field_element_as_nat(&point.Y) as int, p as int);
+    lemma_mod_bound(spec_field_element_as_nat(&point.Z) as int, p as int);
+    lemma_small_mod(y, p);
+    lemma_small_mod(z, p);
+
+    // Z·y = Z·(Y·inv(Z)) = (Z·inv(Z))·Y = Y
+    assert(math_field_mul(z, y_aff) == y) by {
+        field_inv_property(z);
+        lemma_field_mul_comm(y, z_inv);
+        lemma_field_mul_assoc(z, z_inv, y);
+        assert(math_field_mul(1, y) == y);
+    };
+    assert(math_field_mul(z, 1) == z);
+
+    // Z·(1 + y) = Z + Y and Z·(1 - y) = Z - Y
+    let num = math_field_add(1, y_aff);
+    let den = math_field_sub(1, y_aff);
+    lemma_field_mul_distributes_over_add(z, 1, y_aff);
+    lemma_field_mul_distributes_over_sub(z, 1, y_aff);
+    assert(math_field_add(z, y) == math_field_mul(z, num));
+    assert(math_field_sub(z, y) == math_field_mul(z, den));
+
+    if den == 0 {
+        lemma_field_mul_zero_right(z, den);
+        assert(math_field_inv(0) == 0);
+        lemma_field_mul_zero_right(math_field_add(z, y), 0);
+    } else {
+        // (Z·num)·inv(Z·den) = num·(Z·inv(Z·den)) = num·inv(den)
+        lemma_field_mul_comm(z, num);
+        lemma_field_mul_assoc(num, z, math_field_inv(math_field_mul(z, den)));
+        lemma_a_times_inv_ab_is_inv_b(z, den);
+    }
+}
+
+} // verus!
@@ -168,7 +168,7 @@ pub proof fn lemma_decompress_field_element_sign_bit(
 /// Combined: sign_bit = 1 ==> x ≠ 0
 pub proof fn lemma_sign_bit_one_implies_x_nonzero(bytes: &[u8; 32], x: nat, y: nat)
     requires
-        compressed_y_has_valid_sign_bit(bytes),  // decompress precondition
+        compressed_y_has_valid_sign_bit(bytes),
         y == spec_field_element_from_bytes(bytes),  // Y from bytes
         math_on_edwards_curve(x, y),  // (x, y) on curve
         x < p(),  // X bounded
@@ -209,12 +209,14 @@ pub proof fn lemma_sign_bit_one_implies_x_nonzero(bytes: &[u8; 32], x: nat, y: n
 /// - `point`: The final EdwardsPoint from step_2
 ///
 /// ## Proves
-/// - is_valid_edwards_point(point)
+/// - is_valid_edwards_point(point), with (X, Y) itself on the curve
 /// - spec_field_element(&point.Y) == spec_field_element_from_bytes(repr_bytes)
-/// - spec_field_element_sign_bit(&point.X) == (repr_bytes[31] >> 7)
+/// - spec_field_element_sign_bit(&point.X) == (repr_bytes[31] >> 7), if the sign bit is valid
+///
+/// The sign bit is not required to be valid: for y² = 1 and sign bit 1, x = 0 is
+/// "negated" to itself, so the point is still valid but its sign bit is 0.
 pub proof fn lemma_decompress_valid_branch(repr_bytes: &[u8; 32], x_orig: nat, point: &EdwardsPoint)
     requires
-        compressed_y_has_valid_sign_bit(repr_bytes),
         // step_1 postconditions
         spec_field_element(&point.Y) == spec_field_element_from_bytes(repr_bytes),
         math_on_edwards_curve(x_orig, spec_field_element(&point.Y)),
@@ -236,8 +238,10 @@ pub proof fn lemma_decompress_valid_branch(repr_bytes: &[u8; 32], x_orig: nat, p
         ),
     ensures
         is_valid_edwards_point(*point),
+        math_on_edwards_curve(spec_field_element(&point.X), spec_field_element(&point.Y)),
         spec_field_element(&point.Y) == spec_field_element_from_bytes(repr_bytes),
-        spec_field_element_sign_bit(&point.X) == (repr_bytes[31] >> 7),
+        compressed_y_has_valid_sign_bit(repr_bytes) ==> spec_field_element_sign_bit(&point.X) == (
+        repr_bytes[31] >> 7),
 {
     let x_final = spec_field_element(&point.X);
     let y_final = spec_field_element(&point.Y);
@@ -248,18 +252,18 @@ pub proof fn lemma_decompress_valid_branch(repr_bytes: &[u8; 32], x_orig: nat, p
     // =========================================================================
     // Goal 1: is_valid_edwards_point(point)
     // =========================================================================
-    assert(is_valid_edwards_point(*point)) by {
-        // Establish that (x_final, y_final) is on curve
-        assert(math_on_edwards_curve(x_final, y_final)) by {
-            // X is conditionally negated; negation preserves curve membership
-            if sign_bit == 1 {
-                assert(x_final == math_field_neg(x_orig));
-                lemma_negation_preserves_curve(x_orig, y_final);
-            } else {
-                assert(x_final == x_orig);
-            }
-        };
+    // Establish that (x_final, y_final) is on curve
+    assert(math_on_edwards_curve(x_final, y_final)) by {
+        // X is conditionally negated; negation preserves curve membership
+        if sign_bit == 1 {
+            assert(x_final == math_field_neg(x_orig));
+            lemma_negation_preserves_curve(x_orig, y_final);
+        } else {
+            assert(x_final == x_orig);
+        }
+    };
 
+    assert(is_valid_edwards_point(*point)) by {
         // Z = 1, T = X * Y
         assert(z_final == 1);
         assert(t_final == math_field_mul(x_final, y_final));
@@ -273,9 +277,9 @@ pub proof fn lemma_decompress_valid_branch(repr_bytes: &[u8; 32], x_orig: nat, p
     // =========================================================================
 
     // =========================================================================
-    // Goal 3: Sign bit correctness
+    // Goal 3: Sign bit correctness (for a valid sign bit)
     // =========================================================================
-    assert(spec_field_element_sign_bit(&point.X) == (repr_bytes[31] >> 7)) by {
+    if compressed_y_has_valid_sign_bit(repr_bytes) {
         let x_before = x_orig;
         let x_after = x_final;
         let repr_byte_31 = repr_bytes[31];
@@ -312,7 +316,7 @@ pub proof fn lemma_decompress_valid_branch(repr_bytes: &[u8; 32], x_orig: nat, p
 
             lemma_decompress_field_element_sign_bit(x_before, x_after, sign_bit);
         };
-    };
+    }
 }
 
 } // verus!
@@ -4,12 +4,22 @@
 //!
 //! ## Submodules
 //!
-//! - `constants_lemmas`: Lemmas about Edwards curve constants (EDWARDS_D)
+//! - `add_lemmas`: The extended-coordinate addition formula computes the group law
+//! - `compress_lemmas`: Compression stores y and the sign of x; decompress(compress(P)) = P
+//! - `constants_lemmas`: Lemmas about Edwards curve constants (EDWARDS_D, the basepoint)
+//! - `curve_models_lemmas`: Conversions between curve models preserve the affine point
 //! - `curve_equation_lemmas`: General lemmas about the curve equation (negation, extended coords)
 //! - `step1_lemmas`: Lemmas for step_1 of point decompression (curve equation, validity)
 //! - `decompress_lemmas`: Lemmas for point decompression (sign bit, extended coords)
+//! - `scalar_mul_lemmas`: Group-law axioms and [a]P + [b]P = [a+b]P for scalar multiplication
+//! - `torsion_lemmas`: `EIGHT_TORSION[i]` is [i]P for a point P of order 8
 //!
+pub mod add_lemmas;
+pub mod compress_lemmas;
 pub mod constants_lemmas;
 pub mod curve_equation_lemmas;
+pub mod curve_models_lemmas;
 pub mod decompress_lemmas;
+pub mod scalar_mul_lemmas;
 pub mod step1_lemmas;
+pub mod torsion_lemmas;
@@ -0,0 +1,1247 @@
+//! Lemmas for scalar multiplication on the Edwards curve
+//!
+//! `edwards_scalar_mul(P, n)` is defined by repeated addition, [n]P = [n-1]P + P.
+//! The scalar multiplication algorithms instead evaluate a signed-digit expansion
+//! s = Σ dᵢ·16ⁱ by Horner's rule:
+//!
+//! ```text
+//! [s]P = [d_0]P + 16·([d_1]P + 16·( ... + 16·[d_63]P))
+//! ```
+//!
+//! so their proofs need [a]P + [b]P = [a + b]P for signed a and b.
+//!
+//! The multiscalar algorithms run this for all points at once, one digit position
+//! at a time, so their accumulator is a sum Σ [c_i]P_i whose coefficients c_i are the
+//! digits processed so far (`sum_of_signed_scalar_muls`).
+//!
+//! ## Group Law
+//!
+//! Closure, associativity and inverses of `edwards_add` on the curve are taken as
+//! axioms. Commutativity, the identity law, and -P + -Q = -(P + Q) are proven from
+//! the addition formula.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_identity_edwards_point_well_formed`: (0 : 1 : 1 : 0) is a well-formed identity point
+//! - `lemma_edwards_point_is_identity_iff`: a valid point is (0, 1) iff X = 0 and Y = Z
+//! - `lemma_edwards_point_add_identity`: P + O = O + P = P for EdwardsPoints
+//! - `lemma_edwards_point_add_negation`: P + (-P) = (-P) + P = O for EdwardsPoints
+//! - `lemma_edwards_point_as_affine_reduced`: a valid EdwardsPoint is a reduced curve point
+//! - `lemma_identity_projective_point_affine`: the ProjectivePoint identity is (0, 1)
+//! - `lemma_edwards_add_rearrange`: (A + B) + (C + D) = (A + C) + (B + D)
+//! - `lemma_edwards_scalar_mul_on_curve`: [n]P is a reduced curve point
+//! - `lemma_edwards_scalar_mul_additive`: [m]P + [n]P = [m+n]P
+//! - `lemma_edwards_scalar_mul_add_double`: [2]P + [n]P = [n+2]P
+//! - `lemma_edwards_scalar_mul_signed_additive`: the same for signed m, n
+//! - `lemma_edwards_scalar_mul_signed_double`: 2·[a]P = [2a]P
+//! - `lemma_edwards_scalar_mul_signed_scale`: [n]([a]P) = [n·a]P
+//! - `lemma_edwards_scalar_mul_signed_of_multiple`: [d]([m]P) = [d·m]P for signed d
+//! - `lemma_edwards_scalar_mul_mod_period`: [n]P = [n mod k]P when [k]P = O
+//! - `lemma_edwards_scalar_mul_mod_order`: [n]P = [n mod ℓ]P when [ℓ]P = O
+//! - `lemma_edwards_scalar_mul_signed_on_curve`: [a]P is a reduced curve point for signed a
+//! - `lemma_edwards_scalar_mul_signed_unit`: [1]P = P and [-1]P = -P
+//! - `lemma_edwards_scalar_mul_distributes`: [n](A + B) = [n]A + [n]B
+//! - `lemma_sum_of_signed_scalar_muls_on_curve`: Σ [c_i]P_i is a reduced curve point
+//! - `lemma_sum_of_signed_scalar_muls_add`: Σ [a_i]P_i + Σ [b_i]P_i = Σ [a_i + b_i]P_i
+//! - `lemma_sum_of_signed_scalar_muls_scale`: [w](Σ [c_i]P_i) = Σ [w·c_i]P_i
+//! - `lemma_sum_of_scalar_muls_as_signed`: `sum_of_scalar_muls` as a signed sum
+//! - `lemma_select_projective_niels_is_signed_multiple`: `LookupTable::select(x)` is [x]P
+//! - `lemma_select_affine_niels_is_signed_multiple`: the same for AffineNiels tables
+//! - `lemma_reconstruct_radix_16_suffix`: one Horner step of the radix-16 digits
+//! - `lemma_radix_16_parity_split`: s = (even-position digits) + 16·(odd-position digits)
+//! - `lemma_select_naf_projective_niels_is_multiple`: `NafLookupTable5::select(x)` is [x]P
+//! - `lemma_reconstruct_naf_suffix`: one Horner step of the NAF digits
+//! - `lemma_reconstruct_radix_2w_suffix`: one Horner step of the radix-2^w digits
+#![allow(unused_imports)]
+use crate::backend::serial::curve_models::{AffineNielsPoint, ProjectiveNielsPoint};
+use crate::backend::serial::u64::constants::EDWARDS_D;
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::edwards::EdwardsPoint;
+use crate::lemmas::edwards_lemmas::add_lemmas::*;
+use crate::lemmas::edwards_lemmas::constants_lemmas::lemma_ed25519_basepoint_on_curve;
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::*;
+use crate::lemmas::edwards_lemmas::curve_models_lemmas::*;
+use crate::lemmas::edwards_lemmas::step1_lemmas::lemma_u_zero_implies_identity_point;
+use crate::lemmas::field_lemmas::constants_lemmas::*;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::lemma_fe51_limbs_bounded_weaken;
+use crate::scalar::Scalar;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use crate::specs::scalar52_specs::group_order;
+use crate::specs::scalar_mul_specs::{
+    points_as_affine, sum_of_scalar_muls, sum_of_signed_scalar_muls,
+};
+use crate::specs::scalar_specs::*;
+use crate::specs::window_specs::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Group law axioms
+// =============================================================================
+// With a = -1 a square and d a non-square in F_p, the twisted Edwards addition law
+// is complete: both denominators 1 ± d·x1·x2·y1·y2 are non-zero for all pairs of
+// curve points, and the curve is an abelian group under it ([BBJLP2008] Section 6,
+// [HWCD2008] Section 3). The following facts are taken as axioms.
+/// AXIOM: the sum of two curve points is a curve point
+pub proof fn axiom_edwards_add_on_curve(x1: nat, y1: nat, x2: nat, y2: nat)
+    requires
+        math_on_edwards_curve(x1, y1),
+        math_on_edwards_curve(x2, y2),
+    ensures
+        ({
+            let (x3, y3) = edwards_add(x1, y1, x2, y2);
+            math_on_edwards_curve(x3, y3)
+        }),
+{
+    admit();  // Completeness of the addition law on the curve
+}
+
+/// AXIOM: addition of curve points is associative
+pub proof fn axiom_edwards_add_associative(
+    x1: nat,
+    y1: nat,
+    x2: nat,
+    y2: nat,
+    x3: nat,
+    y3: nat,
+)
+    requires
+        math_on_edwards_curve(x1, y1),
+        math_on_edwards_curve(x2, y2),
+        math_on_edwards_curve(x3, y3),
+    ensures
+        ({
+            let ab = edwards_add(x1, y1, x2, y2);
+            let bc = edwards_add(x2, y2, x3, y3);
+            edwards_add(ab.0, ab.1, x3, y3) == edwards_add(x1, y1, bc.0, bc.1)
+        }),
+{
+    admit();  // Group law of the twisted Edwards curve
+}
+
+/// AXIOM: (x, y) + (-x, y) = (0, 1) for a curve point
+///
+/// The x-coordinate is x·y - y·x = 0. The y-coordinate is
+/// (y² - x²)/(1 + d·x²·y²) = 1 by the curve equation, once the denominator is
+/// known to be non-zero, which is where completeness is needed.
+pub proof fn axiom_edwards_add_inverse(x: nat, y: nat)
+    requires
+        math_on_edwards_curve(x, y),
+    ensures
+        edwards_add(x, y, math_field_neg(x), y) == math_edwards_identity(),
+{
+    admit();  // Completeness of the addition law on the curve
+}
+
+/// AXIOM: the Ed25519 basepoint B is a curve point of order ℓ
+///
+/// [RFC8032] Section 5.1: B = (x, 4/5) with x positive, and [ℓ]B = O for
+/// ℓ = 2^252 + 27742317777372353535851937790883648493.
+///
+/// That B is on the curve is proven by evaluation (`lemma_ed25519_basepoint_on_curve`).
+/// Only the order is assumed.
+///
+/// Mathematical justification:
+/// - [RFC8032] Section 5.1 gives B as a generator of the subgroup of prime order ℓ
+/// - Upstream checks [ℓ]B = O numerically in the `basepoint_mult_by_basepoint_order`
+///   test in edwards.rs
+///
+/// Used in: `mul_base_clamped`, `EdwardsBasepointTable::mul_base`
+pub proof fn axiom_ed25519_basepoint_order()
+    ensures
+        math_on_edwards_curve(spec_ed25519_basepoint().0, spec_ed25519_basepoint().1),
+        edwards_scalar_mul(spec_ed25519_basepoint(), group_order()) == math_edwards_identity(),
+{
+    lemma_ed25519_basepoint_on_curve();
+    admit();  // [ℓ]B = O for the standard basepoint
+}
+
+// =============================================================================
+// Group law lemmas
+// =============================================================================
+/// Lemma: the affine coordinates of a sum are reduced
+pub proof fn lemma_edwards_add_reduced(x1: nat, y1: nat, x2: nat, y2: nat)
+    ensures
+        edwards_add(x1, y1, x2, y2).0 < p(),
+        edwards_add(x1, y1, x2, y2).1 < p(),
+{
+    let p = p() as int;
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let x1x2 = math_field_mul(x1, x2);
+    let y1y2 = math_field_mul(y1, y2);
+    let t = math_field_mul(d, math_field_mul(x1x2, y1y2));
+    let num_x = math_field_add(math_field_mul(x1, y2), math_field_mul(y1, x2));
+    let num_y = math_field_add(y1y2, x1x2);
+    lemma_mod_bound((num_x * math_field_inv(math_field_add(1, t))) as int, p);
+    lemma_mod_bound((num_y * math_field_inv(math_field_sub(1, t))) as int, p);
+}
+
+/// Lemma: edwards_add is commutative
+pub proof fn lemma_edwards_add_commutative(x1: nat, y1: nat, x2: nat, y2: nat)
+    ensures
+        edwards_add(x1, y1, x2, y2) == edwards_add(x2, y2, x1, y1),
+{
+    // Every product in the formula is symmetric under swapping the points
+    lemma_field_mul_comm(x1, x2);
+    lemma_field_mul_comm(y1, y2);
+    lemma_field_mul_comm(x1, y2);
+    lemma_field_mul_comm(y1, x2);
+}
+
+/// Lemma: (x, y) + (0, 1) = (x, y) for reduced x, y
+///
+/// ## Mathematical Proof
+/// ```text
+/// x1·x2 = 0, y1·y2 = y, x1·y2 = x, y1·x2 = 0, so t = d·0·y = 0
+/// x3 = (x + 0)/(1 + 0) = x
+/// y3 = (y + 0)/(1 - 0) = y
+/// ```
+pub proof fn lemma_edwards_add_identity_right(x: nat, y: nat)
+    requires
+        x < p(),
+        y < p(),
+    ensures
+        edwards_add(x, y, 0, 1) == (x, y),
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    lemma_small_mod(x, p);
+    lemma_small_mod(y, p);
+
+    assert(math_field_mul(x, 0) == 0);
+    assert(math_field_mul(y, 1) == y);
+    assert(math_field_mul(x, 1) == x);
+    assert(math_field_mul(y, 0) == 0);
+    assert(math_field_mul(0, y) == 0);
+    assert(math_field_mul(d, 0) == 0);
+
+    // Both denominators are 1
+    assert(math_field_add(1, 0) == 1);
+    assert(math_field_sub(1, 0) == 1) by {
+        lemma_mod_add_multiples_vanish(1, p as int);
+    };
+    lemma_field_inv_one();
+    assert(math_field_add(x, 0) == x);
+    assert(math_field_add(y, 0) == y);
+}
+
+/// Lemma: (0, 1) + (x, y) = (x, y) for reduced x, y
+pub proof fn lemma_edwards_add_identity_left(x: nat, y: nat)
+    requires
+        x < p(),
+        y < p(),
+    ensures
+        edwards_add(0, 1, x, y) == (x, y),
+{
+    lemma_edwards_add_commutative(0, 1, x, y);
+    lemma_edwards_add_identity_right(x, y);
+}
+
+/// Lemma: (-x1, y1) + (-x2, y2) = -((x1, y1) + (x2, y2))
+///
+/// ## Mathematical Proof
+/// ```text
+/// (-x1)(-x2) = x1·x2, so t and the y-coordinate are unchanged
+/// (-x1)·y2 + y1·(-x2) = -(x1·y2 + y1·x2), so the x-coordinate is negated
+/// ```
+pub proof fn lemma_edwards_add_of_negations(x1: nat, y1: nat, x2: nat, y2: nat)
+    ensures
+        ({
+            let (x3, y3) = edwards_add(x1, y1, x2, y2);
+            edwards_add(math_field_neg(x1), y1, math_field_neg(x2), y2) == (
+                math_field_neg(x3),
+                y3,
+            )
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let d = spec_field_element(&EDWARDS_D);
+    let x1x2 = math_field_mul(x1, x2);
+    let y1y2 = math_field_mul(y1, y2);
+    let t = math_field_mul(d, math_field_mul(x1x2, y1y2));
+    let num_x = math_field_add(math_field_mul(x1, y2), math_field_mul(y1, x2));
+
+    // (-x1)·(-x2) = -(-(x1·x2)) = x1·x2
+    assert(math_field_mul(math_field_neg(x1), math_field_neg(x2)) == x1x2) by {
+        lemma_field_neg_mul(x1, math_field_neg(x2));
+        lemma_field_mul_neg(x1, x2);
+        lemma_mod_bound((x1 * x2) as int, p as int);
+        lemma_field_neg_neg(x1x2);
+    };
+
+    // The cross terms, and so the x numerator, are negated
+    lemma_field_neg_mul(x1, y2);
+    lemma_field_mul_neg(y1, x2);
+    lemma_field_neg_add(math_field_mul(x1, y2), math_field_mul(y1, x2));
+    lemma_field_neg_mul(num_x, math_field_inv(math_field_add(1, t)));
+}
+
+/// Lemma: the identity (0, 1) is on the curve
+pub proof fn lemma_edwards_identity_on_curve()
+    ensures
+        math_on_edwards_curve(0, 1),
+{
+    let p = p();
+    p_gt_2();
+    lemma_small_mod(1, p);
+    lemma_mod_self_0(p as int);
+    // 1² - 1 = 0
+    assert(math_field_sub(math_field_square(1), 1) == 0);
+    lemma_u_zero_implies_identity_point(1);
+}
+
+/// Lemma: an EdwardsPoint satisfying `is_identity_edwards_point` represents (0, 1)
+pub proof fn lemma_identity_edwards_point_affine(point: EdwardsPoint)
+    requires
+        is_identity_edwards_point(point),
+    ensures
+        edwards_point_as_affine(point) == math_edwards_identity(),
+{
+    let p = p();
+    p_gt_2();
+    let z = spec_field_element(&point.Z);
+    lemma_mod_bound(spec_field_element_as_nat(&point.Z) as int, p as int);
+    lemma_small_mod(0, p);
+    lemma_small_mod(z, p);
+    // X = 0 and Y = Z, so the affine point is (0/Z, Z/Z) = (0, 1)
+    field_inv_property(z);
+}
+
+/// Lemma: the EdwardsPoint (0 : 1 : 1 : 0) built from ZERO and ONE is a
+/// well-formed identity point
+pub proof fn lemma_identity_edwards_point_well_formed(point: EdwardsPoint)
+    requires
+        point.X == FieldElement51::ZERO,
+        point.Y == FieldElement51::ONE,
+        point.Z == FieldElement51::ONE,
+        point.T == FieldElement51::ZERO,
+    ensures
+        is_identity_edwards_point(point),
+        is_well_formed_edwards_point(point),
+{
+    let p = p();
+    p_gt_2();
+    lemma_zero_field_element_value();
+    lemma_one_field_element_value();
+    lemma_one_limbs_bounded_51();
+    lemma_fe51_limbs_bounded_weaken(&point.X, 51, 54);
+    lemma_fe51_limbs_bounded_weaken(&point.Y, 51, 54);
+    lemma_fe51_limbs_bounded_weaken(&point.Z, 51, 54);
+    lemma_fe51_limbs_bounded_weaken(&point.T, 51, 54);
+
+    // Dividing by Z = 1 changes nothing, so the affine point is (0, 1)
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    lemma_field_inv_one();
+    assert(math_field_mul(0, 1) == 0);
+    assert(math_field_mul(1, 1) == 1);
+    lemma_edwards_identity_on_curve();
+}
+
+/// Lemma: a valid EdwardsPoint represents (0, 1) iff X = 0 and Y = Z
+///
+/// ## Mathematical Proof
+/// ```text
+/// X/Z = 0  ⟹  X = 0·Z = 0
+/// Y/Z = 1  ⟹  Y = 1·Z = Z                  [lemma_field_mul_left_cancel by 1/Z]
+/// ```
+pub proof fn lemma_edwards_point_is_identity_iff(point: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+    ensures
+        (edwards_point_as_affine(point) == math_edwards_identity()) == is_identity_edwards_point(
+            point,
+        ),
+{
+    if is_identity_edwards_point(point) {
+        lemma_identity_edwards_point_affine(point);
+    } else if edwards_point_as_affine(point) == math_edwards_identity() {
+        let p = p();
+        p_gt_2();
+        let x = spec_field_element(&point.X);
+        let y = spec_field_element(&point.Y);
+        let z = spec_field_element(&point.Z);
+        let z_inv = math_field_inv(z);
+        lemma_mod_bound(spec_field_element_as_nat(&point.X) as int, p as int);
+        lemma_mod_bound(spec_field_element_as_nat(&point.Y) as int, p as int);
+        lemma_mod_bound(spec_field_element_as_nat(&point.Z) as int, p as int);
+        lemma_small_mod(z, p);
+
+        // 1/Z is a non-zero field element
+        field_inv_property(z);
+        lemma_small_mod(z_inv, p);
+        assert(z_inv != 0) by {
+            lemma_mul_basics(z as int);
+            lemma_small_mod(0, p);
+        };
+
+        // 1/Z · X = 0 = 1/Z · 0
+        lemma_field_mul_comm(x, z_inv);
+        lemma_field_mul_zero_right(z_inv, 0);
+        lemma_field_mul_left_cancel(z_inv, x, 0);
+
+        // 1/Z · Y = 1 = 1/Z · Z
+        lemma_field_mul_comm(y, z_inv);
+        lemma_field_mul_comm(z, z_inv);
+        lemma_field_mul_left_cancel(z_inv, y, z);
+    }
+}
+
+/// Lemma: P + O = P and O + P = P for a valid EdwardsPoint P and any
+/// EdwardsPoint O satisfying `is_identity_edwards_point`
+pub proof fn lemma_edwards_point_add_identity(point: EdwardsPoint, identity: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+        is_identity_edwards_point(identity),
+    ensures
+        ({
+            let (x, y) = edwards_point_as_affine(point);
+            let (ox, oy) = edwards_point_as_affine(identity);
+            edwards_add(x, y, ox, oy) == (x, y) && edwards_add(ox, oy, x, y) == (x, y)
+        }),
+{
+    let p = p();
+    p_gt_2();
+    let (x, y) = edwards_point_as_affine(point);
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+    lemma_mod_bound((spec_field_element(&point.Y) * z_inv) as int, p as int);
+
+    lemma_identity_edwards_point_affine(identity);
+    lemma_edwards_add_identity_right(x, y);
+    lemma_edwards_add_identity_left(x, y);
+}
+
+/// Lemma: P + (-P) = (-P) + P = O for a valid EdwardsPoint P
+///
+/// `neg` is any point representing (-x, y), such as the result of `Neg`.
+pub proof fn lemma_edwards_point_add_negation(point: EdwardsPoint, neg: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+        edwards_point_as_affine(neg) == (
+            math_field_neg(edwards_point_as_affine(point).0),
+            edwards_point_as_affine(point).1,
+        ),
+    ensures
+        ({
+            let (x, y) = edwards_point_as_affine(point);
+            let (nx, ny) = edwards_point_as_affine(neg);
+            edwards_add(x, y, nx, ny) == math_edwards_identity() && edwards_add(nx, ny, x, y)
+                == math_edwards_identity()
+        }),
+{
+    let (x, y) = edwards_point_as_affine(point);
+    axiom_edwards_add_inverse(x, y);
+    lemma_edwards_add_commutative(x, y, math_field_neg(x), y);
+}
+
+/// Lemma: the affine form (X/Z, Y/Z) of a valid EdwardsPoint is a reduced curve point
+pub proof fn lemma_edwards_point_as_affine_reduced(point: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+    ensures
+        math_on_edwards_curve(edwards_point_as_affine(point).0, edwards_point_as_affine(point).1),
+        edwards_point_as_affine(point).0 < p(),
+        edwards_point_as_affine(point).1 < p(),
+{
+    let p = p();
+    p_gt_2();
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_mod_bound((spec_field_element(&point.X) * z_inv) as int, p as int);
+    lemma_mod_bound((spec_field_element(&point.Y) * z_inv) as int, p as int);
+}
+
+/// Lemma: the ProjectivePoint identity (0 : 1 : 1) is valid and represents (0, 1)
+pub proof fn lemma_identity_projective_point_affine()
+    ensures
+        is_valid_projective_point(identity_projective_point_edwards()),
+        projective_point_as_affine_edwards(identity_projective_point_edwards())
+            == math_edwards_identity(),
+{
+    let p = p();
+    p_gt_2();
+    let id = identity_projective_point_edwards();
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    assert(u64_5_as_nat(id.X.limbs) == 0);
+    assert(u64_5_as_nat(id.Y.limbs) == 1);
+    assert(u64_5_as_nat(id.Z.limbs) == 1);
+
+    // Dividing by Z = 1 changes nothing
+    lemma_field_inv_one();
+    assert(math_field_mul(0, 1) == 0);
+    assert(math_field_mul(1, 1) == 1);
+    lemma_edwards_identity_on_curve();
+}
+
+/// Lemma: (A + B) + (C + D) = (A + C) + (B + D) for curve points
+///
+/// ## Mathematical Proof
+/// ```text
+/// (A + B) + (C + D) = A + (B + (C + D))    [associativity]
+///                   = A + ((C + B) + D)    [associativity, commutativity]
+///                   = A + (C + (B + D))    [associativity]
+///                   = (A + C) + (B + D)    [associativity]
+/// ```
+pub proof fn lemma_edwards_add_rearrange(a: (nat, nat), b: (nat, nat), c: (nat, nat), d: (nat, nat))
+    requires
+        math_on_edwards_curve(a.0, a.1),
+        math_on_edwards_curve(b.0, b.1),
+        math_on_edwards_curve(c.0, c.1),
+        math_on_edwards_curve(d.0, d.1),
+    ensures
+        ({
+            let ab = edwards_add(a.0, a.1, b.0, b.1);
+            let cd = edwards_add(c.0, c.1, d.0, d.1);
+            let ac = edwards_add(a.0, a.1, c.0, c.1);
+            let bd = edwards_add(b.0, b.1, d.0, d.1);
+            edwards_add(ab.0, ab.1, cd.0, cd.1) == edwards_add(ac.0, ac.1, bd.0, bd.1)
+        }),
+{
+    let cd = edwards_add(c.0, c.1, d.0, d.1);
+    let bd = edwards_add(b.0, b.1, d.0, d.1);
+    axiom_edwards_add_on_curve(c.0, c.1, d.0, d.1);
+    axiom_edwards_add_on_curve(b.0, b.1, d.0, d.1);
+    axiom_edwards_add_associative(a.0, a.1, b.0, b.1, cd.0, cd.1);
+    axiom_edwards_add_associative(b.0, b.1, c.0, c.1, d.0, d.1);
+    lemma_edwards_add_commutative(b.0, b.1, c.0, c.1);
+    axiom_edwards_add_associative(c.0, c.1, b.0, b.1, d.0, d.1);
+    axiom_edwards_add_associative(a.0, a.1, c.0, c.1, bd.0, bd.1);
+}
+
+// =============================================================================
+// Scalar multiplication
+// =============================================================================
+/// Lemma: [n]P is a reduced curve point
+pub proof fn lemma_edwards_scalar_mul_on_curve(point: (nat, nat), n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        math_on_edwards_curve(edwards_scalar_mul(point, n).0, edwards_scalar_mul(point, n).1),
+        edwards_scalar_mul(point, n).0 < p(),
+        edwards_scalar_mul(point, n).1 < p(),
+    decreases n,
+{
+    if n == 0 {
+        p_gt_2();
+        lemma_edwards_identity_on_curve();
+    } else {
+        let prev = edwards_scalar_mul(point, (n - 1) as nat);
+        lemma_edwards_scalar_mul_on_curve(point, (n - 1) as nat);
+        axiom_edwards_add_on_curve(prev.0, prev.1, point.0, point.1);
+        lemma_edwards_add_reduced(prev.0, prev.1, point.0, point.1);
+    }
+}
+
+/// Lemma: [m]P + [n]P = [m+n]P
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n = 0:  [m]P + O = [m]P
+/// n > 0:  [m]P + ([n-1]P + P) = ([m]P + [n-1]P) + P    [associativity]
+///                             = [m+n-1]P + P = [m+n]P  [induction]
+/// ```
+pub proof fn lemma_edwards_scalar_mul_additive(point: (nat, nat), m: nat, n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        ({
+            let pm = edwards_scalar_mul(point, m);
+            let pn = edwards_scalar_mul(point, n);
+            edwards_add(pm.0, pm.1, pn.0, pn.1) == edwards_scalar_mul(point, m + n)
+        }),
+    decreases n,
+{
+    let pm = edwards_scalar_mul(point, m);
+    lemma_edwards_scalar_mul_on_curve(point, m);
+    if n == 0 {
+        lemma_edwards_add_identity_right(pm.0, pm.1);
+    } else {
+        let prev = edwards_scalar_mul(point, (n - 1) as nat);
+        let sum_prev = edwards_scalar_mul(point, (m + n - 1) as nat);
+        lemma_edwards_scalar_mul_on_curve(point, (n - 1) as nat);
+        axiom_edwards_add_associative(pm.0, pm.1, prev.0, prev.1, point.0, point.1);
+        lemma_edwards_scalar_mul_additive(point, m, (n - 1) as nat);
+        assert(edwards_scalar_mul(point, m + n) == edwards_add(
+            sum_prev.0,
+            sum_prev.1,
+            point.0,
+            point.1,
+        ));
+    }
+}
+
+/// Lemma: [2]P = P + P and [2]P + [n]P = [n+2]P
+///
+/// This is the step between consecutive odd multiples in the NAF lookup tables.
+pub proof fn lemma_edwards_scalar_mul_add_double(point: (nat, nat), n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+        point.0 < p(),
+        point.1 < p(),
+    ensures
+        edwards_double(point.0, point.1) == edwards_scalar_mul(point, 2),
+        ({
+            let p2 = edwards_scalar_mul(point, 2);
+            let pn = edwards_scalar_mul(point, n);
+            edwards_add(p2.0, p2.1, pn.0, pn.1) == edwards_scalar_mul(point, n + 2)
+        }),
+{
+    // [2]P = [1]P + P = P + P
+    lemma_edwards_scalar_mul_signed_unit(point);
+    assert(edwards_scalar_mul(point, 1) == point);
+    lemma_edwards_scalar_mul_additive(point, 2, n);
+}
+
+/// Lemma: [m]P + [-n]P = [m - n]P
+///
+/// ## Mathematical Proof
+/// ```text
+/// m ≥ n:  ([m-n]P + [n]P) + -[n]P = [m-n]P + ([n]P + -[n]P) = [m-n]P + O
+/// m < n:  [m]P + -([m]P + [n-m]P) = ([m]P + -[m]P) + -[n-m]P = O + -[n-m]P
+/// ```
+pub proof fn lemma_edwards_scalar_mul_signed_additive_mixed(point: (nat, nat), m: nat, n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        ({
+            let pa = edwards_scalar_mul_signed(point, m as int);
+            let pb = edwards_scalar_mul_signed(point, -(n as int));
+            edwards_add(pa.0, pa.1, pb.0, pb.1) == edwards_scalar_mul_signed(point, m - n)
+        }),
+{
+    let pm = edwards_scalar_mul(point, m);
+    let pn = edwards_scalar_mul(point, n);
+    let neg_pn = (math_field_neg(pn.0), pn.1);
+    lemma_edwards_scalar_mul_on_curve(point, m);
+    lemma_edwards_scalar_mul_on_curve(point, n);
+    lemma_negation_preserves_curve(pn.0, pn.1);
+
+    if n == 0 {
+        lemma_edwards_add_identity_right(pm.0, pm.1);
+    } else if m >= n {
+        let k = (m - n) as nat;
+        let pk = edwards_scalar_mul(point, k);
+        lemma_edwards_scalar_mul_on_curve(point, k);
+        // [m]P = [k]P + [n]P
+        lemma_edwards_scalar_mul_additive(point, k, n);
+        axiom_edwards_add_associative(pk.0, pk.1, pn.0, pn.1, neg_pn.0, neg_pn.1);
+        axiom_edwards_add_inverse(pn.0, pn.1);
+        lemma_edwards_add_identity_right(pk.0, pk.1);
+    } else {
+        let k = (n - m) as nat;
+        let pk = edwards_scalar_mul(point, k);
+        let neg_pm = (math_field_neg(pm.0), pm.1);
+        let neg_pk = (math_field_neg(pk.0), pk.1);
+        lemma_edwards_scalar_mul_on_curve(point, k);
+        lemma_negation_preserves_curve(pm.0, pm.1);
+        lemma_negation_preserves_curve(pk.0, pk.1);
+        // -[n]P = -([m]P + [k]P) = -[m]P + -[k]P
+        lemma_edwards_scalar_mul_additive(point, m, k);
+        lemma_edwards_add_of_negations(pm.0, pm.1, pk.0, pk.1);
+        axiom_edwards_add_associative(pm.0, pm.1, neg_pm.0, neg_pm.1, neg_pk.0, neg_pk.1);
+        axiom_edwards_add_inverse(pm.0, pm.1);
+        // O + -[k]P = -[k]P
+        lemma_field_neg_as_int(pk.0);
+        lemma_mod_bound(-(pk.0 as int), p() as int);
+        lemma_edwards_add_identity_left(neg_pk.0, neg_pk.1);
+    }
+}
+
+/// Lemma: [a]P + [b]P = [a+b]P for signed a, b
+pub proof fn lemma_edwards_scalar_mul_signed_additive(point: (nat, nat), a: int, b: int)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        ({
+            let pa = edwards_scalar_mul_signed(point, a);
+            let pb = edwards_scalar_mul_signed(point, b);
+            edwards_add(pa.0, pa.1, pb.0, pb.1) == edwards_scalar_mul_signed(point, a + b)
+        }),
+{
+    let pa = edwards_scalar_mul_signed(point, a);
+    let pb = edwards_scalar_mul_signed(point, b);
+    if a >= 0 && b >= 0 {
+        lemma_edwards_scalar_mul_additive(point, a as nat, b as nat);
+    } else if a < 0 && b < 0 {
+        // -[m]P + -[n]P = -([m]P + [n]P) = -[m+n]P
+        let pm = edwards_scalar_mul(point, (-a) as nat);
+        let pn = edwards_scalar_mul(point, (-b) as nat);
+        lemma_edwards_add_of_negations(pm.0, pm.1, pn.0, pn.1);
+        lemma_edwards_scalar_mul_additive(point, (-a) as nat, (-b) as nat);
+    } else if a >= 0 {
+        lemma_edwards_scalar_mul_signed_additive_mixed(point, a as nat, (-b) as nat);
+    } else {
+        lemma_edwards_add_commutative(pa.0, pa.1, pb.0, pb.1);
+        lemma_edwards_scalar_mul_signed_additive_mixed(point, b as nat, (-a) as nat);
+    }
+}
+
+/// Lemma: doubling [a]P gives [2a]P
+pub proof fn lemma_edwards_scalar_mul_signed_double(point: (nat, nat), a: int)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        ({
+            let pa = edwards_scalar_mul_signed(point, a);
+            edwards_double(pa.0, pa.1) == edwards_scalar_mul_signed(point, 2 * a)
+        }),
+{
+    lemma_edwards_scalar_mul_signed_additive(point, a, a);
+}
+
+/// Lemma: [n]([a]P) = [n·a]P
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n = 0:  [0]([a]P) = O = [0]P
+/// n > 0:  [n]([a]P) = [n-1]([a]P) + [a]P = [(n-1)·a]P + [a]P = [n·a]P
+/// ```
+pub proof fn lemma_edwards_scalar_mul_signed_scale(point: (nat, nat), a: int, n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        edwards_scalar_mul(edwards_scalar_mul_signed(point, a), n) == edwards_scalar_mul_signed(
+            point,
+            n * a,
+        ),
+    decreases n,
+{
+    if n > 0 {
+        let m = (n - 1) as nat;
+        lemma_edwards_scalar_mul_signed_scale(point, a, m);
+        lemma_edwards_scalar_mul_signed_additive(point, m * a, a);
+        assert(m * a + a == n * a) by (nonlinear_arith)
+            requires
+                m + 1 == n,
+        ;
+    }
+}
+
+/// Lemma: [d]([m]P) = [d·m]P for signed d and m > 0
+pub proof fn lemma_edwards_scalar_mul_signed_of_multiple(point: (nat, nat), m: nat, d: int)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+        m > 0,
+    ensures
+        edwards_scalar_mul_signed(edwards_scalar_mul(point, m), d) == edwards_scalar_mul_signed(
+            point,
+            d * m,
+        ),
+{
+    if d >= 0 {
+        lemma_edwards_scalar_mul_signed_scale(point, m as int, d as nat);
+        assert(d * m >= 0) by (nonlinear_arith)
+            requires
+                d >= 0,
+        ;
+    } else {
+        // [d]([m]P) = -([-d]([m]P)) = -([(-d)·m]P) = [d·m]P
+        lemma_edwards_scalar_mul_signed_scale(point, m as int, (-d) as nat);
+        assert((-d) * m > 0 && -(d * m) == (-d) * m) by (nonlinear_arith)
+            requires
+                d < 0,
+                m > 0,
+        ;
+    }
+}
+
+/// Lemma: [n]P = [n mod k]P for a point P with [k]P = O
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n < k:  n mod k = n
+/// n ≥ k:  [n]P = [k]P + [n-k]P = O + [n-k]P = [(n-k) mod k]P = [n mod k]P
+/// ```
+pub proof fn lemma_edwards_scalar_mul_mod_period(point: (nat, nat), k: nat, n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+        k > 0,
+        edwards_scalar_mul(point, k) == math_edwards_identity(),
+    ensures
+        edwards_scalar_mul(point, n) == edwards_scalar_mul(point, n % k),
+    decreases n,
+{
+    if n < k {
+        lemma_small_mod(n, k);
+    } else {
+        let rest = (n - k) as nat;
+        let p_rest = edwards_scalar_mul(point, rest);
+        lemma_edwards_scalar_mul_mod_period(point, k, rest);
+        lemma_edwards_scalar_mul_additive(point, k, rest);
+        lemma_edwards_scalar_mul_on_curve(point, rest);
+        lemma_edwards_add_identity_left(p_rest.0, p_rest.1);
+        lemma_mod_add_multiples_vanish(rest as int, k as int);
+    }
+}
+
+/// Lemma: [n]P = [n mod ℓ]P for a point P with [ℓ]P = O
+pub proof fn lemma_edwards_scalar_mul_mod_order(point: (nat, nat), n: nat)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+        edwards_scalar_mul(point, group_order()) == math_edwards_identity(),
+    ensures
+        edwards_scalar_mul(point, n) == edwards_scalar_mul(point, n % group_order()),
+{
+    lemma_pow2_pos(252);
+    lemma_edwards_scalar_mul_mod_period(point, group_order(), n);
+}
+
+/// Lemma: [a]P is a reduced curve point for signed a
+pub proof fn lemma_edwards_scalar_mul_signed_on_curve(point: (nat, nat), a: int)
+    requires
+        math_on_edwards_curve(point.0, point.1),
+    ensures
+        math_on_edwards_curve(
+            edwards_scalar_mul_signed(point, a).0,
+            edwards_scalar_mul_signed(point, a).1,
+        ),
+        edwards_scalar_mul_signed(point, a).0 < p(),
+        edwards_scalar_mul_signed(point, a).1 < p(),
+{
+    if a >= 0 {
+        lemma_edwards_scalar_mul_on_curve(point, a as nat);
+    } else {
+        let pn = edwards_scalar_mul(point, (-a) as nat);
+        lemma_edwards_scalar_mul_on_curve(point, (-a) as nat);
+        lemma_negation_preserves_curve(pn.0, pn.1);
+        p_gt_2();
+        lemma_mod_bound((p() - (pn.0 % p())) as int, p() as int);
+    }
+}
+
+/// Lemma: [1]P = P and [-1]P = -P for a reduced point P
+pub proof fn lemma_edwards_scalar_mul_signed_unit(point: (nat, nat))
+    requires
+        point.0 < p(),
+        point.1 < p(),
+    ensures
+        edwards_scalar_mul_signed(point, 1) == point,
+        edwards_scalar_mul_signed(point, -1) == (math_field_neg(point.0), point.1),
+{
+    // [1]P = [0]P + P = O + P
+    assert(edwards_scalar_mul(point, 0) == math_edwards_identity());
+    assert(edwards_scalar_mul(point, 1) == edwards_add(0, 1, point.0, point.1));
+    lemma_edwards_add_identity_left(point.0, point.1);
+}
+
+/// Lemma: [n](A + B) = [n]A + [n]B
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n = 0:  [0](A + B) = O = O + O
+/// n > 0:  [n](A + B) = ([n-1]A + [n-1]B) + (A + B)
+///                    = ([n-1]A + A) + ([n-1]B + B) = [n]A + [n]B    [rearrangement]
+/// ```
+pub proof fn lemma_edwards_scalar_mul_distributes(a: (nat, nat), b: (nat, nat), n: nat)
+    requires
+        math_on_edwards_curve(a.0, a.1),
+        math_on_edwards_curve(b.0, b.1),
+    ensures
+        ({
+            let na = edwards_scalar_mul(a, n);
+            let nb = edwards_scalar_mul(b, n);
+            edwards_scalar_mul(edwards_add(a.0, a.1, b.0, b.1), n) == edwards_add(
+                na.0,
+                na.1,
+                nb.0,
+                nb.1,
+            )
+        }),
+    decreases n,
+{
+    if n == 0 {
+        p_gt_2();
+        lemma_edwards_add_identity_right(0, 1);
+    } else {
+        let m = (n - 1) as nat;
+        lemma_edwards_scalar_mul_distributes(a, b, m);
+        lemma_edwards_scalar_mul_on_curve(a, m);
+        lemma_edwards_scalar_mul_on_curve(b, m);
+        lemma_edwards_add_rearrange(edwards_scalar_mul(a, m), edwards_scalar_mul(b, m), a, b);
+    }
+}
+
+// =============================================================================
+// Sums of scalar multiples
+// =============================================================================
+/// Lemma: Σ [c_i]P_i over i < n is a reduced curve point
+pub proof fn lemma_sum_of_signed_scalar_muls_on_curve(
+    points: Seq<(nat, nat)>,
+    coeffs: Seq<int>,
+    n: nat,
+)
+    requires
+        forall|i: int|
+            #![trigger points[i]]
+            0 <= i < n ==> math_on_edwards_curve(points[i].0, points[i].1),
+    ensures
+        math_on_edwards_curve(
+            sum_of_signed_scalar_muls(points, coeffs, n).0,
+            sum_of_signed_scalar_muls(points, coeffs, n).1,
+        ),
+        sum_of_signed_scalar_muls(points, coeffs, n).0 < p(),
+        sum_of_signed_scalar_muls(points, coeffs, n).1 < p(),
+    decreases n,
+{
+    if n == 0 {
+        p_gt_2();
+        lemma_edwards_identity_on_curve();
+    } else {
+        let prev = sum_of_signed_scalar_muls(points, coeffs, (n - 1) as nat);
+        let term = edwards_scalar_mul_signed(points[n - 1], coeffs[n - 1]);
+        lemma_sum_of_signed_scalar_muls_on_curve(points, coeffs, (n - 1) as nat);
+        lemma_edwards_scalar_mul_signed_on_curve(points[n - 1], coeffs[n - 1]);
+        axiom_edwards_add_on_curve(prev.0, prev.1, term.0, term.1);
+        lemma_edwards_add_reduced(prev.0, prev.1, term.0, term.1);
+    }
+}
+
+/// Lemma: Σ [a_i]P_i + Σ [b_i]P_i = Σ [a_i + b_i]P_i
+///
+/// ## Mathematical Proof
+/// By induction on n, with S_a, S_b the sums over i < n - 1 and P = P_{n-1}:
+/// ```text
+/// (S_a + [a]P) + (S_b + [b]P) = (S_a + S_b) + ([a]P + [b]P)    [rearrangement]
+///                             = S_{a+b} + [a + b]P
+/// ```
+pub proof fn lemma_sum_of_signed_scalar_muls_add(
+    points: Seq<(nat, nat)>,
+    a: Seq<int>,
+    b: Seq<int>,
+    sum: Seq<int>,
+    n: nat,
+)
+    requires
+        forall|i: int|
+            #![trigger points[i]]
+            0 <= i < n ==> math_on_edwards_curve(points[i].0, points[i].1),
+        forall|i: int| 0 <= i < n ==> #[trigger] sum[i] == a[i] + b[i],
+    ensures
+        ({
+            let sa = sum_of_signed_scalar_muls(points, a, n);
+            let sb = sum_of_signed_scalar_muls(points, b, n);
+            edwards_add(sa.0, sa.1, sb.0, sb.1) == sum_of_signed_scalar_muls(points, sum, n)
+        }),
+    decreases n,
+{
+    if n == 0 {
+        p_gt_2();
+        lemma_edwards_add_identity_right(0, 1);
+    } else {
+        let m = (n - 1) as nat;
+        let point = points[m as int];
+        assert(math_on_edwards_curve(point.0, point.1));
+        assert(sum[m as int] == a[m as int] + b[m as int]);
+        lemma_sum_of_signed_scalar_muls_add(points, a, b, sum, m);
+        lemma_sum_of_signed_scalar_muls_on_curve(points, a, m);
+        lemma_sum_of_signed_scalar_muls_on_curve(points, b, m);
+        lemma_edwards_scalar_mul_signed_on_curve(point, a[m as int]);
+        lemma_edwards_scalar_mul_signed_on_curve(point, b[m as int]);
+        lemma_edwards_add_rearrange(
+            sum_of_signed_scalar_muls(points, a, m),
+            edwards_scalar_mul_signed(point, a[m as int]),
+            sum_of_signed_scalar_muls(points, b, m),
+            edwards_scalar_mul_signed(point, b[m as int]),
+        );
+        lemma_edwards_scalar_mul_signed_additive(point, a[m as int], b[m as int]);
+    }
+}
+
+/// Lemma: [w](Σ [c_i]P_i) = Σ [w·c_i]P_i
+///
+/// With w = 0 this says a sum whose coefficients are all 0 is the identity.
+///
+/// ## Mathematical Proof
+/// By induction on n, with S the sum over i < n - 1 and P = P_{n-1}:
+/// ```text
+/// n = 0:  [w]O = [w]([0]O) = [w·0]O = O
+/// n > 0:  [w](S + [c]P) = [w]S + [w]([c]P) = S_{w·c} + [w·c]P    [distributivity]
+/// ```
+pub proof fn lemma_sum_of_signed_scalar_muls_scale(
+    points: Seq<(nat, nat)>,
+    coeffs: Seq<int>,
+    scaled: Seq<int>,
+    w: nat,
+    n: nat,
+)
+    requires
+        forall|i: int|
+            #![trigger points[i]]
+            0 <= i < n ==> math_on_edwards_curve(points[i].0, points[i].1),
+        forall|i: int| 0 <= i < n ==> #[trigger] scaled[i] == w * coeffs[i],
+    ensures
+        edwards_scalar_mul(sum_of_signed_scalar_muls(points, coeffs, n), w)
+            == sum_of_signed_scalar_muls(points, scaled, n),
+    decreases n,
+{
+    if n == 0 {
+        lemma_edwards_identity_on_curve();
+        lemma_edwards_scalar_mul_signed_scale(math_edwards_identity(), 0, w);
+        assert(w * 0 == 0) by (nonlinear_arith);
+    } else {
+        let m = (n - 1) as nat;
+        let point = points[m as int];
+        let c = coeffs[m as int];
+        assert(math_on_edwards_curve(point.0, point.1));
+        assert(scaled[m as int] == w * c);
+        lemma_sum_of_signed_scalar_muls_scale(points, coeffs, scaled, w, m);
+        lemma_sum_of_signed_scalar_muls_on_curve(points, coeffs, m);
+        lemma_edwards_scalar_mul_signed_on_curve(point, c);
+        lemma_edwards_scalar_mul_distributes(
+            sum_of_signed_scalar_muls(points, coeffs, m),
+            edwards_scalar_mul_signed(point, c),
+            w,
+        );
+        lemma_edwards_scalar_mul_signed_scale(point, c, w);
+    }
+}
+
+/// Lemma: `sum_of_scalar_muls` is the signed sum with coefficients `scalar_to_nat(s_i)`
+///
+/// Stated for the first n pairs; at n = len it relates the two specs directly.
+pub proof fn lemma_sum_of_scalar_muls_as_signed(
+    scalars: Seq<Scalar>,
+    points: Seq<EdwardsPoint>,
+    coeffs: Seq<int>,
+    n: nat,
+)
+    requires
+        scalars.len() == points.len(),
+        n <= points.len(),
+        forall|i: int| 0 <= i < n ==> #[trigger] coeffs[i] == scalar_to_nat(&scalars[i]),
+    ensures
+        sum_of_scalar_muls(scalars.subrange(0, n as int), points.subrange(0, n as int))
+            == sum_of_signed_scalar_muls(points_as_affine(points), coeffs, n),
+    decreases n,
+{
+    if n > 0 {
+        let m = (n - 1) as int;
+        let s = scalars.subrange(0, n as int);
+        let q = points.subrange(0, n as int);
+        assert(s.subrange(0, m) =~= scalars.subrange(0, m));
+        assert(q.subrange(0, m) =~= points.subrange(0, m));
+        assert(coeffs[m] == scalar_to_nat(&scalars[m]));
+        lemma_sum_of_scalar_muls_as_signed(scalars, points, coeffs, m as nat);
+    }
+}
+
+// =============================================================================
+// Lookup tables and radix-16 digits
+// =============================================================================
+/// Lemma: `LookupTable::select(x)` on a table of [P, 2P, ..., 8P] represents [x]P
+///
+/// The requires are the postconditions of `LookupTable<ProjectiveNielsPoint>::select`.
+pub proof fn lemma_select_projective_niels_is_signed_multiple(
+    table: [ProjectiveNielsPoint; 8],
+    point: EdwardsPoint,
+    x: i8,
+    result: ProjectiveNielsPoint,
+)
+    requires
+        is_valid_lookup_table_projective(table, point, 8),
+        -8 <= x <= 8,
+        x > 0 ==> result == table[(x - 1) as int],
+        x == 0 ==> result == identity_projective_niels(),
+        x < 0 ==> result == negate_projective_niels(table[((-x) - 1) as int]),
+    ensures
+        projective_niels_point_as_affine_edwards(result) == edwards_scalar_mul_signed(
+            edwards_point_as_affine(point),
+            x as int,
+        ),
+{
+    let base = edwards_point_as_affine(point);
+    if x > 0 {
+        assert(projective_niels_point_as_affine_edwards(table[(x - 1) as int])
+            == edwards_scalar_mul(base, x as nat));
+    } else if x == 0 {
+        lemma_identity_projective_niels_affine();
+    } else {
+        let entry = table[((-x) - 1) as int];
+        assert(projective_niels_point_as_affine_edwards(entry) == edwards_scalar_mul(
+            base,
+            (-x) as nat,
+        ));
+        lemma_negate_projective_niels_affine(entry);
+    }
+}
+
+/// Lemma: `LookupTable::select(x)` on an AffineNiels table of [Q, 2Q, ..., 8Q] represents [x]Q
+///
+/// The requires are the postconditions of `LookupTable<AffineNielsPoint>::select`.
+pub proof fn lemma_select_affine_niels_is_signed_multiple(
+    table: [AffineNielsPoint; 8],
+    base: (nat, nat),
+    x: i8,
+    result: AffineNielsPoint,
+)
+    requires
+        is_valid_lookup_table_affine_coords(table, base, 8),
+        -8 <= x <= 8,
+        x > 0 ==> result == table[(x - 1) as int],
+        x == 0 ==> result == identity_affine_niels(),
+        x < 0 ==> result == negate_affine_niels(table[((-x) - 1) as int]),
+    ensures
+        affine_niels_point_as_affine_edwards(result) == edwards_scalar_mul_signed(base, x as int),
+{
+    if x == 0 {
+        lemma_identity_affine_niels_affine();
+    } else if x < 0 {
+        let entry = table[((-x) - 1) as int];
+        assert(affine_niels_point_as_affine_edwards(entry) == edwards_scalar_mul(
+            base,
+            (-x) as nat,
+        ));
+        lemma_negate_affine_niels_affine(entry);
+    }
+}
+
+/// Lemma: the radix-16 value of the digits from index i on is d_i + 16·(value from i+1 on)
+pub proof fn lemma_reconstruct_radix_16_suffix(digits: Seq<i8>, i: int)
+    requires
+        0 <= i < digits.len(),
+    ensures
+        reconstruct_radix_16(digits.skip(i)) == digits[i] as int + 16 * reconstruct_radix_16(
+            digits.skip(i + 1),
+        ),
+{
+    let suffix = digits.skip(i);
+    assert(suffix[0] == digits[i]);
+    assert(suffix.skip(1) =~= digits.skip(i + 1));
+    lemma2_to64();
+}
+
+/// Lemma: the radix-16 value splits into even- and odd-position digits
+///
+/// For n ≤ len, with S_0(n), S_1(n) the `radix_16_parity_sum`s of the first n digits,
+/// ```text
+/// s = S_0(n) + 16·S_1(n) + 16^n·(value from n on)
+/// ```
+/// At n = 64 the remainder is empty and s = S_0 + 16·S_1.
+///
+/// ## Mathematical Proof
+/// By induction on n, with i = n - 1 and r the value from n on:
+/// ```text
+/// 16^i·(value from i on) = 16^i·(d_i + 16·r) = 16^i·d_i + 16^n·r
+/// i even:  16^i = 256^(i/2), so 16^i·d_i is the new term of S_0
+/// i odd:   16^i = 16·256^(i/2), so 16^i·d_i is 16 times the new term of S_1
+/// ```
+pub proof fn lemma_radix_16_parity_split(digits: Seq<i8>, n: nat)
+    requires
+        n <= digits.len(),
+    ensures
+        reconstruct_radix_16(digits) == radix_16_parity_sum(digits, 0, n) + 16
+            * radix_16_parity_sum(digits, 1, n) + pow2(4 * n) * reconstruct_radix_16(
+            digits.skip(n as int),
+        ),
+    decreases n,
+{
+    lemma2_to64();
+    if n == 0 {
+        assert(digits.skip(0) =~= digits);
+    } else {
+        let i = (n - 1) as nat;
+        let k = i / 2;
+        let d = digits[i as int] as int;
+        let r = reconstruct_radix_16(digits.skip(n as int));
+        let s0 = radix_16_parity_sum(digits, 0, i);
+        let s1 = radix_16_parity_sum(digits, 1, i);
+        let w = pow2(4 * i) as int;
+        lemma_radix_16_parity_split(digits, i);
+        lemma_reconstruct_radix_16_suffix(digits, i as int);
+
+        // 16^i·16 = 16^n
+        lemma_pow2_adds(4 * i, 4);
+        assert(w * (d + 16 * r) == w * d + (w * 16) * r) by (nonlinear_arith);
+
+        if i % 2 == 0 {
+            // 16^i = 256^k
+            assert(4 * i == 8 * k);
+            assert(radix_16_parity_sum(digits, 0, n) == s0 + d * w);
+            assert(radix_16_parity_sum(digits, 1, n) == s1);
+        } else {
+            // 16^i = 16·256^k
+            assert(4 * i == 8 * k + 4);
+            lemma_pow2_adds(8 * k, 4);
+            let v = pow256(k) as int;
+            assert(radix_16_parity_sum(digits, 0, n) == s0);
+            assert(radix_16_parity_sum(digits, 1, n) == s1 + d * v);
+            assert(16 * (s1 + d * v) == 16 * s1 + d * (v * 16)) by (nonlinear_arith);
+        }
+    }
+}
+
+/// Lemma: `NafLookupTable5::select(x)` on a table of [P, 3P, ..., 15P] represents [x]P
+///
+/// The requires are the preconditions and postconditions of
+/// `NafLookupTable5<ProjectiveNielsPoint>::select`.
+pub proof fn lemma_select_naf_projective_niels_is_multiple(
+    table: [ProjectiveNielsPoint; 8],
+    point: EdwardsPoint,
+    x: usize,
+    result: ProjectiveNielsPoint,
+)
+    requires
+        is_valid_naf_lookup_table5_projective(table, point),
+        x & 1 == 1,
+        x < 16,
+        result == table[(x / 2) as int],
+    ensures
+        projective_niels_point_as_affine_edwards(result) == edwards_scalar_mul(
+            edwards_point_as_affine(point),
+            x as nat,
+        ),
+{
+    // table[x/2] = [2·(x/2) + 1]P and x is odd
+    assert(x % 2 == 1) by (bit_vector)
+        requires
+            x & 1 == 1,
+    ;
+    assert(projective_niels_point_as_affine_edwards(table[(x / 2) as int]) == edwards_scalar_mul(
+        edwards_point_as_affine(point),
+        (2 * (x / 2) + 1) as nat,
+    ));
+}
+
+/// Lemma: the NAF value of the digits from index i on is d_i + 2·(value from i+1 on)
+pub proof fn lemma_reconstruct_naf_suffix(naf: Seq<i8>, i: int)
+    requires
+        0 <= i < naf.len(),
+    ensures
+        reconstruct(naf.skip(i)) == naf[i] as int + 2 * reconstruct(naf.skip(i + 1)),
+{
+    let suffix = naf.skip(i);
+    assert(suffix[0] == naf[i]);
+    assert(suffix.skip(1) =~= naf.skip(i + 1));
+}
+
+/// Lemma: the radix-2^w value of the digits from index i on is d_i + 2^w·(value from i+1 on)
+pub proof fn lemma_reconstruct_radix_2w_suffix(digits: Seq<i8>, w: nat, i: int)
+    requires
+        0 <= i < digits.len(),
+    ensures
+        reconstruct_radix_2w(digits.skip(i), w) == digits[i] as int + pow2(w)
+            * reconstruct_radix_2w(digits.skip(i + 1), w),
+{
+    let suffix = digits.skip(i);
+    assert(suffix[0] == digits[i]);
+    assert(suffix.skip(1) =~= digits.skip(i + 1));
+}
+
+} // verus!
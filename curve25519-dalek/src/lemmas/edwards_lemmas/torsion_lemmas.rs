@@ -0,0 +1,193 @@
+//! Lemmas about the `EIGHT_TORSION` table
+//!
+//! `EIGHT_TORSION[i]` is documented as [i]P for a point P of order 8, so that
+//! the 4-torsion subgroup is the entries `0,2,4,6` and the 2-torsion subgroup
+//! is the entries `0,4`.
+//!
+//! ## Mathematical Background
+//!
+//! Every entry has Z = 1, so its affine point is (X, Y). The table is checked
+//! one addition at a time by evaluating the 255-bit arithmetic directly
+//! (`by (compute)`):
+//! ```text
+//! T_0 = O,   T_i + T_1 = T_{(i+1) mod 8},   T_i ≠ O for 0 < i < 8
+//! ```
+//! Everything else follows from the scalar multiplication lemmas:
+//! ```text
+//! T_i = [i]T_1,   [8]T_1 = T_7 + T_1 = T_0 = O
+//! [n]T_i = [n·i]T_1 = [n·i mod 8]T_1, which is O iff 8 | n·i
+//! ```
+//!
+//! ## Lemmas
+//!
+//! - `lemma_eight_torsion_table`: the entries step by T_1 and only T_0 is the identity
+//! - `lemma_eight_torsion_is_multiple`: T_i = [i]T_1
+//! - `lemma_eight_torsion_order`: T_1 has order exactly 8
+//! - `lemma_eight_torsion_subgroups`: [8]T_i = O, [4]T_i = O iff i ∈ {0,2,4,6},
+//!   [2]T_i = O iff i ∈ {0,4}
+#![allow(unused_imports)]
+use crate::backend::serial::u64::constants::EIGHT_TORSION;
+use crate::edwards::EdwardsPoint;
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Spec functions
+// =============================================================================
+/// The affine point T_i of `EIGHT_TORSION[i]`
+pub open spec fn eight_torsion_affine(i: int) -> (nat, nat)
+    recommends
+        0 <= i < 8,
+{
+    edwards_point_as_affine(EIGHT_TORSION[i])
+}
+
+/// T_i + T_1
+pub open spec fn eight_torsion_plus_one(i: int) -> (nat, nat) {
+    let (x1, y1) = eight_torsion_affine(i);
+    let (x2, y2) = eight_torsion_affine(1);
+    edwards_add(x1, y1, x2, y2)
+}
+
+// =============================================================================
+// The table
+// =============================================================================
+/// Lemma: T_1 is on the curve, T_0 = O, T_i + T_1 = T_{(i+1) mod 8}, and
+/// T_i ≠ O for 0 < i < 8
+pub proof fn lemma_eight_torsion_table()
+    ensures
+        math_on_edwards_curve(eight_torsion_affine(1).0, eight_torsion_affine(1).1),
+        eight_torsion_affine(0) == math_edwards_identity(),
+        forall|i: int|
+            0 <= i < 8 ==> #[trigger] eight_torsion_plus_one(i) == eight_torsion_affine(
+                (i + 1) % 8,
+            ),
+        forall|i: int| 0 < i < 8 ==> #[trigger] eight_torsion_affine(i) != math_edwards_identity(),
+{
+    assert(math_on_edwards_curve(eight_torsion_affine(1).0, eight_torsion_affine(1).1))
+        by (compute);
+    assert(eight_torsion_affine(0) == math_edwards_identity()) by (compute);
+
+    assert(eight_torsion_plus_one(0) == eight_torsion_affine(1)) by (compute);
+    assert(eight_torsion_plus_one(1) == eight_torsion_affine(2)) by (compute);
+    assert(eight_torsion_plus_one(2) == eight_torsion_affine(3)) by (compute);
+    assert(eight_torsion_plus_one(3) == eight_torsion_affine(4)) by (compute);
+    assert(eight_torsion_plus_one(4) == eight_torsion_affine(5)) by (compute);
+    assert(eight_torsion_plus_one(5) == eight_torsion_affine(6)) by (compute);
+    assert(eight_torsion_plus_one(6) == eight_torsion_affine(7)) by (compute);
+    assert(eight_torsion_plus_one(7) == eight_torsion_affine(0)) by (compute);
+
+    assert(eight_torsion_affine(1) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(2) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(3) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(4) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(5) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(6) != math_edwards_identity()) by (compute);
+    assert(eight_torsion_affine(7) != math_edwards_identity()) by (compute);
+
+    assert forall|i: int| 0 <= i < 8 implies #[trigger] eight_torsion_plus_one(i)
+        == eight_torsion_affine((i + 1) % 8) by {
+        assert(i == 0 || i == 1 || i == 2 || i == 3 || i == 4 || i == 5 || i == 6 || i == 7);
+    };
+    assert forall|i: int| 0 < i < 8 implies #[trigger] eight_torsion_affine(i)
+        != math_edwards_identity() by {
+        assert(i == 1 || i == 2 || i == 3 || i == 4 || i == 5 || i == 6 || i == 7);
+    };
+}
+
+/// Lemma: T_i = [i]T_1
+///
+/// ## Mathematical Proof
+/// By induction on i:
+/// ```text
+/// i = 0:  T_0 = O = [0]T_1
+/// i > 0:  [i]T_1 = [i-1]T_1 + T_1 = T_{i-1} + T_1 = T_i
+/// ```
+pub proof fn lemma_eight_torsion_is_multiple(i: nat)
+    requires
+        i < 8,
+    ensures
+        eight_torsion_affine(i as int) == edwards_scalar_mul(eight_torsion_affine(1), i),
+    decreases i,
+{
+    lemma_eight_torsion_table();
+    if i > 0 {
+        let prev = (i - 1) as nat;
+        lemma_eight_torsion_is_multiple(prev);
+        assert(eight_torsion_plus_one(prev as int) == eight_torsion_affine((prev + 1) % 8));
+    }
+}
+
+/// Lemma: T_1 has order exactly 8
+///
+/// ## Mathematical Proof
+/// ```text
+/// [8]T_1 = [7]T_1 + T_1 = T_7 + T_1 = T_0 = O
+/// [k]T_1 = T_k ≠ O for 0 < k < 8
+/// ```
+pub proof fn lemma_eight_torsion_order()
+    ensures
+        edwards_scalar_mul(eight_torsion_affine(1), 8) == math_edwards_identity(),
+        forall|k: nat|
+            0 < k < 8 ==> #[trigger] edwards_scalar_mul(eight_torsion_affine(1), k)
+                != math_edwards_identity(),
+{
+    lemma_eight_torsion_table();
+    lemma_eight_torsion_is_multiple(7);
+    assert(eight_torsion_plus_one(7) == eight_torsion_affine(0));
+
+    assert forall|k: nat| 0 < k < 8 implies #[trigger] edwards_scalar_mul(
+        eight_torsion_affine(1),
+        k,
+    ) != math_edwards_identity() by {
+        lemma_eight_torsion_is_multiple(k);
+        assert(eight_torsion_affine(k as int) != math_edwards_identity());
+    };
+}
+
+/// Lemma: every T_i is 8-torsion, the 4-torsion entries are i ∈ {0,2,4,6}
+/// and the 2-torsion entries are i ∈ {0,4}
+///
+/// ## Mathematical Proof
+/// ```text
+/// [n]T_i = [n]([i]T_1) = [n·i]T_1 = [n·i mod 8]T_1     [lemma_edwards_scalar_mul_mod_period]
+/// ```
+/// and [r]T_1 = O for r < 8 iff r = 0. For n = 8, 4, 2 this is 8 | n·i,
+/// i.e. always, 2 | i, and 4 | i.
+pub proof fn lemma_eight_torsion_subgroups(i: nat)
+    requires
+        i < 8,
+    ensures
+        edwards_scalar_mul(eight_torsion_affine(i as int), 8) == math_edwards_identity(),
+        (edwards_scalar_mul(eight_torsion_affine(i as int), 4) == math_edwards_identity())
+            == (i % 2 == 0),
+        (edwards_scalar_mul(eight_torsion_affine(i as int), 2) == math_edwards_identity())
+            == (i % 4 == 0),
+{
+    let t1 = eight_torsion_affine(1);
+    let ti = eight_torsion_affine(i as int);
+    lemma_eight_torsion_table();
+    lemma_eight_torsion_order();
+    lemma_eight_torsion_is_multiple(i);
+    assert(edwards_scalar_mul_signed(t1, i as int) == ti);
+
+    assert forall|n: nat| #[trigger]
+        edwards_scalar_mul(ti, n) == edwards_scalar_mul(t1, (n * i) % 8) by {
+        lemma_edwards_scalar_mul_signed_scale(t1, i as int, n);
+        assert(n * i >= 0) by (nonlinear_arith);
+        assert(edwards_scalar_mul_signed(t1, n * i) == edwards_scalar_mul(t1, n * i));
+        lemma_edwards_scalar_mul_mod_period(t1, 8, n * i);
+    };
+
+    assert(edwards_scalar_mul(ti, 8) == edwards_scalar_mul(t1, (8 * i) % 8));
+    assert(edwards_scalar_mul(ti, 4) == edwards_scalar_mul(t1, (4 * i) % 8));
+    assert(edwards_scalar_mul(ti, 2) == edwards_scalar_mul(t1, (2 * i) % 8));
+    assert(i == 0 || i == 1 || i == 2 || i == 3 || i == 4 || i == 5 || i == 6 || i == 7);
+}
+
+} // verus!
@@ -422,7 +422,7 @@ pub proof fn lemma_from_bytes_as_bytes_roundtrip(
     };
 }
 
-/// Lemma: as_bytes(from_bytes(bytes_orig)) preserves the byte value (for canonical inputs)
+/// Lemma: as_bytes(from_bytes(bytes_orig)) == bytes_orig (for canonical inputs)
 ///
 /// This is the reverse roundtrip property for field element serialization.
 /// It only holds when the input bytes represent a canonical value (< p).
@@ -438,7 +438,7 @@ pub proof fn lemma_from_bytes_as_bytes_roundtrip(
 /// let bytes_decoded = fe.as_bytes();
 /// proof {
 ///     lemma_as_bytes_from_bytes_roundtrip(&bytes_orig, &fe, &bytes_decoded);
-///     // Now: bytes32_to_nat(&bytes_decoded) == bytes32_to_nat(&bytes_orig)
+///     // Now: bytes_decoded == bytes_orig
 /// }
 /// ```
 ///
@@ -465,6 +465,7 @@ pub proof fn lemma_as_bytes_from_bytes_roundtrip(
 
     ensures
         bytes32_to_nat(bytes_decoded) == bytes32_to_nat(bytes_orig),
+        *bytes_decoded == *bytes_orig,
 {
     let v = bytes32_to_nat(bytes_orig);
 
@@ -484,6 +485,122 @@ pub proof fn lemma_as_bytes_from_bytes_roundtrip(
             lemma_small_mod(v, p());
         };
     };
+
+    // Equal values give equal byte arrays (bytes32_to_nat is injective)
+    assert(*bytes_decoded == *bytes_orig) by {
+        lemma_canonical_bytes_equal(bytes_decoded, bytes_orig);
+        assert(seq_from32(bytes_decoded) =~= seq_from32(bytes_orig));
+        lemma_seq_eq_implies_array_eq(bytes_decoded, bytes_orig);
+    };
+}
+
+/// Lemma: from_bytes ignores the high bit (bit 255) of its input
+///
+/// `from_bytes` reduces the decoded value modulo 2^255, which is the same as
+/// clearing bit 7 of `bytes[31]` before decoding. Inputs with the high bit already
+/// clear (in particular canonical encodings, which are < p < 2^255) are unchanged.
+///
+/// ## Proof outline (lo = first 31 bytes, hi = bytes[31]):
+/// 1. bytes32_to_nat(bytes) = lo + hi · 2^248, with lo < 2^248
+/// 2. hi = (hi & 127) + 128 · (hi >> 7), so the value is lo + (hi & 127) · 2^248 + (hi >> 7) · 2^255
+/// 3. lo + (hi & 127) · 2^248 < 2^255, so it is the remainder modulo 2^255
+pub proof fn lemma_from_bytes_clears_high_bit(bytes: &[u8; 32])
+    ensures
+        bytes32_to_nat(bytes) % pow2(255) == bytes_to_nat_prefix(bytes@, 31) + ((bytes[31] & 127u8)
+            as nat) * pow2(248),
+        bytes[31] <= 127 ==> bytes32_to_nat(bytes) % pow2(255) == bytes32_to_nat(bytes),
+{
+    let lo = bytes_to_nat_prefix(bytes@, 31);
+    let hi = bytes[31];
+    let hi_low = (hi & 127u8) as nat;
+    let hi_top = (hi >> 7u8) as nat;
+
+    lemma2_to64();
+    lemma_pow2_adds(7, 248);
+
+    // Step 1: split off the top byte
+    assert(bytes32_to_nat(bytes) == lo + (hi as nat) * pow2(248)) by {
+        lemma_bytes32_to_nat_equals_rec(bytes);
+        lemma_decomposition_prefix_rec(bytes, 31);
+        reveal_with_fuel(bytes32_to_nat_rec, 2);
+    };
+    assert(lo < pow2(248)) by {
+        lemma_bytes_to_nat_prefix_bounded(bytes@, 31);
+    };
+
+    // Step 2: split the top byte at bit 7
+    assert(hi == (hi & 127u8) + 128 * (hi >> 7u8) && (hi & 127u8) <= 127 && (hi >> 7u8) <= 1)
+        by (bit_vector);
+    let low_part = lo + hi_low * pow2(248);
+    assert(bytes32_to_nat(bytes) == pow2(255) * hi_top + low_part && low_part < pow2(255))
+        by (nonlinear_arith)
+        requires
+            bytes32_to_nat(bytes) == lo + (hi as nat) * pow2(248),
+            hi as nat == hi_low + 128 * hi_top,
+            hi_low <= 127,
+            lo < pow2(248),
+            low_part == lo + hi_low * pow2(248),
+            pow2(255) == 128 * pow2(248),
+    ;
+
+    // Step 3: the multiple of 2^255 vanishes
+    assert(bytes32_to_nat(bytes) % pow2(255) == low_part) by {
+        lemma_pow2_pos(255);
+        lemma_mod_multiples_vanish(hi_top as int, low_part as int, pow2(255) as int);
+        lemma_small_mod(low_part, pow2(255));
+    };
+
+    if hi <= 127 {
+        assert(hi >> 7u8 == 0) by (bit_vector)
+            requires
+                hi <= 127,
+        ;
+    }
+}
+
+/// Lemma: bytes are a canonical field encoding iff as_bytes(from_bytes(bytes)) == bytes
+///
+/// This is the check behind `FieldElement::from_canonical_bytes`.
+///
+/// ## Proof outline (let v = bytes32_to_nat(bytes)):
+/// - v < p: the roundtrip is the identity (lemma_as_bytes_from_bytes_roundtrip),
+///   and v < 2^255 forces bytes[31] <= 127
+/// - roundtrip is the identity: v = bytes32_to_nat(as_bytes(fe)) < p
+pub proof fn lemma_canonical_field_encoding_roundtrip(
+    bytes: &[u8; 32],
+    fe: &FieldElement51,
+    bytes_decoded: &[u8; 32],
+)
+    requires
+        from_bytes_post(bytes, fe),  // fe = from_bytes(bytes)
+        as_bytes_post(fe, bytes_decoded),  // bytes_decoded = as_bytes(fe)
+
+    ensures
+        (*bytes_decoded == *bytes) == is_canonical_field_encoding(bytes),
+        is_canonical_field_encoding(bytes) ==> bytes[31] <= 127 && spec_field_element(fe)
+            == bytes32_to_nat(bytes),
+{
+    let v = bytes32_to_nat(bytes);
+    pow255_gt_19();
+
+    if is_canonical_field_encoding(bytes) {
+        lemma_as_bytes_from_bytes_roundtrip(bytes, fe, bytes_decoded);
+        assert(spec_field_element(fe) == v) by {
+            lemma_small_mod(v, pow2(255));
+            lemma_small_mod(v, p());
+        };
+        if bytes[31] >= 128 {
+            // v >= bytes[31] * 2^248 >= 128 * 2^248 = 2^255 > p
+            lemma_bytes32_to_nat_lower_bound(bytes, 31);
+            lemma_pow2_adds(7, 248);
+            lemma2_to64();
+            lemma_mul_inequality(128, bytes[31] as int, pow2(248) as int);
+        }
+    }
+    if *bytes_decoded == *bytes {
+        // as_bytes output is reduced mod p
+        lemma_mod_bound(spec_field_element_as_nat(fe) as int, p() as int);
+    }
 }
 
 } // verus!
@@ -1,6 +1,7 @@
-//! Lemmas about the ONE field element constant
+//! Lemmas about the ZERO, ONE and MINUS_ONE field element constants
 //!
-//! This module contains fully proved lemmas about the FieldElement::ONE constant.
+//! This module contains fully proved lemmas about the FieldElement::{ZERO, ONE, MINUS_ONE}
+//! constants.
 //!
 //! ## Mathematical Background
 //!
@@ -13,10 +14,12 @@
 //! - `u64_5_as_nat([1, 0, 0, 0, 0]) = 1 + 0 + 0 + 0 + 0 = 1` (since n·0 = 0)
 //! - `spec_field_element(ONE) = 1 % p = 1` (since p > 2 > 1)
 //!
+//! MINUS_ONE = [2^51 - 20, 2^51 - 1, 2^51 - 1, 2^51 - 1, 2^51 - 1] represents p - 1:
+//! - the sum telescopes to `2^255 - 20 = p - 1`
+//!
 //! ## Note
 //!
 //! - Edwards curve-specific constants (EDWARDS_D, EDWARDS_D2) are in `edwards_lemmas::constants_lemmas`.
-//! - ZERO constant lemmas are in `unused_constants_lemmas.rs` (currently unused).
 #![allow(unused_imports)]
 use crate::backend::serial::u64::field::FieldElement51;
 use crate::specs::field_specs::*;
@@ -86,4 +89,72 @@ pub proof fn lemma_one_field_element_value()
     };
 }
 
+// =============================================================================
+// FieldElement::ZERO Lemmas
+// =============================================================================
+/// ZERO = [0, 0, 0, 0, 0] has 51-bit bounded limbs and represents 0
+pub proof fn lemma_zero_field_element_value()
+    ensures
+        fe51_limbs_bounded(&FieldElement51::ZERO, 51),
+        spec_field_element_as_nat(&FieldElement51::ZERO) == 0,
+        spec_field_element(&FieldElement51::ZERO) == 0,
+{
+    assert(0u64 < (1u64 << 51)) by (bit_vector);
+    assert(u64_5_as_nat(FieldElement51::ZERO.limbs) == 0);
+    p_gt_2();
+    lemma_small_mod(0, p());
+}
+
+// =============================================================================
+// FieldElement::MINUS_ONE Lemmas
+// =============================================================================
+/// MINUS_ONE has 51-bit bounded limbs and represents p - 1
+///
+/// ## Mathematical Proof
+/// With c = 2^51 the limbs are (c - 20, c - 1, c - 1, c - 1, c - 1), so
+/// ```text
+/// (c - 20) + c(c - 1) + c²(c - 1) + c³(c - 1) + c⁴(c - 1) = c⁵ - 20 = p - 1
+/// ```
+pub proof fn lemma_minus_one_field_element_value()
+    ensures
+        fe51_limbs_bounded(&FieldElement51::MINUS_ONE, 51),
+        spec_field_element_as_nat(&FieldElement51::MINUS_ONE) == p() - 1,
+        spec_field_element(&FieldElement51::MINUS_ONE) == p() - 1,
+{
+    let limbs = FieldElement51::MINUS_ONE.limbs;
+    assert(fe51_limbs_bounded(&FieldElement51::MINUS_ONE, 51)) by {
+        assert(2251799813685228u64 < (1u64 << 51) && 2251799813685247u64 < (1u64 << 51))
+            by (bit_vector);
+    }
+
+    let c = pow2(51);
+    assert(c == 2251799813685248) by {
+        lemma2_to64_rest();
+    }
+    lemma_pow2_adds(51, 51);
+    lemma_pow2_adds(102, 51);
+    lemma_pow2_adds(153, 51);
+    lemma_pow2_adds(204, 51);
+
+    assert(u64_5_as_nat(limbs) == pow2(255) - 20) by (nonlinear_arith)
+        requires
+            limbs[0] == c - 20,
+            limbs[1] == c - 1,
+            limbs[2] == c - 1,
+            limbs[3] == c - 1,
+            limbs[4] == c - 1,
+            pow2(102) == c * c,
+            pow2(153) == pow2(102) * c,
+            pow2(204) == pow2(153) * c,
+            pow2(255) == pow2(204) * c,
+            u64_5_as_nat(limbs) == limbs[0] + pow2(51) * limbs[1] + pow2(102) * limbs[2] + pow2(
+                153,
+            ) * limbs[3] + pow2(204) * limbs[4],
+            c == pow2(51),
+    ;
+
+    p_gt_2();
+    lemma_small_mod((p() - 1) as nat, p());
+}
+
 } // verus!
@@ -9,8 +9,11 @@
 //! - `lemma_field_inv_one`: inv(1) = 1
 //! - `lemma_neg_square_eq`: (-x)² = x²
 //! - `lemma_field_mul_distributes_over_add`: a(b+c) = ab + ac
+//! - `lemma_field_mul_distributes_over_sub`: a(b-c) = ab - ac
 //! - `lemma_square_mod_noop`: (x%p)² = x²
+//! - `lemma_square2_matches_math_field_square`: square2 computes 2·x²
 //! - `lemma_field_add_sub_rearrange`: a+b = c-1 ⟹ a+1 = c-b
+//! - `lemma_field_mul_pair_rearrange`: (a·k1)(b·k2) = (a·b)(k1·k2)
 //!
 //! ## Inverse/Division Properties
 //!
@@ -18,6 +21,16 @@
 //! - `lemma_inv_of_square`: inv(x²) = inv(x)²
 //! - `lemma_quotient_of_squares`: a²/b² = (a/b)²
 //! - `lemma_product_of_squares_eq_square_of_product`: x²·y² = (x·y)²
+//!
+//! ## Cancellation Properties
+//!
+//! - `lemma_field_mul_nonzero`: a, b ≠ 0 ⟹ a·b ≠ 0
+//! - `lemma_field_mul_left_cancel`: a·x = a·y with a ≠ 0 ⟹ x = y
+//! - `lemma_equal_squares_equal_or_neg`: a² = b² ⟹ a = ±b
+//!
+//! ## Sign Properties
+//!
+//! - `lemma_neg_flips_sign`: a ≠ 0 ⟹ exactly one of a, -a is negative
 #![allow(unused_imports)]
 use crate::lemmas::common_lemmas::number_theory_lemmas::*;
 use crate::specs::field_specs::*;
@@ -166,6 +179,46 @@ pub proof fn lemma_field_mul_distributes_over_add(a: nat, b: nat, c: nat)
     };
 }
 
+/// Lemma: a · (b - c) = a·b - a·c (mod p)
+///
+/// ## Mathematical Proof
+/// ```text
+/// math_field_sub(b, c) ≡ b - c (mod p)     [p-offset vanishes mod p]
+/// a · ((b - c) % p) % p = a·(b - c) % p     [mod absorbs on right]
+///                       = (a·b - a·c) % p   [integer distributivity]
+///                       = ((a·b)%p - (a·c)%p) % p
+/// ```
+pub proof fn lemma_field_mul_distributes_over_sub(a: nat, b: nat, c: nat)
+    ensures
+        math_field_mul(a, math_field_sub(b, c)) == math_field_sub(
+            math_field_mul(a, b),
+            math_field_mul(a, c),
+        ),
+{
+    let p = p() as int;
+    p_gt_2();
+    let (a_int, b_int, c_int) = (a as int, b as int, c as int);
+    let ab = math_field_mul(a, b) as int;
+    let ac = math_field_mul(a, c) as int;
+
+    // math_field_sub(x, y) = (x % p + p - y % p) % p = (x - y) % p
+    assert(math_field_sub(b, c) as int == (b_int - c_int) % p) by {
+        lemma_mod_add_multiples_vanish((b % p()) as int - (c % p()) as int, p);
+        lemma_sub_mod_noop(b_int, c_int, p);
+    };
+    assert(math_field_sub(ab as nat, ac as nat) as int == (ab - ac) % p) by {
+        lemma_mod_add_multiples_vanish((ab % p) - (ac % p), p);
+        lemma_sub_mod_noop(ab, ac, p);
+    };
+
+    // LHS = a·(b - c) % p
+    lemma_mul_mod_noop_right(a_int, b_int - c_int, p);
+    lemma_mul_is_distributive_sub(a_int, b_int, c_int);
+
+    // RHS = (a·b - a·c) % p
+    lemma_sub_mod_noop(a_int * b_int, a_int * c_int, p);
+}
+
 /// Lemma: (x % p)² = x² (mod p)
 pub proof fn lemma_square_mod_noop(x: nat)
     ensures
@@ -217,6 +270,35 @@ pub proof fn lemma_square_matches_math_field_square(y_raw: nat, y2_raw: nat)
     assert(math_field_mul(y_raw % p, y_raw % p) == math_field_square(y_raw % p));
 }
 
+/// Lemma: Concrete doubled squaring (square2) matches 2 · math_field_square
+///
+/// ## Mathematical Proof
+/// ```text
+/// r_raw % p = (2 · y_raw²) % p
+///           = (2 · (y_raw² % p)) % p          [by lemma_mul_mod_noop_right]
+///           = 2 · math_field_square(y_raw % p) [by lemma_square_mod_noop]
+/// ```
+pub proof fn lemma_square2_matches_math_field_square(y_raw: nat, r_raw: nat)
+    requires
+        r_raw % p() == (2 * pow(y_raw as int, 2)) as nat % p(),
+    ensures
+        r_raw % p() == math_field_mul(2, math_field_square(y_raw % p())),
+{
+    let p = p();
+    p_gt_2();
+
+    assert(pow(y_raw as int, 2) == y_raw as int * y_raw as int) by {
+        reveal(pow);
+        assert(pow(y_raw as int, 1) == y_raw as int * pow(y_raw as int, 0));
+    };
+
+    assert((2 * (y_raw * y_raw)) % p == (2 * ((y_raw * y_raw) % p)) % p) by {
+        lemma_mul_mod_noop_right(2, (y_raw * y_raw) as int, p as int);
+    };
+
+    lemma_square_mod_noop(y_raw);
+}
+
 // =============================================================================
 // Field Equation Rearrangement Lemmas
 // =============================================================================
@@ -719,6 +801,21 @@ pub proof fn lemma_field_mul_comm(a: nat, b: nat)
     lemma_mul_is_commutative(a as int, b as int);
 }
 
+/// Lemma: (a·k1)·(b·k2) = (a·b)·(k1·k2)
+pub proof fn lemma_field_mul_pair_rearrange(a: nat, b: nat, k1: nat, k2: nat)
+    ensures
+        math_field_mul(math_field_mul(a, k1), math_field_mul(b, k2)) == math_field_mul(
+            math_field_mul(a, b),
+            math_field_mul(k1, k2),
+        ),
+{
+    lemma_field_mul_assoc(a, k1, math_field_mul(b, k2));
+    lemma_field_mul_assoc(k1, b, k2);
+    lemma_field_mul_comm(k1, b);
+    lemma_field_mul_assoc(b, k1, k2);
+    lemma_field_mul_assoc(a, b, math_field_mul(k1, k2));
+}
+
 /// Lemma: a · inv(a·b) = inv(b)
 ///
 /// ## Mathematical Proof
@@ -1096,4 +1193,163 @@ pub proof fn lemma_double_negation(a: nat)
     assert((neg_one * neg_a) % p == a);
 }
 
+/// Lemma: a ≠ 0 and b ≠ 0 implies a · b ≠ 0 (in field)
+///
+/// Follows from Euclid's lemma since p is prime.
+pub proof fn lemma_field_mul_nonzero(a: nat, b: nat)
+    requires
+        a % p() != 0,
+        b % p() != 0,
+    ensures
+        math_field_mul(a, b) != 0,
+        math_field_mul(a, b) % p() != 0,
+{
+    p_gt_2();
+    axiom_p_is_prime();
+    lemma_product_nonzero_mod_prime(a, b, p());
+    lemma_mod_twice((a * b) as int, p() as int);
+}
+
+/// Lemma: a · x = a · y with a ≠ 0 implies x = y (for reduced x, y)
+///
+/// ## Mathematical Proof
+/// ```text
+/// x = (a·x)·inv(a) = (a·y)·inv(a) = y     [by lemma_solve_for_left_factor]
+/// ```
+pub proof fn lemma_field_mul_left_cancel(a: nat, x: nat, y: nat)
+    requires
+        a % p() != 0,
+        x < p(),
+        y < p(),
+        math_field_mul(a, x) == math_field_mul(a, y),
+    ensures
+        x == y,
+{
+    let p = p();
+    p_gt_2();
+
+    let c = math_field_mul(a, x);
+    assert(c % p == c) by {
+        lemma_mod_bound((a * x) as int, p as int);
+        lemma_small_mod(c, p);
+    };
+
+    // x · a = c and y · a = c, so both equal c · inv(a)
+    lemma_field_mul_comm(x, a);
+    lemma_field_mul_comm(y, a);
+    lemma_solve_for_left_factor(x, a, c);
+    lemma_solve_for_left_factor(y, a, c);
+
+    lemma_small_mod(x, p);
+    lemma_small_mod(y, p);
+}
+
+/// Lemma: If a² = b² (in field) then a = b or a = -b
+///
+/// ## Mathematical Proof
+/// ```text
+/// a² - b² = (a - b)(a + b) ≡ 0 (mod p)
+/// p prime ⟹ p | (a - b) or p | (a + b)        [Euclid]
+/// 0 ≤ |a - b| < p  ⟹ a = b, or
+/// 0 < a + b < 2p   ⟹ a + b = p, i.e. a = -b
+/// ```
+pub proof fn lemma_equal_squares_equal_or_neg(a: nat, b: nat)
+    requires
+        a < p(),
+        b < p(),
+        math_field_square(a) == math_field_square(b),
+    ensures
+        a == b || a == math_field_neg(b),
+{
+    let p = p();
+    p_gt_2();
+
+    if a != b {
+        let hi: nat = if a > b {
+            a
+        } else {
+            b
+        };
+        let lo: nat = if a > b {
+            b
+        } else {
+            a
+        };
+        let d: nat = (hi - lo) as nat;
+        let s: nat = a + b;
+
+        // (hi - lo)(hi + lo) = hi² - lo² ≡ 0 (mod p)
+        assert((hi * hi) % p == (lo * lo) % p);
+        assert((d * s) % p == 0) by {
+            assert((d * s) as int == (hi * hi) as int - (lo * lo) as int) by (nonlinear_arith)
+                requires
+                    d == hi - lo,
+                    s == hi + lo,
+            ;
+            lemma_sub_mod_noop((hi * hi) as int, (lo * lo) as int, p as int);
+            lemma_small_mod(0, p);
+        };
+
+        // Euclid: p | d or p | s, and p ∤ d since 0 < d < p
+        assert(s % p == 0) by {
+            axiom_p_is_prime();
+            lemma_euclid_prime(d, s, p);
+            lemma_small_mod(d, p);
+        };
+
+        // 0 < s < 2p and p | s, so s = p
+        assert(s == p) by {
+            if s < p {
+                lemma_small_mod(s, p);
+            } else if s > p {
+                lemma_mod_add_multiples_vanish((s - p) as int, p as int);
+                lemma_small_mod((s - p) as nat, p);
+            }
+        };
+
+        // a = p - b = -b (and b ≠ 0, otherwise a = p)
+        assert(math_field_neg(b) == a) by {
+            lemma_small_mod(b, p);
+            lemma_small_mod(a, p);
+        };
+    }
+}
+
+// =============================================================================
+// Sign Properties
+// =============================================================================
+/// Lemma: negation flips the sign of a nonzero field element
+///
+/// ## Mathematical Proof
+/// ```text
+/// x = a mod p with 0 < x < p, so -a = p - x
+/// p is odd ⟹ (p - x) mod 2 = 1 - (x mod 2)
+/// ```
+pub proof fn lemma_neg_flips_sign(a: nat)
+    requires
+        a % p() != 0,
+    ensures
+        math_field_is_negative(math_field_neg(a)) == !math_field_is_negative(a),
+{
+    let p = p();
+    p_gt_2();
+    let x = a % p;
+    assert(0 < x < p) by {
+        lemma_mod_bound(a as int, p as int);
+    };
+
+    let neg_a = math_field_neg(a);
+    assert(neg_a == p - x && neg_a % p == neg_a) by {
+        lemma_small_mod((p - x) as nat, p);
+    };
+    assert(x % p == x) by {
+        lemma_mod_twice(a as int, p as int);
+    };
+
+    assert((p - x) % 2 == 1 <==> x % 2 == 0) by {
+        lemma_p_is_odd();
+        lemma_sub_mod_noop(p as int, x as int, 2);
+    };
+}
+
 } // verus!
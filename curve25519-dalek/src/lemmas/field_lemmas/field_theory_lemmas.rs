@@ -0,0 +1,134 @@
+//! Prime field facts about p = 2^255 - 19
+//!
+//! The invert, sqrt_ratio_i and Elligator proofs all rest on the same handful of
+//! number-theoretic facts about p. This module collects them so those proofs cite
+//! a single source instead of re-deriving them inline.
+//!
+//! ## Foundations (defined elsewhere, re-stated here)
+//!
+//! - `axiom_p_is_prime` (specs::primality_specs) — the only trusted assumption
+//! - `field_inv_property` (specs::field_specs) — inverses exist, via Bezout
+//! - `lemma_fermat_for_p` (specs::field_specs) — x^(p-1) = 1 for x ≠ 0
+//!
+//! ## Lemmas
+//!
+//! - `lemma_p_is_prime` — p is prime
+//! - `lemma_p_mod_8` — p ≡ 5 (mod 8)
+//! - `lemma_field_mul_inv` — a · inv(a) = 1 for a ≠ 0
+//! - `lemma_euler_criterion_square` — a nonzero square a has a^((p-1)/2) = 1
+//! - `lemma_minus_one_is_square` — -1 is a square (witness SQRT_M1), since p ≡ 1 (mod 4)
+#![allow(unused_imports)]
+use crate::lemmas::common_lemmas::number_theory_lemmas::*;
+use crate::lemmas::field_lemmas::pow_p58_lemma::*;
+use crate::lemmas::field_lemmas::sqrt_m1_lemmas::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use crate::specs::primality_specs::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+/// Lemma: p = 2^255 - 19 is prime
+pub proof fn lemma_p_is_prime()
+    ensures
+        is_prime(p()),
+        p() > 2,
+{
+    p_gt_2();
+    axiom_p_is_prime();
+}
+
+/// Lemma: p ≡ 5 (mod 8)
+///
+/// ## Mathematical Proof
+/// ```text
+/// p = 8·(2^252 - 3) + 5     [lemma_p58_exponent]
+/// ```
+pub proof fn lemma_p_mod_8()
+    ensures
+        p() % 8 == 5,
+        p() % 4 == 1,
+{
+    lemma_p58_exponent();
+    let e = p58_exponent() as int;
+    lemma_mod_multiples_vanish(e, 5, 8);
+    lemma_mod_multiples_vanish(2 * e + 1, 1, 4);
+}
+
+/// Lemma: a · inv(a) = 1 for a ≢ 0 (mod p)
+pub proof fn lemma_field_mul_inv(a: nat)
+    requires
+        a % p() != 0,
+    ensures
+        math_field_mul(a, math_field_inv(a)) == 1,
+{
+    p_gt_2();
+    field_inv_property(a);
+    lemma_mul_mod_noop_left(a as int, math_field_inv(a) as int, p() as int);
+}
+
+/// Lemma: Euler's criterion, square direction: a nonzero square a has a^((p-1)/2) = 1
+///
+/// ## Mathematical Proof
+/// ```text
+/// Let y² ≡ a. Then y ≢ 0 and a^((p-1)/2) ≡ (y²)^((p-1)/2) = y^(p-1) ≡ 1   [Fermat]
+/// ```
+pub proof fn lemma_euler_criterion_square(a: nat)
+    requires
+        a % p() != 0,
+        is_square_mod_p(a),
+    ensures
+        (pow(a as int, ((p() - 1) / 2) as nat) as nat) % p() == 1,
+{
+    let p = p();
+    p_gt_2();
+    lemma_p_is_odd();
+    let k = ((p - 1) / 2) as nat;
+    assert(2 * k == p - 1);
+
+    let y = choose|y: nat| (#[trigger] (y * y) % p) == (a % p);
+    let yy = (y * y) as int;
+
+    // y ≢ 0, otherwise y² ≡ 0 ≡ a
+    assert(y % p != 0) by {
+        if y % p == 0 {
+            lemma_mul_mod_noop_left(y as int, y as int, p as int);
+            lemma_small_mod(0, p);
+        }
+    };
+
+    // a^k ≡ (a % p)^k = (y² % p)^k ≡ (y²)^k
+    lemma_pow_mod_noop(a as int, k, p as int);
+    lemma_pow_mod_noop(yy, k, p as int);
+
+    // (y²)^k = y^(2k) = y^(p-1)
+    assert(pow(yy, k) == pow(y as int, (p - 1) as nat)) by {
+        lemma_square_is_pow2(y as int);
+        lemma_pow_multiplies(y as int, 2, k);
+    };
+    lemma_pow_nonnegative(a as int, k);
+    lemma_fermat_for_p(y);
+}
+
+/// Lemma: -1 is a square mod p, with square root SQRT_M1
+///
+/// This is the p ≡ 1 (mod 4) case of the first supplement to quadratic reciprocity;
+/// here the witness is the concrete constant, so no reciprocity argument is needed.
+pub proof fn lemma_minus_one_is_square()
+    ensures
+        is_square_mod_p((p() - 1) as nat),
+        math_is_square((p() - 1) as nat),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    p_gt_2();
+    lemma_sqrt_m1_squared();
+    lemma_small_mod((p - 1) as nat, p);
+    assert((i * i) % p == ((p - 1) as nat) % p);
+}
+
+} // verus!
@@ -5,6 +5,13 @@
 //!
 //! The exponent p-2 = 2^255 - 21 is computed as:
 //!   (2^250 - 1) * 2^5 + 11 = 2^255 - 32 + 11 = 2^255 - 21
+//!
+//! Batch inversion (Montgomery's trick) multiplies the nonzero inputs together, inverts the
+//! product once, and then peels one input off per step on the way back:
+//! ```text
+//! acc = inv(P_{k+1}) = inv(P_k · x_k)  ⟹  acc · P_k = inv(x_k)  and  acc · x_k = inv(P_k)
+//! ```
+//! where P_k is the product of the nonzero inputs before index k.
 #![allow(unused_imports)]
 use vstd::arithmetic::div_mod::*;
 use vstd::arithmetic::power::*;
@@ -14,6 +21,7 @@ use vstd::prelude::*;
 use crate::backend::serial::u64::field::*;
 use crate::lemmas::common_lemmas::div_mod_lemmas::*;
 use crate::lemmas::common_lemmas::pow_lemmas::*;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
 use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
 
@@ -516,4 +524,98 @@ pub proof fn lemma_invert_correctness(
     }
 }
 
+// =============================================================================
+// Batch inversion
+// =============================================================================
+/// Lemma: one step of spec_product_of_nonzero_field_elems over a growing prefix
+pub proof fn lemma_product_of_nonzero_prefix_step(fields: Seq<FieldElement51>, k: int)
+    requires
+        0 <= k < fields.len(),
+    ensures
+        spec_product_of_nonzero_field_elems(fields.subrange(0, k + 1)) == if spec_field_element(
+            &fields[k],
+        ) == 0 {
+            spec_product_of_nonzero_field_elems(fields.subrange(0, k))
+        } else {
+            math_field_mul(
+                spec_product_of_nonzero_field_elems(fields.subrange(0, k)),
+                spec_field_element(&fields[k]),
+            )
+        },
+{
+    let prefix = fields.subrange(0, k + 1);
+    assert(prefix.subrange(0, k) =~= fields.subrange(0, k));
+    assert(prefix[k] == fields[k]);
+}
+
+/// Lemma: the product of the nonzero elements is a nonzero reduced field value
+pub proof fn lemma_product_of_nonzero_is_nonzero(fields: Seq<FieldElement51>)
+    ensures
+        spec_product_of_nonzero_field_elems(fields) < p(),
+        spec_product_of_nonzero_field_elems(fields) % p() != 0,
+    decreases fields.len(),
+{
+    let p = p();
+    p_gt_2();
+    if fields.len() == 0 {
+        lemma_small_mod(1, p);
+    } else {
+        let last = (fields.len() - 1) as int;
+        let prev = spec_product_of_nonzero_field_elems(fields.subrange(0, last));
+        let x = spec_field_element(&fields[last]);
+        lemma_product_of_nonzero_is_nonzero(fields.subrange(0, last));
+        if x != 0 {
+            assert(x % p != 0) by {
+                lemma_mod_bound(u64_5_as_nat(fields[last].limbs) as int, p as int);
+                lemma_small_mod(x, p);
+            };
+            lemma_field_mul_nonzero(prev, x);
+            lemma_mod_bound((prev * x) as int, p as int);
+        }
+    }
+}
+
+/// Lemma: backward pass of batch inversion
+///
+/// With acc = inv(P·x) for nonzero P and x, multiplying by the saved prefix
+/// product P gives inv(x), and multiplying by x gives the next accumulator inv(P).
+///
+/// ## Mathematical Proof
+/// ```text
+/// P · inv(P·x) = inv(x)                       [lemma_a_times_inv_ab_is_inv_b]
+/// x · inv(P·x) = x · inv(x·P) = inv(P)        [commutativity, same lemma]
+/// ```
+pub proof fn lemma_batch_invert_step(prefix: nat, x: nat)
+    requires
+        prefix % p() != 0,
+        x % p() != 0,
+    ensures
+        math_field_mul(math_field_inv(math_field_mul(prefix, x)), prefix) == math_field_inv(x),
+        math_field_mul(math_field_inv(math_field_mul(prefix, x)), x) == math_field_inv(prefix),
+{
+    let acc = math_field_inv(math_field_mul(prefix, x));
+
+    lemma_a_times_inv_ab_is_inv_b(prefix, x);
+    lemma_field_mul_comm(acc, prefix);
+
+    lemma_field_mul_comm(prefix, x);
+    lemma_a_times_inv_ab_is_inv_b(x, prefix);
+    lemma_field_mul_comm(acc, x);
+}
+
+/// Lemma: the inverse of a nonzero reduced element satisfies is_inverse_field
+pub proof fn lemma_inverse_field_of_math_field_inv(a: &FieldElement51, b: &FieldElement51)
+    requires
+        spec_field_element(a) != 0,
+        spec_field_element(b) == math_field_inv(spec_field_element(a)),
+    ensures
+        is_inverse_field(a, b),
+{
+    let x = spec_field_element(a);
+    p_gt_2();
+    lemma_mod_bound(u64_5_as_nat(a.limbs) as int, p() as int);
+    lemma_small_mod(x, p());
+    field_inv_property(x);
+}
+
 } // verus!
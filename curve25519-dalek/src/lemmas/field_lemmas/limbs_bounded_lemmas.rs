@@ -0,0 +1,81 @@
+//! Lemmas about the `fe51_limbs_bounded` limb-bound predicate
+//!
+//! Field operations hand limbs between each other at a few fixed bit bounds
+//! (51, 52, 53, 54). These lemmas centralize the bookkeeping between them.
+#![allow(unused_imports)]
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::lemmas::common_lemmas::pow_lemmas::*;
+use crate::lemmas::common_lemmas::shift_lemmas::*;
+use crate::specs::field_specs::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+/// A limb bound can always be relaxed to a larger one
+pub proof fn lemma_fe51_limbs_bounded_weaken(fe: &FieldElement51, a: u64, b: u64)
+    requires
+        fe51_limbs_bounded(fe, a),
+        a <= b < 64,
+    ensures
+        fe51_limbs_bounded(fe, b),
+{
+    assert((1u64 << a) <= (1u64 << b)) by {
+        lemma_u64_pow2_le_max(b as nat);
+        lemma_u64_shl_nondecreasing(1u64, a as nat, b as nat);
+    };
+}
+
+/// Adding two 52-bit bounded elements limb-wise gives 53-bit bounded limbs
+pub proof fn lemma_add_fe51_limbs_bounded_52(a: &FieldElement51, b: &FieldElement51)
+    requires
+        fe51_limbs_bounded(a, 52),
+        fe51_limbs_bounded(b, 52),
+    ensures
+        fe51_limbs_bounded(&spec_add_fe51_limbs(a, b), 53),
+{
+    assert((1u64 << 52) + (1u64 << 52) == (1u64 << 53)) by (bit_vector);
+    assert forall|i: int| 0 <= i < 5 implies #[trigger] spec_add_fe51_limbs(a, b).limbs[i] < (1u64
+        << 53) by {
+        assert(a.limbs[i] < (1u64 << 52) && b.limbs[i] < (1u64 << 52));
+        assert(i == 0 || i == 1 || i == 2 || i == 3 || i == 4);
+    };
+}
+
+/// Adding two 53-bit bounded elements limb-wise gives 54-bit bounded limbs
+pub proof fn lemma_add_fe51_limbs_bounded_53(a: &FieldElement51, b: &FieldElement51)
+    requires
+        fe51_limbs_bounded(a, 53),
+        fe51_limbs_bounded(b, 53),
+    ensures
+        fe51_limbs_bounded(&spec_add_fe51_limbs(a, b), 54),
+{
+    assert((1u64 << 53) + (1u64 << 53) == (1u64 << 54)) by (bit_vector);
+    assert forall|i: int| 0 <= i < 5 implies #[trigger] spec_add_fe51_limbs(a, b).limbs[i] < (1u64
+        << 54) by {
+        assert(a.limbs[i] < (1u64 << 53) && b.limbs[i] < (1u64 << 53));
+        assert(i == 0 || i == 1 || i == 2 || i == 3 || i == 4);
+    };
+}
+
+/// Two limb-bounded elements can be added limb-wise without overflow
+pub proof fn lemma_sum_of_limbs_bounded_from_fe51_bounded(
+    a: &FieldElement51,
+    b: &FieldElement51,
+    n: u64,
+)
+    requires
+        fe51_limbs_bounded(a, n),
+        fe51_limbs_bounded(b, n),
+        n <= 62,
+    ensures
+        sum_of_limbs_bounded(a, b, u64::MAX),
+{
+    assert((1u64 << n) <= (1u64 << 62u64)) by {
+        lemma_u64_pow2_le_max(62);
+        lemma_u64_shl_nondecreasing(1u64, n as nat, 62);
+    };
+    assert((1u64 << 62u64) == 0x4000000000000000u64) by (bit_vector);
+}
+
+} // verus!
@@ -20,6 +20,10 @@ pub mod reduce_lemmas;
 
 pub mod load8_lemmas;
 
+pub mod limbs_bounded_lemmas;
+
+pub mod mul_lemmas;
+
 pub mod compute_q_lemmas;
 pub mod limbs_to_bytes_lemmas;
 pub mod to_bytes_reduction_lemmas;
@@ -34,6 +38,8 @@ pub mod constants_lemmas;
 
 pub mod field_algebra_lemmas;
 
+pub mod field_theory_lemmas;
+
 pub mod sqrt_m1_lemmas;
 
 pub mod sqrt_ratio_lemmas;
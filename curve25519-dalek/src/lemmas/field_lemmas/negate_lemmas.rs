@@ -24,21 +24,22 @@ pub open spec fn all_neg_limbs_positive(limbs: [u64; 5]) -> bool {
     &&& 36028797018963952u64 >= limbs[4]
 }
 
+/// Subtracting from 16p cannot underflow for 54-bit limbs, since 2^54 < 16 * (2^51 - 19)
 pub proof fn lemma_neg_no_underflow(limbs: [u64; 5])
     requires
-        forall|i: int| 0 <= i < 5 ==> limbs[i] < (1u64 << 51),
+        forall|i: int| 0 <= i < 5 ==> limbs[i] < (1u64 << 54),
     ensures
         all_neg_limbs_positive(limbs),
 {
-    lemma2_to64_rest();  // pow2(51)
+    lemma2_to64_rest();  // pow2(51), pow2(54)
     assert forall|i: int| 0 <= i < 5 implies limbs[i] < 16 * (pow2(51) - 19) by {
-        lemma_u64_shift_is_pow2(51);
+        lemma_u64_shift_is_pow2(54);
     }
 }
 
 pub proof fn proof_negate(limbs: [u64; 5])
     requires
-        forall|i: int| 0 <= i < 5 ==> limbs[i] < (1u64 << 51),
+        forall|i: int| 0 <= i < 5 ==> limbs[i] < (1u64 << 54),
         all_neg_limbs_positive(limbs),
     ensures
         forall|i: int| 0 <= i < 5 ==> spec_negate(limbs)[i] < (1u64 << 52),
@@ -56,7 +57,7 @@ pub proof fn proof_negate(limbs: [u64; 5])
         // This gives us the identity
         // u64_5_as_nat(negate(l)) = u64_5_as_nat(reduce(16 * (c0, c, c, c, c) - l))
         //                   = 16p - u64_5_as_nat(l) - p * ((16c - l4) >> 51)
-        // Note that (16c - l4) >> 51 is either 14 or 15, in either case < 16.
+        // Note that (16c - l4) >> 51 is between 7 and 15 for l4 < 2^54, in either case < 16.
         u64_5_as_nat(spec_negate(limbs)) == 16 * p() - u64_5_as_nat(limbs) - p() * ((
         36028797018963952u64 - limbs[4]) as u64 >> 51),
         (u64_5_as_nat(spec_negate(limbs)) + u64_5_as_nat(limbs)) % p() == 0,
@@ -9,6 +9,10 @@
 //!
 //! This proof uses helper lemmas from pow_chain_lemmas for conciseness.
 //!
+//! The exponent facts consumed by sqrt_ratio_i are proved separately:
+//! - `lemma_p58_exponent`: 8·e + 5 = p, i.e. e = (p-5)/8
+//! - `lemma_pow_p58_fourth_root`: x·(x^e)² = x^((p-1)/4), a fourth root of unity
+//!
 #![allow(unused)]
 use vstd::arithmetic::div_mod::*;
 use vstd::arithmetic::mul::*;
@@ -20,10 +24,101 @@ use crate::lemmas::common_lemmas::div_mod_lemmas::*;
 use crate::lemmas::common_lemmas::mul_lemmas::*;
 use crate::lemmas::common_lemmas::pow_lemmas::*;
 use crate::lemmas::field_lemmas::pow_chain_lemmas::*;
+use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
 
 verus! {
 
+/// Lemma: the pow_p58 exponent 2^252 - 3 is (p-5)/8
+///
+/// ## Mathematical Proof
+/// ```text
+/// p - 5 = 2^255 - 24 = 8·(2^252 - 3)
+/// ```
+pub proof fn lemma_p58_exponent()
+    ensures
+        8 * p58_exponent() + 5 == p(),
+        p58_exponent() == (p() - 5) / 8,
+        4 * (2 * p58_exponent() + 1) == p() - 1,
+{
+    pow255_gt_19();
+    assert(pow2(255) == 8 * pow2(252)) by {
+        lemma2_to64();
+        lemma_pow2_adds(3, 252);
+    };
+    assert(pow2(252) >= 3) by {
+        lemma2_to64();
+        lemma_pow2_strictly_increases(2, 252);
+    };
+    let e = (pow2(252) - 3) as int;
+    assert(p() - 5 == 8 * e);
+    lemma_div_multiples_vanish(e, 8);
+}
+
+/// Lemma: x·(x^((p-5)/8))² is a fourth root of unity
+///
+/// This is the exponent identity sqrt_ratio_i relies on: with e = (p-5)/8,
+/// x·(x^e)² = x^(2e+1) = x^((p-1)/4), whose fourth power is x^(p-1) = 1.
+pub proof fn lemma_pow_p58_fourth_root(x: nat)
+    requires
+        x < p(),
+        x != 0,
+    ensures
+        ({
+            let y = math_field_mul(x, math_field_square(math_field_pow_p58(x)));
+            &&& y == (pow(x as int, ((p() - 1) / 4) as nat) as nat) % p()
+            &&& y < p()
+            &&& math_field_square(math_field_square(y)) == 1
+        }),
+{
+    let p = p();
+    let e = p58_exponent();
+    pow255_gt_19();
+    lemma_p58_exponent();
+    lemma_small_mod(x, p);
+
+    // x·β² = x^(2e+1)
+    let beta = math_field_pow_p58(x);
+    let beta_sq = math_field_square(beta);
+    let pe = pow(x as int, e);
+    assert(pe > 0) by {
+        lemma_pow_positive(x as int, e);
+    };
+    assert(beta_sq == (pow(x as int, 2 * e) as nat) % p) by {
+        lemma_mul_mod_noop(pe, pe, p as int);
+        lemma_pow_adds(x as int, e, e);
+    };
+    let k = (2 * e + 1) as nat;
+    let y = (pow(x as int, k) as nat) % p;
+    assert(math_field_mul(x, beta_sq) == y) by {
+        lemma_pow_positive(x as int, 2 * e);
+        lemma_mul_mod_noop_right(x as int, pow(x as int, 2 * e), p as int);
+        lemma_pow1(x as int);
+        lemma_pow_adds(x as int, 1, 2 * e);
+    };
+
+    // 4k = p - 1, so k = (p-1)/4 and y⁴ = x^(p-1) = 1
+    assert(k == (p - 1) / 4) by {
+        lemma_div_multiples_vanish(k as int, 4);
+    };
+    assert(y < p) by {
+        lemma_mod_bound(pow(x as int, k), p as int);
+    };
+    assert(math_field_square(math_field_square(y)) == 1) by {
+        let pk = pow(x as int, k);
+        let p2k = pow(x as int, 2 * k);
+        lemma_pow_positive(x as int, k);
+        lemma_pow_positive(x as int, 2 * k);
+        // y² = x^(2k)
+        lemma_mul_mod_noop(pk, pk, p as int);
+        lemma_pow_adds(x as int, k, k);
+        // y⁴ = x^(4k) = x^(p-1)
+        lemma_mul_mod_noop(p2k, p2k, p as int);
+        lemma_pow_adds(x as int, 2 * k, 2 * k);
+        lemma_fermat_for_p(x);
+    };
+}
+
 /// Proves that pow_p58 correctly computes x^(2^252-3)
 ///
 /// # Arguments
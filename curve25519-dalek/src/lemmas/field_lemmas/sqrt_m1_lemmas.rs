@@ -3,11 +3,15 @@
 //! This module contains lemmas about the specific field element `spec_sqrt_m1()`,
 //! which is the square root of -1 in the curve25519 prime field.
 //!
+//! ## Concrete facts
+//!
+//! - `lemma_sqrt_m1_squared` — i² = -1 (mod p), checked by computation
+//! - `lemma_sqrt_m1_squared_is_minus_one` — SQRT_M1² = MINUS_ONE = p - 1
+//!
 //! ## Axioms
 //!
 //! These are concrete numerical facts about i that are mathematically proven
 //! but complex to formalize in Verus:
-//! - `axiom_sqrt_m1_squared` — i² = -1 (mod p)
 //! - `axiom_sqrt_m1_not_square` — i is not a square (Euler's criterion)
 //! - `axiom_neg_sqrt_m1_not_square` — -i is not a square
 //!
@@ -17,11 +21,16 @@
 //! - `lemma_i_inverse_is_neg_i` — i⁻¹ = -i
 //! - `lemma_u_times_inv_iu_is_neg_i` — u · inv(i·u) = -i
 //! - `lemma_neg_u_times_inv_iu_is_i` — (-u) · inv(i·u) = i
+//! - `lemma_sqrt_m1_limbs_bounded` — SQRT_M1 has 51-bit limbs
+//! - `lemma_fourth_roots_of_unity` — y⁴ = 1 implies y ∈ {1, -1, i, -i}
+//! - `lemma_fourth_roots_of_unity_distinct` — 1, -1, i, -i are pairwise distinct
 #![allow(unused_imports)]
+use crate::backend::serial::u64::field::FieldElement51;
 use crate::constants;
 use crate::lemmas::common_lemmas::div_mod_lemmas::*;
 use crate::lemmas::common_lemmas::mul_lemmas::*;
 use crate::lemmas::common_lemmas::number_theory_lemmas::*;
+use crate::lemmas::field_lemmas::constants_lemmas::*;
 use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
 use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
@@ -35,27 +44,37 @@ use vstd::prelude::*;
 verus! {
 
 // =============================================================================
-// AXIOMS: Number-Theoretic Facts about i = sqrt(-1) in F_p where p = 2^255 - 19
-//
-// These are concrete numerical facts that are mathematically proven but
-// complex to formalize in Verus. Each axiom includes its justification.
+// Concrete value of i = sqrt(-1)
 // =============================================================================
-/// AXIOM: i² = -1 (mod p) — Definition of SQRT_M1
+/// Lemma: i² = -1 (mod p) — Definition of SQRT_M1
 ///
-/// Mathematical justification:
-/// - SQRT_M1 is a specific constant computed to satisfy i² ≡ -1 (mod p)
-/// - The value is approximately 2^252.3 (a ~252-bit number)
-/// - Verification would require BigInt computation of the actual product
+/// SQRT_M1 is a specific ~252-bit constant; the product is evaluated directly.
 ///
 /// Used in: lemma_sqrt_m1_neq_one, lemma_sqrt_m1_neq_neg_one,
 ///          lemma_multiply_by_i_flips_sign, lemma_no_square_root_when_times_i
-pub proof fn axiom_sqrt_m1_squared()
+pub proof fn lemma_sqrt_m1_squared()
     ensures
         (spec_sqrt_m1() * spec_sqrt_m1()) % p() == (p() - 1),
 {
-    admit();
+    assert((spec_sqrt_m1() * spec_sqrt_m1()) % p() == (p() - 1)) by (compute);
 }
 
+/// Lemma: SQRT_M1² = MINUS_ONE as field elements
+pub proof fn lemma_sqrt_m1_squared_is_minus_one()
+    ensures
+        math_field_square(spec_sqrt_m1()) == spec_field_element(&FieldElement51::MINUS_ONE),
+        spec_field_element(&FieldElement51::MINUS_ONE) == p() - 1,
+{
+    lemma_sqrt_m1_squared();
+    lemma_minus_one_field_element_value();
+}
+
+// =============================================================================
+// AXIOMS: Number-Theoretic Facts about i = sqrt(-1) in F_p where p = 2^255 - 19
+//
+// These are concrete numerical facts that are mathematically proven but
+// complex to formalize in Verus. Each axiom includes its justification.
+// =============================================================================
 /// AXIOM: i = sqrt(-1) is not a square in F_p
 ///
 /// Mathematical justification:
@@ -130,9 +149,9 @@ pub proof fn lemma_multiply_by_i_flips_sign(r: nat)
             assert((r * i) * (r * i) == (r * r) * (i * i)) by (nonlinear_arith);
         };
 
-        // i² % p = p - 1 (from axiom)
+        // i² % p = p - 1 (lemma_sqrt_m1_squared)
         assert(i2 % pn == pn_minus_1) by {
-            axiom_sqrt_m1_squared();
+            lemma_sqrt_m1_squared();
         };
 
         // (r²·i²) % p = (r²·(p-1)) % p
@@ -176,7 +195,7 @@ pub proof fn lemma_multiply_by_i_flips_sign(r: nat)
 ///
 /// ## Mathematical Proof
 /// ```text
-/// i² = -1           (by axiom_sqrt_m1_squared)
+/// i² = -1           (by lemma_sqrt_m1_squared)
 /// i · (-i) = -i²    (factor out)
 ///         = -(-1)   (substitute i² = -1)
 ///         = 1       (negation of -1)
@@ -209,9 +228,9 @@ pub proof fn lemma_i_inverse_is_neg_i()
             assert(0nat % p == 0) by {
                 lemma_small_mod(0nat, p);
             };
-            // But axiom says i² % p = p - 1
+            // But i² % p = p - 1
             assert((i * i) % p == (p - 1) as nat) by {
-                axiom_sqrt_m1_squared();
+                lemma_sqrt_m1_squared();
             };
             // Since i = 0, we have 0 = p - 1, but p > 2
             assert(false);
@@ -243,10 +262,10 @@ pub proof fn lemma_i_inverse_is_neg_i()
             lemma_mod_multiples_basic(i as int, p as int);
         };
 
-        // Step 4b: i² % p = p - 1 (from axiom)
+        // Step 4b: i² % p = p - 1 (lemma_sqrt_m1_squared)
         let i2_mod: nat = (p - 1) as nat;
         assert((i * i) % p == i2_mod) by {
-            axiom_sqrt_m1_squared();
+            lemma_sqrt_m1_squared();
         };
 
         // Step 4c: i * (p - i) = i*p - i² by distributivity
@@ -409,7 +428,7 @@ pub proof fn lemma_neg_u_times_inv_iu_is_i(u: nat, i: nat)
     };
     assert(i != 0) by {
         if i == 0 {
-            axiom_sqrt_m1_squared();
+            lemma_sqrt_m1_squared();
             lemma_small_mod(0nat, p);
             assert((0nat * 0nat) % p == 0);
             assert(math_field_neg(1nat) != 0);  // -1 ≠ 0
@@ -424,4 +443,135 @@ pub proof fn lemma_neg_u_times_inv_iu_is_i(u: nat, i: nat)
     lemma_small_mod(i, p);
 }
 
+//=============================================================================
+// Fourth roots of unity
+//=============================================================================
+/// Lemma: SQRT_M1 has 51-bit bounded limbs (hence also 54-bit bounded)
+pub proof fn lemma_sqrt_m1_limbs_bounded()
+    ensures
+        fe51_limbs_bounded(&constants::SQRT_M1, 51),
+        fe51_limbs_bounded(&constants::SQRT_M1, 54),
+{
+    assert(fe51_limbs_bounded(&constants::SQRT_M1, 51)) by {
+        assert(1718705420411056u64 < (1u64 << 51)) by (bit_vector);
+        assert(234908883556509u64 < (1u64 << 51)) by (bit_vector);
+        assert(2233514472574048u64 < (1u64 << 51)) by (bit_vector);
+        assert(2117202627021982u64 < (1u64 << 51)) by (bit_vector);
+        assert(765476049583133u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert((1u64 << 51) < (1u64 << 54)) by (bit_vector);
+}
+
+/// Lemma: The only solutions of y⁴ = 1 in F_p are 1, -1, i and -i
+///
+/// ## Mathematical Proof
+/// ```text
+/// Let s = y². Then s² = 1 = 1², so s = ±1     [lemma_equal_squares_equal_or_neg]
+/// s = 1:  y² = 1²,  so y = ±1
+/// s = -1: y² = i²,  so y = ±i                 [lemma_sqrt_m1_squared]
+/// ```
+pub proof fn lemma_fourth_roots_of_unity(y: nat)
+    requires
+        y < p(),
+        math_field_square(math_field_square(y)) == 1,
+    ensures
+        y == 1 || y == math_field_neg(1) || y == spec_sqrt_m1() || y == math_field_neg(
+            spec_sqrt_m1(),
+        ),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    p_gt_2();
+
+    let s = math_field_square(y);
+    assert(s < p) by {
+        lemma_mod_bound((y * y) as int, p as int);
+    };
+    assert(math_field_square(1) == 1) by {
+        lemma_small_mod(1, p);
+    };
+    assert(math_field_neg(1) == p - 1) by {
+        lemma_small_mod(1, p);
+        lemma_small_mod((p - 1) as nat, p);
+    };
+
+    // s² = 1², so s = 1 or s = -1
+    lemma_equal_squares_equal_or_neg(s, 1);
+
+    if s == 1 {
+        // y² = 1², so y = ±1
+        lemma_equal_squares_equal_or_neg(y, 1);
+    } else {
+        // y² = -1 = i², so y = ±i
+        assert(i < p) by {
+            lemma_mod_bound(spec_field_element_as_nat(&constants::SQRT_M1) as int, p as int);
+        };
+        assert(math_field_square(i) == p - 1) by {
+            lemma_sqrt_m1_squared();
+        };
+        lemma_equal_squares_equal_or_neg(y, i);
+    }
+}
+
+/// Lemma: The fourth roots of unity 1, -1, i, -i are pairwise distinct
+///
+/// ## Mathematical Proof
+/// ```text
+/// 1 ≠ -1 and i ≠ -i            since p is odd
+/// i ≠ ±1                       since i² = -1 ≠ 1 = (±1)²
+/// -i ≠ ±1                      equivalently i ≠ ∓1
+/// ```
+pub proof fn lemma_fourth_roots_of_unity_distinct()
+    ensures
+        1 != math_field_neg(1),
+        1 != spec_sqrt_m1(),
+        1 != math_field_neg(spec_sqrt_m1()),
+        math_field_neg(1) != spec_sqrt_m1(),
+        math_field_neg(1) != math_field_neg(spec_sqrt_m1()),
+        spec_sqrt_m1() != math_field_neg(spec_sqrt_m1()),
+        // Representatives, for callers that need the values reduced
+        spec_sqrt_m1() < p(),
+        math_field_neg(1) == p() - 1,
+        math_field_neg(spec_sqrt_m1()) == p() - spec_sqrt_m1(),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    p_gt_2();
+    lemma_p_is_odd();
+    lemma_sqrt_m1_squared();
+    lemma_small_mod(1, p);
+
+    assert(i < p) by {
+        lemma_mod_bound(spec_field_element_as_nat(&constants::SQRT_M1) as int, p as int);
+    };
+    assert(math_field_neg(1) == p - 1) by {
+        lemma_small_mod(1, p);
+        lemma_small_mod((p - 1) as nat, p);
+    };
+
+    // i² = p - 1, while 1² = (-1)² = 1
+    assert(math_field_square(1) == 1) by {
+        lemma_small_mod(1, p);
+    };
+    assert(math_field_square(math_field_neg(1)) == 1) by {
+        lemma_neg_square_eq(1);
+        lemma_small_mod(1, p);
+    };
+    assert(i != 0) by {
+        if i == 0 {
+            lemma_small_mod(0nat, p);
+        }
+    };
+    assert(i != 1 && i != p - 1);
+
+    // -i = p - i
+    assert(math_field_neg(i) == p - i) by {
+        lemma_small_mod(i, p);
+        lemma_small_mod((p - i) as nat, p);
+    };
+
+    // i = p - i would make p = 2i even
+    assert(i != p - i);
+}
+
 } // verus!
@@ -7,6 +7,12 @@
 //! - `lemma_no_square_root_when_times_i` — failure case: x²·v = i·u implies no r with r²·v = ±u
 //! - `lemma_flipped_sign_becomes_correct` — if v·r² = -u, then v·(r·i)² = u
 //! - `lemma_algebraic_chain_base` — proves q² = (r²·v) · inv(i·u)
+//! - `lemma_sqrt_ratio_i_candidate_check` — v·r² ∈ {u, -u, u·i, -u·i} for the candidate r
+//! - `lemma_sqrt_ratio_i_cases` — the i-adjusted, sign-normalized result matches all four cases
+//! - `lemma_sqrt_ratio_i_one_denominator` — with v = 1 the cases reduce to a plain square root
+//! - `lemma_nonnegative_square_root_unique` — two non-negative roots of the same square are equal
+//! - `lemma_sqrt_ratio_i_cases_unique` — `spec_sqrt_ratio_i_cases` determines the result
+//! - `lemma_spec_sqrt_ratio_i_eq` — any result satisfying the cases is `spec_sqrt_ratio_i(u, v)`
 //!
 //! ## Dependencies
 //!
@@ -15,7 +21,7 @@
 //! ## Lemma Dependency Graph
 //!
 //! ```text
-//! sqrt_m1_lemmas::axiom_sqrt_m1_squared ──► sqrt_m1_lemmas::lemma_multiply_by_i_flips_sign
+//! sqrt_m1_lemmas::lemma_sqrt_m1_squared ──► sqrt_m1_lemmas::lemma_multiply_by_i_flips_sign
 //!                                                           │
 //!                                                           ▼
 //! sqrt_m1_lemmas::axiom_sqrt_m1_not_square ──┐    lemma_flipped_sign_becomes_correct ◄── field.rs
@@ -29,6 +35,7 @@ use crate::lemmas::common_lemmas::div_mod_lemmas::*;
 use crate::lemmas::common_lemmas::mul_lemmas::*;
 use crate::lemmas::common_lemmas::number_theory_lemmas::*;
 use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::lemmas::field_lemmas::pow_p58_lemma::*;
 use crate::lemmas::field_lemmas::sqrt_m1_lemmas::*;
 use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
@@ -308,7 +315,7 @@ pub proof fn lemma_no_square_root_when_times_i(u: nat, v: nat, r: nat)
             assert(i != 0) by {
                 if i == 0 {
                     assert((i * i) % the_p == 0);
-                    axiom_sqrt_m1_squared();
+                    lemma_sqrt_m1_squared();
                     assert((i * i) % the_p == (the_p - 1) as nat);
                     // Contradiction: (i*i) % p is both 0 and p-1
                     assert(false);
@@ -339,7 +346,7 @@ pub proof fn lemma_no_square_root_when_times_i(u: nat, v: nat, r: nat)
     // i ≠ 0
     assert(i != 0) by {
         if i == 0 {
-            axiom_sqrt_m1_squared();
+            lemma_sqrt_m1_squared();
             assert(math_field_square(0) == 0);
             assert(math_field_neg(1nat) != 0);
             assert(false);
@@ -469,13 +476,13 @@ pub proof fn lemma_no_square_root_when_times_i(u: nat, v: nat, r: nat)
 /// Mathematical proof (using reviewer's simplified derivation):
 ///   Precondition: v·r² ≡ -u (mod p)
 ///
-///   i² = -1                             [axiom_sqrt_m1_squared]
+///   i² = -1                             [lemma_sqrt_m1_squared]
 ///   v·r²·i² = -u·i²                     [multiply both sides by i²]
 ///   v·(r·i)² = -u·(-1)                  [regroup left, substitute i² = -1 on right]
 ///   v·(r·i)² = u                        [double negation: (-1)·(-u) = u]  ✓
 ///
 /// The proof uses:
-/// 1. axiom_sqrt_m1_squared: i² = -1 (mod p)
+/// 1. lemma_sqrt_m1_squared: i² = -1 (mod p)
 /// 2. lemma_double_negation: (-1)·(-a) = a
 ///
 /// NOTE: For the case v·r² = -u·i, simply call:
@@ -500,7 +507,7 @@ pub proof fn lemma_flipped_sign_becomes_correct(u: nat, v: nat, r: nat)
     let r_prime = math_field_mul(r, i);  // = (r * i) % p
 
     // === Key fact: i² = -1 (mod p), i.e., i² % p = p - 1 ===
-    axiom_sqrt_m1_squared();
+    lemma_sqrt_m1_squared();
     let neg_one = math_field_neg(1nat);
     assert(i2 % pn == neg_one) by {
         lemma_small_mod(1nat, pn);
@@ -630,4 +637,551 @@ pub proof fn lemma_flipped_sign_becomes_correct(u: nat, v: nat, r: nat)
     }
 }
 
+//=============================================================================
+// Case analysis for sqrt_ratio_i
+//
+// For the candidate r = (uv³)(uv⁷)^((p-5)/8) we have
+//   check = v·r² = u · (uv⁷)^((p-1)/4)
+// and (uv⁷)^((p-1)/4) is a fourth root of unity, so check ∈ {u, -u, u·i, -u·i}.
+// The algorithm multiplies r by i when check ∈ {-u, -u·i}, then negates r if odd.
+//=============================================================================
+/// Lemma: u multiplied by the fourth roots of unity 1, -1, -i
+///
+/// ```text
+/// u·1 = u,   u·(-1) = -u,   u·(-i) = (-u)·i
+/// ```
+pub proof fn lemma_mul_by_fourth_roots(u: nat)
+    requires
+        u < p(),
+    ensures
+        math_field_mul(u, 1) == u,
+        math_field_mul(u, math_field_neg(1)) == math_field_neg(u),
+        math_field_mul(u, math_field_neg(spec_sqrt_m1())) == math_field_mul(
+            math_field_neg(u),
+            spec_sqrt_m1(),
+        ),
+{
+    let i = spec_sqrt_m1();
+    let neg_one = math_field_neg(1);
+    p_gt_2();
+
+    assert(math_field_mul(u, 1) == u) by {
+        lemma_small_mod(u, p());
+    };
+
+    // u·(-1) = (-1)·u = -u
+    assert(math_field_mul(u, neg_one) == math_field_neg(u)) by {
+        lemma_field_mul_comm(u, neg_one);
+        lemma_neg_one_times_is_neg(u);
+    };
+
+    // u·(-i) = u·((-1)·i) = (u·(-1))·i = (-u)·i
+    assert(math_field_mul(u, math_field_neg(i)) == math_field_mul(math_field_neg(u), i)) by {
+        lemma_neg_one_times_is_neg(i);
+        lemma_field_mul_assoc(u, neg_one, i);
+    };
+}
+
+/// Lemma: For u ≠ 0 the four possible check values u, -u, u·i, -u·i are pairwise distinct
+///
+/// Each value is u times a distinct fourth root of unity, and multiplication by u ≠ 0
+/// is injective.
+pub proof fn lemma_sqrt_ratio_check_values_distinct(u: nat)
+    requires
+        u < p(),
+        u != 0,
+    ensures
+        u != math_field_neg(u),
+        u != math_field_mul(u, spec_sqrt_m1()),
+        u != math_field_mul(math_field_neg(u), spec_sqrt_m1()),
+        math_field_neg(u) != math_field_mul(u, spec_sqrt_m1()),
+        math_field_neg(u) != math_field_mul(math_field_neg(u), spec_sqrt_m1()),
+        math_field_mul(u, spec_sqrt_m1()) != math_field_mul(math_field_neg(u), spec_sqrt_m1()),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    let neg_one = math_field_neg(1);
+    let neg_i = math_field_neg(i);
+    p_gt_2();
+    lemma_small_mod(u, p);
+
+    lemma_mul_by_fourth_roots(u);
+    lemma_fourth_roots_of_unity_distinct();
+
+    assert(1 < p && neg_one < p && i < p && neg_i < p);
+
+    // u·a = u·b ⟹ a = b, so distinct roots give distinct values
+    if u == math_field_neg(u) {
+        lemma_field_mul_left_cancel(u, 1, neg_one);
+    }
+    if u == math_field_mul(u, i) {
+        lemma_field_mul_left_cancel(u, 1, i);
+    }
+    if u == math_field_mul(math_field_neg(u), i) {
+        lemma_field_mul_left_cancel(u, 1, neg_i);
+    }
+    if math_field_neg(u) == math_field_mul(u, i) {
+        lemma_field_mul_left_cancel(u, neg_one, i);
+    }
+    if math_field_neg(u) == math_field_mul(math_field_neg(u), i) {
+        lemma_field_mul_left_cancel(u, neg_one, neg_i);
+    }
+    if math_field_mul(u, i) == math_field_mul(math_field_neg(u), i) {
+        lemma_field_mul_left_cancel(u, i, neg_i);
+    }
+}
+
+/// Lemma: The sqrt_ratio_i candidate satisfies v·r² ∈ {u, -u, u·i, -u·i}
+///
+/// ## Mathematical Proof
+/// ```text
+/// If u = 0 or v = 0 then u·v³ = 0, so r = 0.
+///
+/// Otherwise let x = u·v⁷ ≠ 0, e = (p-5)/8 = 2^252 - 3 and β = x^e, so r = u·v³·β.
+///   v·r² = u²·v⁷·β² = u·x·x^(2e) = u·x^(2e+1) = u·x^((p-1)/4)
+/// With y = x^((p-1)/4):  y⁴ = x^(p-1) = 1       [Fermat]
+///   so y ∈ {1, -1, i, -i}                       [lemma_fourth_roots_of_unity]
+///   and v·r² = u·y ∈ {u, -u, u·i, -u·i}
+/// ```
+pub proof fn lemma_sqrt_ratio_i_candidate_check(u: nat, v: nat)
+    requires
+        u < p(),
+        v < p(),
+    ensures
+        spec_sqrt_ratio_i_candidate(u, v) < p(),
+        (u == 0 || v == 0) ==> spec_sqrt_ratio_i_candidate(u, v) == 0,
+        (u != 0 && v != 0) ==> ({
+            let check = math_field_mul(
+                v,
+                math_field_square(spec_sqrt_ratio_i_candidate(u, v)),
+            );
+            check == u || check == math_field_neg(u) || check == math_field_mul(u, spec_sqrt_m1())
+                || check == math_field_mul(math_field_neg(u), spec_sqrt_m1())
+        }),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    p_gt_2();
+
+    let v2 = math_field_square(v);
+    let v3 = math_field_mul(v2, v);
+    let v3_sq = math_field_square(v3);
+    let v7 = math_field_mul(v3_sq, v);
+    let x = math_field_mul(u, v7);
+    let beta = math_field_pow_p58(x);
+    let uv3 = math_field_mul(u, v3);
+    let r = math_field_mul(uv3, beta);
+    assert(r == spec_sqrt_ratio_i_candidate(u, v));
+    assert(r < p) by {
+        lemma_mod_bound((uv3 * beta) as int, p as int);
+    };
+
+    if u == 0 || v == 0 {
+        // u·v³ = 0, hence r = 0
+        lemma_small_mod(0, p);
+        if u == 0 {
+            lemma_field_mul_zero_left(u, v3);
+        } else {
+            lemma_field_mul_zero_left(v, v);
+            lemma_field_mul_zero_left(v2, v);
+            lemma_field_mul_zero_right(u, v3);
+        }
+        lemma_field_mul_zero_left(uv3, beta);
+    } else {
+        lemma_small_mod(u, p);
+        lemma_small_mod(v, p);
+
+        // ===== Step 1: x = u·v⁷ ≠ 0 =====
+        assert(x % p != 0 && x != 0) by {
+            lemma_field_mul_nonzero(v, v);
+            lemma_field_mul_nonzero(v2, v);
+            lemma_field_mul_nonzero(v3, v3);
+            lemma_field_mul_nonzero(v3_sq, v);
+            lemma_field_mul_nonzero(u, v7);
+        };
+
+        // ===== Step 2: v·r² = u·(x·β²) =====
+        let beta_sq = math_field_square(beta);
+        let check = math_field_mul(v, math_field_square(r));
+        assert(check == math_field_mul(u, math_field_mul(x, beta_sq))) by {
+            let u2 = math_field_square(u);
+            // r² = (u·v³)²·β² = u²·(v³)²·β²
+            lemma_product_of_squares_eq_square_of_product(uv3, beta);
+            lemma_product_of_squares_eq_square_of_product(u, v3);
+            let a = math_field_mul(u2, v3_sq);
+            // v·(a·β²) = (v·a)·β²
+            lemma_field_mul_assoc(v, a, beta_sq);
+            // u·(x·β²) = (u·x)·β²
+            lemma_field_mul_assoc(u, x, beta_sq);
+            // u·x = u·(u·(v³)²·v) = u²·((v³)²·v) = u²·(v·(v³)²) = (u²·v)·(v³)² = (v·u²)·(v³)² = v·a
+            assert(math_field_mul(u, x) == math_field_mul(v, a)) by {
+                lemma_field_mul_assoc(u, u, v7);
+                lemma_field_mul_comm(v3_sq, v);
+                lemma_field_mul_assoc(u2, v, v3_sq);
+                lemma_field_mul_comm(u2, v);
+                lemma_field_mul_assoc(v, u2, v3_sq);
+            };
+        };
+
+        // ===== Steps 3-4: y = x·β² = x^((p-1)/4) satisfies y⁴ = 1 =====
+        assert(x < p) by {
+            lemma_mod_bound((u * v7) as int, p as int);
+        };
+        lemma_pow_p58_fourth_root(x);
+        let y = math_field_mul(x, beta_sq);
+
+        // ===== Step 5: y is a fourth root of unity, so check = u·y is one of the four values =====
+        lemma_fourth_roots_of_unity(y);
+        lemma_mul_by_fourth_roots(u);
+        assert(check == math_field_mul(u, y));
+    }
+}
+
+/// Lemma: (r·r·v) % p and (r²·v) are both the field value v·r²
+pub proof fn lemma_sqrt_ratio_check_form(v: nat, r: nat)
+    ensures
+        (r * r * v) % p() == math_field_mul(v, math_field_square(r)),
+        math_field_mul(math_field_square(r), v) == math_field_mul(v, math_field_square(r)),
+{
+    p_gt_2();
+    lemma_mul_mod_noop_left((r * r) as int, v as int, p() as int);
+    lemma_field_mul_comm(math_field_square(r), v);
+}
+
+/// Lemma: The result of sqrt_ratio_i satisfies the four-case specification
+///
+/// Takes the values computed by sqrt_ratio_i:
+/// - `r` — the candidate (uv³)(uv⁷)^((p-5)/8)
+/// - `check` — v·r²
+/// - `r_adj` — r·i if check ∈ {-u, -u·i}, else r
+/// - `r_out` — -r_adj if r_adj is odd, else r_adj
+/// - `success` — check ∈ {u, -u}
+///
+/// ## Mathematical Proof
+/// ```text
+/// u = 0:          r = 0, check = 0 = u, so success and r_out = 0
+/// v = 0, u ≠ 0:   r = 0, check = 0 ∉ {u, -u}, so failure and r_out = 0
+/// u, v ≠ 0:       check ∈ {u, -u, u·i, -u·i}, pairwise distinct
+///   check = u:      success, v·r_adj² = u
+///   check = -u:     success, v·(r·i)² = -v·r² = u        [lemma_flipped_sign_becomes_correct]
+///   check = u·i:    failure, v·r_adj² = i·u
+///   check = -u·i:   failure, v·(r·i)² = -v·r² = i·u
+///   negating r_adj does not change r², and makes r_out even (p is odd)
+///   success ⟹ r_out witnesses that u/v is square
+///   failure ⟹ u/v is nonsquare                          [lemma_no_square_root_when_times_i]
+/// ```
+pub proof fn lemma_sqrt_ratio_i_cases(
+    u: nat,
+    v: nat,
+    r: nat,
+    check: nat,
+    r_adj: nat,
+    r_out: nat,
+    success: bool,
+)
+    requires
+        u < p(),
+        v < p(),
+        r == spec_sqrt_ratio_i_candidate(u, v),
+        check == math_field_mul(v, math_field_square(r)),
+        r_adj == if check == math_field_neg(u) || check == math_field_mul(
+            math_field_neg(u),
+            spec_sqrt_m1(),
+        ) {
+            math_field_mul(spec_sqrt_m1(), r)
+        } else {
+            r
+        },
+        r_out == if math_field_is_negative(r_adj) {
+            math_field_neg(r_adj)
+        } else {
+            r_adj
+        },
+        success == (check == u || check == math_field_neg(u)),
+    ensures
+        spec_sqrt_ratio_i_cases(u, v, success, r_out),
+        spec_sqrt_ratio_i_post(u, v, success, r_out),
+{
+    let p = p();
+    let i = spec_sqrt_m1();
+    let w = math_field_mul(u, math_field_inv(v));
+    p_gt_2();
+    lemma_small_mod(0, p);
+    lemma_small_mod(u, p);
+    lemma_small_mod(v, p);
+
+    lemma_sqrt_ratio_i_candidate_check(u, v);
+    assert(r_adj < p) by {
+        lemma_mod_bound((i * r) as int, p as int);
+    };
+    assert(math_field_is_negative(r_adj) == (r_adj % 2 == 1)) by {
+        lemma_small_mod(r_adj, p);
+    };
+
+    // ===== Sign normalization: r_out is reduced, even, and r_out² = r_adj² =====
+    assert(r_out < p && r_out % 2 == 0 && math_field_square(r_out) == math_field_square(r_adj))
+        by {
+        if r_adj % 2 == 1 {
+            // r_adj is odd, so r_adj ≠ 0 and -r_adj = p - r_adj, which is even since p is odd
+            lemma_p_is_odd();
+            lemma_small_mod(r_adj, p);
+            lemma_small_mod((p - r_adj) as nat, p);
+            lemma_sub_mod_noop(p as int, r_adj as int, 2);
+            lemma_neg_square_eq(r_adj);
+        }
+    };
+    lemma_sqrt_ratio_check_form(v, r_out);
+    lemma_sqrt_ratio_check_form(v, r_adj);
+
+    if u == 0 {
+        // r = 0, so check = 0 = u and r_adj = 0
+        lemma_field_mul_zero_right(v, math_field_square(r));
+        lemma_field_mul_zero_right(i, r);
+        assert(success && r_out == 0);
+        assert((r_out * r_out * v) % p == 0);
+    } else if v == 0 {
+        // r = 0, so check = 0, which is neither u nor -u = p - u
+        lemma_field_mul_zero_left(v, math_field_square(r));
+        lemma_field_mul_zero_right(i, r);
+        assert(math_field_neg(u) == p - u) by {
+            lemma_small_mod((p - u) as nat, p);
+        };
+        assert(!success && r_out == 0);
+    } else {
+        lemma_sqrt_ratio_check_values_distinct(u);
+        let neg_u = math_field_neg(u);
+        assert((neg_u as int) == (p as int - (u % p) as int) % p as int);
+
+        if check == u {
+            // r_adj = r and v·r² = u
+            assert(r_adj == r);
+            assert(success && math_is_sqrt_ratio(u, v, r_out));
+        } else if check == neg_u {
+            // r_adj = r·i and v·(r·i)² = u
+            assert((v * r * r) % p == check) by {
+                lemma_mul_is_associative(v as int, r as int, r as int);
+                lemma_mul_mod_noop_right(v as int, (r * r) as int, p as int);
+            };
+            lemma_flipped_sign_becomes_correct(u, v, r);
+            lemma_field_mul_comm(r, i);
+            assert(success && math_is_sqrt_ratio(u, v, r_out));
+        } else if check == math_field_mul(u, i) {
+            // r_adj = r and v·r² = i·u
+            assert(r_adj == r);
+            lemma_mul_is_commutative(u as int, i as int);
+            assert(!success && math_is_sqrt_ratio_times_i(u, v, r_out));
+        } else {
+            // check = (-u)·i = -(u·i), so r_adj = r·i and v·(r·i)² = u·i
+            let ui = math_field_mul(u, i);
+            assert(check == math_field_mul(neg_u, i));
+            assert(check == math_field_neg(ui)) by {
+                let neg_one = math_field_neg(1);
+                lemma_neg_one_times_is_neg(u);
+                lemma_field_mul_assoc(neg_one, u, i);
+                lemma_neg_one_times_is_neg(ui);
+            };
+            assert((v * r * r) % p == ((p as int - ((u * i) % p) as int) % p as int) as nat) by {
+                lemma_mul_is_associative(v as int, r as int, r as int);
+                lemma_mul_mod_noop_right(v as int, (r * r) as int, p as int);
+                lemma_mod_twice((u * i) as int, p as int);
+            };
+            lemma_flipped_sign_becomes_correct(u * i, v, r);
+            lemma_field_mul_comm(r, i);
+            lemma_mul_is_commutative(u as int, i as int);
+            assert(!success && math_is_sqrt_ratio_times_i(u, v, r_out));
+        }
+
+        // ===== Squareness of u/v =====
+        let inv_v = math_field_inv(v);
+        assert(math_field_mul(v, inv_v) == 1 && math_field_mul(inv_v, v) == 1) by {
+            field_inv_property(v);
+            lemma_small_mod(v, p);
+            lemma_field_mul_comm(v, inv_v);
+        };
+        assert(w < p) by {
+            lemma_mod_bound((u * inv_v) as int, p as int);
+        };
+
+        if success {
+            // u = r_out²·v, so u/v = r_out²
+            let ro_sq = math_field_square(r_out);
+            assert(w == ro_sq) by {
+                lemma_field_mul_assoc(ro_sq, v, inv_v);
+                lemma_mod_bound((r_out * r_out) as int, p as int);
+                lemma_small_mod(ro_sq, p);
+            };
+            lemma_small_mod(w, p);
+            assert((r_out * r_out) % p == w % p);
+            assert(math_is_square(w));
+        } else {
+            // r_out² · v = i·u, so no y has y² · v = u
+            if math_is_square(w) {
+                let y = choose|y: nat| (#[trigger] (y * y) % p) == (w % p);
+                let y0 = y % p;
+                assert(y0 < p) by {
+                    lemma_mod_bound(y as int, p as int);
+                };
+                assert(math_field_square(y0) == w) by {
+                    lemma_mul_mod_noop(y as int, y as int, p as int);
+                    lemma_small_mod(w, p);
+                };
+                assert(math_field_mul(math_field_square(y0), v) == u) by {
+                    lemma_field_mul_assoc(u, inv_v, v);
+                };
+                assert(r_out < p && math_field_mul(math_field_square(r_out), v) == (
+                spec_sqrt_m1() * u) % p);
+                lemma_no_square_root_when_times_i(u, v, y0);
+                assert(false);
+            }
+        }
+    }
+}
+
+/// Lemma: With denominator 1, sqrt_ratio_i is a square root
+///
+/// ## Mathematical Proof
+/// ```text
+/// inv(1) = 1, so u·inv(v) = u and the "u/v is square" case is "u is square".
+/// In that case r²·1 = u; otherwise the failure flag is returned.
+/// u = 0 is square (0² = 0) and the result is r = 0.
+/// r < p and r even, so r is non-negative.
+/// ```
+pub proof fn lemma_sqrt_ratio_i_one_denominator(u: nat, success: bool, r: nat)
+    requires
+        u < p(),
+        spec_sqrt_ratio_i_cases(u, 1, success, r),
+    ensures
+        success == math_is_square(u),
+        success ==> math_field_square(r) == u,
+        r < p(),
+        !math_field_is_negative(r),
+{
+    p_gt_2();
+    lemma_small_mod(1, p());
+    lemma_small_mod(u, p());
+    lemma_small_mod(r, p());
+    lemma_field_inv_one();
+    assert(math_field_mul(u, math_field_inv(1)) == u);
+    assert(r * r * 1 == r * r);
+
+    if u == 0 {
+        assert(math_is_square(0)) by {
+            lemma_small_mod(0, p());
+            assert((0nat * 0nat) % p() == 0nat % p());
+        };
+    }
+}
+
+/// Lemma: two non-negative square roots of the same value are equal
+///
+/// ## Mathematical Proof
+/// ```text
+/// r1² = r2²  ⟹  (r1 - r2)·(r1 + r2) ≡ 0  ⟹  p | r1 - r2  or  p | r1 + r2   [p prime]
+/// |r1 - r2| < p, so the first case is r1 = r2.
+/// 0 < r1 + r2 < 2p is even and p is odd, so r1 + r2 ≠ p and the second case needs r1 + r2 = 0.
+/// ```
+pub proof fn lemma_nonnegative_square_root_unique(r1: nat, r2: nat)
+    requires
+        r1 < p(),
+        r2 < p(),
+        r1 % 2 == 0,
+        r2 % 2 == 0,
+        math_field_square(r1) == math_field_square(r2),
+    ensures
+        r1 == r2,
+{
+    let p = p();
+    p_gt_2();
+    lemma_p_is_odd();
+    axiom_p_is_prime();
+    if r1 != r2 {
+        let (a, b) = if r1 > r2 {
+            (r1, r2)
+        } else {
+            (r2, r1)
+        };
+        let diff = (a - b) as nat;
+        let sum = a + b;
+        // (a - b)·(a + b) = a² - b² ≡ 0
+        assert((diff * sum) % p == 0) by {
+            assert(diff * sum + b * b == a * a) by (nonlinear_arith)
+                requires
+                    diff == a - b,
+                    sum == a + b,
+            ;
+            lemma_mod_equivalence((a * a) as int, (b * b) as int, p as int);
+        };
+        lemma_euclid_prime(diff, sum, p);
+        // 0 < a - b < p
+        lemma_small_mod(diff, p);
+        // 0 < a + b < 2p and a + b is even, so a + b is not a multiple of p
+        if sum < p {
+            lemma_small_mod(sum, p);
+        } else {
+            lemma_mod_sub_multiples_vanish(sum as int, p as int);
+            lemma_small_mod((sum - p) as nat, p);
+        }
+    }
+}
+
+/// Lemma: `spec_sqrt_ratio_i_cases` determines the flag and the root
+///
+/// ## Mathematical Proof
+/// ```text
+/// u = 0 or v = 0: both results are fixed by the first two cases
+/// otherwise: both flags say whether u/v is a square, and both roots satisfy
+///            r²·v = u (or i·u); cancelling v ≠ 0 gives r1² = r2²,
+///            so the non-negative roots agree
+/// ```
+pub proof fn lemma_sqrt_ratio_i_cases_unique(
+    u: nat,
+    v: nat,
+    success1: bool,
+    r1: nat,
+    success2: bool,
+    r2: nat,
+)
+    requires
+        v < p(),
+        spec_sqrt_ratio_i_cases(u, v, success1, r1),
+        spec_sqrt_ratio_i_cases(u, v, success2, r2),
+    ensures
+        success1 == success2,
+        r1 == r2,
+{
+    let p = p();
+    p_gt_2();
+    if u != 0 && v != 0 {
+        // r1²·v ≡ r2²·v
+        assert(((r1 * r1) * v) % p == ((r2 * r2) * v) % p);
+        lemma_small_mod(v, p);
+        // Cancel v: r1² = (r1²·v)·inv(v) = (r2²·v)·inv(v) = r2²
+        assert(math_field_square(r1) == math_field_square(r2)) by {
+            let (c1, c2) = ((r1 * r1) * v, (r2 * r2) * v);
+            lemma_solve_for_left_factor(r1 * r1, v, c1);
+            lemma_solve_for_left_factor(r2 * r2, v, c2);
+            lemma_mul_mod_noop_left(c1 as int, math_field_inv(v) as int, p as int);
+            lemma_mul_mod_noop_left(c2 as int, math_field_inv(v) as int, p as int);
+        };
+        lemma_nonnegative_square_root_unique(r1, r2);
+    }
+}
+
+/// Lemma: a result satisfying `spec_sqrt_ratio_i_cases` is `spec_sqrt_ratio_i(u, v)`
+///
+/// The `choose` in `spec_sqrt_ratio_i` has a witness, and by
+/// `lemma_sqrt_ratio_i_cases_unique` it is the only one.
+pub proof fn lemma_spec_sqrt_ratio_i_eq(u: nat, v: nat, success: bool, r: nat)
+    requires
+        v < p(),
+        spec_sqrt_ratio_i_cases(u, v, success, r),
+    ensures
+        spec_sqrt_ratio_i(u, v) == (success, r),
+{
+    let witness = (success, r);
+    assert(spec_sqrt_ratio_i_cases(u, v, witness.0, witness.1));
+    let chosen = spec_sqrt_ratio_i(u, v);
+    lemma_sqrt_ratio_i_cases_unique(u, v, success, r, chosen.0, chosen.1);
+}
+
 } // verus!
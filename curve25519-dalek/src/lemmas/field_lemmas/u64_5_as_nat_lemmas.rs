@@ -9,6 +9,7 @@ use super::super::common_lemmas::mul_lemmas::*;
 use super::super::common_lemmas::pow_lemmas::*;
 
 use crate::backend::serial::u64::field::FieldElement51;
+use crate::specs::core_specs::*;
 use crate::specs::field_specs::*;
 use crate::specs::field_specs_u64::*;
 
@@ -513,6 +514,244 @@ pub proof fn lemma_u64_5_as_nat_squared(v: [u64; 5])
     assert(c0 == (v[0] * v[0] + 19 * (2 * (v[2] * v[3]) + 2 * (v[1] * v[4]))));
 }
 
+// Explicit and mod-p identities for the product of two u64_5_as_nat conversions
+#[verusfmt::skip]
+pub proof fn lemma_u64_5_as_nat_product(a: [u64; 5], b: [u64; 5])
+    ensures
+        u64_5_as_nat(a) * u64_5_as_nat(b) ==
+            pow2(8 * 51) * (a[4] * b[4]) +
+            pow2(7 * 51) * (a[3] * b[4] + a[4] * b[3]) +
+            pow2(6 * 51) * (a[2] * b[4] + a[3] * b[3] + a[4] * b[2]) +
+            pow2(5 * 51) * (a[1] * b[4] + a[2] * b[3] + a[3] * b[2] + a[4] * b[1]) +
+            pow2(4 * 51) * (a[0] * b[4] + a[1] * b[3] + a[2] * b[2] + a[3] * b[1] + a[4] * b[0]) +
+            pow2(3 * 51) * (a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0]) +
+            pow2(2 * 51) * (a[0] * b[2] + a[1] * b[1] + a[2] * b[0]) +
+            pow2(1 * 51) * (a[0] * b[1] + a[1] * b[0]) +
+                           (a[0] * b[0]),
+        // and the mod equality
+        (u64_5_as_nat(a) * u64_5_as_nat(b)) % p() ==
+            (
+                pow2(4 * 51) * (a[0] * b[4] + a[1] * b[3] + a[2] * b[2] + a[3] * b[1] + a[4] * b[0]) +
+                pow2(3 * 51) * (a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0] + 19 * (a[4] * b[4])) +
+                pow2(2 * 51) * (a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + 19 * (a[3] * b[4] + a[4] * b[3])) +
+                pow2(1 * 51) * (a[0] * b[1] + a[1] * b[0] + 19 * (a[2] * b[4] + a[3] * b[3] + a[4] * b[2])) +
+                               (a[0] * b[0] + 19 * (a[1] * b[4] + a[2] * b[3] + a[3] * b[2] + a[4] * b[1]))
+            ) as nat % p(),
+{
+    let a0 = a[0];
+    let a1 = a[1];
+    let a2 = a[2];
+    let a3 = a[3];
+    let a4 = a[4];
+
+    let b0 = b[0];
+    let b1 = b[1];
+    let b2 = b[2];
+    let b3 = b[3];
+    let b4 = b[4];
+
+    let s1 = pow2(1 * 51);
+    let s2 = pow2(2 * 51);
+    let s3 = pow2(3 * 51);
+    let s4 = pow2(4 * 51);
+    let s5 = pow2(5 * 51);
+    let s6 = pow2(6 * 51);
+    let s7 = pow2(7 * 51);
+    let s8 = pow2(8 * 51);
+
+    let vb = u64_5_as_nat(b);
+
+    assert(s1 * s1 == s2) by { lemma_pow2_adds(51, 51) }
+    assert(s1 * s2 == s2 * s1 == s3) by { lemma_pow2_adds(51, 102) }
+    assert(s1 * s3 == s3 * s1 == s4) by { lemma_pow2_adds(51, 153) }
+    assert(s1 * s4 == s4 * s1 == s5) by { lemma_pow2_adds(51, 204) }
+    assert(s2 * s2 == s4) by { lemma_pow2_adds(102, 102) }
+    assert(s2 * s3 == s3 * s2 == s5) by { lemma_pow2_adds(102, 153) }
+    assert(s2 * s4 == s4 * s2 == s6) by { lemma_pow2_adds(102, 204) }
+    assert(s3 * s3 == s6) by { lemma_pow2_adds(153, 153) }
+    assert(s3 * s4 == s4 * s3 == s7) by { lemma_pow2_adds(153, 204) }
+    assert(s4 * s4 == s8) by { lemma_pow2_adds(204, 204) }
+
+    assert(u64_5_as_nat(a) * vb == a0 * vb + (s1 * a1) * vb + (s2 * a2) * vb + (s3 * a3) * vb
+        + (s4 * a4) * vb) by {
+        // (x1 + x2 + x3 + x4 + x5) * n == x1 * n + x2 * n + x3 * n + x4 * n + x5 * n
+        lemma_mul_distributive_5_terms(vb as int, a0 as int, s1 * a1, s2 * a2, s3 * a3, s4 * a4);
+    }
+
+    // Unlike squaring, each row of the product is a different limb of a times all of b
+    assert(a0 * vb == s4 * (a0 * b4) + s3 * (a0 * b3) + s2 * (a0 * b2) + s1 * (a0 * b1) + a0 * b0)
+        by {
+        lemma_mul_distributive_5_terms(a0 as int, b0 as int, s1 * b1, s2 * b2, s3 * b3, s4 * b4);
+        lemma_mul_is_associative(a0 as int, b1 as int, s1 as int);
+        lemma_mul_is_associative(a0 as int, b2 as int, s2 as int);
+        lemma_mul_is_associative(a0 as int, b3 as int, s3 as int);
+        lemma_mul_is_associative(a0 as int, b4 as int, s4 as int);
+    }
+
+    assert((s1 * a1) * vb == s5 * (a1 * b4) + s4 * (a1 * b3) + s3 * (a1 * b2) + s2 * (a1 * b1)
+        + s1 * (a1 * b0)) by {
+        lemma_mul_si_vi_and_reorder(
+            s1 as int, a1 as int, b0 as int, s1 as int, b1 as int, s2 as int, b2 as int, s3 as int,
+            b3 as int, s4 as int, b4 as int,
+        )
+    }
+
+    assert((s2 * a2) * vb == s6 * (a2 * b4) + s5 * (a2 * b3) + s4 * (a2 * b2) + s3 * (a2 * b1)
+        + s2 * (a2 * b0)) by {
+        lemma_mul_si_vi_and_reorder(
+            s2 as int, a2 as int, b0 as int, s1 as int, b1 as int, s2 as int, b2 as int, s3 as int,
+            b3 as int, s4 as int, b4 as int,
+        )
+    }
+
+    assert((s3 * a3) * vb == s7 * (a3 * b4) + s6 * (a3 * b3) + s5 * (a3 * b2) + s4 * (a3 * b1)
+        + s3 * (a3 * b0)) by {
+        lemma_mul_si_vi_and_reorder(
+            s3 as int, a3 as int, b0 as int, s1 as int, b1 as int, s2 as int, b2 as int, s3 as int,
+            b3 as int, s4 as int, b4 as int,
+        )
+    }
+
+    assert((s4 * a4) * vb == s8 * (a4 * b4) + s7 * (a4 * b3) + s6 * (a4 * b2) + s5 * (a4 * b1)
+        + s4 * (a4 * b0)) by {
+        lemma_mul_si_vi_and_reorder(
+            s4 as int, a4 as int, b0 as int, s1 as int, b1 as int, s2 as int, b2 as int, s3 as int,
+            b3 as int, s4 as int, b4 as int,
+        )
+    }
+
+    // we now mash them all together, collecting the terms of each power of s
+    assert(u64_5_as_nat(a) * vb == s8 * (a4 * b4) + s7 * (a3 * b4 + a4 * b3) + s6 * (a2 * b4 + a3
+        * b3 + a4 * b2) + s5 * (a1 * b4 + a2 * b3 + a3 * b2 + a4 * b1) + s4 * (a0 * b4 + a1 * b3 + a2
+        * b2 + a3 * b1 + a4 * b0) + s3 * (a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0) + s2 * (a0 * b2 + a1
+        * b1 + a2 * b0) + s1 * (a0 * b1 + a1 * b0) + (a0 * b0)) by {
+        lemma_mul_is_distributive_add(s1 as int, a0 * b1, a1 * b0);
+        lemma_mul_distributive_3_terms(s2 as int, a0 * b2, a1 * b1, a2 * b0);
+        lemma_mul_distributive_4_terms(s3 as int, a0 * b3, a1 * b2, a2 * b1, a3 * b0);
+        lemma_mul_distributive_5_terms(s4 as int, a0 * b4, a1 * b3, a2 * b2, a3 * b1, a4 * b0);
+        lemma_mul_distributive_4_terms(s5 as int, a1 * b4, a2 * b3, a3 * b2, a4 * b1);
+        lemma_mul_distributive_3_terms(s6 as int, a2 * b4, a3 * b3, a4 * b2);
+        lemma_mul_is_distributive_add(s7 as int, a3 * b4, a4 * b3);
+    }
+
+    // This is the explicit version, now we can take everything mod p
+
+    // p well defined
+    pow255_gt_19();
+
+    // By definition, p = s^5 - 19
+    // equivalently,
+    // s^5 = (p + 19)
+    // s^6 = s * (p + 19)
+    // s^7 = s^2 * (p + 19)
+    // s^8 = s^3 * (p + 19)
+    assert(s5 == (p() + 19));
+
+    // we pack together terms to slim down expressions;
+
+    let c0_base = a0 * b0;
+    let c0_x19 = a1 * b4 + a2 * b3 + a3 * b2 + a4 * b1;
+    let c0 = c0_base + 19 * c0_x19;
+
+    let c1_base = a0 * b1 + a1 * b0;
+    let c1_x19 = a2 * b4 + a3 * b3 + a4 * b2;
+    let c1 = c1_base + 19 * c1_x19;
+
+    let c2_base = a0 * b2 + a1 * b1 + a2 * b0;
+    let c2_x19 = a3 * b4 + a4 * b3;
+    let c2 = c2_base + 19 * c2_x19;
+
+    let c3_base = a0 * b3 + a1 * b2 + a2 * b1 + a3 * b0;
+    let c3_x19 = a4 * b4;
+    let c3 = c3_base + 19 * c3_x19;
+
+    let c4 = a0 * b4 + a1 * b3 + a2 * b2 + a3 * b1 + a4 * b0;
+
+    // group in preparation for the substitution
+    assert(u64_5_as_nat(a) * vb == s4 * c4 + s3 * (s5 * c3_x19 + c3_base) + s2 * (s5 * c2_x19
+        + c2_base) + s1 * (s5 * c1_x19 + c1_base) + (s5 * c0_x19 + c0_base)) by {
+        // s3 terms
+        assert(s8 * c3_x19 + s3 * c3_base == s3 * (s5 * c3_x19 + c3_base)) by {
+            assert(s8 == (s3 * s5)) by {
+                lemma_pow2_adds(3 * 51, 5 * 51);
+            }
+            lemma_mul_is_associative(s3 as int, s5 as int, c3_x19);
+            lemma_mul_is_distributive_add(s3 as int, s5 * c3_x19, c3_base)
+        }
+
+        // s2 terms
+        assert(s7 * c2_x19 + s2 * c2_base == s2 * (s5 * c2_x19 + c2_base)) by {
+            assert(s7 == (s2 * s5)) by {
+                lemma_pow2_adds(2 * 51, 5 * 51);
+            }
+            lemma_mul_is_associative(s2 as int, s5 as int, c2_x19);
+            lemma_mul_is_distributive_add(s2 as int, s5 * c2_x19, c2_base)
+        }
+
+        // s1 terms
+        assert(s6 * c1_x19 + s1 * c1_base == s1 * (s5 * c1_x19 + c1_base)) by {
+            assert(s6 == (s1 * s5)) by {
+                lemma_pow2_adds(1 * 51, 5 * 51);
+            }
+            lemma_mul_is_associative(s1 as int, s5 as int, c1_x19);
+            lemma_mul_is_distributive_add(s1 as int, s5 * c1_x19, c1_base)
+        }
+    }
+
+    // Next we use the identity s5 = p + 19
+    assert(s5 * c3_x19 + c3_base == p() * c3_x19 + c3) by {
+        lemma_mul_is_distributive_add(c3_x19 as int, p() as int, 19);
+    }
+
+    assert(s5 * c2_x19 + c2_base == p() * c2_x19 + c2) by {
+        lemma_mul_is_distributive_add(c2_x19 as int, p() as int, 19);
+    }
+
+    assert(s5 * c1_x19 + c1_base == p() * c1_x19 + c1) by {
+        lemma_mul_is_distributive_add(c1_x19 as int, p() as int, 19);
+    }
+
+    assert(s5 * c0_x19 + c0_base == p() * c0_x19 + c0) by {
+        lemma_mul_is_distributive_add(c0_x19 as int, p() as int, 19);
+    }
+
+    // in summary, we can reorder and regroup terms to get X * p() + Y
+    assert(u64_5_as_nat(a) * vb == p() * (s3 * c3_x19 + s2 * c2_x19 + s1 * c1_x19 + c0_x19) + (s4
+        * c4 + s3 * c3 + s2 * c2 + s1 * c1 + c0)) by {
+        lemma_mul_is_distributive_add(s3 as int, p() * c3_x19, c3 as int);
+        lemma_mul_is_distributive_add(s2 as int, p() * c2_x19, c2 as int);
+        lemma_mul_is_distributive_add(s1 as int, p() * c1_x19, c1 as int);
+
+        assert(s3 * (p() * c3_x19) + s2 * (p() * c2_x19) + s1 * (p() * c1_x19) + p() * c0_x19 == p()
+            * (s3 * c3_x19 + s2 * c2_x19 + s1 * c1_x19 + c0_x19)) by {
+            lemma_mul_is_associative(s3 as int, c3_x19 as int, p() as int);
+            lemma_mul_is_associative(s2 as int, c2_x19 as int, p() as int);
+            lemma_mul_is_associative(s1 as int, c1_x19 as int, p() as int);
+
+            lemma_mul_is_distributive_add(p() as int, s3 * c3_x19, s2 * c2_x19);
+            lemma_mul_is_distributive_add(p() as int, s3 * c3_x19 + s2 * c2_x19, s1 * c1_x19);
+            lemma_mul_is_distributive_add(
+                p() as int,
+                s3 * c3_x19 + s2 * c2_x19 + s1 * c1_x19,
+                c0_x19 as int,
+            );
+        }
+    }
+
+    let k = (s3 * c3_x19 + s2 * c2_x19 + s1 * c1_x19 + c0_x19);
+    let sum = (s4 * c4 + s3 * c3 + s2 * c2 + s1 * c1 + c0);
+
+    assert(u64_5_as_nat(a) * vb == k * p() + sum);
+    assert(k * p() + sum == (k as nat) * p() + (sum as nat));
+
+    // Now, we simply move to mod p
+
+    assert((u64_5_as_nat(a) * vb) % p() == ((k as nat) * p() + (sum as nat)) % p());
+    assert(((k as nat) * p() + (sum as nat)) % p() == (sum as nat) % p()) by {
+        lemma_mod_sum_factor(k as int, sum as int, p() as int);
+    }
+}
+
 pub proof fn lemma_u64_5_as_nat_k(a: [u64; 5], k: u64)
     requires
         forall|i: int| 0 <= i < 5 ==> (k * a[i]) <= u64::MAX,
@@ -564,4 +803,12 @@ pub proof fn lemma_u64_5_as_nat_k(a: [u64; 5], k: u64)
 fn main() {
 }
 
+/// `u64_5_as_nat` is the radix-2^51 instance of the backend-agnostic `u64_5_as_nat_generic_radix`
+pub proof fn lemma_u64_5_as_nat_equals_generic_radix(limbs: [u64; 5])
+    ensures
+        u64_5_as_nat(limbs) == u64_5_as_nat_generic_radix(limbs, 51),
+{
+    assert(1 * 51nat == 51 && 2 * 51nat == 102 && 3 * 51nat == 153 && 4 * 51nat == 204);
+}
+
 } // verus!
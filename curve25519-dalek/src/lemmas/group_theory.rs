@@ -0,0 +1,380 @@
+//! Abelian group laws, stated once and instantiated by the Edwards curve
+//!
+//! `AbelianGroup` collects the laws of an abelian group written additively:
+//! closure, associativity, commutativity, identity and inverses, each as a
+//! proof obligation of the implementing type. Scalar multiplication [n]a is
+//! defined once for every instance by repeated addition, and its laws are
+//! proven here from the group laws alone:
+//!
+//! ```text
+//! [m]a + [n]a = [m+n]a      [n](a + b) = [n]a + [n]b      [m]([n]a) = [m·n]a
+//! ```
+//!
+//! The affine points of the Edwards curve are an instance: the group operation
+//! is `edwards_add`, the identity is (0, 1), the inverse of (x, y) is (-x, y), and
+//! `group_scalar_mul` agrees with `edwards_scalar_mul`. The last section connects
+//! this instance to the `EdwardsPoint` operations through `edwards_point_as_affine`,
+//! so a protocol proof (Schnorr, Pedersen, ...) can reason with the laws below
+//! instead of the coordinates.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_group_identity_left`: e + a = a
+//! - `lemma_group_rearrange`: (a + b) + (c + d) = (a + c) + (b + d)
+//! - `lemma_group_scalar_mul_closed`: [n]a is a group element
+//! - `lemma_group_scalar_mul_additive`: [m]a + [n]a = [m+n]a
+//! - `lemma_group_scalar_mul_distributes`: [n](a + b) = [n]a + [n]b
+//! - `lemma_group_scalar_mul_mul`: [m]([n]a) = [m·n]a
+//! - `lemma_edwards_scalar_mul_is_group_scalar_mul`: `edwards_scalar_mul` is [n]a
+//! - `lemma_edwards_point_is_group_element`: a valid EdwardsPoint is a group element
+//! - `lemma_edwards_point_neg_is_group_inverse`: `Neg` on EdwardsPoint is the group inverse
+#![allow(unused_imports)]
+use crate::edwards::EdwardsPoint;
+use crate::lemmas::edwards_lemmas::add_lemmas::lemma_field_neg_mul;
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::lemma_negation_preserves_curve;
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
+use crate::lemmas::field_lemmas::constants_lemmas::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// The group laws
+// =============================================================================
+/// An abelian group, written additively, on the elements of `Self` satisfying
+/// `is_group_element`
+pub trait AbelianGroup: Sized {
+    /// The element belongs to the group
+    spec fn is_group_element(a: Self) -> bool;
+
+    /// a + b
+    spec fn group_op(a: Self, b: Self) -> Self;
+
+    /// The identity element e
+    spec fn group_identity() -> Self;
+
+    /// -a
+    spec fn group_inverse(a: Self) -> Self;
+
+    /// e is a group element
+    proof fn lemma_group_identity_is_element()
+        ensures
+            Self::is_group_element(Self::group_identity()),
+    ;
+
+    /// a + b is a group element
+    proof fn lemma_group_closed(a: Self, b: Self)
+        requires
+            Self::is_group_element(a),
+            Self::is_group_element(b),
+        ensures
+            Self::is_group_element(Self::group_op(a, b)),
+    ;
+
+    /// (a + b) + c = a + (b + c)
+    proof fn lemma_group_associative(a: Self, b: Self, c: Self)
+        requires
+            Self::is_group_element(a),
+            Self::is_group_element(b),
+            Self::is_group_element(c),
+        ensures
+            Self::group_op(Self::group_op(a, b), c) == Self::group_op(a, Self::group_op(b, c)),
+    ;
+
+    /// a + b = b + a
+    proof fn lemma_group_commutative(a: Self, b: Self)
+        requires
+            Self::is_group_element(a),
+            Self::is_group_element(b),
+        ensures
+            Self::group_op(a, b) == Self::group_op(b, a),
+    ;
+
+    /// a + e = a
+    proof fn lemma_group_identity(a: Self)
+        requires
+            Self::is_group_element(a),
+        ensures
+            Self::group_op(a, Self::group_identity()) == a,
+    ;
+
+    /// -a is a group element and a + (-a) = e
+    proof fn lemma_group_inverse(a: Self)
+        requires
+            Self::is_group_element(a),
+        ensures
+            Self::is_group_element(Self::group_inverse(a)),
+            Self::group_op(a, Self::group_inverse(a)) == Self::group_identity(),
+    ;
+}
+
+/// [n]a, defined like `edwards_scalar_mul` as [n]a = [n-1]a + a
+pub open spec fn group_scalar_mul<G: AbelianGroup>(a: G, n: nat) -> G
+    decreases n,
+{
+    if n == 0 {
+        G::group_identity()
+    } else {
+        G::group_op(group_scalar_mul(a, (n - 1) as nat), a)
+    }
+}
+
+// =============================================================================
+// Consequences of the group laws
+// =============================================================================
+/// Lemma: e + a = a
+pub proof fn lemma_group_identity_left<G: AbelianGroup>(a: G)
+    requires
+        G::is_group_element(a),
+    ensures
+        G::group_op(G::group_identity(), a) == a,
+{
+    G::lemma_group_identity_is_element();
+    G::lemma_group_commutative(G::group_identity(), a);
+    G::lemma_group_identity(a);
+}
+
+/// Lemma: (a + b) + (c + d) = (a + c) + (b + d)
+///
+/// ## Mathematical Proof
+/// ```text
+/// (a + b) + (c + d) = a + (b + (c + d)) = a + ((b + c) + d)
+///                   = a + ((c + b) + d) = a + (c + (b + d)) = (a + c) + (b + d)
+/// ```
+pub proof fn lemma_group_rearrange<G: AbelianGroup>(a: G, b: G, c: G, d: G)
+    requires
+        G::is_group_element(a),
+        G::is_group_element(b),
+        G::is_group_element(c),
+        G::is_group_element(d),
+    ensures
+        G::group_op(G::group_op(a, b), G::group_op(c, d)) == G::group_op(
+            G::group_op(a, c),
+            G::group_op(b, d),
+        ),
+{
+    G::lemma_group_closed(a, b);
+    G::lemma_group_closed(c, d);
+    G::lemma_group_closed(b, c);
+    G::lemma_group_closed(c, b);
+    G::lemma_group_closed(b, d);
+    G::lemma_group_associative(a, b, G::group_op(c, d));
+    G::lemma_group_associative(b, c, d);
+    G::lemma_group_commutative(b, c);
+    G::lemma_group_associative(c, b, d);
+    G::lemma_group_associative(a, c, G::group_op(b, d));
+}
+
+/// Lemma: [n]a is a group element
+pub proof fn lemma_group_scalar_mul_closed<G: AbelianGroup>(a: G, n: nat)
+    requires
+        G::is_group_element(a),
+    ensures
+        G::is_group_element(group_scalar_mul(a, n)),
+    decreases n,
+{
+    if n == 0 {
+        G::lemma_group_identity_is_element();
+    } else {
+        lemma_group_scalar_mul_closed(a, (n - 1) as nat);
+        G::lemma_group_closed(group_scalar_mul(a, (n - 1) as nat), a);
+    }
+}
+
+/// Lemma: [m]a + [n]a = [m+n]a
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n = 0:  [m]a + e = [m]a
+/// n > 0:  [m]a + ([n-1]a + a) = ([m]a + [n-1]a) + a = [m+n-1]a + a = [m+n]a
+/// ```
+pub proof fn lemma_group_scalar_mul_additive<G: AbelianGroup>(a: G, m: nat, n: nat)
+    requires
+        G::is_group_element(a),
+    ensures
+        G::group_op(group_scalar_mul(a, m), group_scalar_mul(a, n)) == group_scalar_mul(a, m + n),
+    decreases n,
+{
+    lemma_group_scalar_mul_closed(a, m);
+    if n == 0 {
+        G::lemma_group_identity(group_scalar_mul(a, m));
+    } else {
+        let prev = group_scalar_mul(a, (n - 1) as nat);
+        lemma_group_scalar_mul_closed(a, (n - 1) as nat);
+        G::lemma_group_associative(group_scalar_mul(a, m), prev, a);
+        lemma_group_scalar_mul_additive(a, m, (n - 1) as nat);
+        assert(group_scalar_mul(a, m + n) == G::group_op(
+            group_scalar_mul(a, (m + n - 1) as nat),
+            a,
+        ));
+    }
+}
+
+/// Lemma: [n](a + b) = [n]a + [n]b
+///
+/// ## Mathematical Proof
+/// By induction on n:
+/// ```text
+/// n = 0:  [0](a + b) = e = e + e
+/// n > 0:  [n](a + b) = ([n-1]a + [n-1]b) + (a + b)
+///                    = ([n-1]a + a) + ([n-1]b + b) = [n]a + [n]b    [rearrangement]
+/// ```
+pub proof fn lemma_group_scalar_mul_distributes<G: AbelianGroup>(a: G, b: G, n: nat)
+    requires
+        G::is_group_element(a),
+        G::is_group_element(b),
+    ensures
+        group_scalar_mul(G::group_op(a, b), n) == G::group_op(
+            group_scalar_mul(a, n),
+            group_scalar_mul(b, n),
+        ),
+    decreases n,
+{
+    if n == 0 {
+        G::lemma_group_identity_is_element();
+        G::lemma_group_identity(G::group_identity());
+    } else {
+        let m = (n - 1) as nat;
+        lemma_group_scalar_mul_distributes(a, b, m);
+        lemma_group_scalar_mul_closed(a, m);
+        lemma_group_scalar_mul_closed(b, m);
+        lemma_group_rearrange(group_scalar_mul(a, m), group_scalar_mul(b, m), a, b);
+    }
+}
+
+/// Lemma: [m]([n]a) = [m·n]a
+///
+/// ## Mathematical Proof
+/// By induction on m:
+/// ```text
+/// m = 0:  [0]([n]a) = e = [0]a
+/// m > 0:  [m]([n]a) = [m-1]([n]a) + [n]a = [(m-1)·n]a + [n]a = [m·n]a
+/// ```
+pub proof fn lemma_group_scalar_mul_mul<G: AbelianGroup>(a: G, m: nat, n: nat)
+    requires
+        G::is_group_element(a),
+    ensures
+        group_scalar_mul(group_scalar_mul(a, n), m) == group_scalar_mul(a, m * n),
+    decreases m,
+{
+    if m == 0 {
+        assert(m * n == 0);
+    } else {
+        let k = (m - 1) as nat;
+        lemma_group_scalar_mul_mul(a, k, n);
+        lemma_group_scalar_mul_additive(a, k * n, n);
+        assert(k * n + n == m * n) by {
+            lemma_mul_is_distributive_add_other_way(n as int, k as int, 1);
+        };
+    }
+}
+
+// =============================================================================
+// Instance: affine points of the Edwards curve
+// =============================================================================
+/// The group elements are the reduced affine points (x, y) on the curve, which
+/// is what `edwards_point_as_affine` yields for every valid `EdwardsPoint`
+impl AbelianGroup for (nat, nat) {
+    open spec fn is_group_element(a: (nat, nat)) -> bool {
+        math_on_edwards_curve(a.0, a.1) && a.0 < p() && a.1 < p()
+    }
+
+    open spec fn group_op(a: (nat, nat), b: (nat, nat)) -> (nat, nat) {
+        edwards_add(a.0, a.1, b.0, b.1)
+    }
+
+    open spec fn group_identity() -> (nat, nat) {
+        math_edwards_identity()
+    }
+
+    open spec fn group_inverse(a: (nat, nat)) -> (nat, nat) {
+        (math_field_neg(a.0), a.1)
+    }
+
+    proof fn lemma_group_identity_is_element() {
+        p_gt_2();
+        lemma_edwards_identity_on_curve();
+    }
+
+    proof fn lemma_group_closed(a: (nat, nat), b: (nat, nat)) {
+        axiom_edwards_add_on_curve(a.0, a.1, b.0, b.1);
+        lemma_edwards_add_reduced(a.0, a.1, b.0, b.1);
+    }
+
+    proof fn lemma_group_associative(a: (nat, nat), b: (nat, nat), c: (nat, nat)) {
+        axiom_edwards_add_associative(a.0, a.1, b.0, b.1, c.0, c.1);
+    }
+
+    proof fn lemma_group_commutative(a: (nat, nat), b: (nat, nat)) {
+        lemma_edwards_add_commutative(a.0, a.1, b.0, b.1);
+    }
+
+    proof fn lemma_group_identity(a: (nat, nat)) {
+        lemma_edwards_add_identity_right(a.0, a.1);
+    }
+
+    proof fn lemma_group_inverse(a: (nat, nat)) {
+        p_gt_2();
+        lemma_negation_preserves_curve(a.0, a.1);
+        lemma_mod_bound((p() - (a.0 % p())) as int, p() as int);
+        axiom_edwards_add_inverse(a.0, a.1);
+    }
+}
+
+/// Lemma: `edwards_scalar_mul` is [n]a for the Edwards instance
+pub proof fn lemma_edwards_scalar_mul_is_group_scalar_mul(point: (nat, nat), n: nat)
+    ensures
+        edwards_scalar_mul(point, n) == group_scalar_mul(point, n),
+    decreases n,
+{
+    if n > 0 {
+        lemma_edwards_scalar_mul_is_group_scalar_mul(point, (n - 1) as nat);
+    }
+}
+
+// =============================================================================
+// EdwardsPoint operations
+// =============================================================================
+// `Add`, `identity` and scalar multiplication on EdwardsPoint already state their
+// results through `edwards_add`, `math_edwards_identity` and `edwards_scalar_mul`,
+// which are `group_op`, `group_identity` and (by the lemma above) `group_scalar_mul`
+// of the instance. What remains is that their inputs are group elements, and that
+// `Neg` computes `group_inverse`.
+/// Lemma: the affine point of a valid EdwardsPoint is a group element
+pub proof fn lemma_edwards_point_is_group_element(point: EdwardsPoint)
+    requires
+        is_valid_edwards_point(point),
+    ensures
+        <(nat, nat) as AbelianGroup>::is_group_element(edwards_point_as_affine(point)),
+{
+    lemma_edwards_point_as_affine_reduced(point);
+}
+
+/// Lemma: a point with X and T negated and Y, Z kept, as `Neg` returns it,
+/// represents the group inverse
+///
+/// ## Mathematical Proof
+/// ```text
+/// (-X)/Z = -(X/Z), and Y/Z is unchanged
+/// ```
+pub proof fn lemma_edwards_point_neg_is_group_inverse(point: EdwardsPoint, neg: EdwardsPoint)
+    requires
+        spec_field_element(&neg.X) == math_field_neg(spec_field_element(&point.X)),
+        neg.Y == point.Y,
+        neg.Z == point.Z,
+    ensures
+        edwards_point_as_affine(neg) == <(nat, nat) as AbelianGroup>::group_inverse(
+            edwards_point_as_affine(point),
+        ),
+{
+    let z_inv = math_field_inv(spec_field_element(&point.Z));
+    lemma_field_neg_mul(spec_field_element(&point.X), z_inv);
+}
+
+} // verus!
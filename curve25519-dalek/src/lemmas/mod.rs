@@ -8,10 +8,20 @@ pub mod scalar_montgomery_lemmas;
 
 pub mod montgomery_lemmas;
 
+pub mod montgomery_curve_lemmas;
+
+pub mod ristretto_lemmas;
+
+pub mod group_theory;
+
 pub mod scalar_lemmas_extra;
 
 pub mod scalar_byte_lemmas;
 
+pub mod scalar_pow_lemmas;
+
+pub mod scalar_sqrt_lemmas;
+
 // See: https://github.com/Beneficial-AI-Foundation/dalek-lite/issues/386
 pub mod edwards_lemmas;
 pub mod scalar_lemmas_;
@@ -0,0 +1,869 @@
+//! Lemmas for the Montgomery ladder step and the Elligator2 map
+//!
+//! `differential_add_and_double` evaluates one step of the x-only Montgomery ladder
+//! (Costello-Smith 2017, Algorithm 8). For P = (U_P : W_P), Q = (U_Q : W_Q) and the
+//! affine u-coordinate u_D of P - Q it computes
+//!
+//! ```text
+//! [2]P  = ((U_P + W_P)²·(U_P - W_P)² : 4U_P·W_P·((U_P - W_P)² + a24·4U_P·W_P))
+//! P + Q = (4·(U_P·U_Q - W_P·W_Q)² : u_D·4·(W_P·U_Q - U_P·W_Q)²)
+//! ```
+//! with a24 = (A + 2)/4 = `APLUS2_OVER_FOUR`.
+//!
+//! ## Mathematical Proof Sketch
+//!
+//! Substituting U = u·W turns every intermediate into an integer polynomial in u and W:
+//! ```text
+//! (U_P + W_P)²·(U_P - W_P)²               = W_P⁴·(u_P² - 1)²
+//! 4U_P·W_P·((U_P - W_P)² + (A+2)·U_P·W_P) = W_P⁴·4u_P·(u_P² + A·u_P + 1)
+//! 4·(U_P·U_Q - W_P·W_Q)²                  = 4W_P²W_Q²·(u_P·u_Q - 1)²
+//! u_D·4·(W_P·U_Q - U_P·W_Q)²              = 4W_P²W_Q²·u_D·(u_P - u_Q)²
+//! ```
+//! The non-zero factors W_P⁴ and 4W_P²W_Q² cancel, leaving the affine x-only formulas
+//! `montgomery_xdbl_u` and `montgomery_xadd_u`. Since A + 2 = 486664 = 4·121666, the
+//! constant a24 is an integer and 4·a24 = A + 2 holds without any field inversion.
+//!
+//! That the x-only formulas agree with the chord-tangent law `montgomery_add` is the
+//! classical result of Montgomery (1987); it is taken as an axiom here, like the
+//! Edwards group-law axioms in `scalar_mul_lemmas`.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_aplus2_over_four_value`: a24 = 121666, A = 486662 and 4·a24 = A + 2
+//! - `axiom_montgomery_xdbl_u`, `axiom_montgomery_xadd_u`: the x-only formulas compute
+//!   `montgomery_add` on canonical lifts
+//! - `lemma_projective_common_factor`: a projective ratio k·n / k·d equals n / d
+//! - `lemma_ladder_double_formula`: the doubling half of the ladder step
+//! - `lemma_ladder_add_formula`: the differential-addition half of the ladder step
+//! - `lemma_x25519_ignores_high_bit`: X25519 masks bit 255 of the u-coordinate
+//! - `lemma_montgomery_a_neg_value`: MONTGOMERY_A_NEG = -A = p - 486662
+//! - `axiom_two_not_square`, `axiom_nonsquare_times_nonsquare_is_square`: quadratic
+//!   residue facts behind the choice of 2 as the Elligator2 non-square
+//! - `lemma_elligator_encode_is_valid_u_coordinate`: the Elligator2 map lands on the curve
+#![allow(unused_imports)]
+use crate::backend::serial::u64::constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::lemmas::common_lemmas::to_nat_lemmas::*;
+use crate::lemmas::edwards_lemmas::add_lemmas::*;
+use crate::lemmas::field_lemmas::as_bytes_lemmas::lemma_from_bytes_clears_high_bit;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+use crate::lemmas::field_lemmas::field_theory_lemmas::lemma_field_mul_inv;
+use crate::specs::core_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use crate::specs::montgomery_specs::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Curve constants
+// =============================================================================
+/// APLUS2_OVER_FOUR = [121666, 0, 0, 0, 0] is (A + 2)/4 for A = MONTGOMERY_A = 486662
+pub proof fn lemma_aplus2_over_four_value()
+    ensures
+        fe51_limbs_bounded(&APLUS2_OVER_FOUR, 51),
+        spec_field_element(&APLUS2_OVER_FOUR) == 121666,
+        spec_field_element(&MONTGOMERY_A) == 486662,
+        4 * spec_field_element(&APLUS2_OVER_FOUR) == spec_field_element(&MONTGOMERY_A) + 2,
+{
+    assert(fe51_limbs_bounded(&APLUS2_OVER_FOUR, 51)) by {
+        assert(0u64 < (1u64 << 51) && 121666u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(u64_5_as_nat(APLUS2_OVER_FOUR.limbs) == 121666);
+    assert(u64_5_as_nat(MONTGOMERY_A.limbs) == 486662);
+    // p = 2^255 - 19 > 2^20 - 19 > 486662
+    lemma2_to64();
+    lemma_pow2_strictly_increases(20, 255);
+    lemma_small_mod(121666, p());
+    lemma_small_mod(486662, p());
+}
+
+// =============================================================================
+// x-only formula axioms
+// =============================================================================
+/// AXIOM: x-only doubling computes u([2]P) for the canonical lift P of u
+///
+/// If [2]P = ∞ (P = (0, 0) or another 2-torsion point) both sides are 0.
+pub proof fn axiom_montgomery_xdbl_u(u: nat)
+    requires
+        is_valid_u_coordinate(u),
+    ensures
+        ({
+            let P = canonical_montgomery_lift(u);
+            spec_u_coordinate(montgomery_add(P, P)) == montgomery_xdbl_u(u % p())
+        }),
+{
+    admit();  // Montgomery 1987, Section 10.3.1
+}
+
+/// AXIOM: x-only differential addition computes u(P + Q) from u(P), u(Q) and u(P - Q)
+///
+/// The identity u(P + Q)·u(P - Q)·(u_P - u_Q)² = (u_P·u_Q - 1)² holds for any two
+/// distinct curve points; u(P - Q) ≠ 0 is needed to solve it for u(P + Q). In
+/// particular P - Q ≠ ∞, since ∞ has u-coordinate 0 under `spec_u_coordinate`.
+pub proof fn axiom_montgomery_xadd_u(u_p: nat, u_q: nat, u_d: nat)
+    requires
+        is_valid_u_coordinate(u_p),
+        is_valid_u_coordinate(u_q),
+        canonical_montgomery_lift(u_p) != canonical_montgomery_lift(u_q),
+        spec_u_coordinate(
+            montgomery_sub(canonical_montgomery_lift(u_p), canonical_montgomery_lift(u_q)),
+        ) == u_d,
+        u_d != 0,
+    ensures
+        spec_u_coordinate(
+            montgomery_add(canonical_montgomery_lift(u_p), canonical_montgomery_lift(u_q)),
+        ) == montgomery_xadd_u(u_p % p(), u_q % p(), u_d),
+{
+    admit();  // Montgomery 1987, Section 10.3.1
+}
+
+// =============================================================================
+// Projective ladder formulas
+// =============================================================================
+/// Lemma: if X ≡ k·n and Z ≡ k·d (mod p) with k ≢ 0, then X/Z = n/d
+///
+/// Both sides use the `spec_projective_u_coordinate` convention that a zero
+/// denominator gives 0; Z vanishes exactly when d does.
+pub proof fn lemma_projective_common_factor(k: int, n: int, d: int, x: nat, z: nat)
+    requires
+        k % (p() as int) != 0,
+        x as int == (k * n) % (p() as int),
+        z as int == (k * d) % (p() as int),
+    ensures
+        ({
+            let num = (n % (p() as int)) as nat;
+            let den = (d % (p() as int)) as nat;
+            (if z == 0 {
+                0
+            } else {
+                math_field_mul(x, math_field_inv(z))
+            }) == (if den == 0 {
+                0
+            } else {
+                math_field_mul(num, math_field_inv(den))
+            })
+        }),
+{
+    let pi = p() as int;
+    p_gt_2();
+    lemma_mod_bound(k, pi);
+    lemma_mod_bound(n, pi);
+    lemma_mod_bound(d, pi);
+    let kf = (k % pi) as nat;
+    let num = (n % pi) as nat;
+    let den = (d % pi) as nat;
+
+    // X = kf·num and Z = kf·den as field products
+    lemma_mul_mod_noop(k, n, pi);
+    lemma_mul_mod_noop(k, d, pi);
+    assert(x == math_field_mul(kf, num));
+    assert(z == math_field_mul(kf, den));
+
+    lemma_small_mod(kf, p());
+    if den == 0 {
+        lemma_field_mul_zero_right(kf, den);
+    } else {
+        lemma_small_mod(den, p());
+        lemma_field_mul_nonzero(kf, den);
+        lemma_field_common_factor(kf, num, den);
+    }
+}
+
+/// Lemma: the doubling half of the ladder step computes `montgomery_xdbl_u`
+///
+/// ## Mathematical Proof
+/// With U = u·W:
+/// ```text
+/// t6  = (U + W)² - (U - W)²       = 4u·W²
+/// t15 = a24·t6 + (U - W)²         = W²·(u² + A·u + 1)          [4·a24 = A + 2]
+/// U'  = (U + W)²·(U - W)²         = (W²·(u² - 1))² = W⁴·(u² - 1)²
+/// W'  = t6·t15                    = W⁴·4u·(u² + A·u + 1)
+/// ```
+/// and the factor W⁴ ≠ 0 cancels.
+pub proof fn lemma_ladder_double_formula(U: nat, W: nat)
+    requires
+        U < p(),
+        W < p(),
+        W != 0,
+    ensures
+        ({
+            let a24 = spec_field_element(&APLUS2_OVER_FOUR);
+            let t4 = math_field_square(math_field_add(U, W));
+            let t5 = math_field_square(math_field_sub(U, W));
+            let t6 = math_field_sub(t4, t5);
+            let t15 = math_field_add(math_field_mul(a24, t6), t5);
+            let U2 = math_field_mul(t4, t5);
+            let W2 = math_field_mul(t6, t15);
+            (if W2 == 0 {
+                0
+            } else {
+                math_field_mul(U2, math_field_inv(W2))
+            }) == montgomery_xdbl_u(math_field_mul(U, math_field_inv(W)))
+        }),
+{
+    let pi = p() as int;
+    p_gt_2();
+    lemma_aplus2_over_four_value();
+    let A = spec_field_element(&MONTGOMERY_A);
+    let a24 = spec_field_element(&APLUS2_OVER_FOUR);
+    let t0 = math_field_add(U, W);
+    let t1 = math_field_sub(U, W);
+    let t4 = math_field_square(t0);
+    let t5 = math_field_square(t1);
+    let t6 = math_field_sub(t4, t5);
+    let t13 = math_field_mul(a24, t6);
+    let t15 = math_field_add(t13, t5);
+    let U2 = math_field_mul(t4, t5);
+    let W2 = math_field_mul(t6, t15);
+
+    // U = u·W
+    let u = math_field_mul(U, math_field_inv(W));
+    lemma_small_mod(W, p());
+    lemma_affine_times_z(U, W);
+    assert(U == math_field_mul(u, W));
+    let (ui, wi) = (u as int, W as int);
+
+    // Step 1: every intermediate is an integer polynomial in u and W, mod p
+    let e0 = ui * wi + wi;
+    let e1 = ui * wi - wi;
+    assert(t0 as int == e0 % pi) by {
+        lemma_add_mod_noop(ui * wi, wi, pi);
+    };
+    assert(t1 as int == e1 % pi) by {
+        lemma_field_sub_as_int(U, W);
+        lemma_sub_mod_noop(ui * wi, wi, pi);
+    };
+    let e4 = e0 * e0;
+    let e5 = e1 * e1;
+    assert(t4 as int == e4 % pi) by {
+        lemma_mul_mod_noop(e0, e0, pi);
+    };
+    assert(t5 as int == e5 % pi) by {
+        lemma_mul_mod_noop(e1, e1, pi);
+    };
+    let e6 = e4 - e5;
+    assert(t6 as int == e6 % pi) by {
+        lemma_field_sub_as_int(t4, t5);
+        lemma_sub_mod_noop(e4, e5, pi);
+    };
+    let e13 = 121666 * e6;
+    assert(t13 as int == e13 % pi) by {
+        lemma_mul_mod_noop_right(121666, e6, pi);
+    };
+    let e15 = e13 + e5;
+    assert(t15 as int == e15 % pi) by {
+        lemma_add_mod_noop(e13, e5, pi);
+    };
+    let e14 = e4 * e5;
+    let e16 = e6 * e15;
+    assert(U2 as int == e14 % pi) by {
+        lemma_mul_mod_noop(e4, e5, pi);
+    };
+    assert(W2 as int == e16 % pi) by {
+        lemma_mul_mod_noop(e6, e15, pi);
+    };
+
+    // Step 2: the same for numerator and denominator of montgomery_xdbl_u(u)
+    let u2 = math_field_square(u);
+    let m = ui * ui - 1;
+    let N = m * m;
+    let q = ui * ui + 486662 * ui + 1;
+    let D = (4 * ui) * q;
+    let num = math_field_square(math_field_sub(u2, 1));
+    let s = math_field_add(math_field_add(u2, math_field_mul(A, u)), 1);
+    let den = math_field_mul(math_field_mul(4, u), s);
+    assert(math_field_sub(u2, 1) as int == m % pi) by {
+        lemma_field_sub_as_int(u2, 1);
+        lemma_small_mod(1, p());
+        lemma_sub_mod_noop(ui * ui, 1, pi);
+    };
+    assert(num as int == N % pi) by {
+        lemma_mul_mod_noop(m, m, pi);
+    };
+    assert(s as int == q % pi) by {
+        lemma_add_mod_noop(ui * ui, 486662 * ui, pi);
+        lemma_small_mod(1, p());
+        lemma_add_mod_noop(ui * ui + 486662 * ui, 1, pi);
+    };
+    assert(den as int == D % pi) by {
+        lemma_mul_mod_noop(4 * ui, q, pi);
+    };
+
+    // Step 3: polynomial identities over ℤ
+    let w2 = wi * wi;
+    assert(e6 == (4 * ui) * w2) by (nonlinear_arith)
+        requires
+            e0 == ui * wi + wi,
+            e1 == ui * wi - wi,
+            e6 == e0 * e0 - e1 * e1,
+            w2 == wi * wi,
+    ;
+    assert(e15 == w2 * q) by (nonlinear_arith)
+        requires
+            e1 == ui * wi - wi,
+            e15 == 121666 * ((4 * ui) * w2) + e1 * e1,
+            w2 == wi * wi,
+            q == ui * ui + 486662 * ui + 1,
+    ;
+    assert(e0 * e1 == w2 * m) by (nonlinear_arith)
+        requires
+            e0 == ui * wi + wi,
+            e1 == ui * wi - wi,
+            w2 == wi * wi,
+            m == ui * ui - 1,
+    ;
+    assert(e14 == (w2 * w2) * N) by (nonlinear_arith)
+        requires
+            e14 == (e0 * e0) * (e1 * e1),
+            e0 * e1 == w2 * m,
+            N == m * m,
+    ;
+    assert(e16 == (w2 * w2) * D) by (nonlinear_arith)
+        requires
+            e16 == e6 * e15,
+            e6 == (4 * ui) * w2,
+            e15 == w2 * q,
+            D == (4 * ui) * q,
+    ;
+
+    // Step 4: W⁴ ≢ 0, so it cancels
+    assert((w2 * w2) % pi != 0) by {
+        lemma_field_mul_nonzero(W, W);
+        let ww = math_field_mul(W, W);
+        lemma_field_mul_nonzero(ww, ww);
+        lemma_mul_mod_noop(w2, w2, pi);
+    };
+    lemma_projective_common_factor(w2 * w2, N, D, U2, W2);
+}
+
+/// Lemma: the differential-addition half of the ladder step computes `montgomery_xadd_u`
+///
+/// ## Mathematical Proof
+/// With U_P = u_P·W_P, U_Q = u_Q·W_Q and k = W_P·W_Q:
+/// ```text
+/// t7 = (U_P + W_P)·(U_Q - W_Q) = k·(u_P + 1)·(u_Q - 1)
+/// t8 = (U_P - W_P)·(U_Q + W_Q) = k·(u_P - 1)·(u_Q + 1)
+/// t7 + t8 = 2k·(u_P·u_Q - 1)
+/// t7 - t8 = 2k·(u_Q - u_P)
+/// ```
+/// so U' = 4k²·(u_P·u_Q - 1)² and W' = 4k²·u_D·(u_P - u_Q)², and 4k² ≠ 0 cancels.
+pub proof fn lemma_ladder_add_formula(U_P: nat, W_P: nat, U_Q: nat, W_Q: nat, u_d: nat)
+    requires
+        U_P < p(),
+        W_P < p(),
+        U_Q < p(),
+        W_Q < p(),
+        u_d < p(),
+        W_P != 0,
+        W_Q != 0,
+    ensures
+        ({
+            let t7 = math_field_mul(math_field_add(U_P, W_P), math_field_sub(U_Q, W_Q));
+            let t8 = math_field_mul(math_field_sub(U_P, W_P), math_field_add(U_Q, W_Q));
+            let t11 = math_field_square(math_field_add(t7, t8));
+            let t17 = math_field_mul(u_d, math_field_square(math_field_sub(t7, t8)));
+            (if t17 == 0 {
+                0
+            } else {
+                math_field_mul(t11, math_field_inv(t17))
+            }) == montgomery_xadd_u(
+                math_field_mul(U_P, math_field_inv(W_P)),
+                math_field_mul(U_Q, math_field_inv(W_Q)),
+                u_d,
+            )
+        }),
+{
+    let pi = p() as int;
+    p_gt_2();
+    let t0 = math_field_add(U_P, W_P);
+    let t1 = math_field_sub(U_P, W_P);
+    let t2 = math_field_add(U_Q, W_Q);
+    let t3 = math_field_sub(U_Q, W_Q);
+    let t7 = math_field_mul(t0, t3);
+    let t8 = math_field_mul(t1, t2);
+    let t9 = math_field_add(t7, t8);
+    let t10 = math_field_sub(t7, t8);
+    let t11 = math_field_square(t9);
+    let t12 = math_field_square(t10);
+    let t17 = math_field_mul(u_d, t12);
+
+    // U_P = u_P·W_P and U_Q = u_Q·W_Q
+    let u_p = math_field_mul(U_P, math_field_inv(W_P));
+    let u_q = math_field_mul(U_Q, math_field_inv(W_Q));
+    lemma_small_mod(W_P, p());
+    lemma_small_mod(W_Q, p());
+    lemma_small_mod(u_d, p());
+    lemma_affine_times_z(U_P, W_P);
+    lemma_affine_times_z(U_Q, W_Q);
+    assert(U_P == math_field_mul(u_p, W_P));
+    assert(U_Q == math_field_mul(u_q, W_Q));
+    let (up, wp, uq, wq, di) = (u_p as int, W_P as int, u_q as int, W_Q as int, u_d as int);
+
+    // Step 1: every intermediate is an integer polynomial, mod p
+    let e0 = up * wp + wp;
+    let e1 = up * wp - wp;
+    let e2 = uq * wq + wq;
+    let e3 = uq * wq - wq;
+    assert(t0 as int == e0 % pi) by {
+        lemma_add_mod_noop(up * wp, wp, pi);
+    };
+    assert(t1 as int == e1 % pi) by {
+        lemma_field_sub_as_int(U_P, W_P);
+        lemma_sub_mod_noop(up * wp, wp, pi);
+    };
+    assert(t2 as int == e2 % pi) by {
+        lemma_add_mod_noop(uq * wq, wq, pi);
+    };
+    assert(t3 as int == e3 % pi) by {
+        lemma_field_sub_as_int(U_Q, W_Q);
+        lemma_sub_mod_noop(uq * wq, wq, pi);
+    };
+    let e7 = e0 * e3;
+    let e8 = e1 * e2;
+    assert(t7 as int == e7 % pi) by {
+        lemma_mul_mod_noop(e0, e3, pi);
+    };
+    assert(t8 as int == e8 % pi) by {
+        lemma_mul_mod_noop(e1, e2, pi);
+    };
+    let e9 = e7 + e8;
+    let e10 = e7 - e8;
+    assert(t9 as int == e9 % pi) by {
+        lemma_add_mod_noop(e7, e8, pi);
+    };
+    assert(t10 as int == e10 % pi) by {
+        lemma_field_sub_as_int(t7, t8);
+        lemma_sub_mod_noop(e7, e8, pi);
+    };
+    let e11 = e9 * e9;
+    let e12 = e10 * e10;
+    let e17 = di * e12;
+    assert(t11 as int == e11 % pi) by {
+        lemma_mul_mod_noop(e9, e9, pi);
+    };
+    assert(t12 as int == e12 % pi) by {
+        lemma_mul_mod_noop(e10, e10, pi);
+    };
+    assert(t17 as int == e17 % pi) by {
+        lemma_mul_mod_noop_right(di, e12, pi);
+    };
+
+    // Step 2: the same for numerator and denominator of montgomery_xadd_u
+    let m = up * uq - 1;
+    let N = m * m;
+    let s = up - uq;
+    let D = di * (s * s);
+    let num = math_field_square(math_field_sub(math_field_mul(u_p, u_q), 1));
+    let sq = math_field_square(math_field_sub(u_p, u_q));
+    let den = math_field_mul(u_d, sq);
+    assert(math_field_sub(math_field_mul(u_p, u_q), 1) as int == m % pi) by {
+        lemma_field_sub_as_int(math_field_mul(u_p, u_q), 1);
+        lemma_small_mod(1, p());
+        lemma_sub_mod_noop(up * uq, 1, pi);
+    };
+    assert(num as int == N % pi) by {
+        lemma_mul_mod_noop(m, m, pi);
+    };
+    assert(sq as int == (s * s) % pi) by {
+        lemma_field_sub_as_int(u_p, u_q);
+        lemma_mul_mod_noop(s, s, pi);
+    };
+    assert(den as int == D % pi) by {
+        lemma_mul_mod_noop_right(di, s * s, pi);
+    };
+
+    // Step 3: polynomial identities over ℤ
+    let k = wp * wq;
+    assert(e7 == k * ((up + 1) * (uq - 1)) && e8 == k * ((up - 1) * (uq + 1)))
+        by (nonlinear_arith)
+        requires
+            e0 == up * wp + wp,
+            e1 == up * wp - wp,
+            e2 == uq * wq + wq,
+            e3 == uq * wq - wq,
+            e7 == e0 * e3,
+            e8 == e1 * e2,
+            k == wp * wq,
+    ;
+    assert(e9 == (2 * k) * m && e10 == (2 * k) * (uq - up)) by (nonlinear_arith)
+        requires
+            e7 == k * ((up + 1) * (uq - 1)),
+            e8 == k * ((up - 1) * (uq + 1)),
+            e9 == e7 + e8,
+            e10 == e7 - e8,
+            m == up * uq - 1,
+    ;
+    let K = 4 * (k * k);
+    assert(e11 == K * N) by (nonlinear_arith)
+        requires
+            e11 == e9 * e9,
+            e9 == (2 * k) * m,
+            K == 4 * (k * k),
+            N == m * m,
+    ;
+    assert(e17 == K * D) by (nonlinear_arith)
+        requires
+            e17 == di * (e10 * e10),
+            e10 == (2 * k) * (uq - up),
+            K == 4 * (k * k),
+            D == di * (s * s),
+            s == up - uq,
+    ;
+
+    // Step 4: 4·W_P²·W_Q² ≢ 0, so it cancels
+    assert(K % pi != 0) by {
+        // p > 2^20 - 19 > 4
+        lemma2_to64();
+        lemma_pow2_strictly_increases(20, 255);
+        lemma_small_mod(4, p());
+        lemma_field_mul_nonzero(W_P, W_Q);
+        let kf = math_field_mul(W_P, W_Q);
+        lemma_field_mul_nonzero(kf, kf);
+        lemma_mul_mod_noop(k, k, pi);
+        lemma_field_mul_nonzero(4, math_field_mul(kf, kf));
+        lemma_mul_mod_noop_right(4, k * k, pi);
+    };
+    lemma_projective_common_factor(K, N, D, t11, t17);
+}
+
+// =============================================================================
+// X25519 input decoding
+// =============================================================================
+/// Lemma: X25519 ignores bit 255 of the u-coordinate (RFC 7748 Section 5)
+///
+/// Two encodings that differ only in the top bit of byte 31 decode to the same
+/// field element, so X25519 gives the same result on both.
+pub proof fn lemma_x25519_ignores_high_bit(k: [u8; 32], u: [u8; 32], u_masked: [u8; 32])
+    requires
+        forall|i: int| 0 <= i < 31 ==> #[trigger] u_masked[i] == u[i],
+        u_masked[31] == u[31] & 127u8,
+    ensures
+        spec_field_element_from_bytes(&u_masked) == spec_field_element_from_bytes(&u),
+        spec_x25519(k, u_masked) == spec_x25519(k, u),
+{
+    lemma_from_bytes_clears_high_bit(&u);
+    lemma_from_bytes_clears_high_bit(&u_masked);
+    lemma_prefix_equal_when_bytes_match(u@, u_masked@, 31);
+    let b = u[31];
+    assert((b & 127u8) & 127u8 == b & 127u8) by (bit_vector);
+}
+
+// =============================================================================
+// Elligator2
+// =============================================================================
+/// MONTGOMERY_A_NEG has 51-bit bounded limbs and represents -A = p - 486662
+///
+/// ## Mathematical Proof
+/// With c = 2^51 the limbs are (c - 19 - 486662, c - 1, c - 1, c - 1, c - 1), so
+/// ```text
+/// (c - 19 - 486662) + c(c - 1) + c²(c - 1) + c³(c - 1) + c⁴(c - 1) = c⁵ - 19 - 486662
+/// ```
+pub proof fn lemma_montgomery_a_neg_value()
+    ensures
+        fe51_limbs_bounded(&MONTGOMERY_A, 51),
+        fe51_limbs_bounded(&MONTGOMERY_A_NEG, 51),
+        spec_field_element(&MONTGOMERY_A_NEG) == p() - 486662,
+        spec_field_element(&MONTGOMERY_A_NEG) == math_field_neg(spec_field_element(&MONTGOMERY_A)),
+{
+    lemma_aplus2_over_four_value();
+    let limbs = MONTGOMERY_A_NEG.limbs;
+    assert(fe51_limbs_bounded(&MONTGOMERY_A, 51)) by {
+        assert(0u64 < (1u64 << 51) && 486662u64 < (1u64 << 51)) by (bit_vector);
+    };
+    assert(fe51_limbs_bounded(&MONTGOMERY_A_NEG, 51)) by {
+        assert(2251799813198567u64 < (1u64 << 51) && 2251799813685247u64 < (1u64 << 51))
+            by (bit_vector);
+    };
+
+    let c = pow2(51);
+    assert(c == 2251799813685248) by {
+        lemma2_to64_rest();
+    };
+    lemma_pow2_adds(51, 51);
+    lemma_pow2_adds(102, 51);
+    lemma_pow2_adds(153, 51);
+    lemma_pow2_adds(204, 51);
+
+    assert(u64_5_as_nat(limbs) == pow2(255) - 19 - 486662) by (nonlinear_arith)
+        requires
+            limbs[0] == c - 19 - 486662,
+            limbs[1] == c - 1,
+            limbs[2] == c - 1,
+            limbs[3] == c - 1,
+            limbs[4] == c - 1,
+            pow2(102) == c * c,
+            pow2(153) == pow2(102) * c,
+            pow2(204) == pow2(153) * c,
+            pow2(255) == pow2(204) * c,
+            u64_5_as_nat(limbs) == limbs[0] + pow2(51) * limbs[1] + pow2(102) * limbs[2] + pow2(
+                153,
+            ) * limbs[3] + pow2(204) * limbs[4],
+            c == pow2(51),
+    ;
+
+    // p = 2^255 - 19 > 2^20 - 19 > 486662
+    lemma2_to64();
+    lemma_pow2_strictly_increases(20, 255);
+    lemma_small_mod(486662, p());
+    lemma_small_mod((p() - 486662) as nat, p());
+}
+
+/// AXIOM: 2 is not a square in F_p
+///
+/// Mathematical justification:
+/// - By the second supplement to quadratic reciprocity, 2 is a square mod an odd
+///   prime p iff p ≡ ±1 (mod 8)
+/// - p = 2^255 - 19 ≡ 5 (mod 8) (`lemma_p_mod_8`)
+/// - Therefore 2 is NOT a square; this is why RFC 9380 uses Z = 2 for curve25519
+///
+/// Used in: lemma_elligator_encode_is_valid_u_coordinate
+pub proof fn axiom_two_not_square()
+    ensures
+        !math_is_square(2),
+{
+    admit();
+}
+
+/// AXIOM: the product of two non-squares in F_p is a square
+///
+/// Mathematical justification:
+/// - By Euler's criterion a non-square a has a^((p-1)/2) = -1
+/// - So (ab)^((p-1)/2) = (-1)·(-1) = 1 for non-squares a, b
+/// - By Euler's criterion, ab is a square
+/// - Only the square direction of the criterion is proved
+///   (`lemma_euler_criterion_square`)
+///
+/// Used in: lemma_elligator_encode_is_valid_u_coordinate
+pub proof fn axiom_nonsquare_times_nonsquare_is_square(a: nat, b: nat)
+    requires
+        !math_is_square(a),
+        !math_is_square(b),
+    ensures
+        math_is_square(math_field_mul(a, b)),
+{
+    admit();
+}
+
+/// Lemma: `montgomery_rhs(u)` is u·(u² + A·u + 1) mod p
+pub proof fn lemma_montgomery_rhs_as_int(u: nat)
+    requires
+        u < p(),
+    ensures
+        montgomery_rhs(u) as int == ((u as int) * ((u as int) * (u as int) + 486662 * (u as int)
+            + 1)) % (p() as int),
+{
+    let pi = p() as int;
+    p_gt_2();
+    lemma_aplus2_over_four_value();
+    lemma_small_mod(u, p());
+    let ui = u as int;
+    let A = spec_field_element(&MONTGOMERY_A);
+    let u2 = math_field_mul(u, u);
+    let u3 = math_field_mul(u2, u);
+    let Au2 = math_field_mul(A, u2);
+    let s = math_field_add(u3, Au2);
+    assert(u3 as int == (ui * ui * ui) % pi) by {
+        lemma_mul_mod_noop_left(ui * ui, ui, pi);
+    };
+    assert(Au2 as int == (486662 * (ui * ui)) % pi) by {
+        lemma_mul_mod_noop_right(486662, ui * ui, pi);
+    };
+    assert(s as int == (ui * ui * ui + 486662 * (ui * ui)) % pi) by {
+        lemma_add_mod_noop(ui * ui * ui, 486662 * (ui * ui), pi);
+    };
+    assert(montgomery_rhs(u) as int == (ui * ui * ui + 486662 * (ui * ui) + ui) % pi) by {
+        lemma_add_mod_noop(ui * ui * ui + 486662 * (ui * ui), ui, pi);
+    };
+    assert(ui * ui * ui + 486662 * (ui * ui) + ui == ui * (ui * ui + 486662 * ui + 1))
+        by (nonlinear_arith);
+}
+
+/// Lemma: the Elligator2 value ε = d·((d² + A·d) + 1) is d·(d² + A·d + 1) mod p
+pub proof fn lemma_elligator_eps_as_int(d: nat)
+    requires
+        d < p(),
+    ensures
+        ({
+            let A = spec_field_element(&MONTGOMERY_A);
+            let inner = math_field_add(
+                math_field_add(math_field_square(d), math_field_mul(A, d)),
+                1,
+            );
+            math_field_mul(d, inner) as int == ((d as int) * ((d as int) * (d as int) + 486662 * (
+            d as int) + 1)) % (p() as int)
+        }),
+{
+    let pi = p() as int;
+    p_gt_2();
+    lemma_aplus2_over_four_value();
+    lemma_small_mod(1, p());
+    let di = d as int;
+    let A = spec_field_element(&MONTGOMERY_A);
+    let s = math_field_add(math_field_square(d), math_field_mul(A, d));
+    let inner = math_field_add(s, 1);
+    assert(s as int == (di * di + 486662 * di) % pi) by {
+        lemma_add_mod_noop(di * di, 486662 * di, pi);
+    };
+    assert(inner as int == (di * di + 486662 * di + 1) % pi) by {
+        lemma_add_mod_noop(di * di + 486662 * di, 1, pi);
+    };
+    assert(math_field_mul(d, inner) as int == (di * (di * di + 486662 * di + 1)) % pi) by {
+        lemma_mul_mod_noop_right(di, di * di + 486662 * di + 1, pi);
+    };
+}
+
+/// Lemma: the Elligator2 map lands on the curve
+///
+/// ## Mathematical Proof
+/// Let t = 2r², d = -A/(1 + t), g(x) = x² + A·x + 1 and f(x) = x·g(x), so ε = f(d)
+/// and f = `montgomery_rhs`.
+/// ```text
+/// ε square:     u = d and f(d) = ε is a square
+/// ε non-square: u = -d - A, so u² + A·u = (d + A)·d and g(u) = g(d).
+///               d·(1 + t) = -A gives u = t·d, so f(u) = t·ε = r²·(2ε),
+///               and 2ε is a square as a product of two non-squares
+/// ```
+/// In the non-square case ε ≠ 0, so 1 + t ≠ 0 (otherwise d = 0 and ε = 0).
+pub proof fn lemma_elligator_encode_is_valid_u_coordinate(r: nat)
+    ensures
+        is_valid_u_coordinate(spec_elligator_encode(r)),
+{
+    let pi = p() as int;
+    p_gt_2();
+    lemma_aplus2_over_four_value();
+    lemma_montgomery_a_neg_value();
+    lemma_small_mod(0, p());
+    let A = spec_field_element(&MONTGOMERY_A);
+    let neg_A = math_field_neg(A);
+    let r_sq = math_field_square(r);
+    let t = math_field_mul(2, r_sq);
+    let D = math_field_add(1, t);
+    let d = math_field_mul(neg_A, math_field_inv(D));
+    let inner = math_field_add(math_field_add(math_field_square(d), math_field_mul(A, d)), 1);
+    let eps = math_field_mul(d, inner);
+    lemma_mod_bound((neg_A * math_field_inv(D)) as int, pi);
+    lemma_mod_bound((1 + t) as int, pi);
+    lemma_mod_bound((2 * r_sq) as int, pi);
+
+    let (di, ti) = (d as int, t as int);
+    let g_d = di * di + 486662 * di + 1;
+    lemma_elligator_eps_as_int(d);
+    lemma_montgomery_rhs_as_int(d);
+
+    if math_is_square(eps) {
+        // u = d and f(d) = ε
+        assert(montgomery_rhs(d) == eps);
+    } else {
+        let u = math_field_neg(math_field_add(d, A));
+        lemma_mod_bound((p() - (math_field_add(d, A) % p())) as nat as int, pi);
+        let ui = u as int;
+        let w = -di - 486662;
+
+        // ε ≠ 0, since 0 is a square
+        assert(eps != 0) by {
+            if eps == 0 {
+                assert((0nat * 0nat) % p() == eps % p());
+            }
+        };
+        // 1 + t ≠ 0, otherwise d = -A·inv(0) = 0 and ε = 0
+        assert(D != 0) by {
+            if D == 0 {
+                field_inv_zero();
+                lemma_field_mul_zero_right(neg_A, 0);
+                lemma_field_mul_zero_left(0, inner);
+            }
+        };
+
+        // d·(1 + t) = -A
+        assert(math_field_mul(d, D) == neg_A) by {
+            lemma_small_mod(D, p());
+            lemma_field_mul_inv(D);
+            lemma_field_mul_assoc(neg_A, math_field_inv(D), D);
+            lemma_field_mul_comm(math_field_inv(D), D);
+            lemma_small_mod(neg_A, p());
+        };
+        // So d·t ≡ -d - A
+        assert((di * ti) % pi == w % pi) by {
+            lemma_mul_mod_noop_right(di, 1 + ti, pi);
+            lemma_small_mod(486662, p());
+            lemma_add_mod_noop(di * (1 + ti), 486662, pi);
+            lemma_mod_self_0(pi);
+            assert(di * (1 + ti) + 486662 == di * ti - w) by (nonlinear_arith)
+                requires
+                    w == -di - 486662,
+            ;
+            lemma_mod_equivalence(di * ti, w, pi);
+        };
+        // u = -(d + A) ≡ -d - A
+        assert(ui % pi == w % pi) by {
+            let s = math_field_add(d, A);
+            lemma_mod_bound(di + 486662, pi);
+            lemma_small_mod(s, p());
+            lemma_small_mod(u, p());
+            lemma_add_mod_noop(ui, di + 486662, pi);
+            lemma_mod_self_0(pi);
+            if s != 0 {
+                lemma_small_mod((p() - s) as nat, p());
+            }
+            assert(ui - w == ui + (di + 486662));
+            lemma_mod_equivalence(ui, w, pi);
+        };
+        assert((ui - di * ti) % pi == 0) by {
+            lemma_mod_equivalence(ui, di * ti, pi);
+        };
+
+        // g(u) ≡ g(-d - A) = g(d)
+        let g_u = ui * ui + 486662 * ui + 1;
+        assert((g_u - g_d) % pi == 0) by {
+            assert(g_u - g_d == (ui - w) * (ui + w + 486662)) by (nonlinear_arith)
+                requires
+                    w == -di - 486662,
+                    g_u == ui * ui + 486662 * ui + 1,
+                    g_d == di * di + 486662 * di + 1,
+            ;
+            lemma_mod_equivalence(ui, w, pi);
+            lemma_mul_mod_noop_left(ui - w, ui + w + 486662, pi);
+        };
+
+        // f(u) = u·g(u) ≡ t·d·g(d) = t·ε
+        let f_u = ui * g_u;
+        let te = ti * (di * g_d);
+        assert(f_u % pi == te % pi) by {
+            assert(f_u - te == ui * (g_u - g_d) + (ui - di * ti) * g_d) by (nonlinear_arith)
+                requires
+                    f_u == ui * g_u,
+                    te == ti * (di * g_d),
+            ;
+            lemma_mul_mod_noop_right(ui, g_u - g_d, pi);
+            lemma_mul_mod_noop_left(ui - di * ti, g_d, pi);
+            lemma_add_mod_noop(ui * (g_u - g_d), (ui - di * ti) * g_d, pi);
+            lemma_mod_equivalence(f_u, te, pi);
+        };
+        lemma_montgomery_rhs_as_int(u);
+        assert(montgomery_rhs(u) == math_field_mul(t, eps)) by {
+            lemma_mul_mod_noop_right(ti, di * g_d, pi);
+        };
+
+        // t·ε = r²·(2ε), a product of squares
+        axiom_two_not_square();
+        axiom_nonsquare_times_nonsquare_is_square(2, eps);
+        let s = choose|s: nat| (#[trigger] (s * s) % p()) == (math_field_mul(2, eps) % p());
+        lemma_mod_twice((2 * eps) as int, pi);
+        assert(math_field_square(s) == math_field_mul(2, eps));
+        assert(math_field_mul(t, eps) == math_field_mul(r_sq, math_field_mul(2, eps))) by {
+            lemma_field_mul_comm(2, r_sq);
+            lemma_field_mul_assoc(r_sq, 2, eps);
+        };
+        lemma_product_of_squares_eq_square_of_product(r, s);
+        let y = math_field_mul(r, s);
+        lemma_mod_twice((y * y) as int, pi);
+        assert((y * y) % p() == montgomery_rhs(u) % p());
+    }
+}
+
+} // verus!
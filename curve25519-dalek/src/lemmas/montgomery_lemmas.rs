@@ -5,6 +5,7 @@ use crate::backend::serial::u64::constants;
 use crate::specs::scalar52_specs::*;
 use crate::specs::scalar_specs::*;
 use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
 use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
 
@@ -61,4 +62,37 @@ pub(crate) proof fn lemma_rr_equals_radix_squared()
         == rr_stored);
 }
 
+/// Lemma: for a < L, m is the Montgomery form of a (m ≡ a·R mod L) iff m·R⁻¹ ≡ a (mod L)
+///
+/// ## Mathematical Proof
+/// ```text
+/// m ≡ a·R   ⟹  m·R⁻¹ ≡ a·R·R⁻¹ ≡ a
+/// m·R⁻¹ ≡ a ⟹  a·R ≡ m·R⁻¹·R ≡ m
+/// ```
+pub proof fn lemma_montgomery_form_value(m: nat, a: nat)
+    requires
+        a < group_order(),
+    ensures
+        (m % group_order() == (a * montgomery_radix()) % group_order()) <==> ((m
+            * inv_montgomery_radix()) % group_order() == a),
+{
+    let L = group_order() as int;
+    let R = montgomery_radix() as int;
+    let I = inv_montgomery_radix() as int;
+    lemma_montgomery_inverse();
+    lemma_small_mod(a, group_order());
+    if m % group_order() == (a * montgomery_radix()) % group_order() {
+        lemma_mul_mod_noop_left(m as int, I, L);
+        lemma_mul_mod_noop_left(a * R, I, L);
+        lemma_mul_is_associative(a as int, R, I);
+        lemma_mul_mod_noop_right(a as int, R * I, L);
+    }
+    if (m * inv_montgomery_radix()) % group_order() == a {
+        lemma_mul_mod_noop_left(m * I, R, L);
+        lemma_mul_is_associative(m as int, I, R);
+        lemma_mul_is_commutative(I, R);
+        lemma_mul_mod_noop_right(m as int, I * R, L);
+    }
+}
+
 } // verus!
@@ -0,0 +1,724 @@
+//! Lemmas for the Ristretto group: cosets of the 4-torsion and their encoding
+//!
+//! A Ristretto element is a coset P + E[4] with P in the even subgroup 2E
+//! (`ristretto_specs`). `RistrettoPoint::compress` and `CompressedRistretto::decompress`
+//! are proven to compute `spec_ristretto_encode` and `spec_ristretto_decode`; this
+//! module connects those field computations to the cosets.
+//!
+//! ## Mathematical Background
+//!
+//! E[4] = {T_0, T_2, T_4, T_6} is cyclic, generated by T_2 = [2]T_1, so
+//! T_{2j} + T_{2k} = T_{2(j+k mod 4)} and being in the same coset is an equivalence
+//! relation. These facts are proven from the `EIGHT_TORSION` table and the group law.
+//!
+//! That the encoding is a well-defined injective function on cosets, and that
+//! decoding inverts it, is the main theorem of [Decaf] Section 5, transported to
+//! Curve25519 by the Ristretto construction ([RFC9496] Section 4.3). Its proof goes
+//! through the Jacobi quartic and the 2-isogeny to the Edwards curve, which the
+//! field-level specs here do not model, so it is taken as three axioms:
+//! ```text
+//! P ~ Q in 2E                 ⟹  encode(P) = encode(Q)        [well defined]
+//! P ∈ 2E                      ⟹  decode(encode(P)) ~ P        [decode is a left inverse]
+//! decode(s) = Q               ⟹  Q ∈ 2E, encode(Q) = s        [encode is a left inverse]
+//! ```
+//! Together they make encode a bijection from 2E / E[4] onto the canonical encodings,
+//! with inverse decode.
+//!
+//! The equality test of [RFC9496] Section 4.3.3 compares x1·y2 with y1·x2 and x1·x2
+//! with y1·y2. Points of the same coset pass it, which is proven from the explicit
+//! 4-torsion points; that points of 2E passing it are in the same coset is an axiom.
+//!
+//! The Elligator map of [RFC9496] Section 4.3.4 lands in 2E and is at most 16-to-1
+//! onto cosets (each coset has 4 representatives, each the image of two Jacobi
+//! quartic points, each with at most the two preimages ±r_0). These are
+//! likewise axioms; that the one-way map `from_uniform_bytes` inherits both facts is
+//! proven from them and the group law.
+//!
+//! ## Lemmas
+//!
+//! - `lemma_four_torsion_add`: T_{2j} + T_{2k} = T_{2((j+k) mod 4)}
+//! - `lemma_ristretto_equivalent_reflexive`, `lemma_ristretto_equivalent_symmetric`,
+//!   `lemma_ristretto_equivalent_transitive`: same-coset is an equivalence relation
+//! - `axiom_ristretto_encode_well_defined`, `axiom_ristretto_decode_encode`,
+//!   `axiom_ristretto_encode_decode`: the encoding theorem of [Decaf] / [RFC9496]
+//! - `lemma_ristretto_decode_reduced`: decoded coordinates are reduced
+//! - `lemma_ristretto_encode_injective`: equal encodings mean the same coset
+//! - `lemma_ristretto_decode_injective`: distinct encodings decode to distinct cosets
+//! - `lemma_ristretto_compress_unique`: points of the same coset compress to the same bytes
+//! - `lemma_four_torsion_values`: T_0 = (0, 1), T_2 = (-i, 0), T_4 = (0, -1), T_6 = (i, 0)
+//! - `lemma_ristretto_equivalent_passes_check`, `axiom_ristretto_equality_check_complete`:
+//!   on 2E the equality test decides same-coset
+//! - `lemma_cross_products_projective`: the test can be run on projective coordinates
+//! - `axiom_elligator_ristretto_flavor_valid`, `axiom_elligator_ristretto_flavor_preimages`:
+//!   the Elligator output is a point of 2E, and each coset has at most 16 preimages
+//! - `lemma_even_subgroup_add`: 2E is closed under addition
+//! - `lemma_from_uniform_bytes_preimages_bounded`: for each first half, at most 16
+//!   second halves reach a given coset
+#![allow(unused_imports)]
+use crate::backend::serial::u64::constants::{EDWARDS_D, EIGHT_TORSION, SQRT_M1};
+use crate::core_assumes::seq_from32;
+use crate::lemmas::common_lemmas::to_nat_lemmas::lemma_canonical_bytes_equal;
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::{
+    lemma_affine_to_extended_valid, lemma_negation_preserves_curve,
+};
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::*;
+use crate::lemmas::edwards_lemmas::torsion_lemmas::*;
+use crate::lemmas::field_lemmas::as_bytes_lemmas::lemma_seq_eq_implies_array_eq;
+use crate::lemmas::field_lemmas::field_algebra_lemmas::{
+    lemma_field_inv_one, lemma_field_mul_assoc, lemma_field_mul_comm, lemma_field_mul_left_cancel,
+    lemma_field_mul_nonzero, lemma_field_mul_pair_rearrange,
+};
+use crate::specs::core_specs::*;
+use crate::specs::edwards_specs::*;
+use crate::specs::field_specs::*;
+use crate::specs::field_specs_u64::*;
+use crate::specs::ristretto_specs::*;
+use vstd::arithmetic::div_mod::*;
+use vstd::prelude::*;
+use vstd::set_lib::lemma_len_subset;
+
+verus! {
+
+// =============================================================================
+// Cosets of the 4-torsion
+// =============================================================================
+/// Lemma: T_{2j} + T_{2k} = T_{2((j+k) mod 4)}, and every T_{2j} is a reduced curve point
+///
+/// ## Mathematical Proof
+/// ```text
+/// T_{2j} + T_{2k} = [2j]T_1 + [2k]T_1 = [2j + 2k]T_1 = [(2j + 2k) mod 8]T_1   [[8]T_1 = O]
+///                 = T_{2((j+k) mod 4)}
+/// ```
+pub proof fn lemma_four_torsion_add(j: int, k: int)
+    requires
+        0 <= j < 4,
+        0 <= k < 4,
+    ensures
+        math_on_edwards_curve(ristretto_four_torsion(j).0, ristretto_four_torsion(j).1),
+        ristretto_four_torsion(j).0 < p(),
+        ristretto_four_torsion(j).1 < p(),
+        ({
+            let tj = ristretto_four_torsion(j);
+            let tk = ristretto_four_torsion(k);
+            edwards_add(tj.0, tj.1, tk.0, tk.1) == ristretto_four_torsion((j + k) % 4)
+        }),
+{
+    let t1 = eight_torsion_affine(1);
+    let (a, b, c) = ((2 * j) as nat, (2 * k) as nat, (2 * ((j + k) % 4)) as nat);
+    lemma_eight_torsion_table();
+    lemma_eight_torsion_order();
+    lemma_eight_torsion_is_multiple(a);
+    lemma_eight_torsion_is_multiple(b);
+    lemma_eight_torsion_is_multiple(c);
+    assert(ristretto_four_torsion(j) == eight_torsion_affine(a as int));
+    assert(ristretto_four_torsion(k) == eight_torsion_affine(b as int));
+    assert(ristretto_four_torsion((j + k) % 4) == eight_torsion_affine(c as int));
+
+    lemma_edwards_scalar_mul_on_curve(t1, a);
+    lemma_edwards_scalar_mul_additive(t1, a, b);
+    lemma_edwards_scalar_mul_mod_period(t1, 8, a + b);
+    assert((a + b) % 8 == c);
+}
+
+/// Lemma: every reduced point is in its own coset (P = P + T_0)
+pub proof fn lemma_ristretto_equivalent_reflexive(P: (nat, nat))
+    requires
+        P.0 < p(),
+        P.1 < p(),
+    ensures
+        ristretto_equivalent(P, P),
+{
+    lemma_eight_torsion_table();
+    assert(ristretto_four_torsion(0) == math_edwards_identity());
+    lemma_edwards_add_identity_right(P.0, P.1);
+    assert(P == edwards_add(P.0, P.1, ristretto_four_torsion(0).0, ristretto_four_torsion(0).1));
+}
+
+/// Lemma: Q = P + T_{2j} gives P = Q + T_{2(4-j)}
+///
+/// ## Mathematical Proof
+/// ```text
+/// Q + T_{2(4-j)} = (P + T_{2j}) + T_{2(4-j)} = P + T_0 = P
+/// ```
+pub proof fn lemma_ristretto_equivalent_symmetric(P: (nat, nat), Q: (nat, nat))
+    requires
+        math_on_edwards_curve(P.0, P.1),
+        P.0 < p(),
+        P.1 < p(),
+        ristretto_equivalent(P, Q),
+    ensures
+        ristretto_equivalent(Q, P),
+{
+    let j = choose|j: int|
+        0 <= j < 4 && Q == edwards_add(
+            P.0,
+            P.1,
+            #[trigger] ristretto_four_torsion(j).0,
+            ristretto_four_torsion(j).1,
+        );
+    let k = (4 - j) % 4;
+    let tj = ristretto_four_torsion(j);
+    let tk = ristretto_four_torsion(k);
+    lemma_four_torsion_add(j, k);
+    lemma_four_torsion_add(k, 0);
+    assert((j + k) % 4 == 0);
+
+    // (P + T_{2j}) + T_{2k} = P + (T_{2j} + T_{2k}) = P + T_0 = P
+    axiom_edwards_add_associative(P.0, P.1, tj.0, tj.1, tk.0, tk.1);
+    lemma_eight_torsion_table();
+    assert(ristretto_four_torsion(0) == math_edwards_identity());
+    lemma_edwards_add_identity_right(P.0, P.1);
+    assert(P == edwards_add(Q.0, Q.1, tk.0, tk.1));
+}
+
+/// Lemma: Q = P + T_{2j} and R = Q + T_{2k} give R = P + T_{2((j+k) mod 4)}
+pub proof fn lemma_ristretto_equivalent_transitive(P: (nat, nat), Q: (nat, nat), R: (nat, nat))
+    requires
+        math_on_edwards_curve(P.0, P.1),
+        ristretto_equivalent(P, Q),
+        ristretto_equivalent(Q, R),
+    ensures
+        ristretto_equivalent(P, R),
+{
+    let j = choose|j: int|
+        0 <= j < 4 && Q == edwards_add(
+            P.0,
+            P.1,
+            #[trigger] ristretto_four_torsion(j).0,
+            ristretto_four_torsion(j).1,
+        );
+    let k = choose|k: int|
+        0 <= k < 4 && R == edwards_add(
+            Q.0,
+            Q.1,
+            #[trigger] ristretto_four_torsion(k).0,
+            ristretto_four_torsion(k).1,
+        );
+    let tj = ristretto_four_torsion(j);
+    let tk = ristretto_four_torsion(k);
+    lemma_four_torsion_add(j, k);
+    lemma_four_torsion_add(k, j);
+
+    // (P + T_{2j}) + T_{2k} = P + (T_{2j} + T_{2k})
+    axiom_edwards_add_associative(P.0, P.1, tj.0, tj.1, tk.0, tk.1);
+    let m = (j + k) % 4;
+    assert(R == edwards_add(P.0, P.1, ristretto_four_torsion(m).0, ristretto_four_torsion(m).1));
+}
+
+// =============================================================================
+// The encoding theorem
+// =============================================================================
+/// AXIOM: the encoding depends only on the coset of the point
+///
+/// Mathematical justification:
+/// - [Decaf] Section 5 and [RFC9496] Section 4.3.2: the encoding of (X:Y:Z:T) is
+///   unchanged by rescaling (X:Y:Z:T) and by adding any point of E[4]; rotating by
+///   (i, 0) and the sign choices on x and s select the same representative of the
+///   coset every time
+/// - The flag of the inverse square root is ignored because u1·u2² is a square
+///   for every point of 2E
+///
+/// Used in: `lemma_ristretto_encode_injective`, `lemma_ristretto_decode_injective`
+pub proof fn axiom_ristretto_encode_well_defined(
+    x1: nat,
+    y1: nat,
+    z1: nat,
+    t1: nat,
+    x2: nat,
+    y2: nat,
+    z2: nat,
+    t2: nat,
+)
+    requires
+        math_is_valid_extended_edwards_point(x1, y1, z1, t1),
+        math_is_valid_extended_edwards_point(x2, y2, z2, t2),
+        is_in_even_subgroup(math_extended_as_affine(x1, y1, z1)),
+        ristretto_equivalent(math_extended_as_affine(x1, y1, z1), math_extended_as_affine(x2, y2, z2)),
+    ensures
+        spec_ristretto_encode(x1, y1, z1, t1) == spec_ristretto_encode(x2, y2, z2, t2),
+{
+    admit();  // [Decaf] Section 5, [RFC9496] Section 4.3.2
+}
+
+/// AXIOM: decoding the encoding of a point of 2E gives a point of the same coset
+///
+/// Mathematical justification:
+/// - [RFC9496] Section 4.3: every element of the group has an encoding that
+///   the decoding function accepts, and decoding returns a representative of
+///   that element
+///
+/// Used in: `RistrettoPoint::compress`, `lemma_ristretto_encode_injective`
+pub proof fn axiom_ristretto_decode_encode(x: nat, y: nat, z: nat, t: nat)
+    requires
+        math_is_valid_extended_edwards_point(x, y, z, t),
+        is_in_even_subgroup(math_extended_as_affine(x, y, z)),
+    ensures
+        spec_ristretto_decode(spec_ristretto_encode(x, y, z, t)).is_some(),
+        ristretto_equivalent(
+            math_extended_as_affine(x, y, z),
+            spec_ristretto_decode(spec_ristretto_encode(x, y, z, t)).unwrap(),
+        ),
+{
+    admit();  // [RFC9496] Section 4.3
+}
+
+/// AXIOM: a decoded point lies in 2E and re-encodes to the same field element
+///
+/// Mathematical justification:
+/// - [RFC9496] Section 4.3.1: decoding only accepts canonical encodings, and its
+///   output is the image of a Jacobi quartic point under the 2-isogeny, hence a double
+/// - [Decaf] Section 5: the encoding of a decoded point is the input
+///
+/// Used in: `CompressedRistretto::decompress`, `lemma_ristretto_decode_injective`
+pub proof fn axiom_ristretto_encode_decode(s: nat)
+    requires
+        spec_ristretto_decode(s).is_some(),
+    ensures
+        ({
+            let Q = spec_ristretto_decode(s).unwrap();
+            is_in_even_subgroup(Q) && spec_ristretto_encode(Q.0, Q.1, 1, math_field_mul(Q.0, Q.1))
+                == s
+        }),
+{
+    admit();  // [RFC9496] Section 4.3.1, [Decaf] Section 5
+}
+
+// =============================================================================
+// Consequences
+// =============================================================================
+/// Lemma: decoded coordinates are reduced
+pub proof fn lemma_ristretto_decode_reduced(s: nat)
+    requires
+        spec_ristretto_decode(s).is_some(),
+    ensures
+        spec_ristretto_decode(s).unwrap().0 < p(),
+        spec_ristretto_decode(s).unwrap().1 < p(),
+{
+    let p = p();
+    p_gt_2();
+    // x = |2s·D_x| is a negation or a reduction mod p, and y = u1·D_y is a product mod p
+    assert forall|a: nat| #[trigger] math_field_abs(a) < p by {
+        lemma_mod_bound(a as int, p as int);
+        lemma_mod_bound((p - (a % p)) as int, p as int);
+    };
+    assert forall|a: nat, b: nat| #[trigger] math_field_mul(a, b) < p by {
+        lemma_mod_bound((a * b) as int, p as int);
+    };
+}
+
+/// Lemma: points of 2E with equal encodings are in the same coset
+///
+/// ## Mathematical Proof
+/// ```text
+/// decode(encode(P1)) = Q = decode(encode(P2))   with P1 ~ Q and P2 ~ Q
+/// ⟹ P1 ~ Q ~ P2
+/// ```
+pub proof fn lemma_ristretto_encode_injective(
+    x1: nat,
+    y1: nat,
+    z1: nat,
+    t1: nat,
+    x2: nat,
+    y2: nat,
+    z2: nat,
+    t2: nat,
+)
+    requires
+        math_is_valid_extended_edwards_point(x1, y1, z1, t1),
+        math_is_valid_extended_edwards_point(x2, y2, z2, t2),
+        is_in_even_subgroup(math_extended_as_affine(x1, y1, z1)),
+        is_in_even_subgroup(math_extended_as_affine(x2, y2, z2)),
+        spec_ristretto_encode(x1, y1, z1, t1) == spec_ristretto_encode(x2, y2, z2, t2),
+    ensures
+        ristretto_equivalent(math_extended_as_affine(x1, y1, z1), math_extended_as_affine(x2, y2, z2)),
+{
+    let P1 = math_extended_as_affine(x1, y1, z1);
+    let P2 = math_extended_as_affine(x2, y2, z2);
+    let s = spec_ristretto_encode(x1, y1, z1, t1);
+    let Q = spec_ristretto_decode(s).unwrap();
+    axiom_ristretto_decode_encode(x1, y1, z1, t1);
+    axiom_ristretto_decode_encode(x2, y2, z2, t2);
+
+    // P2 is reduced, so Q ~ P2 follows from P2 ~ Q
+    p_gt_2();
+    lemma_mod_bound((y2 * math_field_inv(z2)) as int, p() as int);
+    lemma_mod_bound((x2 * math_field_inv(z2)) as int, p() as int);
+    lemma_ristretto_equivalent_symmetric(P2, Q);
+    lemma_ristretto_equivalent_transitive(P1, Q, P2);
+}
+
+/// Lemma: field elements that decode to the same coset are equal
+///
+/// ## Mathematical Proof
+/// ```text
+/// s1 = encode(Q1) = encode(Q2) = s2     [axiom_ristretto_encode_decode, Q1 ~ Q2]
+/// ```
+pub proof fn lemma_ristretto_decode_injective(s1: nat, s2: nat)
+    requires
+        spec_ristretto_decode(s1).is_some(),
+        spec_ristretto_decode(s2).is_some(),
+        ristretto_equivalent(spec_ristretto_decode(s1).unwrap(), spec_ristretto_decode(s2).unwrap()),
+    ensures
+        s1 == s2,
+{
+    let Q1 = spec_ristretto_decode(s1).unwrap();
+    let Q2 = spec_ristretto_decode(s2).unwrap();
+    let (t1, t2) = (math_field_mul(Q1.0, Q1.1), math_field_mul(Q2.0, Q2.1));
+    axiom_ristretto_encode_decode(s1);
+    axiom_ristretto_encode_decode(s2);
+    lemma_ristretto_decode_reduced(s1);
+    lemma_ristretto_decode_reduced(s2);
+
+    // (x : y : 1 : xy) is a valid extended point whose affine point is (x, y)
+    lemma_affine_to_extended_valid(Q1.0, Q1.1, t1);
+    lemma_affine_to_extended_valid(Q2.0, Q2.1, t2);
+    lemma_field_inv_one();
+    lemma_small_mod(Q1.0, p());
+    lemma_small_mod(Q1.1, p());
+    lemma_small_mod(Q2.0, p());
+    lemma_small_mod(Q2.1, p());
+    assert(math_extended_as_affine(Q1.0, Q1.1, 1) == Q1);
+    assert(math_extended_as_affine(Q2.0, Q2.1, 1) == Q2);
+
+    axiom_ristretto_encode_well_defined(Q1.0, Q1.1, 1, t1, Q2.0, Q2.1, 1, t2);
+}
+
+/// Lemma: byte strings holding the encodings of two points of the same coset are equal
+///
+/// The postcondition of `RistrettoPoint::compress` pins its bytes to
+/// `spec_ristretto_encode` of the point's field values, so two implementations that
+/// represent the same Ristretto element differently (another Z, or another
+/// representative of the coset) still produce the same bytes.
+///
+/// ## Mathematical Proof
+/// ```text
+/// b1 = encode(P1) = encode(P2) = b2 as integers   [axiom_ristretto_encode_well_defined]
+/// ⟹ b1 = b2 byte for byte                         [lemma_canonical_bytes_equal]
+/// ```
+pub proof fn lemma_ristretto_compress_unique(
+    b1: &[u8; 32],
+    b2: &[u8; 32],
+    x1: nat,
+    y1: nat,
+    z1: nat,
+    t1: nat,
+    x2: nat,
+    y2: nat,
+    z2: nat,
+    t2: nat,
+)
+    requires
+        math_is_valid_extended_edwards_point(x1, y1, z1, t1),
+        math_is_valid_extended_edwards_point(x2, y2, z2, t2),
+        is_in_even_subgroup(math_extended_as_affine(x1, y1, z1)),
+        ristretto_equivalent(math_extended_as_affine(x1, y1, z1), math_extended_as_affine(x2, y2, z2)),
+        bytes32_to_nat(b1) == spec_ristretto_encode(x1, y1, z1, t1),
+        bytes32_to_nat(b2) == spec_ristretto_encode(x2, y2, z2, t2),
+    ensures
+        *b1 == *b2,
+{
+    axiom_ristretto_encode_well_defined(x1, y1, z1, t1, x2, y2, z2, t2);
+    lemma_canonical_bytes_equal(b1, b2);
+    assert(seq_from32(b1) =~= seq_from32(b2));
+    lemma_seq_eq_implies_array_eq(b1, b2);
+}
+
+// =============================================================================
+// The equality test ([RFC9496] Section 4.3.3)
+// =============================================================================
+/// Lemma: T_0 = (0, 1), T_2 = (-i, 0), T_4 = (0, -1), T_6 = (i, 0), with i = SQRT_M1
+pub proof fn lemma_four_torsion_values()
+    ensures
+        ristretto_four_torsion(0) == (0nat, 1nat),
+        ristretto_four_torsion(1) == (math_field_neg(spec_field_element(&SQRT_M1)), 0nat),
+        ristretto_four_torsion(2) == (0nat, math_field_neg(1)),
+        ristretto_four_torsion(3) == (spec_field_element(&SQRT_M1), 0nat),
+{
+    assert(ristretto_four_torsion(0) == (0nat, 1nat)) by (compute);
+    assert(ristretto_four_torsion(1) == (math_field_neg(spec_field_element(&SQRT_M1)), 0nat))
+        by (compute);
+    assert(ristretto_four_torsion(2) == (0nat, math_field_neg(1))) by (compute);
+    assert(ristretto_four_torsion(3) == (spec_field_element(&SQRT_M1), 0nat)) by (compute);
+}
+
+/// Lemma: a point and its translate by a 4-torsion point pass the equality test
+///
+/// ## Mathematical Proof
+/// ```text
+/// (x, y) + (0, m) = (m·x, m·y)   for m = ±1  ⟹  x·(m·y) = y·(m·x)
+/// (x, y) + (c, 0) = (c·y, c·x)   for c = ±i  ⟹  x·(c·y) = y·(c·x)
+/// ```
+/// In both cases t = d·x1·x2·y1·y2 = 0, so both denominators are 1.
+pub proof fn lemma_ristretto_equivalent_passes_check(P: (nat, nat), Q: (nat, nat))
+    requires
+        P.0 < p(),
+        P.1 < p(),
+        ristretto_equivalent(P, Q),
+    ensures
+        ristretto_equality_check(P, Q),
+{
+    let (x, y) = P;
+    let j = choose|j: int|
+        0 <= j < 4 && Q == edwards_add(
+            P.0,
+            P.1,
+            #[trigger] ristretto_four_torsion(j).0,
+            ristretto_four_torsion(j).1,
+        );
+    let T = ristretto_four_torsion(j);
+    let p = p();
+    let d = spec_field_element(&EDWARDS_D);
+    p_gt_2();
+    lemma_four_torsion_values();
+    lemma_small_mod(0, p);
+    lemma_small_mod(1, p);
+    lemma_small_mod(x, p);
+    lemma_small_mod(y, p);
+
+    // Both denominators are 1
+    assert(math_field_add(1, 0) == 1);
+    assert(math_field_sub(1, 0) == 1) by {
+        lemma_mod_add_multiples_vanish(1, p as int);
+    };
+    lemma_field_inv_one();
+    assert(math_field_mul(d, 0) == 0);
+
+    if j == 0 || j == 2 {
+        // T = (0, m)
+        let m = T.1;
+        let (xm, ym) = (math_field_mul(x, m), math_field_mul(y, m));
+        assert(math_field_mul(x, 0) == 0);
+        assert(math_field_mul(y, 0) == 0);
+        assert(math_field_mul(0, ym) == 0);
+        lemma_mod_bound((x * m) as int, p as int);
+        lemma_mod_bound((y * m) as int, p as int);
+        lemma_small_mod(xm, p);
+        lemma_small_mod(ym, p);
+        assert(math_field_add(xm, 0) == xm);
+        assert(math_field_add(ym, 0) == ym);
+        assert(Q == (xm, ym));
+
+        lemma_field_mul_assoc(x, y, m);
+        lemma_field_mul_assoc(y, x, m);
+        lemma_field_mul_comm(x, y);
+        assert(math_field_mul(x, Q.1) == math_field_mul(y, Q.0));
+    } else {
+        // T = (c, 0)
+        let c = T.0;
+        let (xc, yc) = (math_field_mul(x, c), math_field_mul(y, c));
+        assert(math_field_mul(x, 0) == 0);
+        assert(math_field_mul(y, 0) == 0);
+        assert(math_field_mul(xc, 0) == 0);
+        lemma_mod_bound((x * c) as int, p as int);
+        lemma_mod_bound((y * c) as int, p as int);
+        lemma_small_mod(xc, p);
+        lemma_small_mod(yc, p);
+        assert(math_field_add(0, yc) == yc);
+        assert(math_field_add(0, xc) == xc);
+        assert(Q == (yc, xc));
+
+        lemma_field_mul_assoc(x, y, c);
+        lemma_field_mul_assoc(y, x, c);
+        lemma_field_mul_comm(x, y);
+        assert(math_field_mul(x, Q.0) == math_field_mul(y, Q.1));
+    }
+}
+
+/// AXIOM: points of 2E that pass the equality test are in the same coset
+///
+/// Mathematical justification:
+/// - [RFC9496] Section 4.3.3: on 2E the test holds exactly when the two points
+///   represent the same group element
+/// - If x1·y2 = y1·x2 then (x2, y2) = λ·(x1, y1), and the curve equation leaves
+///   λ² = 1 or λ² = 1/(d·x1²·y1²). The second root is P1 plus a point of order 8,
+///   which is not in 2E, so λ = ±1. The second test is the first one after
+///   rotating by (i, 0)
+///
+/// Used in: `RistrettoPoint::ct_eq`
+pub proof fn axiom_ristretto_equality_check_complete(P: (nat, nat), Q: (nat, nat))
+    requires
+        is_in_even_subgroup(P),
+        is_in_even_subgroup(Q),
+        ristretto_equality_check(P, Q),
+    ensures
+        ristretto_equivalent(P, Q),
+{
+    admit();  // [RFC9496] Section 4.3.3
+}
+
+/// Lemma: a1·b2 = b1·a2 exactly when (a1/z1)·(b2/z2) = (b1/z1)·(a2/z2), for z1, z2 ≠ 0
+///
+/// ## Mathematical Proof
+/// ```text
+/// (a1/z1)·(b2/z2) = (a1·b2)·k  and  (b1/z1)·(a2/z2) = (b1·a2)·k,   k = 1/(z1·z2) ≠ 0
+/// ```
+/// and multiplying by k ≠ 0 is injective.
+pub proof fn lemma_cross_products_projective(a1: nat, b1: nat, z1: nat, a2: nat, b2: nat, z2: nat)
+    requires
+        z1 % p() != 0,
+        z2 % p() != 0,
+    ensures
+        (math_field_mul(a1, b2) == math_field_mul(b1, a2)) == (math_field_mul(
+            math_field_mul(a1, math_field_inv(z1)),
+            math_field_mul(b2, math_field_inv(z2)),
+        ) == math_field_mul(
+            math_field_mul(b1, math_field_inv(z1)),
+            math_field_mul(a2, math_field_inv(z2)),
+        )),
+{
+    let p = p();
+    p_gt_2();
+    let (k1, k2) = (math_field_inv(z1), math_field_inv(z2));
+    let k = math_field_mul(k1, k2);
+    let (lhs, rhs) = (math_field_mul(a1, b2), math_field_mul(b1, a2));
+
+    // k1, k2 ≠ 0 since z·k = 1
+    field_inv_property(z1);
+    field_inv_property(z2);
+    lemma_small_mod(0, p);
+    lemma_small_mod(k1, p);
+    lemma_small_mod(k2, p);
+    lemma_field_mul_nonzero(k1, k2);
+    lemma_mod_bound((k1 * k2) as int, p as int);
+    lemma_small_mod(k, p);
+
+    lemma_field_mul_pair_rearrange(a1, b2, k1, k2);
+    lemma_field_mul_pair_rearrange(b1, a2, k1, k2);
+    if math_field_mul(lhs, k) == math_field_mul(rhs, k) {
+        lemma_field_mul_comm(lhs, k);
+        lemma_field_mul_comm(rhs, k);
+        lemma_mod_bound((a1 * b2) as int, p as int);
+        lemma_mod_bound((b1 * a2) as int, p as int);
+        lemma_field_mul_left_cancel(k, lhs, rhs);
+    }
+}
+
+// =============================================================================
+// Elligator map and the one-way map
+// =============================================================================
+/// AXIOM: the Elligator map produces a valid completed point of 2E
+///
+/// Mathematical justification:
+/// - [RFC9496] Section 4.3.4: the map sends r_0 to a point (s, t) of the Jacobi
+///   quartic, and the final step is the 2-isogeny to the Edwards curve, whose image
+///   is 2E; the denominators Z = N_t·sqrt(ad - 1) and T = 1 + s² never vanish
+///
+/// Used in: `RistrettoPoint::elligator_ristretto_flavor`,
+/// `lemma_from_uniform_bytes_preimages_bounded`
+pub proof fn axiom_elligator_ristretto_flavor_valid(r_0: nat)
+    ensures
+        ({
+            let (x, y, z, t) = spec_elligator_ristretto_flavor_completed(r_0);
+            z != 0 && t != 0
+        }),
+        is_in_even_subgroup(spec_elligator_ristretto_flavor(r_0)),
+{
+    admit();  // [RFC9496] Section 4.3.4
+}
+
+/// AXIOM: at most 16 field elements are sent into any one coset
+///
+/// Mathematical justification:
+/// - [RFC9496] Section 4.3.4: a coset has 4 representatives in 2E, each is the image of
+///   two Jacobi quartic points, and each of those has at most one non-negative
+///   Elligator preimage; `elligator_ristretto_flavor_inverse` in `lizard` computes
+///   these 8 candidates
+/// - r_0 and -r_0 have the same image, since r = i·r_0² and s' = -|s·r_0|
+///
+/// Used in: `lemma_from_uniform_bytes_preimages_bounded`
+pub proof fn axiom_elligator_ristretto_flavor_preimages(Q: (nat, nat))
+    ensures
+        elligator_ristretto_preimages(Q).finite(),
+        elligator_ristretto_preimages(Q).len() <= 16,
+{
+    admit();  // [RFC9496] Section 4.3.4
+}
+
+/// Lemma: 2E is closed under addition
+///
+/// ## Mathematical Proof
+/// ```text
+/// [2]A + [2]B = (A + A) + (B + B) = (A + B) + (A + B) = [2](A + B)
+/// ```
+pub proof fn lemma_even_subgroup_add(P: (nat, nat), Q: (nat, nat))
+    requires
+        is_in_even_subgroup(P),
+        is_in_even_subgroup(Q),
+    ensures
+        is_in_even_subgroup(edwards_add(P.0, P.1, Q.0, Q.1)),
+{
+    let A = choose|A: (nat, nat)|
+        math_on_edwards_curve(A.0, A.1) && P == #[trigger] edwards_double(A.0, A.1);
+    let B = choose|B: (nat, nat)|
+        math_on_edwards_curve(B.0, B.1) && Q == #[trigger] edwards_double(B.0, B.1);
+    let C = edwards_add(A.0, A.1, B.0, B.1);
+    axiom_edwards_add_on_curve(P.0, P.1, Q.0, Q.1);
+    axiom_edwards_add_on_curve(A.0, A.1, B.0, B.1);
+    lemma_edwards_add_rearrange(A, A, B, B);
+    assert(edwards_add(P.0, P.1, Q.0, Q.1) == edwards_double(C.0, C.1));
+}
+
+/// Lemma: for a fixed first half r_1, at most 16 second halves r_2 send the
+/// one-way map into the coset of Q
+///
+/// So every coset has at most 16·p preimages (r_1, r_2) among the p² inputs.
+///
+/// ## Mathematical Proof
+/// ```text
+/// E(r_1) + E(r_2) = Q + T_{2j}
+/// ⟹ E(r_2) = -E(r_1) + (Q + T_{2j}) = (Q - E(r_1)) + T_{2j}
+/// ```
+/// so r_2 is an Elligator preimage of the coset of Q - E(r_1), of which there are
+/// at most 16.
+pub proof fn lemma_from_uniform_bytes_preimages_bounded(r_1: nat, Q: (nat, nat))
+    requires
+        math_on_edwards_curve(Q.0, Q.1),
+    ensures
+        from_uniform_bytes_preimages(r_1, Q).finite(),
+        from_uniform_bytes_preimages(r_1, Q).len() <= 16,
+{
+    let P1 = spec_elligator_ristretto_flavor(r_1);
+    let N = (math_field_neg(P1.0), P1.1);
+    let Q2 = edwards_add(N.0, N.1, Q.0, Q.1);
+    p_gt_2();
+    axiom_elligator_ristretto_flavor_valid(r_1);
+    lemma_negation_preserves_curve(P1.0, P1.1);
+    // -E(r_1) + E(r_1) = O
+    axiom_edwards_add_inverse(P1.0, P1.1);
+    lemma_edwards_add_commutative(P1.0, P1.1, N.0, N.1);
+
+    assert forall|r_2: nat| #[trigger]
+        from_uniform_bytes_preimages(r_1, Q).contains(r_2) implies elligator_ristretto_preimages(
+        Q2,
+    ).contains(r_2) by {
+        let P2 = spec_elligator_ristretto_flavor(r_2);
+        let S = edwards_add(P1.0, P1.1, P2.0, P2.1);
+        let j = choose|j: int|
+            0 <= j < 4 && S == edwards_add(
+                Q.0,
+                Q.1,
+                #[trigger] ristretto_four_torsion(j).0,
+                ristretto_four_torsion(j).1,
+            );
+        let tj = ristretto_four_torsion(j);
+        axiom_elligator_ristretto_flavor_valid(r_2);
+        lemma_four_torsion_add(j, 0);
+
+        // (-E(r_1) + E(r_1)) + E(r_2) = O + E(r_2) = E(r_2)
+        axiom_edwards_add_associative(N.0, N.1, P1.0, P1.1, P2.0, P2.1);
+        lemma_edwards_add_commutative(0, 1, P2.0, P2.1);
+        let (x, y, z, t) = spec_elligator_ristretto_flavor_completed(r_2);
+        lemma_mod_bound((x * math_field_inv(z)) as int, p() as int);
+        lemma_mod_bound((y * math_field_inv(t)) as int, p() as int);
+        lemma_edwards_add_identity_right(P2.0, P2.1);
+
+        // -E(r_1) + (Q + T_{2j}) = (Q - E(r_1)) + T_{2j}
+        axiom_edwards_add_associative(N.0, N.1, Q.0, Q.1, tj.0, tj.1);
+        assert(P2 == edwards_add(Q2.0, Q2.1, tj.0, tj.1));
+    };
+    axiom_elligator_ristretto_flavor_preimages(Q2);
+    assert(from_uniform_bytes_preimages(r_1, Q).subset_of(elligator_ristretto_preimages(Q2)));
+    lemma_len_subset(from_uniform_bytes_preimages(r_1, Q), elligator_ristretto_preimages(Q2));
+}
+
+} // verus!
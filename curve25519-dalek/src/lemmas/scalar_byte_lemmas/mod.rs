@@ -1,2 +1,3 @@
 pub mod bytes_to_scalar_lemmas;
+pub mod random_scalar_lemmas;
 pub mod scalar_to_bytes_lemmas;
@@ -0,0 +1,146 @@
+//! Proofs about the rejection sampling in `Scalar::random_canonical`
+//!
+//! See `specs::rng_specs` for the RNG model and how the counts below translate into
+//! probabilities.
+//!
+//! The main lemmas are:
+//! - `lemma_random_canonical_candidate_bytes`: clearing the top 3 bits of byte 31 keeps
+//!   the low 253 bits of the prefix
+//! - `lemma_random_canonical_uniform`: every v < ℓ is returned for exactly 8 prefixes
+//! - `lemma_random_canonical_acceptance`: 8ℓ prefixes are accepted, more than half
+#![allow(unused)]
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+use super::super::common_lemmas::counting_lemmas::*;
+use super::super::common_lemmas::pow_lemmas::*;
+use super::super::common_lemmas::to_nat_lemmas::*;
+use super::super::scalar_lemmas::lemma_pow252;
+
+use crate::specs::core_specs::*;
+use crate::specs::rng_specs::*;
+use crate::specs::scalar52_specs::*;
+
+verus! {
+
+/// Lemma: 2^252 < ℓ < 2^253, so 2ℓ > 2^253
+proof fn lemma_group_order_between_pow2_252_and_253()
+    ensures
+        pow2(252) < group_order(),
+        group_order() < pow2(253),
+        2 * group_order() > pow2(253),
+{
+    lemma_pow252();
+    lemma2_to64();
+    lemma_pow2_adds(1, 252);
+    assert(27742317777372353535851937790883648493nat
+        < 0x1000000000000000000000000000000000000000000000000000000000000000) by (compute_only);
+}
+
+/// Lemma: the candidate bytes hold the low 253 bits of the draw prefix
+///
+/// ## Mathematical Proof
+/// With hi = prefix[31] = (hi & 31) + 32 · (hi >> 5):
+/// ```text
+/// prefix = candidate + (hi >> 5) · 32 · 2^248 = candidate + (hi >> 5) · 2^253
+/// candidate = lo + (hi & 31) · 2^248 < 2^248 + 31 · 2^248 = 2^253
+/// ```
+/// so candidate is prefix mod 2^253.
+pub proof fn lemma_random_canonical_candidate_bytes(prefix: &[u8; 32], candidate: &[u8; 32])
+    requires
+        is_random_canonical_candidate_bytes(prefix, candidate),
+    ensures
+        bytes32_to_nat(candidate) == random_canonical_candidate(bytes32_to_nat(prefix)),
+{
+    let hi = prefix[31];
+    let lo_bits = candidate[31];
+    let top = (hi >> 5u8) as nat;
+    lemma2_to64();
+    lemma_pow2_adds(5, 248);
+
+    assert(hi == (hi & 31u8) + 32 * (hi >> 5u8) && (hi & 31u8) <= 31) by (bit_vector);
+    assert(lo_bits == hi & 31u8);
+
+    // The two sums agree on bytes 0..30
+    assert(forall|j: int| 0 <= j < 31 ==> #[trigger] candidate[j] == prefix[j]);
+    assert(bytes32_to_nat(prefix) == bytes32_to_nat(candidate) + ((hi as nat) - (lo_bits as nat))
+        * pow2(248));
+    assert(bytes32_to_nat(prefix) == bytes32_to_nat(candidate) + top * pow2(253))
+        by (nonlinear_arith)
+        requires
+            bytes32_to_nat(prefix) == bytes32_to_nat(candidate) + ((hi as nat) - (lo_bits as nat))
+                * pow2(248),
+            hi as nat == lo_bits as nat + 32 * top,
+            pow2(253) == 32 * pow2(248),
+    ;
+
+    // candidate < 2^253
+    let lo = bytes_to_nat_prefix(candidate@, 31);
+    assert(bytes32_to_nat(candidate) == lo + (lo_bits as nat) * pow2(248)) by {
+        lemma_bytes32_to_nat_equals_rec(candidate);
+        lemma_decomposition_prefix_rec(candidate, 31);
+        reveal_with_fuel(bytes32_to_nat_rec, 2);
+    };
+    assert(lo < pow2(248)) by {
+        lemma_bytes_to_nat_prefix_bounded(candidate@, 31);
+    };
+    assert(bytes32_to_nat(candidate) < pow2(253)) by (nonlinear_arith)
+        requires
+            bytes32_to_nat(candidate) == lo + (lo_bits as nat) * pow2(248),
+            lo < pow2(248),
+            lo_bits <= 31,
+            pow2(253) == 32 * pow2(248),
+    ;
+
+    lemma_pow2_pos(253);
+    lemma_mod_multiples_vanish(top as int, bytes32_to_nat(candidate) as int, pow2(253) as int);
+    lemma_small_mod(bytes32_to_nat(candidate), pow2(253));
+}
+
+/// Lemma: every v < ℓ is returned by a round for exactly 8 of the 2^256 draw prefixes
+///
+/// ## Mathematical Proof
+/// The round returns v for x iff x mod 2^253 = v. Among the r < 2^253 exactly one is v,
+/// and [0, 2^256) consists of 8 blocks of 2^253, so 8 prefixes qualify.
+pub proof fn lemma_random_canonical_uniform(v: nat)
+    requires
+        v < group_order(),
+    ensures
+        count_draws_32(random_canonical_outputs(v)) == 8,
+{
+    let p = random_canonical_outputs(v);
+    let q = |r: nat| r == v;
+    lemma_group_order_between_pow2_252_and_253();
+    lemma_pow2_pos(253);
+    assert forall|x: nat| #[trigger] p(x) == q(x % pow2(253)) by {}
+    lemma_count_range_single(pow2(253), v, q);
+    lemma_count_range_periodic(8, pow2(253), p, q);
+    lemma2_to64();
+    lemma_pow2_adds(3, 253);
+}
+
+/// Lemma: a round accepts 8ℓ of the 2^256 draw prefixes, which is more than half
+///
+/// ## Mathematical Proof
+/// The round accepts x iff x mod 2^253 < ℓ. Exactly ℓ of the r < 2^253 are below ℓ,
+/// so 8ℓ prefixes are accepted over the 8 blocks, and 2 · 8ℓ > 8 · 2^253 = 2^256
+/// because 2ℓ > 2^253.
+pub proof fn lemma_random_canonical_acceptance()
+    ensures
+        count_draws_32(random_canonical_accepted()) == 8 * group_order(),
+        2 * count_draws_32(random_canonical_accepted()) > pow2(256),
+{
+    let p = random_canonical_accepted();
+    let q = |r: nat| r < group_order();
+    lemma_group_order_between_pow2_252_and_253();
+    lemma_pow2_pos(253);
+    assert forall|x: nat| #[trigger] p(x) == q(x % pow2(253)) by {}
+    lemma_count_range_below(pow2(253), group_order(), q);
+    lemma_count_range_periodic(8, pow2(253), p, q);
+    lemma2_to64();
+    lemma_pow2_adds(3, 253);
+}
+
+} // verus!
@@ -7,6 +7,8 @@ use crate::backend::serial::u64::constants;
 #[allow(unused_imports)]
 use crate::backend::serial::u64::scalar::Scalar52;
 #[allow(unused_imports)]
+use crate::scalar::Scalar;
+#[allow(unused_imports)]
 use crate::specs::scalar52_specs::*;
 #[allow(unused_imports)]
 use crate::specs::scalar_specs::*;
@@ -1902,6 +1904,105 @@ pub proof fn lemma_is_canonical_correctness(self_bytes: &[u8; 32], reduced_bytes
     }
 }
 
+/// Zero Montgomery squarings leave the value unchanged
+pub proof fn lemma_montgomery_square_chain_zero(y0: nat)
+    ensures
+        is_montgomery_square_chain(y0, y0, 0),
+{
+    lemma2_to64();
+    lemma_pow0(montgomery_radix() as int);
+    lemma_pow1(y0 as int);
+    assert(y0 * 1 == y0);
+}
+
+/// A single Montgomery squaring is a chain of length one
+pub proof fn lemma_montgomery_square_chain_one(y: nat, y0: nat)
+    requires
+        (y * montgomery_radix()) % group_order() == (y0 * y0) % group_order(),
+    ensures
+        is_montgomery_square_chain(y, y0, 1),
+{
+    lemma2_to64();
+    lemma_pow1(montgomery_radix() as int);
+    lemma_square_is_pow2(y0 as int);
+}
+
+/// Chains of Montgomery squarings compose: `j` squarings followed by `k` squarings
+/// is a chain of `j + k` squarings.
+///
+/// Key identity: 2^(j+k) - 1 = (2^k - 1) + (2^j - 1) * 2^k, so
+/// y * R^(2^(j+k) - 1) = (y * R^(2^k - 1)) * (R^(2^j - 1))^(2^k)
+///                     ≡ (y_mid * R^(2^j - 1))^(2^k) ≡ (y0^(2^j))^(2^k) = y0^(2^(j+k))
+pub proof fn lemma_montgomery_square_chain_compose(y: nat, y_mid: nat, y0: nat, j: nat, k: nat)
+    requires
+        is_montgomery_square_chain(y_mid, y0, j),
+        is_montgomery_square_chain(y, y_mid, k),
+    ensures
+        is_montgomery_square_chain(y, y0, j + k),
+{
+    let R = montgomery_radix() as int;
+    let L = group_order() as int;
+    let pj = pow2(j);
+    let pk = pow2(k);
+    let a = pow(R, (pj - 1) as nat);
+    let b = pow(R, (pk - 1) as nat);
+    let e = (pow2(j + k) - 1) as nat;
+
+    assert(L > 0 && R > 0) by {
+        lemma_pow2_pos(252);
+        lemma_pow2_pos(260);
+    }
+    assert(pj > 0 && pk > 0) by {
+        lemma_pow2_pos(j);
+        lemma_pow2_pos(k);
+    }
+    assert(a >= 0 && b >= 0) by {
+        lemma_pow_nonnegative(R, (pj - 1) as nat);
+        lemma_pow_nonnegative(R, (pk - 1) as nat);
+    }
+
+    // Exponent bookkeeping: R^(2^(j+k) - 1) == b * a^(2^k)
+    assert(pow(R, e) == b * pow(a, pk)) by {
+        lemma_pow2_adds(j, k);
+        assert(e == (pk - 1) + (pj - 1) * pk) by (nonlinear_arith)
+            requires
+                e == pj * pk - 1,
+                pj > 0,
+                pk > 0,
+        ;
+        lemma_pow_multiplies(R, (pj - 1) as nat, pk);
+        lemma_pow_adds(R, (pk - 1) as nat, ((pj - 1) * pk) as nat);
+    }
+
+    // Outer chain: y * b ≡ y_mid^(2^k)
+    assert((y as int * b) % L == pow(y_mid as int, pk) % L);
+    // Inner chain lifted to the 2^k-th power: (y_mid * a)^(2^k) ≡ (y0^(2^j))^(2^k)
+    assert(pow(y_mid as int * a, pk) % L == pow(pow(y0 as int, pj), pk) % L) by {
+        assert((y_mid as int * a) % L == pow(y0 as int, pj) % L);
+        lemma_pow_mod_congruent(y_mid as int * a, pow(y0 as int, pj), pk, L);
+    }
+
+    calc! {
+        (==)
+        (y as int * pow(R, e)) % L; {
+            lemma_mul_is_associative(y as int, b, pow(a, pk));
+        }
+        ((y as int * b) * pow(a, pk)) % L; {
+            lemma_mul_mod_noop_left(y as int * b, pow(a, pk), L);
+            lemma_mul_mod_noop_left(pow(y_mid as int, pk), pow(a, pk), L);
+        }
+        (pow(y_mid as int, pk) * pow(a, pk)) % L; {
+            lemma_pow_distributes(y_mid as int, a, pk);
+        }
+        pow(y_mid as int * a, pk) % L; {}
+        pow(pow(y0 as int, pj), pk) % L; {
+            lemma_pow_multiplies(y0 as int, pj, pk);
+            lemma_pow2_adds(j, k);
+        }
+        pow(y0 as int, pow2(j + k)) % L;
+    }
+}
+
 /// Lemma: Montgomery squaring preserves the squares property
 /// Key insight: 2^(k+1) - 1 = 2*(2^k - 1) + 1, so R^(2^(k+1) - 1) = R * (R^(2^k - 1))^2
 pub proof fn lemma_square_multiply_step(new_y: nat, y_before: nat, y0: nat, R: nat, L: nat, k: nat)
@@ -1999,6 +2100,35 @@ pub proof fn lemma_canonical_bytes_high_bit_clear(bytes: &[u8; 32])
     }
 }
 
+/// Scalar invariant #1 (high bit clear) is equivalent to the scalar being less than 2^255
+pub proof fn lemma_scalar_high_bit_clear_iff_lt_pow2_255(s: &Scalar)
+    ensures
+        scalar_high_bit_clear(s) == (bytes32_to_nat(&s.bytes) < pow2(255)),
+{
+    lemma_pow2_adds(7, 248);
+    lemma2_to64();
+    if s.bytes[31] <= 127 {
+        // The low 31 bytes contribute less than 2^248, the top byte at most 127 * 2^248
+        assert(bytes32_to_nat(&s.bytes) < pow2(255)) by {
+            lemma_bytes32_to_nat_equals_rec(&s.bytes);
+            lemma_decomposition_prefix_rec(&s.bytes, 31);
+            lemma_bytes_to_nat_prefix_bounded(s.bytes@, 31);
+            reveal_with_fuel(bytes32_to_nat_rec, 2);
+            let lo = bytes_to_nat_prefix(s.bytes@, 31);
+            let hi = s.bytes[31] as nat;
+            assert(lo + hi * pow2(248) < pow2(255)) by (nonlinear_arith)
+                requires
+                    lo < pow2(248),
+                    hi <= 127,
+                    pow2(255) == 128 * pow2(248),
+            ;
+        }
+    } else {
+        lemma_bytes32_to_nat_lower_bound(&s.bytes, 31);
+        lemma_mul_inequality(128, s.bytes[31] as int, pow2(248) as int);
+    }
+}
+
 /// Proves that Scalar52::ZERO has bounded limbs (all limbs are 0 < 2^52)
 /// and that its natural number value is 0
 pub proof fn lemma_zero_bounded(z: Scalar52)
@@ -2239,4 +2369,115 @@ pub proof fn lemma_invert_correctness(self_val: nat, mont_val: nat, inv_val: nat
     }
 }
 
+/// The little-endian bytes of `BASEPOINT_ORDER_PRIVATE` encode exactly the group order ℓ
+pub proof fn lemma_basepoint_order_bytes_equals_group_order()
+    ensures
+        bytes32_to_nat(&crate::constants::BASEPOINT_ORDER_PRIVATE.bytes) == group_order(),
+{
+    let b = crate::constants::BASEPOINT_ORDER_PRIVATE.bytes;
+
+    // Bytes 16..=30 are zero and contribute nothing
+    assert forall|i: nat| 16 <= i < 31 implies (b[i as int] as nat) * #[trigger] pow2(i * 8)
+        == 0 by {
+        lemma_mul_basics(pow2(i * 8) as int);
+    }
+
+    // Concrete powers of two for the low 16 bytes
+    lemma2_to64();
+    lemma2_to64_rest();
+    assert(pow2(72) == 0x1000000000000000000) by {
+        lemma_pow2_adds(64, 8);
+    }
+    assert(pow2(80) == 0x100000000000000000000) by {
+        lemma_pow2_adds(64, 16);
+    }
+    assert(pow2(88) == 0x10000000000000000000000) by {
+        lemma_pow2_adds(64, 24);
+    }
+    assert(pow2(96) == 0x1000000000000000000000000) by {
+        lemma_pow2_adds(64, 32);
+    }
+    assert(pow2(104) == 0x100000000000000000000000000) by {
+        lemma_pow2_adds(64, 40);
+    }
+    assert(pow2(112) == 0x10000000000000000000000000000) by {
+        lemma_pow2_adds(64, 48);
+    }
+    assert(pow2(120) == 0x1000000000000000000000000000000) by {
+        lemma_pow2_adds(64, 56);
+    }
+
+    // The top byte is 0x10, contributing 2^4 * 2^248 = 2^252
+    assert((b[31] as nat) * pow2(248) == pow2(252)) by {
+        lemma_pow2_adds(4, 248);
+    }
+
+    assert(bytes32_to_nat(&b) == pow2(252) + 0x14def9dea2f79cd65812631a5cf5d3ed);
+    assert(0x14def9dea2f79cd65812631a5cf5d3ed == 27742317777372353535851937790883648493nat);
+}
+
+/// One step of the byte-wise borrow chain computing `a - l`.
+///
+/// With `t = 256 + a[i] - l[i] - borrow`, bit 8 of `t` is set exactly when byte `i` does not
+/// borrow, so `1 - (t >> 8)` is the outgoing borrow. The borrow out of the first `i + 1` bytes
+/// is set iff the `(i + 1)`-byte prefix of `a` is smaller than that of `l`.
+pub proof fn lemma_borrow_propagation_step(a: Seq<u8>, l: Seq<u8>, i: nat, borrow: u8, t: u16)
+    requires
+        i < a.len(),
+        i < l.len(),
+        borrow <= 1,
+        (borrow == 1) == (bytes_to_nat_prefix(a, i) < bytes_to_nat_prefix(l, i)),
+        t == 256 + a[i as int] - l[i as int] - borrow,
+    ensures
+        t >> 8 <= 1,
+        ((1 - (t >> 8)) == 1) == (bytes_to_nat_prefix(a, i + 1) < bytes_to_nat_prefix(l, i + 1)),
+{
+    let pa = bytes_to_nat_prefix(a, i);
+    let pl = bytes_to_nat_prefix(l, i);
+    let w = pow2(i * 8);
+    let ai = a[i as int] as nat;
+    let li = l[i as int] as nat;
+
+    assert(t >> 8 == t / 256) by (bit_vector);
+    assert(t < 512);
+
+    assert(pa < w && pl < w) by {
+        lemma_bytes_to_nat_prefix_bounded(a, i);
+        lemma_bytes_to_nat_prefix_bounded(l, i);
+    }
+
+    // Unfold one step of the prefix sums
+    assert(bytes_to_nat_prefix(a, i + 1) == pa + w * ai);
+    assert(bytes_to_nat_prefix(l, i + 1) == pl + w * li);
+
+    if ai < li {
+        assert(t / 256 == 0);
+        assert(pa + w * ai < pl + w * li) by (nonlinear_arith)
+            requires
+                pa < w,
+                ai + 1 <= li,
+        ;
+    } else if ai > li {
+        assert(t / 256 == 1);
+        assert(pa + w * ai > pl + w * li) by (nonlinear_arith)
+            requires
+                pl < w,
+                li + 1 <= ai,
+        ;
+    } else {
+        assert(t / 256 == 1 - borrow);
+    }
+}
+
+/// `seq_to_nat_52` is the radix-2^52 instance of the backend-agnostic `seq_to_nat_radix`
+pub proof fn lemma_seq_to_nat_52_equals_radix(limbs: Seq<nat>)
+    ensures
+        seq_to_nat_52(limbs) == seq_to_nat_radix(limbs, 52),
+    decreases limbs.len(),
+{
+    if limbs.len() > 0 {
+        lemma_seq_to_nat_52_equals_radix(limbs.subrange(1, limbs.len() as int));
+    }
+}
+
 } // verus!
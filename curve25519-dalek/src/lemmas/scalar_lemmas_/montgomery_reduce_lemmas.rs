@@ -9,22 +9,12 @@ use super::super::common_lemmas::div_mod_lemmas::*;
 use super::super::common_lemmas::mul_lemmas::*;
 use super::super::common_lemmas::pow_lemmas::*;
 use super::super::common_lemmas::shift_lemmas::*;
+use super::super::common_lemmas::u128_lemmas::*;
 
 use crate::specs::field_specs_u64::*;
 
 verus! {
 
-// NOT IN VSTD YET
-pub broadcast proof fn lemma_u128_shl_is_mul(x: u128, shift: u128)
-    requires
-        0 <= shift < <u128>::BITS,
-        x * pow2(shift as nat) <= <u128>::MAX,
-    ensures
-        #[trigger] (x << shift) == x * pow2(shift as nat),
-{
-    assume(false);
-}
-
 pub proof fn lemma_part2_bounds(sum: u128)
     ensures
         ({
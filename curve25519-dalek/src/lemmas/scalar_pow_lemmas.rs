@@ -0,0 +1,89 @@
+//! Lemmas for `Scalar::pow_u256` and `Scalar::pow_u256_vartime`
+//!
+//! Both run left-to-right square-and-multiply over the bits of the exponent, most
+//! significant first. The loop keeps a ghost exponent e, the bits read so far, with
+//! acc ≡ x^e (mod ℓ); each step doubles e and adds the next bit.
+//!
+//! - `lemma_pow_square_step`: squaring acc doubles e
+//! - `lemma_pow_mul_step`: multiplying by x^bit adds the bit to e
+//! - `lemma_exponent_bit_step`: w / 2^j = 2·(w / 2^(j+1)) + bit j of w
+#![allow(unused)]
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power::*;
+use vstd::arithmetic::power2::*;
+use vstd::bits::*;
+use vstd::prelude::*;
+
+use super::common_lemmas::pow_lemmas::*;
+
+verus! {
+
+/// Lemma: if a ≡ x^e (mod m) then a² ≡ x^(2e) (mod m)
+pub proof fn lemma_pow_square_step(x: int, e: nat, a: int, r: int, m: int)
+    requires
+        m > 0,
+        a % m == pow(x, e) % m,
+        r % m == (a * a) % m,
+    ensures
+        r % m == pow(x, 2 * e) % m,
+{
+    let p = pow(x, e);
+    lemma_pow_adds(x, e, e);
+    assert(e + e == 2 * e);
+    lemma_mul_mod_noop(a, a, m);
+    lemma_mul_mod_noop(p, p, m);
+}
+
+/// Lemma: if a ≡ x^e (mod m) then a·f ≡ x^(e + bit) (mod m), where f is x if the
+/// bit is set and 1 otherwise
+pub proof fn lemma_pow_mul_step(x: int, e: nat, a: int, f: int, r: int, m: int, bit: nat)
+    requires
+        m > 0,
+        bit <= 1,
+        f == if bit == 1 {
+            x
+        } else {
+            1
+        },
+        a % m == pow(x, e) % m,
+        r % m == (a * f) % m,
+    ensures
+        r % m == pow(x, e + bit) % m,
+{
+    if bit == 1 {
+        let p = pow(x, e);
+        lemma_pow_adds(x, e, 1);
+        lemma_pow1(x);
+        lemma_mul_mod_noop(a, x, m);
+        lemma_mul_mod_noop(p, x, m);
+    }
+}
+
+/// Lemma: the bits of w above j are twice the bits above j + 1, plus bit j
+///
+/// ## Mathematical Proof
+/// ```text
+/// q = w / 2^j = w >> j,   w / 2^(j+1) = q / 2
+/// q = 2·(q / 2) + q % 2   and   q % 2 = q & 1
+/// ```
+pub proof fn lemma_exponent_bit_step(w: u64, j: u64)
+    requires
+        j < 64,
+    ensures
+        (w as nat) / pow2(j as nat) == 2 * ((w as nat) / pow2((j + 1) as nat)) + ((w >> j) & 1) as nat,
+{
+    let q = w >> j;
+    assert(q == (w as nat) / pow2(j as nat)) by {
+        lemma_u64_pow2_le_max(j as nat);
+        lemma_u64_shr_is_div(w, j);
+    };
+    assert(q & 1 == q % 2) by (bit_vector);
+    lemma_pow2_pos(j as nat);
+    lemma_pow2_adds(j as nat, 1);
+    lemma2_to64();
+    lemma_div_denominator(w as int, pow2(j as nat) as int, 2);
+    lemma_fundamental_div_mod(q as int, 2);
+}
+
+} // verus!
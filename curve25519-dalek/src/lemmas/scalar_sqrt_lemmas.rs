@@ -0,0 +1,161 @@
+//! Lemmas for `Scalar::is_square` and `Scalar::sqrt`
+//!
+//! ## Mathematical Background
+//!
+//! ℓ is prime and ℓ ≡ 5 (mod 8), so square roots mod ℓ work as for the field
+//! modulus p (Atkin's method):
+//! ```text
+//! a ≠ 0:  a^((ℓ-1)/2) = 1 if a is a square, -1 otherwise     [Euler's criterion]
+//! a square:  r = a^((ℓ+3)/8) has r² = a · a^((ℓ-1)/4) = ±a
+//! i = 2^((ℓ-1)/4) has i² = 2^((ℓ-1)/2) = -1                  [2 is not a square, ℓ ≡ 5 mod 8]
+//! r² = -a  ⟹  (r·i)² = a
+//! ```
+//! The number theory is taken as axioms: primality of ℓ, Euler's criterion, that 2
+//! is not a square (the second supplement to quadratic reciprocity), and that
+//! a^((ℓ-1)/4) = ±1 for a square a (a square root of 1 mod a prime is ±1).
+//!
+//! ## Lemmas
+//!
+//! - `axiom_group_order_is_prime`, `axiom_euler_criterion_mod_l`,
+//!   `axiom_two_is_not_square_mod_l`, `axiom_sqrt_candidate_mod_l`: the facts above
+//! - `lemma_sqrt_m1_mod_l`: 2^((ℓ-1)/4) squares to -1
+//! - `lemma_sqrt_fix_sign`: r² = -a and i² = -1 give (r·i)² = a
+#![allow(unused)]
+use vstd::arithmetic::div_mod::*;
+use vstd::arithmetic::mul::*;
+use vstd::arithmetic::power::*;
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+use super::scalar_lemmas::lemma_pow252;
+use crate::specs::primality_specs::*;
+use crate::specs::scalar52_specs::*;
+use crate::specs::scalar_specs::*;
+
+verus! {
+
+// =============================================================================
+// Axioms
+// =============================================================================
+/// AXIOM: the group order ℓ = 2^252 + 27742317777372353535851937790883648493 is prime
+pub proof fn axiom_group_order_is_prime()
+    ensures
+        is_prime(group_order()),
+{
+    admit();  // Mathematical fact, see the Ed25519 paper, Section 1
+}
+
+/// AXIOM: Euler's criterion modulo ℓ
+///
+/// Mathematical justification:
+/// - (Z/ℓ)* is cyclic of even order ℓ-1, so for a ≠ 0, e = a^((ℓ-1)/2) satisfies
+///   e² = 1, hence e = ±1, and e = 1 exactly on the squares
+pub proof fn axiom_euler_criterion_mod_l(a: nat)
+    requires
+        a % group_order() != 0,
+    ensures
+        ({
+            let e = pow(a as int, ((group_order() - 1) / 2) as nat) % (group_order() as int);
+            &&& e == 1 || e == group_order() - 1
+            &&& (e == 1) == is_square_mod_l(a)
+        }),
+{
+    admit();  // Euler's criterion, ℓ prime
+}
+
+/// AXIOM: 2 is not a square modulo ℓ
+///
+/// Mathematical justification:
+/// - Second supplement to quadratic reciprocity: 2 is a square mod an odd prime q
+///   iff q ≡ ±1 (mod 8), and ℓ ≡ 5 (mod 8)
+pub proof fn axiom_two_is_not_square_mod_l()
+    ensures
+        !is_square_mod_l(2),
+{
+    admit();  // Quadratic reciprocity, ℓ ≡ 5 (mod 8)
+}
+
+/// AXIOM: for a square a, r = a^((ℓ+3)/8) satisfies r² = ±a (mod ℓ)
+///
+/// Mathematical justification:
+/// - r² = a^((ℓ+3)/4) = a · a^((ℓ-1)/4), and (a^((ℓ-1)/4))² = a^((ℓ-1)/2) = 1 for a
+///   nonzero square [Euler's criterion]. A square root of 1 mod a prime is ±1.
+/// - For a = 0, r = 0.
+pub proof fn axiom_sqrt_candidate_mod_l(a: nat)
+    requires
+        is_square_mod_l(a),
+    ensures
+        ({
+            let l = group_order() as int;
+            let r = pow(a as int, ((group_order() + 3) / 8) as nat) % l;
+            (r * r) % l == (a as int) % l || (r * r) % l == (l - (a as int) % l) % l
+        }),
+{
+    admit();  // Atkin's square root, ℓ ≡ 5 (mod 8)
+}
+
+// =============================================================================
+// Consequences
+// =============================================================================
+/// Lemma: i = 2^((ℓ-1)/4) is a square root of -1 modulo ℓ
+///
+/// ## Mathematical Proof
+/// ```text
+/// i² = 2^(2·(ℓ-1)/4) = 2^((ℓ-1)/2) = -1     [Euler's criterion, 2 is not a square]
+/// ```
+pub proof fn lemma_sqrt_m1_mod_l()
+    ensures
+        ({
+            let l = group_order() as int;
+            let i = pow(2, ((group_order() - 1) / 4) as nat) % l;
+            (i * i) % l == l - 1
+        }),
+{
+    let l = group_order() as int;
+    let k = ((group_order() - 1) / 4) as nat;
+    let q = pow(2, k);
+    lemma_pow252();
+    assert(k + k == ((group_order() - 1) / 2) as nat);
+    assert(2 % group_order() != 0) by {
+        lemma_small_mod(2, group_order());
+    };
+    axiom_euler_criterion_mod_l(2);
+    axiom_two_is_not_square_mod_l();
+    lemma_pow_adds(2, k, k);
+    lemma_mul_mod_noop(q, q, l);
+}
+
+/// Lemma: if r² = -a and i² = -1 (mod ℓ) then (r·i)² = a, for a reduced
+///
+/// ## Mathematical Proof
+/// ```text
+/// (r·i)² = r²·i² = (-a)·(-1) = a
+/// ```
+pub proof fn lemma_sqrt_fix_sign(a: int, r: int, i: int)
+    requires
+        0 <= a < group_order(),
+        (r * r) % (group_order() as int) == (group_order() - a) % (group_order() as int),
+        (i * i) % (group_order() as int) == group_order() - 1,
+    ensures
+        ((r * i) * (r * i)) % (group_order() as int) == a,
+{
+    let l = group_order() as int;
+    lemma_pow252();
+    assert((r * i) * (r * i) == (r * r) * (i * i)) by (nonlinear_arith);
+    lemma_mul_mod_noop(r * r, i * i, l);
+    if a == 0 {
+        lemma_mod_self_0(l);
+        assert((r * r) % l == 0);
+        lemma_mul_mod_noop_left(r * r, i * i, l);
+        lemma_small_mod(0, l as nat);
+    } else {
+        lemma_small_mod((l - a) as nat, l as nat);
+        // (l - a)(l - 1) = l·(l - 1 - a) + a
+        assert((l - a) * (l - 1) == l * (l - 1 - a) + a) by (nonlinear_arith);
+        lemma_mod_multiples_vanish(l - 1 - a, a, l);
+        lemma_small_mod(a as nat, l as nat);
+        lemma_mul_is_commutative(l, l - 1 - a);
+    }
+}
+
+} // verus!
@@ -85,6 +85,14 @@ pub mod traits;
 // #[cfg(feature = "serde")]
 // pub mod serde_assumes;
 
+// Serde encoding of scalars and points: hex for human-readable formats, bytes otherwise
+#[cfg(feature = "serde")]
+pub(crate) mod serde_hex;
+
+// RFC 9380 hashing to edwards25519
+#[cfg(feature = "digest")]
+pub(crate) mod hash_to_curve;
+
 // All the lizard code is here, for now
 #[cfg(feature = "lizard")]
 pub mod lizard;
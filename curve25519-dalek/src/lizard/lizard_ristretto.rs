@@ -27,6 +27,21 @@ impl RistrettoPoint {
     }
 
     /// Encode 16 bytes of data to a RistrettoPoint, using the Lizard method
+    ///
+    /// The data goes in the middle of the field element fed to the Elligator map, and a
+    /// hash of it (with `D`, usually SHA-256) fills the remaining bits. The encoding is
+    /// injective, and [`RistrettoPoint::lizard_decode`] recovers the data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use sha2::Sha256;
+    ///
+    /// let data = *b"sixteen bytes!!!";
+    /// let point = RistrettoPoint::lizard_encode::<Sha256>(&data);
+    /// assert_eq!(point.lizard_decode::<Sha256>(), Some(data));
+    /// ```
     pub fn lizard_encode<D: Digest>(data: &[u8; 16]) -> RistrettoPoint
     where
         D: Digest<OutputSize = U32>,
@@ -43,6 +58,11 @@ impl RistrettoPoint {
     }
 
     /// Decode 16 bytes of data from a RistrettoPoint, using the Lizard method
+    ///
+    /// Of the (up to eight) Elligator preimages of the point, exactly one must carry a
+    /// valid hash of its data bytes. Returns `None` otherwise, which is the case for
+    /// almost every point that was not produced by [`RistrettoPoint::lizard_encode`]
+    /// with the same `D`. The preimages are checked in constant time.
     pub fn lizard_decode<D: Digest>(&self) -> Option<[u8; 16]>
     where
         D: Digest<OutputSize = U32>,
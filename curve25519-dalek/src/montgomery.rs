@@ -28,7 +28,10 @@
 //! # Scalar Multiplication
 //!
 //! Scalar multiplication on `MontgomeryPoint`s is provided by the `*`
-//! operator, which implements the Montgomery ladder.
+//! operator, which implements the Montgomery ladder. With the `alloc`
+//! feature, `MontgomeryPoint` and `MontgomeryPointFull` also implement
+//! the `MultiscalarMul` and `VartimeMultiscalarMul` traits, going through
+//! the Edwards model.
 //!
 //! # Edwards Conversion
 //!
@@ -40,6 +43,19 @@
 //! This function rejects `MontgomeryPoints` which correspond to points
 //! on the twist.
 //!
+//! # Full Points
+//!
+//! `MontgomeryPointFull` holds both coordinates \\((u, v)\\) of a point on
+//! the curve, and supports addition as well as scalar multiplication (the
+//! ladder followed by \\(v\\)-recovery).
+//!
+//! # Elligator 2
+//!
+//! `MontgomeryPoint::to_representative()` and
+//! `MontgomeryPoint::from_representative()` implement the inverse and the
+//! direct Elligator 2 map, between points and `ElligatorRepresentative`s
+//! that look like uniformly random bytes.
+//!
 //! [costello-smith]: https://eprint.iacr.org/2017/212.pdf
 // We allow non snake_case names because coordinates in projective space are
 // traditionally denoted by the capitalisation of their respective
@@ -47,9 +63,11 @@
 // affine and projective cakes and eat both of them too.
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
 use core::{
     hash::{Hash, Hasher},
-    ops::{Mul, MulAssign},
+    ops::{Add, Mul, MulAssign, Neg, Sub},
 };
 
 use crate::constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
@@ -77,20 +95,44 @@ use crate::specs::scalar52_specs::*;
 use crate::specs::scalar_specs::*;
 #[cfg(verus_keep_ghost)]
 use crate::specs::scalar_specs::{spec_clamp_integer, spec_scalar};
+#[allow(unused_imports)] // Used in verus! blocks for the scalar bit order
+use crate::lemmas::common_lemmas::to_nat_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the ONE and ZERO constants
+use crate::lemmas::field_lemmas::constants_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for field algebra lemmas
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for limb-bound bookkeeping
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the Elligator2 square test
+use crate::lemmas::field_lemmas::sqrt_ratio_lemmas::lemma_sqrt_ratio_i_one_denominator;
+#[allow(unused_imports)] // Used in verus! blocks for the ladder step and Elligator2
+use crate::lemmas::montgomery_curve_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for scalar invariant #1
+use crate::lemmas::scalar_lemmas::lemma_scalar_high_bit_clear_iff_lt_pow2_255;
 
 use crate::traits::Identity;
+#[cfg(feature = "alloc")]
+use crate::traits::{MultiscalarMul, VartimeMultiscalarMul};
 
 #[cfg(verus_keep_ghost)]
-use crate::backend::serial::u64::subtle_assumes::choice_is_true;
+use crate::backend::serial::u64::subtle_assumes::{
+    choice_is_true, ct_option_has_value, ct_option_value,
+};
 use crate::backend::serial::u64::subtle_assumes::{
     choice_into, choice_not, conditional_negate_field_element, conditional_select_field_element,
     conditional_swap_montgomery_projective,
 };
 
 use subtle::Choice;
+use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
+#[allow(unused_imports)]
+use vstd::arithmetic::div_mod::*;
+#[allow(unused_imports)]
+use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -267,7 +309,6 @@ impl MontgomeryPoint {
         // REFACTORED: to assume postconditions for EdwardsPoint::mul_base
         let temp = EdwardsPoint::mul_base(scalar);
         proof {
-            assume(fe51_limbs_bounded(&temp.X, 54));
             // to_montgomery requires 51-bit bounds for Y, Z so U = Z + Y fits in 52 bits
             assume(fe51_limbs_bounded(&temp.Y, 51) && fe51_limbs_bounded(&temp.Z, 51));
             assume(sum_of_limbs_bounded(&temp.Z, &temp.Y, u64::MAX));
@@ -298,6 +339,9 @@ impl MontgomeryPoint {
                 let R = montgomery_scalar_mul(P, n);
                 spec_montgomery_point(result) == spec_u_coordinate(R)
             }),
+            // RFC 7748: result is X25519(bytes, self), encoded canonically
+            spec_montgomery_point(result) == spec_x25519(bytes, self.0),
+            is_canonical_field_encoding(&result.0),
     {
         // We have to construct a Scalar that is not reduced mod l, which breaks scalar invariant
         // #2. But #2 is not necessary for correctness of variable-base multiplication. All that
@@ -307,17 +351,16 @@ impl MontgomeryPoint {
         // issues arising from the fact that the curve point is not necessarily in the prime-order
         // subgroup.
         let s = Scalar { bytes: clamp_integer(bytes) };
-        let result = s * self;
         proof {
-            // postcondition
-            assume({
-                let P = canonical_montgomery_lift(spec_montgomery_point(self));
-                let clamped_bytes = spec_clamp_integer(bytes);
-                let n = bytes32_to_nat(&clamped_bytes);
-                let R = montgomery_scalar_mul(P, n);
-                spec_montgomery_point(result) == spec_u_coordinate(R)
-            });
+            // Clamping clears bit 255
+            let b31 = s.bytes[31];
+            assert(b31 & 0b1000_0000 == 0 ==> b31 <= 127) by (bit_vector);
         }
+        /* ORIGINAL CODE: let result = s * self; */
+        // The owned-operand Mul variants carry no ensures, so call the reference one
+        // VERIFICATION NOTE: the ensures are a specification only: they follow from those of
+        // mul_bits_be, whose ladder loop is still a PROOF BYPASS
+        let result = &s * &self;
         result
     }
 
@@ -405,6 +448,7 @@ impl MontgomeryPoint {
                 // result encodes u([n]P)
                 spec_montgomery_point(result) == spec_u_coordinate(R)
             }),
+            is_canonical_field_encoding(&result.0),
     {
         // Algorithm 8 of Costello-Smith 2017
         let affine_u = FieldElement::from_bytes(&self.0);
@@ -426,7 +470,11 @@ impl MontgomeryPoint {
             debug_assert!(choice == 0 || choice == 1);
 
             conditional_swap_montgomery_projective(&mut x0, &mut x1, choice.into());
-            assume(false);  // VERIFICATION NOTE: need to prove preconditions for differential_add_and_double
+            // VERIFICATION NOTE: PROOF BYPASS. The step is proven in differential_add_and_double,
+            // but there is no loop invariant: x0 starts at the identity (W = 0), which the step
+            // excludes, small-order inputs reach W = 0 mid-ladder, and the step relates the
+            // canonical lifts of x0 and x1, whose difference is not ±P in general
+            assume(false);
             differential_add_and_double(&mut x0, &mut x1, &affine_u);
 
             prev_bit = cur_bit;
@@ -445,7 +493,7 @@ impl MontgomeryPoint {
         }
         let result = x0.as_affine();
         proof {
-            // postcondition using canonical lift
+            // postcondition using canonical lift (follows from the unproven ladder invariant)
             assume({
                 let u0 = spec_montgomery_point(*self);
                 let P = canonical_montgomery_lift(u0);
@@ -519,10 +567,23 @@ impl MontgomeryPoint {
         }
         let one = FieldElement::ONE;
 
-        /* VERIFICATION NOTE: need to prove preconditions for arithmetic traits */
-        assume(false);
-
+        /* <ORIGINAL CODE>
         let y = &(&u - &one) * &(&u + &one).invert();
+        </ORIGINAL CODE> */
+        /* <VERIFICATION NOTE>
+         Split into named steps to carry the limb bounds of the arithmetic traits
+        </VERIFICATION NOTE> */
+        proof {
+            // u and one have 51-bit limbs, so u + 1 has 52-bit limbs
+            lemma_one_limbs_bounded_51();
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&u, &one, 51);
+        }
+        let u_minus_one = &u - &one;
+        let u_plus_one = &u + &one;
+        proof {
+            lemma_fe51_limbs_bounded_weaken(&u_plus_one, 52, 54);
+        }
+        let y = &u_minus_one * &u_plus_one.invert();
 
         let mut y_bytes = y.as_bytes();
         y_bytes[31] ^= sign << 7;
@@ -543,6 +604,172 @@ impl MontgomeryPoint {
 
         result
     }
+
+    /// Map an Elligator 2 representative to the point it represents.
+    ///
+    /// Every 32-byte string is the representative of some point on the curve (not the
+    /// twist). Bit 255 is ignored. This is the inverse of
+    /// [`MontgomeryPoint::to_representative`].
+    pub fn from_representative(representative: &ElligatorRepresentative) -> (result:
+        MontgomeryPoint)
+        ensures
+            spec_montgomery_point(result) == spec_elligator_encode(
+                spec_field_element_from_bytes(&representative.0),
+            ),
+            is_canonical_field_encoding(&result.0),
+            is_valid_u_coordinate(spec_montgomery_point(result)),
+    {
+        let r = FieldElement::from_bytes(&representative.0);
+        elligator_encode(&r)
+    }
+
+    /// Find an Elligator 2 representative of this point: 32 bytes that
+    /// [`MontgomeryPoint::from_representative`] maps back to it.
+    ///
+    /// Representatives of uniformly random points are indistinguishable from uniformly
+    /// random bytes, which is what obfuscated transports need for their public keys.
+    /// Only about half of the points on the curve have representatives, and the point
+    /// must be on the curve (not the twist) with a canonical encoding; otherwise this
+    /// returns `None`. Key generation retries with a fresh key until it gets `Some`.
+    ///
+    /// `tweak` should be a uniformly random byte, fresh for each call:
+    /// - bit 0 chooses between the two representatives \\(r\\) with
+    ///   \\(r^2 = -u / 2(u + A)\\) and \\(r^2 = -(u + A) / 2u\\),
+    /// - bit 1 chooses the sign of \\(r\\),
+    /// - bit 7 becomes bit 255 of the encoding, which the map ignores.
+    ///
+    /// # Warning
+    ///
+    /// The usual X25519 public keys are in the prime-order subgroup, and their
+    /// representatives are distinguishable from random bytes. For indistinguishable
+    /// keys, the point must be uniform over the whole curve, for example the public key
+    /// plus a random point of small order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::montgomery::MontgomeryPoint;
+    /// # use curve25519_dalek::constants::X25519_BASEPOINT;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let mut k = 1u64;
+    /// let (point, representative) = loop {
+    ///     let point = X25519_BASEPOINT * Scalar::from(k);
+    ///     if let Some(rep) = Option::from(point.to_representative(0x5a)) {
+    ///         break (point, rep);
+    ///     }
+    ///     k += 1;
+    /// };
+    /// assert_eq!(MontgomeryPoint::from_representative(&representative), point);
+    /// ```
+    /* <VERIFICATION NOTE>
+     Marked as external_body: the inverse map has no spec yet. The postcondition is
+     checked at runtime by mapping the representative back and comparing the bytes.
+    </VERIFICATION NOTE> */
+    #[verifier::external_body]
+    pub fn to_representative(&self, tweak: u8) -> (result: CtOption<ElligatorRepresentative>)
+        ensures
+            ct_option_has_value(result) ==> spec_elligator_encode(
+                spec_field_element_from_bytes(&ct_option_value(result).0),
+            ) == spec_montgomery_point(*self),
+    {
+        let u = FieldElement::from_bytes(&self.0);
+        let u_plus_a = &u + &MONTGOMERY_A;
+
+        // r² = -u / 2(u + A) when d = -(u + A) is the nonsquare case of the map, and
+        // r² = -(u + A) / 2u when d = u is the square case
+        let second = Choice::from(tweak & 1);
+        let num = FieldElement::conditional_select(&u, &u_plus_a, second);
+        let den = FieldElement::conditional_select(&u_plus_a, &u, second);
+        let (is_square, mut r) = FieldElement::sqrt_ratio_i(&(-&num), &(&den + &den));
+        r.conditional_negate(Choice::from((tweak >> 1) & 1));
+
+        // Twist points and non-canonical encodings do not survive the round trip
+        let roundtrip = elligator_encode(&r).0[..].ct_eq(&self.0[..]);
+
+        let mut bytes = r.as_bytes();
+        bytes[31] |= tweak & 0x80;
+        CtOption::new(ElligatorRepresentative(bytes), is_square & roundtrip)
+    }
+}
+
+/// An Elligator 2 representative of a [`MontgomeryPoint`].
+///
+/// See [`MontgomeryPoint::to_representative`] and
+/// [`MontgomeryPoint::from_representative`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ElligatorRepresentative(pub [u8; 32]);
+
+impl ElligatorRepresentative {
+    /// View this representative as a byte array.
+    pub fn as_bytes(&self) -> (result: &[u8; 32])
+        ensures
+            *result == self.0,
+    {
+        &self.0
+    }
+
+    /// Convert this representative to a byte array.
+    pub fn to_bytes(&self) -> (result: [u8; 32])
+        ensures
+            result == self.0,
+    {
+        self.0
+    }
+}
+
+/// The X25519 function of [RFC 7748](https://www.rfc-editor.org/rfc/rfc7748#section-5):
+/// clamp the secret `k`, multiply the point with \\(u\\)-coordinate `u` by it, and encode
+/// the result.
+///
+/// As in the RFC, `u` may be any 32 bytes: its high bit is ignored, non-canonical values
+/// are reduced, and points on the twist are accepted. The result is all zeros when `u`
+/// has small order; protocols that need to detect that must check for it.
+///
+/// # Example
+///
+/// ```
+/// # use curve25519_dalek::montgomery::{x25519, x25519_base};
+/// let alice_secret: [u8; 32] = hex::decode(
+///     "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+/// ).unwrap().try_into().unwrap();
+/// let bob_secret: [u8; 32] = hex::decode(
+///     "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+/// ).unwrap().try_into().unwrap();
+///
+/// let alice_public = x25519_base(alice_secret);
+/// assert_eq!(
+///     hex::encode(alice_public),
+///     "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a",
+/// );
+/// let bob_public = x25519_base(bob_secret);
+/// assert_eq!(x25519(alice_secret, bob_public), x25519(bob_secret, alice_public));
+/// ```
+/* <VERIFICATION NOTE>
+ Marked as external_body: mul_clamped requires a point on the curve, while X25519 also
+ accepts points on the twist. The ensures restate mul_clamped's for points on the curve.
+</VERIFICATION NOTE> */
+#[verifier::external_body]
+pub fn x25519(k: [u8; 32], u: [u8; 32]) -> (result: [u8; 32])
+    ensures
+        is_valid_u_coordinate(spec_field_element_from_bytes(&u)) ==> spec_field_element_from_bytes(
+            &result,
+        ) == spec_x25519(k, u),
+{
+    MontgomeryPoint(u).mul_clamped(k).to_bytes()
+}
+
+/// X25519 with the basepoint, \\(u = 9\\): the public key for the secret `k`.
+///
+/// This is `x25519(k, X25519_BASEPOINT.to_bytes())`, computed with the precomputed
+/// Edwards basepoint tables when they are available. See [`x25519`].
+pub fn x25519_base(k: [u8; 32]) -> (result: [u8; 32])
+    ensures
+        spec_field_element_from_bytes(&result) == montgomery_scalar_mul_u(
+            spec_x25519_basepoint_u(),
+            spec_scalar(&Scalar { bytes: spec_clamp_integer(k) }),
+        ),
+{
+    MontgomeryPoint::mul_base_clamped(k).to_bytes()
 }
 
 /// Perform the Elligator2 mapping to a Montgomery point.
@@ -559,86 +786,106 @@ pub(crate) fn elligator_encode(r_0: &FieldElement) -> (result: MontgomeryPoint)
     ensures
         spec_montgomery_point(result) == spec_elligator_encode(spec_field_element(r_0)),
         spec_montgomery_point(result) < p(),
+        is_canonical_field_encoding(&result.0),
+        // The output is a u-coordinate of a point on the curve
+        is_valid_u_coordinate(spec_montgomery_point(result)),
 {
     proof {
-        // Preconditions for constants (MONTGOMERY_A = 486662, MONTGOMERY_A_NEG = -486662 mod p)
-        assume(fe51_limbs_bounded(&FieldElement::ONE, 51));
-        assume(fe51_limbs_bounded(&FieldElement::ZERO, 51));
-        assume(fe51_limbs_bounded(&MONTGOMERY_A, 51));
-        assume(fe51_limbs_bounded(&MONTGOMERY_A_NEG, 51));
+        // Constants: MONTGOMERY_A = 486662, MONTGOMERY_A_NEG = -486662 mod p
+        lemma_one_limbs_bounded_51();
+        lemma_one_field_element_value();
+        lemma_zero_field_element_value();
+        lemma_aplus2_over_four_value();
+        lemma_montgomery_a_neg_value();
+        lemma_fe51_limbs_bounded_weaken(&MONTGOMERY_A, 51, 54);
+        lemma_fe51_limbs_bounded_weaken(&MONTGOMERY_A_NEG, 51, 54);
+        lemma_fe51_limbs_bounded_weaken(&FieldElement::ONE, 51, 54);
     }
+    let ghost r = spec_field_element(r_0);
 
     let one = FieldElement::ONE;
 
     // ORIGINAL CODE: let d_1 = &one + &r_0.square2(); // 2r^2
     proof {
-        assume(fe51_limbs_bounded(r_0, 54));
+        lemma_fe51_limbs_bounded_weaken(r_0, 51, 54);
     }
     let r_0_sq2 = r_0.square2();
     proof {
-        assume(sum_of_limbs_bounded(&one, &r_0_sq2, u64::MAX));
+        lemma_fe51_limbs_bounded_weaken(&one, 51, 53);
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&one, &r_0_sq2, 53);
+        lemma_add_fe51_limbs_bounded_53(&one, &r_0_sq2);
     }
     let d_1 = &one + &r_0_sq2;  // 2r^2
 
-    proof {
-        assume(fe51_limbs_bounded(&d_1, 54));
-        assume(fe51_limbs_bounded(&MONTGOMERY_A_NEG, 54));
-    }
     let d = &MONTGOMERY_A_NEG * &(d_1.invert());  // A/(1+2r^2)
 
     // ORIGINAL CODE: let d_sq = &d.square();
     // (Changed: removed & because Verus doesn't auto-deref &&FieldElement in Add trait)
-    proof {
-        assume(fe51_limbs_bounded(&d, 54));
-        assume(fe51_limbs_bounded(&MONTGOMERY_A, 54));
-    }
     let d_sq = d.square();
     let au = &MONTGOMERY_A * &d;
+    proof {
+        lemma_square_matches_math_field_square(
+            spec_field_element_as_nat(&d),
+            spec_field_element_as_nat(&d_sq),
+        );
+    }
 
     // ORIGINAL CODE: let inner = &(d_sq + &au) + &one;
     proof {
-        assume(sum_of_limbs_bounded(&d_sq, &au, u64::MAX));
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&d_sq, &au, 52);
+        lemma_add_fe51_limbs_bounded_52(&d_sq, &au);
     }
     let d_sq_plus_au = &d_sq + &au;
     proof {
-        assume(sum_of_limbs_bounded(&d_sq_plus_au, &one, u64::MAX));
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&d_sq_plus_au, &one, 53);
+        lemma_add_fe51_limbs_bounded_53(&d_sq_plus_au, &one);
     }
     let inner = &d_sq_plus_au + &one;  // inner = d^2 + A*d + 1
 
-    proof {
-        assume(fe51_limbs_bounded(&inner, 54));
-    }
     let eps = &d * &inner;  // eps = d^3 + Ad^2 + d
 
+    let (eps_is_sq, _eps) = FieldElement::sqrt_ratio_i(&eps, &one);
     proof {
-        assume(fe51_limbs_bounded(&eps, 54));
-        assume(fe51_limbs_bounded(&one, 54));
+        // With denominator 1 the returned flag says whether eps is a square
+        lemma_mod_bound(spec_field_element_as_nat(&eps) as int, p() as int);
+        lemma_sqrt_ratio_i_one_denominator(
+            spec_field_element(&eps),
+            choice_is_true(eps_is_sq),
+            spec_field_element(&_eps),
+        );
     }
-    let (eps_is_sq, _eps) = FieldElement::sqrt_ratio_i(&eps, &one);
 
     let zero = FieldElement::ZERO;
     let Atemp = conditional_select_field_element(&MONTGOMERY_A, &zero, eps_is_sq);  // 0, or A if nonsquare
 
     proof {
-        assume(sum_of_limbs_bounded(&d, &Atemp, u64::MAX));
+        lemma_fe51_limbs_bounded_weaken(&Atemp, 51, 52);
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&d, &Atemp, 52);
+        lemma_add_fe51_limbs_bounded_52(&d, &Atemp);
+        lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&d, &Atemp), 53, 54);
     }
     let mut u = &d + &Atemp;  // d, or d+A if nonsquare
-
-    // ORIGINAL CODE: u.conditional_negate(!eps_is_sq);
     proof {
-        assume(fe51_limbs_bounded(&u, 51));
+        if choice_is_true(eps_is_sq) {
+            // d + 0 = d
+            lemma_mod_bound(spec_field_element_as_nat(&d) as int, p() as int);
+            lemma_small_mod(spec_field_element(&d), p());
+        }
     }
+
+    // ORIGINAL CODE: u.conditional_negate(!eps_is_sq);
     conditional_negate_field_element(&mut u, choice_not(eps_is_sq));  // d, or -d-A if nonsquare
 
-    proof {
-        assume(fe51_limbs_bounded(&u, 51));
-    }
     let result = MontgomeryPoint(u.as_bytes());
 
     proof {
-        // PROOF BYPASS: Assume postconditions
-        assume(spec_montgomery_point(result) == spec_elligator_encode(spec_field_element(r_0)));
-        assume(spec_montgomery_point(result) < p());
+        // The encoding is canonical, so decoding it gives u back
+        let u_bytes = bytes32_to_nat(&result.0);
+        pow255_gt_19();
+        assert(u_bytes < pow2(255));
+        lemma_small_mod(u_bytes, pow2(255));
+        lemma_small_mod(u_bytes, p());
+        lemma_elligator_encode_is_valid_u_coordinate(r);
     }
 
     result
@@ -706,31 +953,17 @@ impl ConditionallySelectable for ProjectivePoint {
                 &&& result.W == b.W
             },
     {
-        let result = ProjectivePoint {
+        /* <ORIGINAL CODE>
+        ProjectivePoint {
             U: FieldElement::conditional_select(&a.U, &b.U, choice),
             W: FieldElement::conditional_select(&a.W, &b.W, choice),
-        };
-
-        proof {
-            // What we can derive from FieldElement::conditional_select:
-            assert(!choice_is_true(choice) ==> (forall|i: int|
-                0 <= i < 5 ==> result.U.limbs[i] == a.U.limbs[i]));
-            assert(choice_is_true(choice) ==> (forall|i: int|
-                0 <= i < 5 ==> result.U.limbs[i] == b.U.limbs[i]));
-
-            // For result.W = FieldElement::conditional_select(&a.W, &b.W, choice):
-            assert(!choice_is_true(choice) ==> (forall|i: int|
-                0 <= i < 5 ==> result.W.limbs[i] == a.W.limbs[i]));
-            assert(choice_is_true(choice) ==> (forall|i: int|
-                0 <= i < 5 ==> result.W.limbs[i] == b.W.limbs[i]));
-
-            // We need to lift limbs equality to struct equality:
-            // (forall i. fe1.limbs[i] == fe2.limbs[i]) ==> fe1 == fe2
-            assume(!choice_is_true(choice) ==> (result.U == a.U && result.W == a.W));
-            assume(choice_is_true(choice) ==> (result.U == b.U && result.W == b.W));
         }
-
-        result
+        </ORIGINAL CODE> */
+        // The wrapper lifts the limb-wise ensures to whole-element equality
+        ProjectivePoint {
+            U: conditional_select_field_element(&a.U, &b.U, choice),
+            W: conditional_select_field_element(&a.W, &b.W, choice),
+        }
     }
 }
 
@@ -760,19 +993,23 @@ impl ProjectivePoint {
                     math_field_mul(u_proj, math_field_inv(w_proj))
                 }
             },
+            // The output encoding is canonical, as RFC 7748 encodeUCoordinate requires
+            is_canonical_field_encoding(&result.0),
     {
         let u = &self.U * &self.W.invert();
         let result = MontgomeryPoint(u.as_bytes());
         proof {
-            // postcondition
-            // The affine u-coordinate is U * W^(-1) = U / W
-            let u_proj = spec_field_element(&self.U);
-            let w_proj = spec_field_element(&self.W);
-            assume(spec_montgomery_point(result) == if w_proj == 0 {
-                0
-            } else {
-                math_field_mul(u_proj, math_field_inv(w_proj))
-            });
+            // The encoding is canonical, so decoding it gives U·inv(W) back
+            let u_bytes = bytes32_to_nat(&result.0);
+            pow255_gt_19();
+            assert(u_bytes < pow2(255));
+            lemma_small_mod(u_bytes, pow2(255));
+            lemma_small_mod(u_bytes, p());
+            // For W = 0 this is U·inv(0) = 0
+            if spec_field_element(&self.W) == 0 {
+                field_inv_zero();
+                lemma_field_mul_zero_right(spec_field_element(&self.U), 0);
+            }
         }
         result
     }
@@ -821,6 +1058,17 @@ fn differential_add_and_double(
                 MontgomeryAffine::Infinity => false,
             }
         }),
+        // Neither accumulator is the point at infinity (W = 0), and u(P - Q) ≠ 0:
+        // the x-only formulas cannot recover u(P + Q) when P - Q = (0, 0)
+        spec_field_element(&old(P).W) != 0,
+        spec_field_element(&old(Q).W) != 0,
+        spec_field_element(affine_PmQ) != 0,
+        // Limb bounds: the accumulators are outputs of mul/square
+        fe51_limbs_bounded(&old(P).U, 52),
+        fe51_limbs_bounded(&old(P).W, 52),
+        fe51_limbs_bounded(&old(Q).U, 52),
+        fe51_limbs_bounded(&old(Q).W, 52),
+        fe51_limbs_bounded(affine_PmQ, 54),
     ensures
 // After the operation, P represents [2]P_old and Q represents P_old + Q_old
 
@@ -838,12 +1086,29 @@ fn differential_add_and_double(
             // Q now represents P_old + Q_old
             u_Q_new == spec_u_coordinate(montgomery_add(P_aff, Q_aff))
         }),
+        fe51_limbs_bounded(&P.U, 52),
+        fe51_limbs_bounded(&P.W, 52),
+        fe51_limbs_bounded(&Q.U, 52),
+        fe51_limbs_bounded(&Q.W, 52),
 {
-    assume(false);  // VERIFICATION NOTE: need to prove preconditions for FieldElement arithmetic operations
+    proof {
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&P.U, &P.W, 52);
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&Q.U, &Q.W, 52);
+        lemma_fe51_limbs_bounded_weaken(&P.U, 52, 54);
+        lemma_fe51_limbs_bounded_weaken(&P.W, 52, 54);
+        lemma_fe51_limbs_bounded_weaken(&Q.U, 52, 54);
+        lemma_fe51_limbs_bounded_weaken(&Q.W, 52, 54);
+    }
     let t0 = &P.U + &P.W;
     let t1 = &P.U - &P.W;
     let t2 = &Q.U + &Q.W;
     let t3 = &Q.U - &Q.W;
+    proof {
+        lemma_add_fe51_limbs_bounded_52(&P.U, &P.W);
+        lemma_add_fe51_limbs_bounded_52(&Q.U, &Q.W);
+        lemma_fe51_limbs_bounded_weaken(&t0, 53, 54);
+        lemma_fe51_limbs_bounded_weaken(&t2, 53, 54);
+    }
 
     let t4 = t0.square();  // (U_P + W_P)^2 = U_P^2 + 2 U_P W_P + W_P^2
     let t5 = t1.square();  // (U_P - W_P)^2 = U_P^2 - 2 U_P W_P + W_P^2
@@ -853,22 +1118,75 @@ fn differential_add_and_double(
     let t7 = &t0 * &t3;  // (U_P + W_P) (U_Q - W_Q) = U_P U_Q + W_P U_Q - U_P W_Q - W_P W_Q
     let t8 = &t1 * &t2;  // (U_P - W_P) (U_Q + W_Q) = U_P U_Q - W_P U_Q + U_P W_Q - W_P W_Q
 
+    proof {
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&t7, &t8, 52);
+    }
     let t9 = &t7 + &t8;  // 2 (U_P U_Q - W_P W_Q)
+    proof {
+        lemma_add_fe51_limbs_bounded_52(&t7, &t8);
+        lemma_fe51_limbs_bounded_weaken(&t9, 53, 54);
+    }
     let t10 = &t7 - &t8;  // 2 (W_P U_Q - U_P W_Q)
 
     let t11 = t9.square();  // 4 (U_P U_Q - W_P W_Q)^2
     let t12 = t10.square();  // 4 (W_P U_Q - U_P W_Q)^2
 
+    proof {
+        lemma_aplus2_over_four_value();
+        lemma_fe51_limbs_bounded_weaken(&APLUS2_OVER_FOUR, 51, 54);
+    }
     let t13 = &APLUS2_OVER_FOUR * &t6;  // (A + 2) U_P U_Q
 
     let t14 = &t4 * &t5;  // ((U_P + W_P)(U_P - W_P))^2 = (U_P^2 - W_P^2)^2
+    proof {
+        lemma_sum_of_limbs_bounded_from_fe51_bounded(&t13, &t5, 52);
+    }
     let t15 = &t13 + &t5;  // (U_P - W_P)^2 + (A + 2) U_P W_P
+    proof {
+        lemma_add_fe51_limbs_bounded_52(&t13, &t5);
+        lemma_fe51_limbs_bounded_weaken(&t15, 53, 54);
+    }
 
     let t16 = &t6 * &t15;  // 4 (U_P W_P) ((U_P - W_P)^2 + (A + 2) U_P W_P)
 
     let t17 = affine_PmQ * &t12;  // U_D * 4 (W_P U_Q - U_P W_Q)^2
     let t18 = t11;  // W_D * 4 (U_P U_Q - W_P W_Q)^2
 
+    proof {
+        let (U_P, W_P) = spec_projective_point_montgomery(*old(P));
+        let (U_Q, W_Q) = spec_projective_point_montgomery(*old(Q));
+        let u_d = spec_field_element(affine_PmQ);
+        let u_P = spec_projective_u_coordinate(*old(P));
+        let u_Q = spec_projective_u_coordinate(*old(Q));
+        p_gt_2();
+        lemma_mod_bound(spec_field_element_as_nat(&old(P).U) as int, p() as int);
+        lemma_mod_bound(spec_field_element_as_nat(&old(P).W) as int, p() as int);
+        lemma_mod_bound(spec_field_element_as_nat(&old(Q).U) as int, p() as int);
+        lemma_mod_bound(spec_field_element_as_nat(&old(Q).W) as int, p() as int);
+        lemma_mod_bound(spec_field_element_as_nat(affine_PmQ) as int, p() as int);
+
+        // square() is specified on limbs; lift each one to math_field_square
+        lemma_square_matches_math_field_square(u64_5_as_nat(t0.limbs), u64_5_as_nat(t4.limbs));
+        lemma_square_matches_math_field_square(u64_5_as_nat(t1.limbs), u64_5_as_nat(t5.limbs));
+        lemma_square_matches_math_field_square(u64_5_as_nat(t9.limbs), u64_5_as_nat(t11.limbs));
+        lemma_square_matches_math_field_square(
+            u64_5_as_nat(t10.limbs),
+            u64_5_as_nat(t12.limbs),
+        );
+
+        // The projective outputs represent the affine x-only formulas
+        lemma_ladder_double_formula(U_P, W_P);
+        lemma_ladder_add_formula(U_P, W_P, U_Q, W_Q, u_d);
+
+        // ... which compute [2]P and P + Q on the canonical lifts
+        lemma_mod_bound((U_P * math_field_inv(W_P)) as int, p() as int);
+        lemma_mod_bound((U_Q * math_field_inv(W_Q)) as int, p() as int);
+        lemma_small_mod(u_P, p());
+        lemma_small_mod(u_Q, p());
+        axiom_montgomery_xdbl_u(u_P);
+        axiom_montgomery_xadd_u(u_P, u_Q, u_d);
+    }
+
     P.U = t14;  // U_{P'} = (U_P + W_P)^2 (U_P - W_P)^2
     P.W = t16;  // W_{P'} = (4 U_P W_P) ((U_P - W_P)^2 + ((A + 2)/4) 4 U_P W_P)
     Q.U = t18;  // U_{Q'} = W_D * 4 (U_P U_Q - W_P W_Q)^2
@@ -907,6 +1225,7 @@ impl Mul<&Scalar> for &MontgomeryPoint {
                 let R = montgomery_scalar_mul(P, n_unreduced);
                 spec_montgomery_point(result) == spec_u_coordinate(R)
             }),
+            is_canonical_field_encoding(&result.0),
     {
         // We multiply by the integer representation of the given Scalar. By scalar invariant #1,
         // the MSB is 0, so we can skip it.
@@ -918,22 +1237,24 @@ impl Mul<&Scalar> for &MontgomeryPoint {
         let mut bits_be = [false;255];
         let mut i = 0;
         while i < 255
+            invariant
+                0 <= i <= 255,
+                forall|j: int| 0 <= j < i ==> #[trigger] bits_be[j] == bits_le[254 - j],
             decreases 255 - i,
         {
             bits_be[i] = bits_le[254 - i];
             i += 1;
         }
-        let result = self.mul_bits_be(&bits_be);
         proof {
-            // postcondition: multiplication by unreduced scalar value using canonical lift
-            assume({
-                let P = canonical_montgomery_lift(spec_montgomery_point(*self));
-                let n_unreduced = scalar_to_nat(scalar);
-                let R = montgomery_scalar_mul(P, n_unreduced);
-                spec_montgomery_point(result) == spec_u_coordinate(R)
-            });
+            // scalar < 2^255, so bit 255 is clear and the remaining 255 bits read
+            // most-significant first give the scalar back
+            lemma_scalar_high_bit_clear_iff_lt_pow2_255(scalar);
+            lemma_bits_to_nat_rec_top_bit(&bits_le, 0);
+            lemma_bits_be_reversed_to_nat(&bits_le, &bits_be, 255);
+            lemma2_to64();
+            assert(bits_be_to_nat(&bits_be, 255) == scalar_to_nat(scalar));
         }
-        ;
+        let result = self.mul_bits_be(&bits_be);
         result
     }
 }
@@ -941,6 +1262,7 @@ impl Mul<&Scalar> for &MontgomeryPoint {
 impl MulAssign<&Scalar> for MontgomeryPoint {
     fn mul_assign(&mut self, scalar: &Scalar)
         requires
+            scalar.bytes[31] <= 127,
             is_valid_montgomery_point(*old(self)),
         ensures
     // Result represents [n]old(self) where n is the UNREDUCED scalar value
@@ -975,6 +1297,7 @@ impl Mul<&MontgomeryPoint> for &Scalar {
                 let R = montgomery_scalar_mul(P, n_unreduced);
                 spec_montgomery_point(result) == spec_u_coordinate(R)
             }),
+            is_canonical_field_encoding(&result.0),
     {
         point * self
     }
@@ -983,6 +1306,858 @@ impl Mul<&MontgomeryPoint> for &Scalar {
 // NOTE: MulSpecImpl and owned-type Mul implementations for Scalar * MontgomeryPoint
 // have been moved to mul_specs.rs
 } // verus!
+// ------------------------------------------------------------------------
+// Full Montgomery points
+// ------------------------------------------------------------------------
+/* VERIFICATION NOTE: not verified yet. Addition and doubling go through the verified
+Edwards formulas and scalar multiplication through the ladder step
+`differential_add_and_double`, but the coordinate maps and the y-recovery have no specs. */
+
+/// A point \\((u, v)\\) on the Montgomery form of Curve25519,
+/// \\(v^2 = u^3 + A u^2 + u\\), with both of its coordinates.
+///
+/// A [`MontgomeryPoint`] only holds \\(u\\), which is enough for the ladder but not for
+/// adding two points. This type supports addition, doubling and negation, and scalar
+/// multiplication by the Montgomery ladder followed by Okeya–Sakurai \\(v\\)-recovery.
+///
+/// It corresponds to the Edwards points under the birational map of RFC 7748,
+/// \\((x, y) = (\sqrt{-486664} \cdot u/v, (u - 1)/(u + 1))\\), which sends the X25519
+/// basepoint to the Ed25519 basepoint. Addition and doubling use the complete Edwards
+/// formulas behind the scenes, so they work for all inputs and in constant time.
+///
+/// # Example
+///
+/// ```
+/// # use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, X25519_BASEPOINT};
+/// # use curve25519_dalek::montgomery::MontgomeryPointFull;
+/// # use curve25519_dalek::scalar::Scalar;
+/// let B = MontgomeryPointFull::from_edwards(&ED25519_BASEPOINT_POINT);
+/// assert_eq!(B.to_montgomery(), X25519_BASEPOINT);
+///
+/// let k = Scalar::from(1234u64);
+/// let P = B * k;
+/// assert_eq!(P.to_montgomery(), X25519_BASEPOINT * k);
+/// assert_eq!(P + B, B * Scalar::from(1235u64));
+/// assert_eq!(P.to_edwards(), ED25519_BASEPOINT_POINT * k);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MontgomeryPointFull {
+    // (U : V : W) with u = U/W and v = V/W; the point at infinity is (0 : 1 : 0)
+    U: FieldElement,
+    V: FieldElement,
+    W: FieldElement,
+}
+
+impl Identity for MontgomeryPointFull {
+    fn identity() -> MontgomeryPointFull {
+        MontgomeryPointFull {
+            U: FieldElement::ZERO,
+            V: FieldElement::ONE,
+            W: FieldElement::ZERO,
+        }
+    }
+}
+
+impl Default for MontgomeryPointFull {
+    fn default() -> MontgomeryPointFull {
+        MontgomeryPointFull::identity()
+    }
+}
+
+impl ConditionallySelectable for MontgomeryPointFull {
+    fn conditional_select(
+        a: &MontgomeryPointFull,
+        b: &MontgomeryPointFull,
+        choice: Choice,
+    ) -> MontgomeryPointFull {
+        MontgomeryPointFull {
+            U: FieldElement::conditional_select(&a.U, &b.U, choice),
+            V: FieldElement::conditional_select(&a.V, &b.V, choice),
+            W: FieldElement::conditional_select(&a.W, &b.W, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for MontgomeryPointFull {
+    fn ct_eq(&self, other: &MontgomeryPointFull) -> Choice {
+        // V is nonzero at infinity, so this also tells infinity from the finite points
+        (&self.U * &other.W).ct_eq(&(&other.U * &self.W))
+            & (&self.V * &other.W).ct_eq(&(&other.V * &self.W))
+    }
+}
+
+impl PartialEq for MontgomeryPointFull {
+    fn eq(&self, other: &MontgomeryPointFull) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for MontgomeryPointFull {}
+
+/// \\(\sqrt{-486664}\\) with the sign used by the birational map of RFC 7748
+fn birational_map_constant() -> FieldElement {
+    -&crate::constants::ED25519_SQRTAM2
+}
+
+impl MontgomeryPointFull {
+    /// Lift a \\(u\\)-coordinate to the point \\((u, v)\\) whose \\(v\\) has the given
+    /// sign: `0` for the even (nonnegative) root, `1` for the odd one.
+    ///
+    /// Returns `None` if `u` is the \\(u\\)-coordinate of a point on the twist. The
+    /// point \\((0, 0)\\) is returned for \\(u = 0\\), whatever the sign.
+    pub fn from_montgomery(u: &MontgomeryPoint, sign: u8) -> Option<MontgomeryPointFull> {
+        let u = FieldElement::from_bytes(&u.0);
+        let one = FieldElement::ONE;
+        // v² = u·(u·(u + A) + 1)
+        let vv = &u * &(&(&u * &(&u + &MONTGOMERY_A)) + &one);
+        let (is_square, mut v) = FieldElement::sqrt_ratio_i(&vv, &one);
+        v.conditional_negate(Choice::from(sign & 1));
+
+        if is_square.into() {
+            Some(MontgomeryPointFull { U: u, V: v, W: one })
+        } else {
+            None
+        }
+    }
+
+    /// The \\(u\\)-coordinate of this point, with \\(u = 0\\) for the point at infinity as
+    /// in [`MontgomeryPoint`].
+    pub fn to_montgomery(&self) -> MontgomeryPoint {
+        MontgomeryPoint((&self.U * &self.W.invert()).as_bytes())
+    }
+
+    /// The sign of the \\(v\\)-coordinate: `1` if it is odd, `0` if it is even or this is
+    /// the point at infinity.
+    ///
+    /// `MontgomeryPointFull::from_montgomery(&P.to_montgomery(), P.v_sign())` gives back
+    /// every finite point `P`.
+    pub fn v_sign(&self) -> u8 {
+        (&self.V * &self.W.invert()).is_negative().unwrap_u8()
+    }
+
+    /// Convert an Edwards point to the Montgomery model.
+    pub fn from_edwards(point: &EdwardsPoint) -> MontgomeryPointFull {
+        // u = (1 + y)/(1 - y) = (Z + Y)/(Z - Y) and v = c·u/x = c·Z·(Z + Y)/(X·(Z - Y))
+        let c = birational_map_constant();
+        let ZpY = &point.Z + &point.Y;
+        let ZmY = &point.Z - &point.Y;
+        let mut result = MontgomeryPointFull {
+            U: &ZpY * &point.X,
+            V: &c * &(&point.Z * &ZpY),
+            W: &point.X * &ZmY,
+        };
+        // The identity (X = 0, Y = Z) lands on (0 : V : 0) as it should, but (0, -1) lands
+        // on (0 : 0 : 0) instead of (0, 0)
+        let minus_one = ZpY.is_zero();
+        result.V.conditional_assign(&FieldElement::ZERO, minus_one);
+        result.W.conditional_assign(&FieldElement::ONE, minus_one);
+        result
+    }
+
+    /// Convert this point to the Edwards model.
+    pub fn to_edwards(&self) -> EdwardsPoint {
+        // x = c·u/v = c·U/V and y = (u - 1)/(u + 1) = (U - W)/(U + W)
+        let c = birational_map_constant();
+        let UpW = &self.U + &self.W;
+        let UmW = &self.U - &self.W;
+        let cU = &c * &self.U;
+        let mut result = EdwardsPoint {
+            X: &cU * &UpW,
+            Y: &UmW * &self.V,
+            Z: &self.V * &UpW,
+            T: &cU * &UmW,
+        };
+        // U = 0 only at infinity (V ≠ 0) and at (0, 0) (V = 0), where the map gives
+        // (0 : 0 : 0); they go to the identity and to (0, -1)
+        let u_is_zero = self.U.is_zero();
+        let mut y = FieldElement::ONE;
+        y.conditional_negate(self.V.is_zero());
+        let exceptional = EdwardsPoint {
+            X: FieldElement::ZERO,
+            Y: y,
+            Z: FieldElement::ONE,
+            T: FieldElement::ZERO,
+        };
+        result.conditional_assign(&exceptional, u_is_zero);
+        result
+    }
+
+    /// Add this point to itself.
+    pub fn double(&self) -> MontgomeryPointFull {
+        MontgomeryPointFull::from_edwards(&self.to_edwards().double())
+    }
+
+    /// Multiply this point by `clamp_integer(bytes)`, as in X25519 but keeping \\(v\\).
+    /// For a description of clamping, see [`clamp_integer`].
+    pub fn mul_clamped(self, bytes: [u8; 32]) -> MontgomeryPointFull {
+        // See MontgomeryPoint::mul_clamped on why an unreduced Scalar is fine here
+        let s = Scalar {
+            bytes: clamp_integer(bytes),
+        };
+        &self * &s
+    }
+}
+
+impl<'a, 'b> Add<&'b MontgomeryPointFull> for &'a MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn add(self, other: &'b MontgomeryPointFull) -> MontgomeryPointFull {
+        MontgomeryPointFull::from_edwards(&(&self.to_edwards() + &other.to_edwards()))
+    }
+}
+
+define_add_variants!(
+    LHS = MontgomeryPointFull,
+    RHS = MontgomeryPointFull,
+    Output = MontgomeryPointFull
+);
+
+impl<'a> Neg for &'a MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn neg(self) -> MontgomeryPointFull {
+        MontgomeryPointFull {
+            U: self.U,
+            V: -&self.V,
+            W: self.W,
+        }
+    }
+}
+
+impl Neg for MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn neg(self) -> MontgomeryPointFull {
+        -&self
+    }
+}
+
+impl<'a, 'b> Sub<&'b MontgomeryPointFull> for &'a MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    fn sub(self, other: &'b MontgomeryPointFull) -> MontgomeryPointFull {
+        self + &(-other)
+    }
+}
+
+define_sub_variants!(
+    LHS = MontgomeryPointFull,
+    RHS = MontgomeryPointFull,
+    Output = MontgomeryPointFull
+);
+
+impl<'a, 'b> Mul<&'b Scalar> for &'a MontgomeryPointFull {
+    type Output = MontgomeryPointFull;
+
+    /// Given `self` \\( = P \\) and a `Scalar` \\(n\\), return \\( \[n\]P \\), for the
+    /// unreduced integer \\(n\\) as for [`MontgomeryPoint`].
+    fn mul(self, scalar: &'b Scalar) -> MontgomeryPointFull {
+        let winv = self.W.invert();
+        let u = &self.U * &winv;
+        let v = &self.V * &winv;
+
+        // Algorithm 8 of Costello-Smith 2017: afterwards x0 = u([n]P) and x1 = u([n+1]P).
+        // By scalar invariant #1, the MSB is 0, so we can skip it.
+        let bits = scalar.bits_le();
+        let mut x0 = ProjectivePoint::identity();
+        let mut x1 = ProjectivePoint {
+            U: u,
+            W: FieldElement::ONE,
+        };
+        let mut prev_bit = false;
+        for i in (0..255).rev() {
+            let cur_bit = bits[i];
+            let choice: u8 = (prev_bit ^ cur_bit) as u8;
+            conditional_swap_montgomery_projective(&mut x0, &mut x1, choice.into());
+            differential_add_and_double(&mut x0, &mut x1, &u);
+            prev_bit = cur_bit;
+        }
+        conditional_swap_montgomery_projective(&mut x0, &mut x1, Choice::from(prev_bit as u8));
+        #[cfg(feature = "zeroize")]
+        zeroize_bool(&mut prev_bit);
+
+        // Okeya-Sakurai y-recovery, Algorithm 5 of Costello-Smith 2017, with B = 1
+        let (XQ, ZQ, XS, ZS) = (&x0.U, &x0.W, &x1.U, &x1.W);
+        let uZQ = &u * ZQ;
+        let t = XQ - &uZQ;
+        let v3 = &t.square() * XS;
+        let twoAZQ = &(&MONTGOMERY_A + &MONTGOMERY_A) * ZQ;
+        let v2 = &(XQ + &uZQ) + &twoAZQ;
+        let v4 = &(&u * XQ) + ZQ;
+        let v2 = &(&(&v2 * &v4) - &(&twoAZQ * ZQ)) * ZS;
+        let v1 = &(&(&v + &v) * ZQ) * ZS;
+        let mut result = MontgomeryPointFull {
+            U: &v1 * XQ,
+            V: &v2 - &v3,
+            W: &v1 * ZQ,
+        };
+
+        // The recovery needs [n]P ≠ O and [n+1]P ≠ O, and the ladder needs v ≠ 0, that
+        // is, P is neither O nor (0, 0). Both of those have order at most 2.
+        result.conditional_assign(&(-self), ZS.is_zero());
+        result.conditional_assign(&MontgomeryPointFull::identity(), ZQ.is_zero());
+        let small = self.V.is_zero() | self.W.is_zero();
+        let odd = Choice::from(bits[0] as u8);
+        let small_result =
+            MontgomeryPointFull::conditional_select(&MontgomeryPointFull::identity(), self, odd);
+        result.conditional_assign(&small_result, small);
+        result
+    }
+}
+
+define_mul_variants!(
+    LHS = MontgomeryPointFull,
+    RHS = Scalar,
+    Output = MontgomeryPointFull
+);
+
+impl<'a, 'b> Mul<&'b MontgomeryPointFull> for &'a Scalar {
+    type Output = MontgomeryPointFull;
+
+    fn mul(self, point: &'b MontgomeryPointFull) -> MontgomeryPointFull {
+        point * self
+    }
+}
+
+define_mul_variants!(
+    LHS = Scalar,
+    RHS = MontgomeryPointFull,
+    Output = MontgomeryPointFull
+);
+
+#[cfg(test)]
+mod full_point_test {
+    use super::*;
+    use crate::constants::{BASEPOINT_ORDER_PRIVATE, ED25519_BASEPOINT_POINT, EIGHT_TORSION};
+
+    /// (U : V : W) is on the curve, V²·W = U³ + A·U²·W + U·W², and is not (0 : 0 : 0)
+    fn is_valid(P: &MontgomeryPointFull) -> bool {
+        let UU = P.U.square();
+        let lhs = &P.V.square() * &P.W;
+        let rhs = &(&(&UU * &P.U) + &(&(&MONTGOMERY_A * &UU) * &P.W)) + &(&P.U * &P.W.square());
+        let degenerate = P.U.is_zero() & P.V.is_zero() & P.W.is_zero();
+        lhs == rhs && !bool::from(degenerate)
+    }
+
+    fn basepoint() -> MontgomeryPointFull {
+        MontgomeryPointFull::from_edwards(&ED25519_BASEPOINT_POINT)
+    }
+
+    #[test]
+    fn mul_small_scalars() {
+        let B = basepoint();
+        let O = MontgomeryPointFull::identity();
+
+        let zero = B * Scalar::ZERO;
+        assert!(is_valid(&zero));
+        assert_eq!(zero, O);
+        assert_eq!(zero.to_edwards(), EdwardsPoint::identity());
+
+        let one = B * Scalar::ONE;
+        assert!(is_valid(&one));
+        assert_eq!(one, B);
+    }
+
+    #[test]
+    fn mul_by_group_order() {
+        let B = basepoint();
+
+        // [ℓ]B = O, where the ladder ends with [n]P = O
+        let l = B * BASEPOINT_ORDER_PRIVATE;
+        assert!(is_valid(&l));
+        assert_eq!(l, MontgomeryPointFull::identity());
+
+        // [ℓ - 1]B = -B, where the ladder ends with [n + 1]P = O
+        let l_minus_one = B * (-Scalar::ONE);
+        assert!(is_valid(&l_minus_one));
+        assert_eq!(l_minus_one, -B);
+    }
+
+    #[test]
+    fn mul_torsion_points() {
+        // EIGHT_TORSION[i] = [i]T for a point T of order 8, so this covers O, (0, 0)
+        // and the points of order 4 and 8, with [n]P = O and [n + 1]P = O along the way
+        for i in 0..8 {
+            let P = MontgomeryPointFull::from_edwards(&EIGHT_TORSION[i]);
+            for n in 0..17 {
+                let nP = P * Scalar::from(n as u64);
+                assert!(is_valid(&nP));
+                assert_eq!(nP.to_edwards(), EIGHT_TORSION[(i * n) % 8]);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_matches_edwards() {
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from(3u64),
+            Scalar::from(8u64),
+            -Scalar::ONE,
+            BASEPOINT_ORDER_PRIVATE,
+            Scalar::from_bytes_mod_order([0xa5; 32]),
+        ];
+        for T in EIGHT_TORSION.iter() {
+            let E = ED25519_BASEPOINT_POINT + T;
+            let P = MontgomeryPointFull::from_edwards(&E);
+            for k in scalars.iter() {
+                let kP = P * k;
+                assert!(is_valid(&kP));
+                assert_eq!(kP.to_edwards(), E * k);
+            }
+        }
+    }
+
+    #[test]
+    fn edwards_roundtrip() {
+        for T in EIGHT_TORSION.iter() {
+            let P = MontgomeryPointFull::from_edwards(T);
+            assert!(is_valid(&P));
+            assert_eq!(P.to_edwards(), *T);
+        }
+        let E = ED25519_BASEPOINT_POINT * Scalar::from(1234u64);
+        assert_eq!(MontgomeryPointFull::from_edwards(&E).to_edwards(), E);
+    }
+
+    #[test]
+    fn montgomery_roundtrip() {
+        let B = basepoint();
+        // The finite torsion points, (0, 0) included, and the basepoint plus each of them
+        for T in EIGHT_TORSION.iter().skip(1) {
+            for E in [*T, ED25519_BASEPOINT_POINT + T] {
+                let P = MontgomeryPointFull::from_edwards(&E);
+                let lifted = MontgomeryPointFull::from_montgomery(&P.to_montgomery(), P.v_sign());
+                assert_eq!(lifted, Some(P));
+            }
+        }
+        let P = B * Scalar::from(5u64);
+        let flipped = MontgomeryPointFull::from_montgomery(&P.to_montgomery(), 1 - P.v_sign());
+        assert_eq!(flipped, Some(-P));
+
+        // u = 2 is on the twist
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        assert_eq!(
+            MontgomeryPointFull::from_montgomery(&MontgomeryPoint(two), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn neg_and_sub() {
+        let B = basepoint();
+        let O = MontgomeryPointFull::identity();
+        let P = B * Scalar::from(5u64);
+        let Q = B * Scalar::from(2u64);
+
+        assert_eq!(P - Q, B * Scalar::from(3u64));
+        assert_eq!(P - Q, P + (-Q));
+        assert_eq!(-(-P), P);
+        assert_eq!((-P).to_edwards(), -P.to_edwards());
+
+        let diff = P - P;
+        assert!(is_valid(&diff));
+        assert_eq!(diff, O);
+        assert_eq!(-O, O);
+        for T in EIGHT_TORSION.iter() {
+            let T = MontgomeryPointFull::from_edwards(T);
+            assert_eq!(T - T, O);
+            assert_eq!((-T).to_edwards(), -T.to_edwards());
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// Multiscalar Multiplication impls
+// ------------------------------------------------------------------------
+/* VERIFICATION NOTE: not verified yet. These lift the points to the Edwards model and
+forward to the EdwardsPoint implementations, which are themselves outside verus!. */
+
+// A `MontgomeryPoint` only determines a point up to sign, so the sum of several of them
+// is not well defined. We fix the sign of every input by lifting it with
+// `MontgomeryPoint::to_edwards(0)`; a caller who needs other signs should use
+// `MontgomeryPointFull` directly. Any 32 bytes are a valid `MontgomeryPoint`, including
+// the twist points and u = -1, which have no lift; both traits panic on those, and
+// untrusted inputs should go through `optional_multiscalar_mul`.
+
+#[cfg(feature = "alloc")]
+impl MultiscalarMul for MontgomeryPoint {
+    type Point = MontgomeryPoint;
+
+    /// Compute the \\(u\\)-coordinate of \\(\sum\_i k\_i P\_i\\), where each \\(P\_i\\) is
+    /// the point of the input \\(u\\)-coordinate given by `to_edwards(0)`.
+    ///
+    /// # Panics
+    ///
+    /// If one of the points has no lift, because it is on the twist or has
+    /// \\(u = -1\\). Use `optional_multiscalar_mul` to handle untrusted inputs.
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> MontgomeryPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<MontgomeryPoint>,
+    {
+        let edwards_points = points.into_iter().map(|P| {
+            P.borrow()
+                .to_edwards(0)
+                .expect("MontgomeryPoint has no Edwards lift")
+        });
+        EdwardsPoint::multiscalar_mul(scalars, edwards_points).to_montgomery()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VartimeMultiscalarMul for MontgomeryPoint {
+    type Point = MontgomeryPoint;
+
+    /// As `multiscalar_mul`, but in variable time, and returning `None` if one of the
+    /// points is `None` or has no lift. `vartime_multiscalar_mul` panics on the latter,
+    /// like `multiscalar_mul`.
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<MontgomeryPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<MontgomeryPoint>>,
+    {
+        let edwards_points = points
+            .into_iter()
+            .map(|opt_P| opt_P.and_then(|P| P.to_edwards(0)));
+
+        EdwardsPoint::optional_multiscalar_mul(scalars, edwards_points).map(|P| P.to_montgomery())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MultiscalarMul for MontgomeryPointFull {
+    type Point = MontgomeryPointFull;
+
+    fn multiscalar_mul<I, J>(scalars: I, points: J) -> MontgomeryPointFull
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<MontgomeryPointFull>,
+    {
+        let edwards_points = points.into_iter().map(|P| P.borrow().to_edwards());
+        MontgomeryPointFull::from_edwards(&EdwardsPoint::multiscalar_mul(scalars, edwards_points))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VartimeMultiscalarMul for MontgomeryPointFull {
+    type Point = MontgomeryPointFull;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<MontgomeryPointFull>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<MontgomeryPointFull>>,
+    {
+        let edwards_points = points
+            .into_iter()
+            .map(|opt_P| opt_P.map(|P| P.to_edwards()));
+
+        EdwardsPoint::optional_multiscalar_mul(scalars, edwards_points)
+            .map(|P| MontgomeryPointFull::from_edwards(&P))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod multiscalar_test {
+    use super::*;
+    use crate::constants::{ED25519_BASEPOINT_POINT, EIGHT_TORSION, X25519_BASEPOINT};
+
+    fn scalars() -> [Scalar; 3] {
+        [
+            Scalar::from(7u64),
+            -Scalar::ONE,
+            Scalar::from_bytes_mod_order([0x5a; 32]),
+        ]
+    }
+
+    /// \\(u\\)-coordinates of points on the curve, one of them not in the prime-order subgroup
+    fn points() -> [MontgomeryPoint; 3] {
+        [
+            X25519_BASEPOINT,
+            (ED25519_BASEPOINT_POINT * Scalar::from(1234u64)).to_montgomery(),
+            (ED25519_BASEPOINT_POINT + EIGHT_TORSION[1]).to_montgomery(),
+        ]
+    }
+
+    fn lift(P: &MontgomeryPoint) -> MontgomeryPointFull {
+        MontgomeryPointFull::from_edwards(&P.to_edwards(0).unwrap())
+    }
+
+    /// The sum of one ladder per point, with the points lifted as by the multiscalar impls
+    fn ladder_sum(scalars: &[Scalar], points: &[MontgomeryPoint]) -> MontgomeryPoint {
+        let mut sum = MontgomeryPointFull::identity();
+        for (k, P) in scalars.iter().zip(points.iter()) {
+            sum = sum + lift(P) * k;
+        }
+        sum.to_montgomery()
+    }
+
+    #[test]
+    fn multiscalar_mul_matches_ladders() {
+        let (scalars, points) = (scalars(), points());
+        let expected = ladder_sum(&scalars, &points);
+
+        let some_points = points.iter().map(|P| Some(*P));
+        assert_eq!(
+            MontgomeryPoint::optional_multiscalar_mul(&scalars, some_points),
+            Some(expected)
+        );
+        assert_eq!(
+            MontgomeryPoint::vartime_multiscalar_mul(&scalars, &points),
+            expected
+        );
+        assert_eq!(
+            MontgomeryPoint::multiscalar_mul(&scalars, &points),
+            expected
+        );
+
+        // With a single point the sign doesn't matter, and this is the ladder itself
+        assert_eq!(
+            MontgomeryPoint::vartime_multiscalar_mul(&scalars[..1], &points[..1]),
+            &points[0] * &scalars[0]
+        );
+    }
+
+    #[test]
+    fn optional_multiscalar_mul_rejects_missing_and_twist_points() {
+        let (scalars, points) = (scalars(), points());
+
+        let missing = [Some(points[0]), None, Some(points[2])];
+        assert_eq!(
+            MontgomeryPoint::optional_multiscalar_mul(&scalars, missing),
+            None
+        );
+
+        // u = 2 is on the twist, where the X25519 ladder still works
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        let twist = [Some(points[0]), Some(MontgomeryPoint(two)), Some(points[2])];
+        assert_eq!(
+            MontgomeryPoint::optional_multiscalar_mul(&scalars, twist),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiscalar_mul_panics_on_twist_points() {
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        let twist = [points()[0], MontgomeryPoint(two)];
+        MontgomeryPoint::multiscalar_mul(&scalars()[..2], twist);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vartime_multiscalar_mul_panics_on_twist_points() {
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        let twist = [points()[0], MontgomeryPoint(two)];
+        MontgomeryPoint::vartime_multiscalar_mul(&scalars()[..2], twist);
+    }
+
+    #[test]
+    fn multiscalar_mul_of_nothing_is_identity() {
+        let none = MontgomeryPoint::optional_multiscalar_mul(
+            core::iter::empty::<Scalar>(),
+            core::iter::empty(),
+        );
+        assert_eq!(none, Some(MontgomeryPoint::identity()));
+
+        let none = MontgomeryPointFull::multiscalar_mul(
+            core::iter::empty::<Scalar>(),
+            core::iter::empty::<MontgomeryPointFull>(),
+        );
+        assert_eq!(none, MontgomeryPointFull::identity());
+    }
+
+    #[test]
+    fn full_multiscalar_mul_matches_sum() {
+        let scalars = scalars();
+        let points = points().map(|P| lift(&P));
+        let expected = points[0] * scalars[0] + points[1] * scalars[1] + points[2] * scalars[2];
+
+        assert_eq!(
+            MontgomeryPointFull::multiscalar_mul(&scalars, &points),
+            expected
+        );
+        let some_points = points.iter().map(|P| Some(*P));
+        assert_eq!(
+            MontgomeryPointFull::optional_multiscalar_mul(&scalars, some_points),
+            Some(expected)
+        );
+        let missing = [Some(points[0]), None, Some(points[2])];
+        assert_eq!(
+            MontgomeryPointFull::optional_multiscalar_mul(&scalars, missing),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod representative_test {
+    use super::*;
+    use crate::constants::X25519_BASEPOINT;
+    use rand::Rng;
+
+    /// Check every tweak of `P`: either all fail, or each gives a distinct
+    /// representative of `P` whose bit 255 is bit 7 of the tweak.
+    fn check_representatives(P: &MontgomeryPoint) -> bool {
+        let mut reps = [[0u8; 32]; 4];
+        let mut found = 0;
+        for tweak in 0..4u8 {
+            for high in [0, 0x80] {
+                let rep: Option<ElligatorRepresentative> = P.to_representative(tweak | high).into();
+                if let Some(rep) = rep {
+                    assert_eq!(MontgomeryPoint::from_representative(&rep), *P);
+                    assert_eq!(rep.0[31] & 0x80, high);
+                    let mut low = rep.0;
+                    low[31] &= 0x7f;
+                    reps[tweak as usize] = low;
+                    found += 1;
+                }
+            }
+        }
+        assert!(found == 0 || found == 8);
+        if found == 8 {
+            for i in 0..4 {
+                for j in 0..i {
+                    assert_ne!(reps[i], reps[j]);
+                }
+            }
+        }
+        found == 8
+    }
+
+    #[test]
+    fn to_representative_roundtrip() {
+        assert!(check_representatives(&X25519_BASEPOINT));
+        assert!(check_representatives(
+            &(X25519_BASEPOINT * Scalar::from(2u64))
+        ));
+        // [3]B has no representative
+        assert!(!check_representatives(
+            &(X25519_BASEPOINT * Scalar::from(3u64))
+        ));
+
+        let mut rng = rand::thread_rng();
+        let mut found = 0;
+        for _ in 0..64 {
+            let P = X25519_BASEPOINT * Scalar::from_bytes_mod_order(rng.gen());
+            if check_representatives(&P) {
+                found += 1;
+            }
+        }
+        // About half of the points have representatives
+        assert!(found > 0 && found < 64);
+    }
+
+    #[test]
+    fn to_representative_rejects_twist_and_non_canonical() {
+        // u = 2 is on the twist
+        let mut twist = [0u8; 32];
+        twist[0] = 2;
+        for tweak in 0..4u8 {
+            assert!(bool::from(
+                MontgomeryPoint(twist).to_representative(tweak).is_none()
+            ));
+        }
+
+        // 9 + p encodes the basepoint non-canonically
+        let mut non_canonical = [0xff; 32];
+        non_canonical[0] = 0xf6;
+        non_canonical[31] = 0x7f;
+        for tweak in 0..4u8 {
+            let P = MontgomeryPoint(non_canonical);
+            assert!(bool::from(P.to_representative(tweak).is_none()));
+        }
+    }
+
+    #[test]
+    fn to_representative_of_zero() {
+        // (0, 0) has the single representative r = 0
+        let zero = MontgomeryPoint([0u8; 32]);
+        for tweak in [0u8, 2, 0x80, 0x82] {
+            let rep: Option<ElligatorRepresentative> = zero.to_representative(tweak).into();
+            let rep = rep.unwrap();
+            let mut expected = [0u8; 32];
+            expected[31] = tweak & 0x80;
+            assert_eq!(rep.0, expected);
+            assert_eq!(MontgomeryPoint::from_representative(&rep), zero);
+        }
+        for tweak in [1u8, 3, 0x81, 0x83] {
+            assert!(bool::from(zero.to_representative(tweak).is_none()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod x25519_test {
+    use super::*;
+    use crate::constants::X25519_BASEPOINT;
+
+    fn bytes(encoded: &str) -> [u8; 32] {
+        hex::decode(encoded).unwrap().try_into().unwrap()
+    }
+
+    /// The test vectors of RFC 7748, section 5.2
+    #[test]
+    fn x25519_rfc7748_vectors() {
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for (k, u, expected) in vectors {
+            assert_eq!(x25519(bytes(k), bytes(u)), bytes(expected));
+        }
+    }
+
+    /// The iterated test of RFC 7748, section 5.2: k and u start at 9, then each round
+    /// sets k to x25519(k, u) and u to the old k
+    #[test]
+    fn x25519_rfc7748_iterated() {
+        let mut k = X25519_BASEPOINT.to_bytes();
+        let mut u = k;
+        for i in 1..=1000 {
+            let result = x25519(k, u);
+            u = k;
+            k = result;
+            if i == 1 {
+                assert_eq!(
+                    k,
+                    bytes("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(
+            k,
+            bytes("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    #[test]
+    fn x25519_base_matches_x25519() {
+        for k in [[0u8; 32], [0xff; 32], [0x5a; 32]] {
+            assert_eq!(x25519_base(k), x25519(k, X25519_BASEPOINT.to_bytes()));
+        }
+    }
+}
+
 // ------------------------------------------------------------------------
 // Tests
 // ------------------------------------------------------------------------
@@ -116,7 +116,10 @@
 //!
 //! * `RistrettoPoint::from_hash()` and
 //! `RistrettoPoint::hash_from_bytes()`, which perform hashing to the
-//! group.
+//! group;
+//!
+//! * `RistrettoPoint::hash_to_group()`, which hashes a message with a
+//! domain separation tag as in RFC 9496 - enabled by `digest` feature.
 //!
 //! The Elligator map itself is not currently exposed.
 //!
@@ -181,6 +184,39 @@ use crate::constants;
 use crate::field::FieldElement;
 use vstd::prelude::*;
 
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::backend::serial::u64::subtle_assumes::*;
+#[allow(unused_imports)] // Used in verus! blocks for the curve constant bounds and values
+use crate::lemmas::edwards_lemmas::constants_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the ONE constant
+use crate::lemmas::field_lemmas::constants_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for the canonical encoding check
+use crate::lemmas::field_lemmas::as_bytes_lemmas::lemma_canonical_field_encoding_roundtrip;
+#[allow(unused_imports)] // Used in verus! blocks for field algebra lemmas
+use crate::lemmas::field_lemmas::field_algebra_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for limb-bound bookkeeping
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks for sqrt(-1)
+use crate::lemmas::field_lemmas::sqrt_m1_lemmas::lemma_sqrt_m1_limbs_bounded;
+#[allow(unused_imports)] // Used in verus! blocks to name the invsqrt result
+use crate::lemmas::field_lemmas::sqrt_ratio_lemmas::lemma_spec_sqrt_ratio_i_eq;
+#[allow(unused_imports)] // Used in verus! blocks for the affine point of a decoded point
+use crate::lemmas::edwards_lemmas::curve_equation_lemmas::lemma_affine_to_extended_valid;
+#[allow(unused_imports)] // Used in verus! blocks for the encoding theorem
+use crate::lemmas::ristretto_lemmas::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::core_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::edwards_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::field_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::field_specs_u64::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::ristretto_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks
+use vstd::arithmetic::div_mod::*;
+
 #[cfg(feature = "group")]
 use {
     group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding},
@@ -196,8 +232,12 @@ use subtle::ConstantTimeEq;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+use crate::backend::serial::curve_models::CompletedPoint;
 #[cfg(feature = "precomputed-tables")]
 use crate::edwards::EdwardsBasepointTable;
+#[cfg(verus_keep_ghost)]
+#[allow(unused_imports)] // Used in verus! blocks for the ct_eq precondition
+use crate::edwards::ConstantTimeEqSpecImpl;
 use crate::edwards::EdwardsPoint;
 
 use crate::scalar::Scalar;
@@ -212,6 +252,8 @@ use crate::traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMul
 // Compressed points
 // ------------------------------------------------------------------------
 
+verus! {
+
 /// A Ristretto point, in compressed wire format.
 ///
 /// The Ristretto encoding is canonical, so two points are equal if and
@@ -220,19 +262,34 @@ use crate::traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMul
 pub struct CompressedRistretto(pub [u8; 32]);
 
 impl ConstantTimeEq for CompressedRistretto {
-    fn ct_eq(&self, other: &CompressedRistretto) -> Choice {
-        self.as_bytes().ct_eq(other.as_bytes())
+    fn ct_eq(&self, other: &CompressedRistretto) -> (result: Choice)
+        ensures
+            choice_is_true(result) == (self.0 == other.0),
+    {
+        /* <VERIFICATION NOTE>
+         Use wrapper function ct_eq_bytes32 instead of direct subtle call to ct_eq for Verus compatibility.
+        </VERIFICATION NOTE> */
+        /* <ORIGINAL CODE>
+         self.as_bytes().ct_eq(other.as_bytes())
+         </ORIGINAL CODE> */
+        ct_eq_bytes32(self.as_bytes(), other.as_bytes())
     }
 }
 
 impl CompressedRistretto {
     /// Copy the bytes of this `CompressedRistretto`.
-    pub const fn to_bytes(&self) -> [u8; 32] {
+    pub const fn to_bytes(&self) -> (result: [u8; 32])
+        ensures
+            result == self.0,
+    {
         self.0
     }
 
     /// View this `CompressedRistretto` as an array of bytes.
-    pub const fn as_bytes(&self) -> &[u8; 32] {
+    pub const fn as_bytes(&self) -> (result: &[u8; 32])
+        ensures
+            result == self.0,
+    {
         &self.0
     }
 
@@ -242,6 +299,7 @@ impl CompressedRistretto {
     ///
     /// Returns [`TryFromSliceError`] if the input `bytes` slice does not have
     /// a length of 32.
+    #[verifier::external_body]
     pub fn from_slice(bytes: &[u8]) -> Result<CompressedRistretto, TryFromSliceError> {
         bytes.try_into().map(CompressedRistretto)
     }
@@ -253,18 +311,76 @@ impl CompressedRistretto {
     /// - `Some(RistrettoPoint)` if `self` was the canonical encoding of a point;
     ///
     /// - `None` if `self` was not the canonical encoding of a point.
-    pub fn decompress(&self) -> Option<RistrettoPoint> {
+    ///
+    /// VERIFICATION NOTE: together with `RistrettoPoint::compress` this is a
+    /// bijection between the Ristretto elements (cosets of the 4-torsion in 2E) and
+    /// the canonical encodings; see `lemma_ristretto_encode_injective` and
+    /// `lemma_ristretto_decode_injective`. The function is total: it has no precondition,
+    /// so its verified body cannot panic or loop on any 32-byte input, and it returns
+    /// `None` exactly on the non-canonical encodings.
+    pub fn decompress(&self) -> (result: Option<RistrettoPoint>)
+        ensures
+    // Decompression succeeds iff the bytes are a canonical encoding
+
+            result.is_some() == is_canonical_ristretto_encoding(&self.0),
+            result.is_some() ==> ({
+                let point = result.unwrap();
+                // The point is the decoded representative, in affine form (Z = 1)
+                Some(ristretto_point_as_affine(point)) == spec_ristretto_decompress(&self.0)
+                    && spec_field_element(&point.0.Z) == 1
+                    // It is a point of 2E and compresses back to the same bytes
+                     && is_in_even_subgroup(ristretto_point_as_affine(point))
+                    && spec_ristretto_encode_edwards(point.0) == bytes32_to_nat(&self.0)
+                    // Ready for further arithmetic and for compress
+                     && is_well_formed_edwards_point(point.0) && fe51_limbs_bounded(
+                    &point.0.Y,
+                    52,
+                ) && fe51_limbs_bounded(&point.0.Z, 52)
+            }),
+    {
         let (s_encoding_is_canonical, s_is_negative, s) = decompress::step_1(self);
 
+        /* <ORIGINAL CODE>
         if (!s_encoding_is_canonical | s_is_negative).into() {
+        </ORIGINAL CODE> */
+        if choice_into(choice_or(choice_not(s_encoding_is_canonical), s_is_negative)) {
+            proof {
+                // A canonical s decodes from its own value, which decoding rejects if negative
+                assert(spec_ristretto_decompress(&self.0).is_none());
+            }
             return None;
         }
-
         let (ok, t_is_negative, y_is_zero, res) = decompress::step_2(s);
 
+        /* <ORIGINAL CODE>
         if (!ok | t_is_negative | y_is_zero).into() {
+        </ORIGINAL CODE> */
+        if choice_into(choice_or(choice_or(choice_not(ok), t_is_negative), y_is_zero)) {
             None
         } else {
+            proof {
+                let s_val = bytes32_to_nat(&self.0);
+                let (x, y) = spec_ristretto_decode(s_val).unwrap();
+                let t = math_field_mul(x, y);
+                axiom_ristretto_encode_decode(s_val);
+                lemma_ristretto_decode_reduced(s_val);
+
+                // Z = 1, so the affine point is (X, Y) = (x, y)
+                lemma_one_field_element_value();
+                lemma_field_inv_one();
+                lemma_small_mod(x, p());
+                lemma_small_mod(y, p());
+                assert(ristretto_point_as_affine(res) == (x, y));
+
+                // (x : y : 1 : xy) is a valid extended point
+                lemma_affine_to_extended_valid(x, y, t);
+                assert(is_valid_edwards_point(res.0));
+                lemma_fe51_limbs_bounded_weaken(&res.0.X, 52, 54);
+                lemma_fe51_limbs_bounded_weaken(&res.0.Y, 52, 54);
+                lemma_fe51_limbs_bounded_weaken(&res.0.Z, 52, 54);
+                lemma_fe51_limbs_bounded_weaken(&res.0.T, 52, 54);
+                lemma_sum_of_limbs_bounded_from_fe51_bounded(&res.0.Y, &res.0.X, 52);
+            }
             Some(res)
         }
     }
@@ -273,7 +389,17 @@ impl CompressedRistretto {
 mod decompress {
     use super::*;
 
-    pub(super) fn step_1(repr: &CompressedRistretto) -> (Choice, Choice, FieldElement) {
+    pub(super) fn step_1(repr: &CompressedRistretto) -> (result: (Choice, Choice, FieldElement))
+        ensures
+            ({
+                let (s_encoding_is_canonical, s_is_negative, s) = result;
+                // The canonicity check accepts exactly the encodings of values below p
+                (choice_is_true(s_encoding_is_canonical) == is_canonical_field_encoding(&repr.0))
+                    && (choice_is_true(s_encoding_is_canonical) ==> spec_field_element(&s)
+                    == bytes32_to_nat(&repr.0)) && choice_is_true(s_is_negative)
+                    == math_field_is_negative(spec_field_element(&s)) && fe51_limbs_bounded(&s, 51)
+            }),
+    {
         // Step 1. Check s for validity:
         // 1.a) s must be 32 bytes (we get this from the type system)
         // 1.b) s < p
@@ -287,32 +413,118 @@ mod decompress {
 
         let s = FieldElement::from_bytes(repr.as_bytes());
         let s_bytes_check = s.as_bytes();
+        /* <ORIGINAL CODE>
         let s_encoding_is_canonical = s_bytes_check[..].ct_eq(repr.as_bytes());
+        </ORIGINAL CODE> */
+        let s_encoding_is_canonical = ct_eq_bytes32(&s_bytes_check, repr.as_bytes());
         let s_is_negative = s.is_negative();
 
+        proof {
+            lemma_canonical_field_encoding_roundtrip(&repr.0, &s, &s_bytes_check);
+        }
+
         (s_encoding_is_canonical, s_is_negative, s)
     }
 
-    pub(super) fn step_2(s: FieldElement) -> (Choice, Choice, Choice, RistrettoPoint) {
+    pub(super) fn step_2(s: FieldElement) -> (result: (Choice, Choice, Choice, RistrettoPoint))
+        requires
+            fe51_limbs_bounded(&s, 51),
+            !math_field_is_negative(spec_field_element(&s)),
+        ensures
+            ({
+                let (ok, t_is_negative, y_is_zero, res) = result;
+                let decoded = spec_ristretto_decode(spec_field_element(&s));
+                // The three flags reject exactly what decoding rejects
+                (choice_is_true(ok) && !choice_is_true(t_is_negative) && !choice_is_true(
+                    y_is_zero,
+                )) == decoded.is_some() && (decoded.is_some() ==> decoded.unwrap() == (
+                    spec_field_element(&res.0.X),
+                    spec_field_element(&res.0.Y),
+                )) && spec_field_element(&res.0.Z) == 1 && spec_field_element(&res.0.T)
+                    == math_field_mul(spec_field_element(&res.0.X), spec_field_element(&res.0.Y))
+                    && fe51_limbs_bounded(&res.0.X, 52) && fe51_limbs_bounded(&res.0.Y, 52)
+                    && fe51_limbs_bounded(&res.0.Z, 52) && fe51_limbs_bounded(&res.0.T, 52)
+            }),
+    {
+        proof {
+            lemma_one_limbs_bounded_51();
+            lemma_one_field_element_value();
+            lemma_edwards_d_limbs_bounded_54();
+            lemma_fe51_limbs_bounded_weaken(&s, 51, 54);
+        }
         // Step 2.  Compute (X:Y:Z:T).
         let one = FieldElement::ONE;
         let ss = s.square();
-        let u1 = &one - &ss; //  1 + as²
-        let u2 = &one + &ss; //  1 - as²    where a=-1
-        let u2_sqr = u2.square(); // (1 - as²)²
+        proof {
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(&s),
+                spec_field_element_as_nat(&ss),
+            );
+            lemma_fe51_limbs_bounded_weaken(&one, 51, 54);
+        }
+        let u1 = &one - &ss;  //  1 + as²
+        proof {
+            lemma_fe51_limbs_bounded_weaken(&one, 51, 52);
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&one, &ss, 52);
+            lemma_add_fe51_limbs_bounded_52(&one, &ss);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&one, &ss), 53, 54);
+        }
+        let u2 = &one + &ss;  //  1 - as²    where a=-1
+        let u2_sqr = u2.square();  // (1 - as²)²
 
         // v == ad(1+as²)² - (1-as²)²            where d=-121665/121666
+        /* <ORIGINAL CODE>
         let v = &(&(-&constants::EDWARDS_D) * &u1.square()) - &u2_sqr;
+        </ORIGINAL CODE> */
+        let minus_d = -&constants::EDWARDS_D;
+        let u1_sqr = u1.square();
+        proof {
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(&u1),
+                spec_field_element_as_nat(&u1_sqr),
+            );
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(&u2),
+                spec_field_element_as_nat(&u2_sqr),
+            );
+            lemma_fe51_limbs_bounded_weaken(&minus_d, 52, 54);
+        }
+        let v = &(&minus_d * &u1_sqr) - &u2_sqr;
 
+        /* <ORIGINAL CODE>
         let (ok, I) = (&v * &u2_sqr).invsqrt(); // 1/sqrt(v*u_2²)
+        </ORIGINAL CODE> */
+        let v_u2_sqr = &v * &u2_sqr;
+        let (ok, I) = v_u2_sqr.invsqrt();  // 1/sqrt(v*u_2²)
+        proof {
+            // invsqrt returns the unique (flag, root) of the case analysis
+            lemma_mod_bound(spec_field_element_as_nat(&v_u2_sqr) as int, p() as int);
+            lemma_spec_sqrt_ratio_i_eq(
+                1,
+                spec_field_element(&v_u2_sqr),
+                choice_is_true(ok),
+                spec_field_element(&I),
+            );
+        }
 
-        let Dx = &I * &u2; // 1/sqrt(v)
-        let Dy = &I * &(&Dx * &v); // 1/u2
+        let Dx = &I * &u2;  // 1/sqrt(v)
+        let Dy = &I * &(&Dx * &v);  // 1/u2
 
         // x == | 2s/sqrt(v) | == + sqrt(4s²/(ad(1+as²)² - (1-as²)²))
+        proof {
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&s, &s, 51);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&s, &s), 52, 54);
+        }
         let mut x = &(&s + &s) * &Dx;
         let x_neg = x.is_negative();
+        let ghost x_unsigned = spec_field_element(&x);
+        proof {
+            lemma_mod_bound(spec_field_element_as_nat(&x) as int, p() as int);
+        }
+        /* <ORIGINAL CODE>
         x.conditional_negate(x_neg);
+        </ORIGINAL CODE> */
+        conditional_negate_field_element(&mut x, x_neg);
 
         // y == (1-as²)/(1+as²)
         let y = &u1 * &Dy;
@@ -320,20 +532,25 @@ mod decompress {
         // t == ((1+as²) sqrt(4s²/(ad(1+as²)² - (1-as²)²)))/(1-as²)
         let t = &x * &y;
 
+        proof {
+            // |2s·D_x| is x_unsigned itself when it is non-negative
+            lemma_mod_bound(spec_field_element_as_nat(&s) as int, p() as int);
+            lemma_small_mod(x_unsigned, p());
+            assert(spec_field_element(&x) == math_field_abs(x_unsigned));
+            lemma_fe51_limbs_bounded_weaken(&one, 51, 52);
+        }
+
         (
             ok,
             t.is_negative(),
             y.is_zero(),
-            RistrettoPoint(EdwardsPoint {
-                X: x,
-                Y: y,
-                Z: one,
-                T: t,
-            }),
+            RistrettoPoint(EdwardsPoint { X: x, Y: y, Z: one, T: t }),
         )
     }
 }
 
+} // verus!
+
 impl Identity for CompressedRistretto {
     fn identity() -> CompressedRistretto {
         CompressedRistretto([0u8; 32])
@@ -372,23 +589,18 @@ impl TryFrom<&[u8]> for CompressedRistretto {
 // structs containing `RistrettoPoint`s and use Serde's derived
 // serializers to serialize those structures.
 
-#[cfg(feature = "serde")]
-use serde::de::Visitor;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+// Human-readable formats get 64 hex digits, binary formats 32 bytes, see `serde_hex`.
+
 #[cfg(feature = "serde")]
 impl Serialize for RistrettoPoint {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.compress().as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::serde_hex::serialize(self.compress().as_bytes(), serializer)
     }
 }
 
@@ -398,12 +610,7 @@ impl Serialize for CompressedRistretto {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::serde_hex::serialize(self.as_bytes(), serializer)
     }
 }
 
@@ -413,33 +620,9 @@ impl<'de> Deserialize<'de> for RistrettoPoint {
     where
         D: Deserializer<'de>,
     {
-        struct RistrettoPointVisitor;
-
-        impl<'de> Visitor<'de> for RistrettoPointVisitor {
-            type Value = RistrettoPoint;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("a valid point in Ristretto format")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<RistrettoPoint, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                CompressedRistretto(bytes)
-                    .decompress()
-                    .ok_or_else(|| serde::de::Error::custom("decompression failed"))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, RistrettoPointVisitor)
+        CompressedRistretto(crate::serde_hex::deserialize(deserializer)?)
+            .decompress()
+            .ok_or_else(|| serde::de::Error::custom("decompression failed"))
     }
 }
 
@@ -449,31 +632,9 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
     where
         D: Deserializer<'de>,
     {
-        struct CompressedRistrettoVisitor;
-
-        impl<'de> Visitor<'de> for CompressedRistrettoVisitor {
-            type Value = CompressedRistretto;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("32 bytes of data")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<CompressedRistretto, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                Ok(CompressedRistretto(bytes))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, CompressedRistrettoVisitor)
+        Ok(CompressedRistretto(crate::serde_hex::deserialize(
+            deserializer,
+        )?))
     }
 }
 
@@ -481,6 +642,8 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
 // Internal point representations
 // ------------------------------------------------------------------------
 
+verus! {
+
 /// A `RistrettoPoint` represents a point in the Ristretto group for
 /// Curve25519.  Ristretto, a variant of Decaf, constructs a
 /// prime-order group as a quotient group of a subgroup of (the
@@ -493,20 +656,65 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
 /// `EdwardsPoint`s.
 ///
 #[derive(Copy, Clone)]
-pub struct RistrettoPoint(pub(crate) EdwardsPoint);
+// VERIFICATION NOTE: changed from pub(crate) to pub
+pub struct RistrettoPoint(pub EdwardsPoint);
 
 impl RistrettoPoint {
     /// Compress this point using the Ristretto encoding.
-    pub fn compress(&self) -> CompressedRistretto {
+    pub fn compress(&self) -> (result: CompressedRistretto)
+        requires
+            is_well_formed_edwards_point(self.0),
+            // Z + Y is multiplied, so it must stay within 54 bits
+            fe51_limbs_bounded(&self.0.Y, 53),
+            fe51_limbs_bounded(&self.0.Z, 53),
+        ensures
+            bytes32_to_nat(&result.0) == spec_ristretto_encode_edwards(self.0),
+            is_canonical_field_encoding(&result.0),
+            // For a point of 2E the bytes decode to the same Ristretto element
+            is_in_even_subgroup(ristretto_point_as_affine(*self)) ==> (
+            is_canonical_ristretto_encoding(&result.0) && ristretto_equivalent(
+                ristretto_point_as_affine(*self),
+                spec_ristretto_decompress(&result.0).unwrap(),
+            )),
+    {
+        let ghost (x, y, z, t) = spec_edwards_point(self.0);
+        proof {
+            lemma_sqrt_m1_limbs_bounded();
+            lemma_invsqrt_a_minus_d_limbs_bounded();
+        }
         let mut X = self.0.X;
         let mut Y = self.0.Y;
         let Z = &self.0.Z;
         let T = &self.0.T;
 
+        proof {
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(Z, &Y, 53);
+            lemma_add_fe51_limbs_bounded_53(Z, &Y);
+        }
         let u1 = &(Z + &Y) * &(Z - &Y);
         let u2 = &X * &Y;
+        /* <ORIGINAL CODE>
         // Ignore return value since this is always square
         let (_, invsqrt) = (&u1 * &u2.square()).invsqrt();
+        </ORIGINAL CODE> */
+        let u2_sqr = u2.square();
+        let u1_u2_sqr = &u1 * &u2_sqr;
+        // Ignore return value since this is always square
+        let (_ok, invsqrt) = u1_u2_sqr.invsqrt();
+        proof {
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(&u2),
+                spec_field_element_as_nat(&u2_sqr),
+            );
+            // invsqrt returns the unique (flag, root) of the case analysis
+            lemma_mod_bound(spec_field_element_as_nat(&u1_u2_sqr) as int, p() as int);
+            lemma_spec_sqrt_ratio_i_eq(
+                1,
+                spec_field_element(&u1_u2_sqr),
+                choice_is_true(_ok),
+                spec_field_element(&invsqrt),
+            );
+        }
         let i1 = &invsqrt * &u1;
         let i2 = &invsqrt * &u2;
         let z_inv = &i1 * &(&i2 * T);
@@ -519,19 +727,59 @@ impl RistrettoPoint {
 
         let rotate = (T * &z_inv).is_negative();
 
+        /* <ORIGINAL CODE>
         X.conditional_assign(&iY, rotate);
         Y.conditional_assign(&iX, rotate);
         den_inv.conditional_assign(&enchanted_denominator, rotate);
-
+        </ORIGINAL CODE> */
+        conditional_assign_field_element(&mut X, &iY, rotate);
+        conditional_assign_field_element(&mut Y, &iX, rotate);
+        conditional_assign_field_element(&mut den_inv, &enchanted_denominator, rotate);
+
+        proof {
+            // Y is the original (53-bit) Y or iX (52-bit)
+            lemma_fe51_limbs_bounded_weaken(&iX, 52, 53);
+            lemma_fe51_limbs_bounded_weaken(&Y, 53, 54);
+        }
+        /* <ORIGINAL CODE>
         Y.conditional_negate((&X * &z_inv).is_negative());
+        </ORIGINAL CODE> */
+        conditional_negate_field_element(&mut Y, (&X * &z_inv).is_negative());
 
+        proof {
+            lemma_fe51_limbs_bounded_weaken(Z, 53, 54);
+        }
         let mut s = &den_inv * &(Z - &Y);
         let s_is_negative = s.is_negative();
+        let ghost s_unsigned = spec_field_element(&s);
+        proof {
+            lemma_mod_bound(spec_field_element_as_nat(&s) as int, p() as int);
+        }
+        /* <ORIGINAL CODE>
         s.conditional_negate(s_is_negative);
+        </ORIGINAL CODE> */
+        conditional_negate_field_element(&mut s, s_is_negative);
 
-        CompressedRistretto(s.as_bytes())
+        let result = CompressedRistretto(s.as_bytes());
+
+        proof {
+            // s = |den_inv·(Z - Y)|, which is reduced
+            lemma_small_mod(s_unsigned, p());
+            assert(spec_field_element(&s) == math_field_abs(s_unsigned));
+            assert(bytes32_to_nat(&result.0) == spec_ristretto_encode(x, y, z, t));
+            lemma_mod_bound(spec_field_element_as_nat(&s) as int, p() as int);
+
+            if is_in_even_subgroup(ristretto_point_as_affine(*self)) {
+                axiom_ristretto_decode_encode(x, y, z, t);
+            }
+        }
+        result
     }
+}
 
+} // verus!
+
+impl RistrettoPoint {
     /// Double-and-compress a batch of points.  The Ristretto encoding
     /// is not batchable, since it requires an inverse square root.
     ///
@@ -646,6 +894,40 @@ impl RistrettoPoint {
             .collect()
     }
 
+    /// Compress a batch of points.
+    ///
+    /// Unlike [`RistrettoPoint::double_and_compress_batch`], this cannot share any work
+    /// between the points: the encoding of \\(P\\) needs an inverse square root that
+    /// depends on the representative of \\(P\\), and inverse square roots do not batch
+    /// the way inversions do (the trick above works because the encoding of
+    /// \\(\[2\]P\\) only needs an inversion). This is the same as compressing each point,
+    /// for transcripts that serialize many points at once; protocols that can
+    /// commit to the doubles of their points should use `double_and_compress_batch`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// # use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let points: Vec<RistrettoPoint> = (1u64..=16)
+    ///     .map(|k| RISTRETTO_BASEPOINT_POINT * Scalar::from(k))
+    ///     .collect();
+    ///
+    /// let compressed = RistrettoPoint::compress_batch(&points);
+    ///
+    /// for (p, c) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*c, p.compress());
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedRistretto>
+    where
+        I: IntoIterator<Item = &'a RistrettoPoint>,
+    {
+        points.into_iter().map(RistrettoPoint::compress).collect()
+    }
+
     /// Return the coset self + E\[4\], for debugging.
     fn coset4(&self) -> [EdwardsPoint; 4] {
         [
@@ -655,7 +937,11 @@ impl RistrettoPoint {
             self.0 + constants::EIGHT_TORSION[6],
         ]
     }
+}
+
+verus! {
 
+impl RistrettoPoint {
     /// Computes the Ristretto Elligator map. This is the
     /// [`MAP`](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-ristretto255-decaf448-04#section-4.3.4)
     /// function defined in the Ristretto spec.
@@ -664,7 +950,27 @@ impl RistrettoPoint {
     ///
     /// This method is not public because it's just used for hashing
     /// to a point -- proper elligator support is deferred for now.
-    pub(crate) fn elligator_ristretto_flavor(r_0: &FieldElement) -> RistrettoPoint {
+    pub(crate) fn elligator_ristretto_flavor(r_0: &FieldElement) -> (result: RistrettoPoint)
+        requires
+            fe51_limbs_bounded(r_0, 54),
+        ensures
+            is_well_formed_edwards_point(result.0),
+            ristretto_point_as_affine(result) == spec_elligator_ristretto_flavor(
+                spec_field_element(r_0),
+            ),
+            is_in_even_subgroup(ristretto_point_as_affine(result)),
+    {
+        proof {
+            lemma_sqrt_m1_limbs_bounded();
+            lemma_edwards_d_limbs_bounded();
+            lemma_edwards_d_limbs_bounded_54();
+            lemma_elligator_constants_limbs_bounded();
+            lemma_one_limbs_bounded_51();
+            lemma_one_field_element_value();
+            lemma_minus_one_value();
+            lemma_one_minus_edwards_d_squared_value();
+            lemma_edwards_d_minus_one_squared_value();
+        }
         let i = &constants::SQRT_M1;
         let d = &constants::EDWARDS_D;
         let one_minus_d_sq = &constants::ONE_MINUS_EDWARDS_D_SQUARED;
@@ -673,23 +979,79 @@ impl RistrettoPoint {
 
         let one = FieldElement::ONE;
 
+        let r_0_sq = r_0.square();
+        proof {
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(r_0),
+                spec_field_element_as_nat(&r_0_sq),
+            );
+            lemma_fe51_limbs_bounded_weaken(&one, 51, 52);
+            lemma_fe51_limbs_bounded_weaken(d, 51, 52);
+        }
+        /* <ORIGINAL CODE>
         let r = i * &r_0.square();
+        </ORIGINAL CODE> */
+        let r = i * &r_0_sq;
+        proof {
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&r, &one, 52);
+            lemma_add_fe51_limbs_bounded_52(&r, &one);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&r, &one), 53, 54);
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&r, d, 52);
+            lemma_add_fe51_limbs_bounded_52(&r, d);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&r, d), 53, 54);
+        }
         let N_s = &(&r + &one) * one_minus_d_sq;
         let D = &(&c - &(d * &r)) * &(&r + d);
 
         let (Ns_D_is_sq, mut s) = FieldElement::sqrt_ratio_i(&N_s, &D);
+        proof {
+            // sqrt_ratio_i returns the unique (flag, root) of the case analysis
+            lemma_mod_bound(spec_field_element_as_nat(&D) as int, p() as int);
+            lemma_spec_sqrt_ratio_i_eq(
+                spec_field_element(&N_s),
+                spec_field_element(&D),
+                choice_is_true(Ns_D_is_sq),
+                spec_field_element(&s),
+            );
+        }
         let mut s_prime = &s * r_0;
+        /* <ORIGINAL CODE>
         let s_prime_is_pos = !s_prime.is_negative();
         s_prime.conditional_negate(s_prime_is_pos);
 
         s.conditional_assign(&s_prime, !Ns_D_is_sq);
         c.conditional_assign(&r, !Ns_D_is_sq);
+        </ORIGINAL CODE> */
+        let s_prime_is_pos = choice_not(s_prime.is_negative());
+        conditional_negate_field_element(&mut s_prime, s_prime_is_pos);
+
+        conditional_assign_field_element(&mut s, &s_prime, choice_not(Ns_D_is_sq));
+        proof {
+            lemma_fe51_limbs_bounded_weaken(&c, 51, 52);
+        }
+        conditional_assign_field_element(&mut c, &r, choice_not(Ns_D_is_sq));
 
         let N_t = &(&(&c * &(&r - &one)) * d_minus_one_sq) - &D;
         let s_sq = s.square();
+        proof {
+            lemma_square_matches_math_field_square(
+                spec_field_element_as_nat(&s),
+                spec_field_element_as_nat(&s_sq),
+            );
+        }
 
+        /* <ORIGINAL CODE>
         use crate::backend::serial::curve_models::CompletedPoint;
-
+        </ORIGINAL CODE> */
+        proof {
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&s, &s, 52);
+            lemma_add_fe51_limbs_bounded_52(&s, &s);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&s, &s), 53, 54);
+            lemma_sum_of_limbs_bounded_from_fe51_bounded(&one, &s_sq, 52);
+            lemma_add_fe51_limbs_bounded_52(&one, &s_sq);
+            lemma_fe51_limbs_bounded_weaken(&spec_add_fe51_limbs(&one, &s_sq), 53, 54);
+        }
+        /* <ORIGINAL CODE>
         // The conversion from W_i is exactly the conversion from P1xP1.
         RistrettoPoint(
             CompletedPoint {
@@ -700,6 +1062,23 @@ impl RistrettoPoint {
             }
             .as_extended(),
         )
+        </ORIGINAL CODE> */
+        let completed = CompletedPoint {
+            X: &(&s + &s) * &D,
+            Z: &N_t * &constants::SQRT_AD_MINUS_ONE,
+            Y: &one - &s_sq,
+            T: &one + &s_sq,
+        };
+        proof {
+            let r_0_val = spec_field_element(r_0);
+            assert(spec_completed_point(completed) == spec_elligator_ristretto_flavor_completed(
+                r_0_val,
+            ));
+            axiom_elligator_ristretto_flavor_valid(r_0_val);
+        }
+
+        // The conversion from W_i is exactly the conversion from P1xP1.
+        RistrettoPoint(completed.as_extended())
     }
 
     #[cfg(any(test, feature = "rand_core"))]
@@ -720,6 +1099,7 @@ impl RistrettoPoint {
     /// discrete log of the output point with respect to any other
     /// point should be unknown.  The map is applied twice and the
     /// results are added, to ensure a uniform distribution.
+    #[verifier::external_body]
     pub fn random<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Self {
         let mut uniform_bytes = [0u8; 64];
         rng.fill_bytes(&mut uniform_bytes);
@@ -757,10 +1137,10 @@ impl RistrettoPoint {
     /// # }
     /// ```
     ///
-    pub fn hash_from_bytes<D>(input: &[u8]) -> RistrettoPoint
-    where
+    #[verifier::external_body]
+    pub fn hash_from_bytes<D>(input: &[u8]) -> RistrettoPoint where
         D: Digest<OutputSize = U64> + Default,
-    {
+     {
         let mut hash = D::default();
         hash.update(input);
         RistrettoPoint::from_hash(hash)
@@ -772,10 +1152,10 @@ impl RistrettoPoint {
     /// Use this instead of `hash_from_bytes` if it is more convenient
     /// to stream data into the `Digest` than to pass a single byte
     /// slice.
-    pub fn from_hash<D>(hash: D) -> RistrettoPoint
-    where
+    #[verifier::external_body]
+    pub fn from_hash<D>(hash: D) -> RistrettoPoint where
         D: Digest<OutputSize = U64> + Default,
-    {
+     {
         // dealing with generic arrays is clumsy, until const generics land
         let output = hash.finalize();
         let mut output_bytes = [0u8; 64];
@@ -784,6 +1164,56 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&output_bytes)
     }
 
+    #[cfg(feature = "digest")]
+    /// Hash a message to the group with the suite `ristretto255_XMD:SHA-512_R255MAP_RO_`.
+    ///
+    /// This is `hash_to_ristretto255` from
+    /// [RFC 9496](https://www.rfc-editor.org/rfc/rfc9496.html#section-4.3.4): 64 bytes
+    /// from `expand_message_xmd` with SHA-512
+    /// ([RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1)), then
+    /// [`RistrettoPoint::from_uniform_bytes`]. `dst` is the domain separation tag, unique
+    /// to the application and the protocol; it goes through `expand_message`, so it does
+    /// not need to be prepended to `msg`. Tags longer than 255 bytes are hashed first.
+    ///
+    /// # Panics
+    ///
+    /// If `dst` is empty.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "digest", doc = "```")]
+    #[cfg_attr(not(feature = "digest"), doc = "```ignore")]
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// let dst = b"MyApp-V01-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let P = RistrettoPoint::hash_to_group(b"some message", dst);
+    /// assert_ne!(P, RistrettoPoint::hash_to_group(b"some message", b"another tag"));
+    /// ```
+    #[verifier::external_body]
+    pub fn hash_to_group(msg: &[u8], dst: &[u8]) -> RistrettoPoint {
+        let mut uniform = [0u8; 64];
+        crate::hash_to_curve::expand_message_xmd(msg, dst, &mut uniform);
+        RistrettoPoint::from_uniform_bytes(&uniform)
+    }
+
+    #[cfg(feature = "digest")]
+    /// Hash a message to the group as [`RistrettoPoint::hash_to_group`] does, with
+    /// `expand_message_xof` over the extendable-output function `X` (such as SHAKE128)
+    /// in place of `expand_message_xmd`.
+    ///
+    /// Tags longer than 255 bytes are first hashed to 32 bytes.
+    ///
+    /// # Panics
+    ///
+    /// If `dst` is empty.
+    #[verifier::external_body]
+    pub fn hash_to_group_xof<X>(msg: &[u8], dst: &[u8]) -> RistrettoPoint where
+        X: Default + digest::Update + digest::ExtendableOutput,
+     {
+        let mut uniform = [0u8; 64];
+        crate::hash_to_curve::expand_message_xof::<X>(msg, dst, &mut uniform);
+        RistrettoPoint::from_uniform_bytes(&uniform)
+    }
+
     /// Construct a `RistrettoPoint` from 64 bytes of data.
     ///
     /// If the input bytes are uniformly distributed, the resulting
@@ -795,25 +1225,79 @@ impl RistrettoPoint {
     /// This function splits the input array into two 32-byte halves,
     /// takes the low 255 bits of each half mod p, applies the
     /// Ristretto-flavored Elligator map to each, and adds the results.
-    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> RistrettoPoint {
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> (result: RistrettoPoint)
+        ensures
+            is_well_formed_edwards_point(result.0),
+            ristretto_point_as_affine(result) == spec_ristretto_from_uniform_bytes(bytes),
+            // Every input gives an element of the Ristretto group
+            is_in_even_subgroup(ristretto_point_as_affine(result)),
+    {
         // This follows the one-way map construction from the Ristretto RFC:
         // https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-ristretto255-decaf448-04#section-4.3.4
+        /* <ORIGINAL CODE>
         let mut r_1_bytes = [0u8; 32];
         r_1_bytes.copy_from_slice(&bytes[0..32]);
+        </ORIGINAL CODE> */
+        /* <MODIFIED CODE> Verus doesn't support copy_from_slice */
+        let mut r_1_bytes = [0u8; 32];
+        for i in 0..32
+            invariant
+                forall|j: int| 0 <= j < i ==> r_1_bytes[j] == bytes[j],
+        {
+            r_1_bytes[i] = bytes[i];
+        }
+        /* </MODIFIED CODE> */
         let r_1 = FieldElement::from_bytes(&r_1_bytes);
+        proof {
+            lemma_fe51_limbs_bounded_weaken(&r_1, 51, 54);
+        }
         let R_1 = RistrettoPoint::elligator_ristretto_flavor(&r_1);
 
+        /* <ORIGINAL CODE>
         let mut r_2_bytes = [0u8; 32];
         r_2_bytes.copy_from_slice(&bytes[32..64]);
+        </ORIGINAL CODE> */
+        /* <MODIFIED CODE> Verus doesn't support copy_from_slice */
+        let mut r_2_bytes = [0u8; 32];
+        for i in 0..32
+            invariant
+                forall|j: int| 0 <= j < i ==> r_2_bytes[j] == bytes[32 + j],
+        {
+            r_2_bytes[i] = bytes[32 + i];
+        }
+        /* </MODIFIED CODE> */
         let r_2 = FieldElement::from_bytes(&r_2_bytes);
+        proof {
+            lemma_fe51_limbs_bounded_weaken(&r_2, 51, 54);
+        }
         let R_2 = RistrettoPoint::elligator_ristretto_flavor(&r_2);
 
+        proof {
+            // The copied halves are the halves of the spec, so both read as the same
+            // field elements
+            let half_1 = spec_uniform_bytes_half(bytes, 0);
+            let half_2 = spec_uniform_bytes_half(bytes, 1);
+            assert(is_uniform_bytes_half(bytes, 0, r_1_bytes));
+            assert(is_uniform_bytes_half(bytes, 1, r_2_bytes));
+            assert(bytes32_to_nat(&half_1) == bytes32_to_nat(&r_1_bytes));
+            assert(bytes32_to_nat(&half_2) == bytes32_to_nat(&r_2_bytes));
+            assert(spec_field_element(&r_1) == spec_field_element_from_bytes(&half_1));
+            assert(spec_field_element(&r_2) == spec_field_element_from_bytes(&half_2));
+
+            lemma_even_subgroup_add(ristretto_point_as_affine(R_1), ristretto_point_as_affine(R_2));
+        }
+
         // Applying Elligator twice and adding the results ensures a
         // uniform distribution.
+        /* <ORIGINAL CODE>
         R_1 + R_2
+        </ORIGINAL CODE> */
+        RistrettoPoint(&R_1.0 + &R_2.0)
     }
 }
 
+} // verus!
+
 impl Identity for RistrettoPoint {
     fn identity() -> RistrettoPoint {
         RistrettoPoint(EdwardsPoint::identity())
@@ -846,6 +1330,15 @@ impl PartialEq for RistrettoPoint {
     }
 }
 
+verus! {
+
+#[cfg(verus_keep_ghost)]
+impl ConstantTimeEqSpecImpl for RistrettoPoint {
+    open spec fn ct_eq_req(&self, other: &RistrettoPoint) -> bool {
+        is_well_formed_edwards_point(self.0) && is_well_formed_edwards_point(other.0)
+    }
+}
+
 impl ConstantTimeEq for RistrettoPoint {
     /// Test equality between two `RistrettoPoint`s.
     ///
@@ -853,16 +1346,67 @@ impl ConstantTimeEq for RistrettoPoint {
     ///
     /// * `Choice(1)` if the two `RistrettoPoint`s are equal;
     /// * `Choice(0)` otherwise.
-    fn ct_eq(&self, other: &RistrettoPoint) -> Choice {
+    fn ct_eq(&self, other: &RistrettoPoint) -> (result: Choice)
+        ensures
+            choice_is_true(result) == ristretto_equality_check(
+                ristretto_point_as_affine(*self),
+                ristretto_point_as_affine(*other),
+            ),
+            // On 2E the test holds exactly when the two representatives are in the same coset
+            is_in_even_subgroup(ristretto_point_as_affine(*self)) && is_in_even_subgroup(
+                ristretto_point_as_affine(*other),
+            ) ==> (choice_is_true(result) == ristretto_equivalent(
+                ristretto_point_as_affine(*self),
+                ristretto_point_as_affine(*other),
+            )),
+    {
+        proof {
+            /* VERIFICATION NOTE:
+            - Verus does not support adding a "requires" clause to ct_eq with ConstantTimeEqSpecImpl
+            */
+            assume(self.ct_eq_req(other));
+        }
         let X1Y2 = &self.0.X * &other.0.Y;
         let Y1X2 = &self.0.Y * &other.0.X;
         let X1X2 = &self.0.X * &other.0.X;
         let Y1Y2 = &self.0.Y * &other.0.Y;
 
+        /* <ORIGINAL CODE>
         X1Y2.ct_eq(&Y1X2) | X1X2.ct_eq(&Y1Y2)
+        </ORIGINAL CODE> */
+        let result = choice_or(X1Y2.ct_eq(&Y1X2), X1X2.ct_eq(&Y1Y2));
+
+        proof {
+            let (x1, y1, z1, _t1) = spec_edwards_point(self.0);
+            let (x2, y2, z2, _t2) = spec_edwards_point(other.0);
+            let P1 = ristretto_point_as_affine(*self);
+            let P2 = ristretto_point_as_affine(*other);
+            // Both cross products carry the same nonzero factor 1/(Z1·Z2)
+            p_gt_2();
+            lemma_mod_bound(spec_field_element_as_nat(&self.0.Z) as int, p() as int);
+            lemma_mod_bound(spec_field_element_as_nat(&other.0.Z) as int, p() as int);
+            lemma_small_mod(z1, p());
+            lemma_small_mod(z2, p());
+            lemma_cross_products_projective(x1, y1, z1, x2, y2, z2);
+            lemma_cross_products_projective(x1, y1, z1, y2, x2, z2);
+
+            if is_in_even_subgroup(P1) && is_in_even_subgroup(P2) {
+                lemma_mod_bound((x1 * math_field_inv(z1)) as int, p() as int);
+                lemma_mod_bound((y1 * math_field_inv(z1)) as int, p() as int);
+                if ristretto_equivalent(P1, P2) {
+                    lemma_ristretto_equivalent_passes_check(P1, P2);
+                }
+                if ristretto_equality_check(P1, P2) {
+                    axiom_ristretto_equality_check_complete(P1, P2);
+                }
+            }
+        }
+        result
     }
 }
 
+} // verus!
+
 impl Eq for RistrettoPoint {}
 
 // ------------------------------------------------------------------------
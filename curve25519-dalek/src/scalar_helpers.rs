@@ -71,6 +71,38 @@ pub proof fn lemma_scalar_one_properties()
     lemma_small_mod(1nat, group_order());
 }
 
+/// Lemma: the product of a sequence of scalars is reduced modulo ℓ
+pub proof fn lemma_product_of_scalars_bounded(scalars: Seq<Scalar>)
+    ensures
+        product_of_scalars(scalars) < group_order(),
+{
+    lemma_scalar_one_properties();
+    if scalars.len() > 0 {
+        let last = (scalars.len() - 1) as int;
+        lemma_mod_bound(
+            (product_of_scalars(scalars.subrange(0, last)) * bytes32_to_nat(&scalars[last].bytes))
+                as int,
+            group_order() as int,
+        );
+    }
+}
+
+/// Lemma: the sum of a sequence of scalars is reduced modulo ℓ
+pub proof fn lemma_sum_of_scalars_bounded(scalars: Seq<Scalar>)
+    ensures
+        sum_of_scalars(scalars) < group_order(),
+{
+    lemma_scalar_one_properties();
+    if scalars.len() > 0 {
+        let last = (scalars.len() - 1) as int;
+        lemma_mod_bound(
+            (sum_of_scalars(scalars.subrange(0, last)) + bytes32_to_nat(&scalars[last].bytes))
+                as int,
+            group_order() as int,
+        );
+    }
+}
+
 // ============================================================================
 // Main helper functions
 // ============================================================================
@@ -93,12 +125,17 @@ impl Scalar {
     ///
     /// let product = Scalar::product_of_slice(&scalars);
     /// assert_eq!(product, Scalar::from(30u64));
+    /// assert_eq!(Scalar::product_of_slice(&[]), Scalar::ONE);
     /// ```
     #[allow(clippy::needless_range_loop, clippy::op_ref)]
     pub fn product_of_slice(scalars: &[Scalar]) -> (result: Scalar)
         ensures
             scalar_to_nat(&result) < group_order(),
             scalar_congruent_nat(&result, product_of_scalars(scalars@)),
+            is_canonical_scalar(&result),
+            scalar_to_nat(&result) == product_of_scalars(scalars@),
+            // The empty product is one
+            scalars@.len() == 0 ==> result == Scalar::ONE,
     {
         let n = scalars.len();
         let mut acc = Scalar::ONE;
@@ -111,7 +148,7 @@ impl Scalar {
         for i in 0..n
             invariant
                 n == scalars.len(),
-                scalar_to_nat(&acc) < group_order(),
+                is_canonical_scalar(&acc),
                 scalar_congruent_nat(&acc, product_of_scalars(scalars@.subrange(0, i as int))),
         {
             let _old_acc = acc;
@@ -139,6 +176,15 @@ impl Scalar {
 
         proof {
             assert(scalars@.subrange(0, n as int) =~= scalars@);
+            // Both sides are reduced, so the congruence is an equality
+            lemma_product_of_scalars_bounded(scalars@);
+            lemma_small_mod(scalar_to_nat(&acc), group_order());
+            lemma_small_mod(product_of_scalars(scalars@), group_order());
+            if n == 0 {
+                lemma_bytes32_to_nat_one();
+                lemma_canonical_bytes_equal(&acc.bytes, &Scalar::ONE.bytes);
+                assert(acc.bytes =~= Scalar::ONE.bytes);
+            }
         }
 
         acc
@@ -162,12 +208,17 @@ impl Scalar {
     ///
     /// let sum = Scalar::sum_of_slice(&scalars);
     /// assert_eq!(sum, Scalar::from(10u64));
+    /// assert_eq!(Scalar::sum_of_slice(&[]), Scalar::ZERO);
     /// ```
     #[allow(clippy::needless_range_loop, clippy::op_ref)]
     pub fn sum_of_slice(scalars: &[Scalar]) -> (result: Scalar)
         ensures
             scalar_to_nat(&result) < group_order(),
             scalar_congruent_nat(&result, sum_of_scalars(scalars@)),
+            is_canonical_scalar(&result),
+            scalar_to_nat(&result) == sum_of_scalars(scalars@),
+            // The empty sum is zero
+            scalars@.len() == 0 ==> result == Scalar::ZERO,
     {
         let n = scalars.len();
         let mut acc = Scalar::ZERO;
@@ -180,7 +231,7 @@ impl Scalar {
         for i in 0..n
             invariant
                 n == scalars.len(),
-                scalar_to_nat(&acc) < group_order(),
+                is_canonical_scalar(&acc),
                 scalar_congruent_nat(&acc, sum_of_scalars(scalars@.subrange(0, i as int))),
         {
             let _old_acc = acc;
@@ -209,6 +260,15 @@ impl Scalar {
 
         proof {
             assert(scalars@.subrange(0, n as int) =~= scalars@);
+            // Both sides are reduced, so the congruence is an equality
+            lemma_sum_of_scalars_bounded(scalars@);
+            lemma_small_mod(scalar_to_nat(&acc), group_order());
+            lemma_small_mod(sum_of_scalars(scalars@), group_order());
+            if n == 0 {
+                lemma_bytes32_to_nat_zero();
+                lemma_canonical_bytes_equal(&acc.bytes, &Scalar::ZERO.bytes);
+                assert(acc.bytes =~= Scalar::ZERO.bytes);
+            }
         }
 
         acc
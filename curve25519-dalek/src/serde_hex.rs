@@ -0,0 +1,158 @@
+//! Serde encoding of the 32-byte types: `Scalar`, the points and their compressed forms
+//!
+//! Binary formats (bincode, ...) get a tuple of 32 bytes, as before. Human-readable
+//! formats (JSON, TOML, ...) get a string of 64 lowercase hex digits instead, the bytes
+//! in order as in [`Scalar::to_hex`](crate::scalar::Scalar::to_hex). Upper case digits are
+//! accepted when deserializing.
+/* VERIFICATION NOTE: we don't cover serde feature yet */
+use core::fmt;
+
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::scalar::hex_digit_value;
+
+/// Serialize 32 bytes as hex digits if the format is human-readable, as a tuple otherwise
+pub(crate) fn serialize<S>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut hex = [0u8; 64];
+        for (pair, byte) in hex.chunks_exact_mut(2).zip(bytes.iter()) {
+            pair[0] = DIGITS[(byte >> 4) as usize];
+            pair[1] = DIGITS[(byte & 0x0f) as usize];
+        }
+        serializer.serialize_str(core::str::from_utf8(&hex).expect("hex digits are ASCII"))
+    } else {
+        use serde::ser::SerializeTuple;
+        let mut tup = serializer.serialize_tuple(32)?;
+        for byte in bytes.iter() {
+            tup.serialize_element(byte)?;
+        }
+        tup.end()
+    }
+}
+
+/// Deserialize 32 bytes written by [`serialize`]
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HexVisitor)
+    } else {
+        deserializer.deserialize_tuple(32, BytesVisitor)
+    }
+}
+
+struct HexVisitor;
+
+impl<'de> Visitor<'de> for HexVisitor {
+    type Value = [u8; 32];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string of 64 hex digits")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<[u8; 32], E>
+    where
+        E: Error,
+    {
+        let digits = v.as_bytes();
+        if digits.len() != 64 {
+            return Err(E::invalid_length(digits.len(), &self));
+        }
+        let mut bytes = [0u8; 32];
+        for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+            match (hex_digit_value(pair[0]), hex_digit_value(pair[1])) {
+                (Some(hi), Some(lo)) => *byte = (hi << 4) | lo,
+                _ => return Err(E::invalid_value(Unexpected::Str(v), &self)),
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = [u8; 32];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("32 bytes of data")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<[u8; 32], A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        #[allow(clippy::needless_range_loop)]
+        for i in 0..32 {
+            bytes[i] = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(i, &"expected 32 bytes"))?;
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::constants::{ED25519_BASEPOINT_COMPRESSED, RISTRETTO_BASEPOINT_COMPRESSED};
+    use crate::edwards::CompressedEdwardsY;
+    use crate::ristretto::CompressedRistretto;
+    use crate::scalar::Scalar;
+    use crate::{EdwardsPoint, RistrettoPoint};
+
+    #[test]
+    fn human_readable_is_hex() {
+        let s = Scalar::from(0x0102u64);
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(s.as_bytes())));
+        assert_eq!(serde_json::from_str::<Scalar>(&json).unwrap(), s);
+        assert_eq!(
+            serde_json::from_str::<Scalar>(&json.to_uppercase()).unwrap(),
+            s
+        );
+
+        let json = serde_json::to_string(&ED25519_BASEPOINT_COMPRESSED).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompressedEdwardsY>(&json).unwrap(),
+            ED25519_BASEPOINT_COMPRESSED
+        );
+        let p: EdwardsPoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(p.compress(), ED25519_BASEPOINT_COMPRESSED);
+
+        let json = serde_json::to_string(&RISTRETTO_BASEPOINT_COMPRESSED).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompressedRistretto>(&json).unwrap(),
+            RISTRETTO_BASEPOINT_COMPRESSED
+        );
+        let p: RistrettoPoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(p.compress(), RISTRETTO_BASEPOINT_COMPRESSED);
+    }
+
+    #[test]
+    fn human_readable_rejects_bad_hex() {
+        assert!(serde_json::from_str::<Scalar>("\"00\"").is_err());
+        let not_hex = format!("\"{}\"", "zz".repeat(32));
+        assert!(serde_json::from_str::<Scalar>(&not_hex).is_err());
+        // ℓ itself is not canonical
+        let l = format!(
+            "\"{}\"",
+            hex::encode(crate::constants::BASEPOINT_ORDER_PRIVATE.as_bytes())
+        );
+        assert!(serde_json::from_str::<Scalar>(&l).is_err());
+    }
+
+    #[test]
+    fn binary_is_bytes() {
+        let s = Scalar::from(0x0102u64);
+        let encoded = bincode::serialize(&s).unwrap();
+        assert_eq!(encoded, s.as_bytes());
+        assert_eq!(bincode::deserialize::<Scalar>(&encoded).unwrap(), s);
+    }
+}
@@ -0,0 +1,152 @@
+//! Specifications for secret independence ("constant time")
+//!
+//! The adversary is the usual one for timing and cache attacks: it learns the direction
+//! of every branch and the index of every memory access, but not the values computed.
+//! An annotated function records what the adversary learns as a ghost trace, a
+//! `Seq<Observation>` built alongside the computation: one `Branch` per conditional whose
+//! condition is not a constant (loop conditions included), and one `Access` per indexed
+//! read of a table or slice.
+//!
+//! Inputs are labeled public or secret by the trace spec of the function (the `*_trace`
+//! functions below): it takes the public inputs as arguments and never the secret ones,
+//! and the body of the function ends by asserting that its ghost trace equals the trace
+//! spec. Two runs that differ only in their secrets then produce the same trace, which is
+//! `is_secret_independent` with the (public, secret) split of the inputs as the labels.
+//!
+//! ## Trusted primitives
+//!
+//! The masked operations of `subtle` (`ct_eq`, `conditional_select`, `conditional_assign`,
+//! `conditional_swap`, `conditional_negate` and the `Choice` operators, wrapped in
+//! `subtle_assumes`) add no observations. For `select` and `conditional_swap_u64` on u64
+//! this is their verified straight-line mask arithmetic; the `external_body` wrappers rely
+//! on `subtle`'s own constant-time contract. `FieldElement51` arithmetic is also treated as
+//! a primitive: its loops run over constant ranges and it never branches on limb values,
+//! but its bodies are not annotated yet.
+//!
+//! ## Annotated functions
+//!
+//! - `Scalar::ct_eq`: both scalars secret, no observations
+//! - `Scalar::conditional_select`: both scalars and the choice secret, reads every byte
+//!   of both in order
+//! - `LookupTable::select`: table public, index secret, reads every entry in order
+//! - `conditional_swap_montgomery_projective` (the Montgomery ladder swap): points and
+//!   choice secret, no observations
+//! - `conditional_swap_edwards_point`: points and choice secret, no observations
+#[allow(unused_imports)]
+use crate::backend::serial::curve_models::{AffineNielsPoint, ProjectiveNielsPoint};
+#[allow(unused_imports)]
+use crate::edwards::EdwardsPoint;
+#[allow(unused_imports)]
+use crate::montgomery::ProjectivePoint;
+#[allow(unused_imports)]
+use crate::scalar::Scalar;
+#[allow(unused_imports)]
+use subtle::Choice;
+use vstd::prelude::*;
+
+verus! {
+
+/// What a timing or cache adversary learns from one step of a computation
+pub enum Observation {
+    /// A branch was taken in the given direction
+    Branch(bool),
+    /// Entry `i` of a table or slice was read
+    Access(int),
+}
+
+/// A trace function over (public, secret) inputs is secret-independent: runs with
+/// equal public inputs produce equal traces, whatever the secrets
+pub open spec fn is_secret_independent<P, S>(trace: spec_fn(P, S) -> Seq<Observation>) -> bool {
+    forall|p: P, s1: S, s2: S| #[trigger] trace(p, s1) == #[trigger] trace(p, s2)
+}
+
+/// The trace of reading entries 0, 1, ..., n-1 of a table in order
+pub open spec fn sequential_access_trace(n: nat) -> Seq<Observation> {
+    Seq::new(n, |i: int| Observation::Access(i))
+}
+
+/// Lemma: reading one more entry extends the sequential trace
+pub proof fn lemma_sequential_access_trace_push(n: nat)
+    ensures
+        sequential_access_trace(n).push(Observation::Access(n as int)) == sequential_access_trace(
+            n + 1,
+        ),
+{
+    assert(sequential_access_trace(n).push(Observation::Access(n as int))
+        =~= sequential_access_trace(n + 1));
+}
+
+// =============================================================================
+// Trace specs of the annotated functions
+// =============================================================================
+/// Trace of `Scalar::ct_eq`: a single `subtle` comparison
+pub open spec fn scalar_ct_eq_trace() -> Seq<Observation> {
+    Seq::empty()
+}
+
+/// Trace of `Scalar::conditional_select`: byte i of both operands, for i = 0..32, each
+/// through a masked `u8` select
+pub open spec fn scalar_conditional_select_trace() -> Seq<Observation> {
+    sequential_access_trace(32)
+}
+
+/// Trace of `LookupTable::select` on a table of 8 entries: every entry, in order
+pub open spec fn lookup_table_select_trace() -> Seq<Observation> {
+    sequential_access_trace(8)
+}
+
+/// Trace of `conditional_swap_montgomery_projective`: two masked field element swaps
+pub open spec fn montgomery_swap_trace() -> Seq<Observation> {
+    Seq::empty()
+}
+
+/// Trace of `conditional_swap_edwards_point`: two masked point assignments
+pub open spec fn edwards_swap_trace() -> Seq<Observation> {
+    Seq::empty()
+}
+
+/// Lemma: `Scalar::ct_eq` is secret-independent in both scalars
+pub proof fn lemma_scalar_ct_eq_secret_independent()
+    ensures
+        is_secret_independent(|p: (), s: (Scalar, Scalar)| scalar_ct_eq_trace()),
+{
+}
+
+/// Lemma: `Scalar::conditional_select` is secret-independent in both scalars and the choice
+pub proof fn lemma_scalar_conditional_select_secret_independent()
+    ensures
+        is_secret_independent(
+            |p: (), s: (Scalar, Scalar, Choice)| scalar_conditional_select_trace(),
+        ),
+{
+}
+
+/// Lemma: `LookupTable::select` is secret-independent in the index (the table is public)
+pub proof fn lemma_lookup_table_select_secret_independent()
+    ensures
+        is_secret_independent(|table: [AffineNielsPoint; 8], x: i8| lookup_table_select_trace()),
+        is_secret_independent(
+            |table: [ProjectiveNielsPoint; 8], x: i8| lookup_table_select_trace(),
+        ),
+{
+}
+
+/// Lemma: the Montgomery ladder swap is secret-independent in the points and the choice
+pub proof fn lemma_montgomery_swap_secret_independent()
+    ensures
+        is_secret_independent(
+            |p: (), s: (ProjectivePoint, ProjectivePoint, Choice)| montgomery_swap_trace(),
+        ),
+{
+}
+
+/// Lemma: the Edwards point swap is secret-independent in the points and the choice
+pub proof fn lemma_edwards_swap_secret_independent()
+    ensures
+        is_secret_independent(
+            |p: (), s: (EdwardsPoint, EdwardsPoint, Choice)| edwards_swap_trace(),
+        ),
+{
+}
+
+} // verus!
@@ -1,8 +1,12 @@
-//! Core shared specifications for 64-bit backend
+//! Core shared specifications
 //!
-//! This module contains byte/nat conversion functions that are shared between
-//! field and scalar implementations. These are domain-neutral utilities that
-//! interpret byte arrays as natural numbers in little-endian format.
+//! This module contains byte/nat and limb/nat conversion functions that are shared
+//! between field and scalar implementations. These are domain-neutral utilities that
+//! interpret byte and limb arrays as natural numbers in little-endian format.
+//!
+//! Nothing here depends on a particular backend: limb widths and radices are
+//! parameters, so the u64 field (radix 2^51), u64 scalar (radix 2^52) and any
+//! u32 backend (radix 2^25.5 / 2^29) specs are all instances of the same definitions.
 #![allow(unused)]
 use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
@@ -168,6 +172,30 @@ pub open spec fn words_to_nat_u64(words: &[u64], num_words: int, bits_per_word:
     words_to_nat_gen(words@.map(|i: int, x: u64| x as nat), num_words, bits_per_word)
 }
 
+/// Convenience wrapper for u32 arrays.
+/// Use this for the common case of &[u32] inputs.
+pub open spec fn words_to_nat_u32(words: &[u32], num_words: int, bits_per_word: int) -> nat {
+    words_to_nat_gen(words@.map(|i: int, x: u32| x as nat), num_words, bits_per_word)
+}
+
+// ============================================================================
+// Limb-to-nat conversion (Horner form, generic over radix)
+// ============================================================================
+/// Little-endian value of a limb sequence in radix 2^radix (Horner form).
+/// Computes: limbs[0] + limbs[1]*2^radix + limbs[2]*2^(2*radix) + ...
+///
+/// Backend-specific limb interpretations are instances of this function,
+/// e.g. `seq_to_nat_52` is `seq_to_nat_radix(_, 52)`.
+pub open spec fn seq_to_nat_radix(limbs: Seq<nat>, radix: nat) -> nat
+    decreases limbs.len(),
+{
+    if limbs.len() == 0 {
+        0
+    } else {
+        limbs[0] + seq_to_nat_radix(limbs.subrange(1, limbs.len() as int), radix) * pow2(radix)
+    }
+}
+
 // ============================================================================
 // Word extraction from byte sequences (generic over any length)
 // ============================================================================
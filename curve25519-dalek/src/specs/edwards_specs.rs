@@ -37,6 +37,8 @@ use crate::edwards::EdwardsBasepointTable;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
 #[allow(unused_imports)]
+use crate::specs::core_specs::*;
+#[allow(unused_imports)]
 use crate::specs::field_specs_u64::*;
 #[allow(unused_imports)]
 use crate::specs::montgomery_specs::*;
@@ -65,15 +67,8 @@ pub open spec fn spec_ed25519_basepoint() -> (nat, nat) {
     (u64_5_as_nat(ED25519_BASEPOINT_POINT.X.limbs), u64_5_as_nat(ED25519_BASEPOINT_POINT.Y.limbs))
 }
 
-/// Proof: The basepoint is on the Edwards curve
-/* SEE IF WE NEED THIS
-pub proof fn lemma_basepoint_on_curve()
-    ensures
-        math_on_edwards_curve(spec_ed25519_basepoint().0, spec_ed25519_basepoint().1),
-{
-    assume(math_on_edwards_curve(spec_ed25519_basepoint().0, spec_ed25519_basepoint().1));
-}
-*/
+// The basepoint is on the curve, y = 4/5 and T = x·y: see
+// `lemma_ed25519_basepoint_well_formed` in edwards_lemmas::constants_lemmas.
 // =============================================================================
 // EdwardsBasepointTable Specification
 // =============================================================================
@@ -83,8 +78,32 @@ pub open spec fn pow256(n: nat) -> nat {
     pow2(8 * n)
 }
 
+/// Sum of the radix-16 digits d_i at positions i < n with i % 2 == parity,
+/// each weighted by 256^(i/2):
+///
+/// - parity 0: d_0 + d_2·16² + d_4·16⁴ + ...
+/// - parity 1: d_1 + d_3·16² + d_5·16⁴ + ...
+///
+/// Digit i is looked up in table i/2 of an EdwardsBasepointTable, so
+/// `mul_base` accumulates s = (parity 0 sum) + 16·(parity 1 sum).
+pub open spec fn radix_16_parity_sum(digits: Seq<i8>, parity: nat, n: nat) -> int
+    decreases n,
+{
+    if n == 0 {
+        0
+    } else {
+        let i = (n - 1) as nat;
+        radix_16_parity_sum(digits, parity, i) + if i % 2 == parity {
+            (digits[i as int] as int) * (pow256(i / 2) as int)
+        } else {
+            0
+        }
+    }
+}
+
 /// Spec: A valid EdwardsBasepointTable for a basepoint B contains 32 LookupTables where:
 /// - table.0[i] contains [1·(16²)^i·B, 2·(16²)^i·B, ..., 8·(16²)^i·B]
+/// - every entry has 54-bit bounded limbs
 ///
 /// This enables computing [scalar] * B via radix-16 representation of scalar.
 #[cfg(feature = "precomputed-tables")]
@@ -99,11 +118,15 @@ pub open spec fn is_valid_edwards_basepoint_table(
             table.0[i].0,
             edwards_scalar_mul(basepoint, pow256(i as nat)),
             8,
-        )
+        ) && crate::specs::window_specs::lookup_table_affine_limbs_bounded(table.0[i].0)
 }
 
 /// Axiom: ED25519_BASEPOINT_TABLE is a valid basepoint table for the Ed25519 basepoint.
 /// This connects the hardcoded constant to our specification.
+///
+/// `EdwardsBasepointTable::is_valid_table_for` checks this at runtime against
+/// `EdwardsBasepointTable::create`, and the `test_ed25519_basepoint_table_matches_create`
+/// test runs that check on the constant.
 #[cfg(feature = "precomputed-tables")]
 #[verifier::external_body]
 pub proof fn axiom_ed25519_basepoint_table_valid()
@@ -190,6 +213,13 @@ pub open spec fn math_is_valid_y_coordinate(y: nat) -> bool {
     }
 }
 
+/// Spec function: y is the y-coordinate of some curve point (x, y).
+/// This is the plain curve-equation statement that `math_is_valid_y_coordinate`
+/// approximates through the sqrt_ratio_i computation.
+pub open spec fn math_is_curve_y_coordinate(y: nat) -> bool {
+    exists|x: nat| x < p() && #[trigger] math_on_edwards_curve(x, y)
+}
+
 /// The identity point in affine coordinates (0, 1)
 pub open spec fn math_edwards_identity() -> (nat, nat) {
     (0, 1)
@@ -228,6 +258,13 @@ pub open spec fn math_is_valid_extended_edwards_point(x: nat, y: nat, z: nat, t:
     ) && t == math_field_mul(math_field_mul(x, y), math_field_inv(z))
 }
 
+/// The affine point (X/Z, Y/Z) of extended coordinates given as field values.
+/// `edwards_point_as_affine` is this applied to the values of an EdwardsPoint.
+pub open spec fn math_extended_as_affine(x: nat, y: nat, z: nat) -> (nat, nat) {
+    let z_inv = math_field_inv(z);
+    (math_field_mul(x, z_inv), math_field_mul(y, z_inv))
+}
+
 /// Check if an EdwardsPoint in projective coordinates is valid
 /// An EdwardsPoint (X:Y:Z:T) is valid if:
 /// 1. The affine point (X/Z, Y/Z) lies on the Edwards curve
@@ -236,6 +273,11 @@ pub open spec fn math_is_valid_extended_edwards_point(x: nat, y: nat, z: nat, t:
 ///
 /// Extended coordinates (X:Y:Z:T) with T = XY/Z enable faster point arithmetic.
 /// Reference: [HWCD2008] Section 3 for extended twisted Edwards coordinates
+///
+/// This is the validity predicate every verified operation returning an `EdwardsPoint`
+/// ensures. `ValidityCheck::is_valid` decides it at runtime (via the projective curve
+/// equation and the Segre relation XY = ZT), and the unverified multiscalar entry points
+/// `debug_assert!` it on their results.
 pub open spec fn is_valid_edwards_point(point: crate::edwards::EdwardsPoint) -> bool {
     let x = spec_field_element(&point.X);
     let y = spec_field_element(&point.Y);
@@ -377,6 +419,17 @@ pub open spec fn compressed_edwards_y_corresponds_to_edwards(
      && (compressed.0[31] >> 7) == (((x_affine % crate::specs::field_specs_u64::p()) % 2) as u8)
 }
 
+/// Spec for Y-compression of the affine point (x, y).
+///
+/// Bits 0..254 hold the canonical little-endian encoding of y, and bit 255
+/// holds the sign of x (the low bit of its canonical value).
+/// Unlike `compressed_edwards_y_corresponds_to_edwards`, this fixes the bytes
+/// exactly: y is not allowed to be encoded as y + p.
+pub open spec fn compressed_y_encodes(bytes: &[u8; 32], x: nat, y: nat) -> bool {
+    &&& bytes32_to_nat(bytes) % pow2(255) == y % p()
+    &&& (bytes[31] >> 7) == ((x % p()) % 2) as u8
+}
+
 /// Check if a CompressedEdwardsY has a valid sign bit.
 ///
 /// ## Mathematical basis
@@ -388,6 +441,9 @@ pub open spec fn compressed_edwards_y_corresponds_to_edwards(
 /// ## Definition
 ///
 /// If the Y coordinate yields x = 0 (i.e., y² ≡ 1 mod p), the sign bit must be 0.
+///
+/// `decompress` accepts encodings that violate this (it returns x = 0 regardless),
+/// so this only guards its sign-bit postcondition.
 pub open spec fn compressed_y_has_valid_sign_bit(bytes: &[u8; 32]) -> bool {
     let y = spec_field_element_from_bytes(bytes);
     let sign_bit = bytes[31] >> 7;
@@ -621,6 +677,31 @@ pub open spec fn spec_edwards_add_projective_niels(p: EdwardsPoint, q: Projectiv
     edwards_add(self_affine.0, self_affine.1, other_affine.0, other_affine.1)
 }
 
+/// The CompletedPoint coordinates (X:Y:Z:T) computed by `&EdwardsPoint + &ProjectiveNielsPoint`:
+///   (PP - MM : PP + MM : ZZ2 + TT2d : ZZ2 - TT2d)
+/// with PP = (Y1+X1)·(Y+X), MM = (Y1-X1)·(Y-X), TT2d = T1·T2d, ZZ2 = 2·Z1·Z.
+///
+/// See `lemma_edwards_add_extended_formula` for the proof that this is the group law.
+pub open spec fn spec_completed_add_projective_niels(p: EdwardsPoint, q: ProjectiveNielsPoint) -> (
+    nat,
+    nat,
+    nat,
+    nat,
+) {
+    let (x1, y1, z1, t1) = spec_edwards_point(p);
+    let pp = math_field_mul(math_field_add(y1, x1), spec_field_element(&q.Y_plus_X));
+    let mm = math_field_mul(math_field_sub(y1, x1), spec_field_element(&q.Y_minus_X));
+    let tt2d = math_field_mul(t1, spec_field_element(&q.T2d));
+    let zz = math_field_mul(z1, spec_field_element(&q.Z));
+    let zz2 = math_field_add(zz, zz);
+    (
+        math_field_sub(pp, mm),
+        math_field_add(pp, mm),
+        math_field_add(zz2, tt2d),
+        math_field_sub(zz2, tt2d),
+    )
+}
+
 /// Helper spec function: Edwards addition of EdwardsPoint and AffineNielsPoint
 /// Combines the affine conversion and addition into a single convenient spec function.
 pub open spec fn spec_edwards_add_affine_niels(
@@ -0,0 +1,357 @@
+//! Executable versions of spec functions, for checking specs at runtime
+//!
+//! Enabled by the `spec-exec` feature, and always compiled for tests. Each function
+//! mirrors the spec function of the same name, with `nat` replaced by `BigUint`, and is
+//! written to stay as close to the spec as possible rather than to be fast. The
+//! proptests of the u64 backend and the edge-case harnesses use these, so that there is
+//! one model of each spec. The `check_*` functions run an implementation and panic if
+//! its result violates the `ensures` of that implementation, evaluated with these
+//! interpreters.
+//!
+//! This catches spec bugs cheaply, before a proof is attempted: a postcondition that
+//! fails on a sampled input cannot be proven, whatever the proof. It is most useful for
+//! functions whose proofs are still bypassed, like `Scalar::add`.
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::backend::serial::u64::scalar::Scalar52;
+use crate::scalar::Scalar;
+
+// =============================================================================
+// Interpreters
+// =============================================================================
+/// Matches the spec: bytes_seq_to_nat(bytes@), little-endian
+pub fn bytes_seq_to_nat(bytes: &[u8]) -> BigUint {
+    let mut result = BigUint::zero();
+    for &b in bytes.iter().rev() {
+        result = (result << 8) + BigUint::from(b);
+    }
+    result
+}
+
+/// Matches the spec: bytes32_to_nat(&[u8; 32])
+pub fn bytes32_to_nat(bytes: &[u8; 32]) -> BigUint {
+    bytes_seq_to_nat(bytes)
+}
+
+/// Matches the spec: u64_5_as_nat([u64; 5]), radix 2^51
+pub fn u64_5_as_nat(limbs: [u64; 5]) -> BigUint {
+    let mut result = BigUint::zero();
+    for &limb in limbs.iter().rev() {
+        result = (result << 51) + BigUint::from(limb);
+    }
+    result
+}
+
+/// Matches the spec: scalar52_to_nat(&Scalar52), radix 2^52
+pub fn scalar52_to_nat(s: &Scalar52) -> BigUint {
+    let mut result = BigUint::zero();
+    for &limb in s.limbs.iter().rev() {
+        result = (result << 52) + BigUint::from(limb);
+    }
+    result
+}
+
+/// Matches the spec: slice128_to_nat(&[u128]), radix 2^52
+pub fn slice128_to_nat(limbs: &[u128]) -> BigUint {
+    let mut result = BigUint::zero();
+    for &limb in limbs.iter().rev() {
+        result = (result << 52) + BigUint::from(limb);
+    }
+    result
+}
+
+/// Matches the spec: p()
+pub fn p() -> BigUint {
+    (BigUint::one() << 255) - 19u32
+}
+
+/// Matches the spec: group_order()
+pub fn group_order() -> BigUint {
+    (BigUint::one() << 252) + 27742317777372353535851937790883648493u128
+}
+
+/// Matches the spec: montgomery_radix()
+pub fn montgomery_radix() -> BigUint {
+    BigUint::one() << 260
+}
+
+/// Matches the spec: mask51
+pub const MASK51: u64 = (1u64 << 51) - 1;
+
+/// Matches the spec: spec_reduce([u64; 5])
+pub fn spec_reduce(limbs: [u64; 5]) -> [u64; 5] {
+    [
+        (limbs[0] & MASK51) + (limbs[4] >> 51) * 19,
+        (limbs[1] & MASK51) + (limbs[0] >> 51),
+        (limbs[2] & MASK51) + (limbs[1] >> 51),
+        (limbs[3] & MASK51) + (limbs[2] >> 51),
+        (limbs[4] & MASK51) + (limbs[3] >> 51),
+    ]
+}
+
+/// Matches the spec: spec_field_element(&FieldElement51)
+pub fn spec_field_element(fe: &FieldElement51) -> BigUint {
+    u64_5_as_nat(fe.limbs) % p()
+}
+
+/// Matches the spec: fe51_limbs_bounded(&FieldElement51, bit_limit)
+pub fn fe51_limbs_bounded(fe: &FieldElement51, bit_limit: u64) -> bool {
+    fe.limbs.iter().all(|&limb| limb < (1u64 << bit_limit))
+}
+
+/// Matches the spec: limbs_bounded(&Scalar52)
+pub fn limbs_bounded(s: &Scalar52) -> bool {
+    s.limbs.iter().all(|&limb| limb < (1u64 << 52))
+}
+
+/// Matches the spec: is_canonical_scalar(&Scalar)
+pub fn is_canonical_scalar(s: &Scalar) -> bool {
+    bytes32_to_nat(&s.bytes) < group_order() && s.bytes[31] <= 127
+}
+
+// =============================================================================
+// Checks of implementations against their ensures
+// =============================================================================
+/// The ensures of `FieldElement51::reduce`, stated about `spec_reduce`
+pub fn check_spec_reduce(limbs: [u64; 5]) {
+    let r = spec_reduce(limbs);
+    let (r_nat, limbs_nat) = (u64_5_as_nat(r), u64_5_as_nat(limbs));
+    assert!(
+        r.iter().all(|&limb| limb < (1u64 << 52)),
+        "spec_reduce({:?}): unbounded",
+        limbs
+    );
+    assert_eq!(
+        r_nat,
+        &limbs_nat - p() * (limbs[4] >> 51),
+        "spec_reduce({:?})",
+        limbs
+    );
+    assert_eq!(
+        &r_nat % p(),
+        &limbs_nat % p(),
+        "spec_reduce({:?}): mod p",
+        limbs
+    );
+    assert!(r_nat < p() * 2u32, "spec_reduce({:?}): not below 2p", limbs);
+}
+
+/// The ensures of `FieldElement51::from_bytes` and `as_bytes`
+pub fn check_field_bytes(bytes: &[u8; 32]) {
+    let fe = FieldElement51::from_bytes(bytes);
+    let value = bytes32_to_nat(bytes) % (BigUint::one() << 255);
+    assert_eq!(u64_5_as_nat(fe.limbs), value, "from_bytes({:?})", bytes);
+    assert!(
+        fe51_limbs_bounded(&fe, 51),
+        "from_bytes({:?}): unbounded",
+        bytes
+    );
+    let encoded = fe.as_bytes();
+    assert_eq!(
+        bytes32_to_nat(&encoded),
+        value % p(),
+        "as_bytes of {:?}",
+        bytes
+    );
+}
+
+/// The ensures of `FieldElement51` multiplication, for inputs meeting its requires
+pub fn check_field_mul(a: &FieldElement51, b: &FieldElement51) {
+    assert!(
+        fe51_limbs_bounded(a, 54) && fe51_limbs_bounded(b, 54),
+        "requires violated"
+    );
+    let r = a * b;
+    let expected = (u64_5_as_nat(a.limbs) * u64_5_as_nat(b.limbs)) % p();
+    assert_eq!(
+        spec_field_element(&r),
+        expected,
+        "{:?} * {:?}",
+        a.limbs,
+        b.limbs
+    );
+    assert!(
+        fe51_limbs_bounded(&r, 52),
+        "{:?} * {:?}: unbounded",
+        a.limbs,
+        b.limbs
+    );
+}
+
+/// The ensures of `FieldElement51` subtraction, for inputs meeting its requires
+pub fn check_field_sub(a: &FieldElement51, b: &FieldElement51) {
+    assert!(
+        fe51_limbs_bounded(a, 54) && fe51_limbs_bounded(b, 54),
+        "requires violated"
+    );
+    let r = a - b;
+    let expected = (u64_5_as_nat(a.limbs) + p() * 16u32 - u64_5_as_nat(b.limbs)) % p();
+    assert_eq!(
+        spec_field_element(&r),
+        expected,
+        "{:?} - {:?}",
+        a.limbs,
+        b.limbs
+    );
+    assert!(
+        fe51_limbs_bounded(&r, 52),
+        "{:?} - {:?}: unbounded",
+        a.limbs,
+        b.limbs
+    );
+}
+
+/// The ensures of `FieldElement51::square` and `square2`
+pub fn check_field_square(a: &FieldElement51) {
+    assert!(fe51_limbs_bounded(a, 54), "requires violated");
+    let a_nat = u64_5_as_nat(a.limbs);
+    let square = (&a_nat * &a_nat) % p();
+    assert_eq!(
+        spec_field_element(&a.square()),
+        square,
+        "square of {:?}",
+        a.limbs
+    );
+    assert_eq!(
+        spec_field_element(&a.square2()),
+        (square * 2u32) % p(),
+        "square2 of {:?}",
+        a.limbs
+    );
+}
+
+/// The ensures of `FieldElement51` negation, which `negate` must match
+pub fn check_field_neg(a: &FieldElement51) {
+    assert!(fe51_limbs_bounded(a, 54), "requires violated");
+    let r = -a;
+    let expected = (p() * 16u32 - u64_5_as_nat(a.limbs)) % p();
+    assert_eq!(spec_field_element(&r), expected, "-{:?}", a.limbs);
+    assert!(fe51_limbs_bounded(&r, 52), "-{:?}: unbounded", a.limbs);
+    let mut negated = *a;
+    negated.negate();
+    assert_eq!(negated.limbs, r.limbs, "negate of {:?}", a.limbs);
+}
+
+/// The ensures of `FieldElement51::as_bytes` on unreduced limbs
+pub fn check_field_as_bytes(a: &FieldElement51) {
+    let encoded = a.as_bytes();
+    assert_eq!(
+        bytes32_to_nat(&encoded),
+        spec_field_element(a),
+        "as_bytes of {:?}",
+        a.limbs
+    );
+}
+
+/// The ensures of `Scalar::from_bytes_mod_order` and `from_bytes_mod_order_wide`
+pub fn check_scalar_from_bytes(bytes: &[u8; 64]) {
+    let l = group_order();
+    let mut low = [0u8; 32];
+    low.copy_from_slice(&bytes[..32]);
+
+    let s = Scalar::from_bytes_mod_order(low);
+    assert_eq!(
+        bytes32_to_nat(&s.bytes) % &l,
+        bytes32_to_nat(&low) % &l,
+        "mod_order({:?})",
+        low
+    );
+    assert!(
+        is_canonical_scalar(&s),
+        "mod_order({:?}): not canonical",
+        low
+    );
+
+    let s = Scalar::from_bytes_mod_order_wide(bytes);
+    assert_eq!(
+        bytes32_to_nat(&s.bytes) % &l,
+        bytes_seq_to_nat(bytes) % &l,
+        "wide({:?})",
+        bytes
+    );
+    assert!(is_canonical_scalar(&s), "wide({:?}): not canonical", bytes);
+}
+
+/// The ensures of `Scalar` addition and multiplication
+pub fn check_scalar_add_mul(a: &Scalar, b: &Scalar) {
+    let l = group_order();
+    let (a_nat, b_nat) = (bytes32_to_nat(&a.bytes), bytes32_to_nat(&b.bytes));
+
+    let sum = a + b;
+    assert_eq!(
+        bytes32_to_nat(&sum.bytes),
+        (&a_nat + &b_nat) % &l,
+        "{:?} + {:?}",
+        a,
+        b
+    );
+    assert!(sum.bytes[31] <= 127, "{:?} + {:?}: high bit set", a, b);
+
+    let product = a * b;
+    assert_eq!(
+        bytes32_to_nat(&product.bytes) % &l,
+        (a_nat * b_nat) % &l,
+        "{:?} * {:?}",
+        a,
+        b
+    );
+    assert!(
+        is_canonical_scalar(&product),
+        "{:?} * {:?}: not canonical",
+        a,
+        b
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A FieldElement51 meeting the 54-bit bound of the arithmetic preconditions
+    fn arb_fe51() -> impl Strategy<Value = FieldElement51> {
+        prop::array::uniform5(0u64..(1u64 << 54)).prop_map(|limbs| FieldElement51 { limbs })
+    }
+
+    /// A canonical scalar
+    fn arb_canonical_scalar() -> impl Strategy<Value = Scalar> {
+        prop::collection::vec(any::<u8>(), 64).prop_map(|bytes| {
+            let mut wide = [0u8; 64];
+            wide.copy_from_slice(&bytes);
+            Scalar::from_bytes_mod_order_wide(&wide)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_spec_reduce(limbs in prop::array::uniform5(any::<u64>())) {
+            check_spec_reduce(limbs);
+        }
+
+        #[test]
+        fn prop_field_bytes(bytes in prop::array::uniform32(any::<u8>())) {
+            check_field_bytes(&bytes);
+        }
+
+        #[test]
+        fn prop_field_mul(a in arb_fe51(), b in arb_fe51()) {
+            check_field_mul(&a, &b);
+        }
+
+        #[test]
+        fn prop_scalar_from_bytes(bytes in prop::collection::vec(any::<u8>(), 64)) {
+            let mut wide = [0u8; 64];
+            wide.copy_from_slice(&bytes);
+            check_scalar_from_bytes(&wide);
+        }
+
+        /// Only canonical inputs: `Scalar::add` has no requires yet, but the
+        /// `Scalar52::add` it calls needs both inputs below ℓ
+        #[test]
+        fn prop_scalar_add_mul(a in arb_canonical_scalar(), b in arb_canonical_scalar()) {
+            check_scalar_add_mul(&a, &b);
+        }
+    }
+}
@@ -24,6 +24,11 @@ use vstd::prelude::*;
 verus! {
 
 /// Spec predicate: all limbs are bounded by a given bit limit
+///
+/// This is the one limb-bound predicate for field element pre/postconditions:
+/// 51 for canonical inputs, 52 for reduced outputs (2^51 + 2^13), and 54 for
+/// unreduced sums/differences. Use `lemma_fe51_limbs_bounded_weaken` to move
+/// between them instead of re-deriving the shift inequality.
 pub open spec fn fe51_limbs_bounded(fe: &FieldElement51, bit_limit: u64) -> bool {
     forall|i: int| 0 <= i < 5 ==> fe.limbs[i] < (1u64 << bit_limit)
 }
@@ -99,12 +104,26 @@ pub open spec fn spec_field_element_from_bytes(bytes: &[u8; 32]) -> nat {
     (bytes32_to_nat(bytes) % pow2(255)) % p()
 }
 
+/// A canonical field encoding is the little-endian encoding of a value in [0, p).
+/// This rejects both high-bit-set inputs and the 19 encodings of p, ..., 2^255 - 1,
+/// which `from_bytes` would otherwise silently reduce.
+pub open spec fn is_canonical_field_encoding(bytes: &[u8; 32]) -> bool {
+    bytes32_to_nat(bytes) < p()
+}
+
 /// Spec function: Get the sign bit of a field element
 /// In Curve25519, the sign bit is the least significant bit of the canonical representation
 pub open spec fn spec_field_element_sign_bit(fe: &FieldElement51) -> u8 {
     ((spec_field_element(fe) % p()) % 2) as u8
 }
 
+/// Spec predicate: the sign convention used by point compression and Ristretto.
+/// A field element is "negative" iff its canonical encoding is odd, i.e. the least
+/// significant bit of the encoding is set. For x ≠ 0 exactly one of x and -x is negative.
+pub open spec fn math_field_is_negative(a: nat) -> bool {
+    (a % p()) % 2 == 1
+}
+
 // Spec-level field operations on natural numbers (mod p)
 /// Math-level field addition
 pub open spec fn math_field_add(a: nat, b: nat) -> nat {
@@ -131,6 +150,18 @@ pub open spec fn math_field_square(a: nat) -> nat {
     (a * a) % p()
 }
 
+/// The exponent (p-5)/8 = 2^252 - 3 used by pow_p58
+///
+/// See `lemma_p58_exponent` for the equality with (p-5)/8.
+pub open spec fn p58_exponent() -> nat {
+    (pow2(252) - 3) as nat
+}
+
+/// Math-level field exponentiation by (p-5)/8, as computed by pow_p58
+pub open spec fn math_field_pow_p58(a: nat) -> nat {
+    (pow(a as int, p58_exponent()) as nat) % p()
+}
+
 /// Math-level field inversion: returns w such that (a * w) % p == 1
 ///
 /// For non-zero elements (a % p() != 0), this returns the unique multiplicative
@@ -444,6 +475,26 @@ pub open spec fn spec_product_of_field_elems(fields: Seq<FieldElement51>) -> nat
     }
 }
 
+/// Spec function: product of the nonzero field elements in a sequence (mod p)
+///
+/// Zero elements are skipped, matching the accumulator in `batch_invert`.
+/// Note: Processes from back to front to match iterative loop order
+pub open spec fn spec_product_of_nonzero_field_elems(fields: Seq<FieldElement51>) -> nat
+    decreases fields.len(),
+{
+    if fields.len() == 0 {
+        1
+    } else {
+        let last = (fields.len() - 1) as int;
+        let prev = spec_product_of_nonzero_field_elems(fields.subrange(0, last));
+        if spec_field_element(&fields[last]) == 0 {
+            prev
+        } else {
+            math_field_mul(prev, spec_field_element(&fields[last]))
+        }
+    }
+}
+
 /// Spec function: b is a square root of a (mod p), i.e., b^2 = a (mod p)
 pub open spec fn is_square_of(a: &FieldElement51, b: &FieldElement51) -> bool {
     (spec_field_element(b) * spec_field_element(b)) % p() == spec_field_element(a) % p()
@@ -518,6 +569,59 @@ pub open spec fn spec_sqrt_ratio_i_post(u: nat, v: nat, success: bool, r: nat) -
     spec_sqrt_ratio_i_math_post(u, v, success, r) && spec_sqrt_ratio_i_bounded_post(r)
 }
 
+/// Complete case analysis of sqrt_ratio_i, split on whether u/v is a square.
+///
+/// Exactly one of the four cases applies to any (u, v):
+/// 1. u = 0:                          returns (true, 0)
+/// 2. v = 0 and u ≠ 0:                returns (false, 0)
+/// 3. u, v ≠ 0 and u/v is square:     returns (true, r) with r² · v ≡ u
+/// 4. u, v ≠ 0 and u/v is nonsquare:  returns (false, r) with r² · v ≡ i·u
+///
+/// In every case r is reduced and nonnegative (even).
+pub open spec fn spec_sqrt_ratio_i_cases(u: nat, v: nat, success: bool, r: nat) -> bool {
+    let ratio_is_square = math_is_square(math_field_mul(u, math_field_inv(v)));
+    &&& spec_sqrt_ratio_i_bounded_post(r)
+    &&& (u == 0 ==> success && r == 0)
+    &&& (v == 0 && u != 0 ==> !success && r == 0)
+    &&& (u != 0 && v != 0 && ratio_is_square ==> success && math_is_sqrt_ratio(u, v, r))
+    &&& (u != 0 && v != 0 && !ratio_is_square ==> !success && math_is_sqrt_ratio_times_i(
+        u,
+        v,
+        r,
+    ))
+}
+
+/// The result of sqrt_ratio_i(u, v) as a function of (u, v).
+///
+/// For v < p exactly one pair satisfies `spec_sqrt_ratio_i_cases`
+/// (`lemma_sqrt_ratio_i_cases_unique`), so this names what sqrt_ratio_i returns.
+pub open spec fn spec_sqrt_ratio_i(u: nat, v: nat) -> (bool, nat) {
+    choose|res: (bool, nat)| spec_sqrt_ratio_i_cases(u, v, res.0, res.1)
+}
+
+/// The result of invsqrt(a) = sqrt_ratio_i(1, a), i.e. (is_square, 1/√a)
+pub open spec fn spec_invsqrt(a: nat) -> (bool, nat) {
+    spec_sqrt_ratio_i(1, a)
+}
+
+/// |a|: whichever of a and -a is non-negative (even)
+pub open spec fn math_field_abs(a: nat) -> nat {
+    if math_field_is_negative(a) {
+        math_field_neg(a)
+    } else {
+        a % p()
+    }
+}
+
+/// Candidate root computed by sqrt_ratio_i before the sqrt(-1) and sign adjustments:
+///   r = (u·v³)·(u·v⁷)^((p-5)/8)
+pub open spec fn spec_sqrt_ratio_i_candidate(u: nat, v: nat) -> nat {
+    let v3 = math_field_mul(math_field_square(v), v);
+    let v7 = math_field_mul(math_field_square(v3), v);
+    let uv7_p58 = math_field_pow_p58(math_field_mul(u, v7));
+    math_field_mul(math_field_mul(u, v3), uv7_p58)
+}
+
 // Square-ness mod p (spec-only).
 pub open spec fn is_square_mod_p(a: nat) -> bool {
     exists|y: nat| (#[trigger] (y * y) % p()) == (a % p())
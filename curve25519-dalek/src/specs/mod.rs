@@ -1,7 +1,12 @@
+pub mod constant_time_specs;
+
 pub mod core_specs;
 
 pub mod edwards_specs;
 
+#[cfg(any(test, feature = "spec-exec"))]
+pub mod exec_specs;
+
 pub mod field_specs;
 
 pub mod field_specs_u64;
@@ -12,6 +17,10 @@ pub mod mul_specs;
 
 pub mod primality_specs;
 
+pub mod ristretto_specs;
+
+pub mod rng_specs;
+
 pub mod scalar_mul_specs;
 
 pub mod scalar_specs;
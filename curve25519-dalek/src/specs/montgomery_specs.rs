@@ -8,7 +8,11 @@ use crate::constants::X25519_BASEPOINT;
 #[allow(unused_imports)]
 use crate::montgomery::ProjectivePoint;
 #[allow(unused_imports)]
+use crate::specs::core_specs::*;
+#[allow(unused_imports)]
 use crate::specs::field_specs_u64::*;
+#[allow(unused_imports)]
+use crate::specs::scalar_specs::spec_clamp_integer;
 use vstd::prelude::*;
 
 verus! {
@@ -170,6 +174,37 @@ pub open spec fn montgomery_sub(P: MontgomeryAffine, Q: MontgomeryAffine) -> Mon
     montgomery_add(P, montgomery_neg(Q))
 }
 
+/// x-only doubling: u([2]P) from u = u(P)
+///   u([2]P) = (u² - 1)² / (4u·(u² + A·u + 1))
+/// The denominator vanishes exactly when [2]P = ∞, which maps to 0.
+pub open spec fn montgomery_xdbl_u(u: nat) -> nat {
+    let A = spec_field_element(&MONTGOMERY_A);
+    let u2 = math_field_square(u);
+    let numerator = math_field_square(math_field_sub(u2, 1));
+    let denominator = math_field_mul(
+        math_field_mul(4, u),
+        math_field_add(math_field_add(u2, math_field_mul(A, u)), 1),
+    );
+    if denominator == 0 {
+        0
+    } else {
+        math_field_mul(numerator, math_field_inv(denominator))
+    }
+}
+
+/// x-only differential addition: u(P + Q) from u(P), u(Q) and u_D = u(P - Q)
+///   u(P + Q) = (u_P·u_Q - 1)² / (u_D·(u_P - u_Q)²)
+/// The denominator vanishes when P = -Q, i.e. P + Q = ∞, which maps to 0.
+pub open spec fn montgomery_xadd_u(u_p: nat, u_q: nat, u_d: nat) -> nat {
+    let numerator = math_field_square(math_field_sub(math_field_mul(u_p, u_q), 1));
+    let denominator = math_field_mul(u_d, math_field_square(math_field_sub(u_p, u_q)));
+    if denominator == 0 {
+        0
+    } else {
+        math_field_mul(numerator, math_field_inv(denominator))
+    }
+}
+
 /// Extract the u-coordinate from a MontgomeryAffine point.
 /// Maps Infinity to 0, and Finite{u, v} to u.
 pub open spec fn spec_u_coordinate(point: MontgomeryAffine) -> nat {
@@ -353,6 +388,20 @@ pub open spec fn montgomery_scalar_mul_u(u: nat, n: nat) -> nat {
     )
 }
 
+/// The X25519 function of [RFC 7748] Section 5, on the u-coordinates it accepts here
+///
+/// - decodeScalar25519 is `spec_clamp_integer`
+/// - decodeUCoordinate masks bit 255 and reduces mod p, which is exactly
+///   `spec_field_element_from_bytes`; non-canonical encodings are therefore accepted
+/// - the result is u([k]P) for the canonical lift P of u, with ∞ encoded as 0
+///
+/// RFC 7748 also defines X25519 on twist points; this spec covers curve points only.
+/// - https://www.rfc-editor.org/rfc/rfc7748#section-5
+pub open spec fn spec_x25519(k: [u8; 32], u: [u8; 32]) -> nat {
+    let P = canonical_montgomery_lift(spec_field_element_from_bytes(&u));
+    spec_u_coordinate(montgomery_scalar_mul(P, bytes32_to_nat(&spec_clamp_integer(k))))
+}
+
 // =============================================================================
 // Elligator2 Mapping (hash-to-curve)
 // =============================================================================
@@ -386,9 +435,9 @@ pub open spec fn montgomery_scalar_mul_u(u: nat, n: nat) -> nat {
 /// - An attacker cannot learn secret key bits by sending a malicious u-coordinate
 ///   that corresponds to a point on the twist (a "small subgroup attack")
 ///
-/// The output u is always a valid u-coordinate on either the Montgomery curve
-/// or its quadratic twist. This provides a deterministic mapping from field
-/// elements to curve points.
+/// The output u always lies on the Montgomery curve itself: when d is on the
+/// twist, -d - A is on the curve (`lemma_elligator_encode_is_valid_u_coordinate`).
+/// This provides a deterministic mapping from field elements to curve points.
 pub open spec fn spec_elligator_encode(r: nat) -> nat {
     let A = spec_field_element(&MONTGOMERY_A);
     let r_sq = math_field_square(r);
@@ -153,7 +153,7 @@ impl vstd::std_specs::ops::MulSpecImpl<EdwardsPoint> for Scalar {
 // SECTION 3: MontgomeryPoint * Scalar
 // =============================================================================
 // Specifications only - implementations are in montgomery.rs
-// Requires: MontgomeryPoint must be valid
+// Requires: MontgomeryPoint must be valid and the scalar's high bit clear (the ladder skips bit 255)
 /// Spec for &MontgomeryPoint * &Scalar (reference implementation)
 #[cfg(verus_keep_ghost)]
 impl vstd::std_specs::ops::MulSpecImpl<&Scalar> for &MontgomeryPoint {
@@ -163,7 +163,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&Scalar> for &MontgomeryPoint {
     }
 
     open spec fn mul_req(self, rhs: &Scalar) -> bool {
-        is_valid_montgomery_point(*self)
+        rhs.bytes[31] <= 127 && is_valid_montgomery_point(*self)
     }
 
     open spec fn mul_spec(self, rhs: &Scalar) -> MontgomeryPoint {
@@ -176,7 +176,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&Scalar> for &MontgomeryPoint {
 // SECTION 4: Scalar * MontgomeryPoint
 // =============================================================================
 // Specifications only - implementations are in montgomery.rs
-// Requires: MontgomeryPoint must be valid
+// Requires: MontgomeryPoint must be valid and the scalar's high bit clear (the ladder skips bit 255)
 /// Spec for &Scalar * &MontgomeryPoint (reference implementation)
 #[cfg(verus_keep_ghost)]
 impl vstd::std_specs::ops::MulSpecImpl<&MontgomeryPoint> for &Scalar {
@@ -186,7 +186,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&MontgomeryPoint> for &Scalar {
     }
 
     open spec fn mul_req(self, rhs: &MontgomeryPoint) -> bool {
-        is_valid_montgomery_point(*rhs)
+        self.bytes[31] <= 127 && is_valid_montgomery_point(*rhs)
     }
 
     open spec fn mul_spec(self, rhs: &MontgomeryPoint) -> MontgomeryPoint {
@@ -203,7 +203,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&MontgomeryPoint> for Scalar {
     }
 
     open spec fn mul_req(self, rhs: &MontgomeryPoint) -> bool {
-        is_valid_montgomery_point(*rhs)
+        self.bytes[31] <= 127 && is_valid_montgomery_point(*rhs)
     }
 
     open spec fn mul_spec(self, rhs: &MontgomeryPoint) -> MontgomeryPoint {
@@ -219,7 +219,7 @@ impl vstd::std_specs::ops::MulSpecImpl<MontgomeryPoint> for &Scalar {
     }
 
     open spec fn mul_req(self, rhs: MontgomeryPoint) -> bool {
-        is_valid_montgomery_point(rhs)
+        self.bytes[31] <= 127 && is_valid_montgomery_point(rhs)
     }
 
     open spec fn mul_spec(self, rhs: MontgomeryPoint) -> MontgomeryPoint {
@@ -235,7 +235,7 @@ impl vstd::std_specs::ops::MulSpecImpl<MontgomeryPoint> for Scalar {
     }
 
     open spec fn mul_req(self, rhs: MontgomeryPoint) -> bool {
-        is_valid_montgomery_point(rhs)
+        self.bytes[31] <= 127 && is_valid_montgomery_point(rhs)
     }
 
     open spec fn mul_spec(self, rhs: MontgomeryPoint) -> MontgomeryPoint {
@@ -256,7 +256,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&Scalar> for &EdwardsBasepointTable {
     }
 
     open spec fn mul_req(self, rhs: &Scalar) -> bool {
-        rhs.bytes[31] <= 127
+        rhs.bytes[31] <= 127 && is_valid_edwards_basepoint_table(*self, spec_ed25519_basepoint())
     }
 
     open spec fn mul_spec(self, rhs: &Scalar) -> EdwardsPoint {
@@ -273,7 +273,7 @@ impl vstd::std_specs::ops::MulSpecImpl<&EdwardsBasepointTable> for &Scalar {
     }
 
     open spec fn mul_req(self, rhs: &EdwardsBasepointTable) -> bool {
-        self.bytes[31] <= 127
+        self.bytes[31] <= 127 && is_valid_edwards_basepoint_table(*rhs, spec_ed25519_basepoint())
     }
 
     open spec fn mul_spec(self, rhs: &EdwardsBasepointTable) -> EdwardsPoint {
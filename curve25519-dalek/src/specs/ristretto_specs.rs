@@ -0,0 +1,262 @@
+// Specifications for the Ristretto group
+//
+// A Ristretto element is a coset P + E[4] of the 4-torsion subgroup
+// E[4] = {(0, 1), (i, 0), (0, -1), (-i, 0)} (the entries 0, 2, 4, 6 of EIGHT_TORSION),
+// taken inside the even subgroup 2E of points that are doubles. 2E has order 4ℓ, so
+// the quotient 2E / E[4] has prime order ℓ.
+//
+// A `RistrettoPoint` stores one representative of its coset as an `EdwardsPoint`.
+// The encoding is written out below exactly as `compress` and `decompress`
+// compute it, and likewise the Elligator map behind `from_uniform_bytes`; the
+// facts connecting them to the cosets are in `ristretto_lemmas`.
+//
+// ## References
+//
+// - [RFC9496] de Valence, H., Grigg, J., Hamburg, M., Lovecruft, I., Tankersley, G.,
+//   Valsorda, F., "The ristretto255 and decaf448 Groups", RFC 9496, December 2023.
+//   https://www.rfc-editor.org/info/rfc9496
+//
+// - [Decaf] Hamburg, M. (2015). "Decaf: Eliminating cofactors through point compression".
+//   https://eprint.iacr.org/2015/673.pdf
+//
+#[allow(unused_imports)]
+use super::field_specs::*;
+#[allow(unused_imports)] // Used in verus! blocks for the encoding constants
+use crate::backend::serial::u64::constants::{
+    EDWARDS_D, EIGHT_TORSION, INVSQRT_A_MINUS_D, SQRT_AD_MINUS_ONE, SQRT_M1,
+};
+#[allow(unused_imports)]
+use crate::ristretto::RistrettoPoint;
+#[allow(unused_imports)]
+use crate::specs::core_specs::*;
+#[allow(unused_imports)]
+use crate::specs::edwards_specs::*;
+#[allow(unused_imports)]
+use crate::specs::field_specs_u64::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Cosets of the 4-torsion
+// =============================================================================
+/// The 4-torsion point T_{2j} = [2j]T_1, for j = 0, 1, 2, 3
+pub open spec fn ristretto_four_torsion(j: int) -> (nat, nat)
+    recommends
+        0 <= j < 4,
+{
+    edwards_point_as_affine(EIGHT_TORSION[2 * j])
+}
+
+/// Two affine points represent the same Ristretto element: Q ∈ P + E[4]
+pub open spec fn ristretto_equivalent(P: (nat, nat), Q: (nat, nat)) -> bool {
+    exists|j: int|
+        0 <= j < 4 && Q == edwards_add(
+            P.0,
+            P.1,
+            #[trigger] ristretto_four_torsion(j).0,
+            ristretto_four_torsion(j).1,
+        )
+}
+
+/// The equality test of [RFC9496] Section 4.3.3 on affine points:
+/// x1·y2 = y1·x2 or x1·x2 = y1·y2
+pub open spec fn ristretto_equality_check(P: (nat, nat), Q: (nat, nat)) -> bool {
+    math_field_mul(P.0, Q.1) == math_field_mul(P.1, Q.0) || math_field_mul(P.0, Q.0)
+        == math_field_mul(P.1, Q.1)
+}
+
+/// P lies in 2E, the subgroup of points that are doubles of curve points
+pub open spec fn is_in_even_subgroup(P: (nat, nat)) -> bool {
+    math_on_edwards_curve(P.0, P.1) && exists|Q: (nat, nat)|
+        math_on_edwards_curve(Q.0, Q.1) && P == #[trigger] edwards_double(Q.0, Q.1)
+}
+
+/// The affine point of the representative stored in a `RistrettoPoint`
+pub open spec fn ristretto_point_as_affine(point: RistrettoPoint) -> (nat, nat) {
+    edwards_point_as_affine(point.0)
+}
+
+// =============================================================================
+// Encoding ([RFC9496] Section 4.3.2)
+// =============================================================================
+/// The field element s encoding the extended point (X:Y:Z:T), step for step as
+/// `RistrettoPoint::compress` computes it
+pub open spec fn spec_ristretto_encode(x: nat, y: nat, z: nat, t: nat) -> nat {
+    let u1 = math_field_mul(math_field_add(z, y), math_field_sub(z, y));
+    let u2 = math_field_mul(x, y);
+    // The flag is ignored: u1·u2² is a square for every point of 2E
+    let invsqrt = spec_invsqrt(math_field_mul(u1, math_field_square(u2))).1;
+    let i1 = math_field_mul(invsqrt, u1);
+    let i2 = math_field_mul(invsqrt, u2);
+    let z_inv = math_field_mul(i1, math_field_mul(i2, t));
+    let sqrt_m1 = spec_field_element(&SQRT_M1);
+    // Rotate by the 4-torsion point (i, 0) when T/Z is negative
+    let rotate = math_field_is_negative(math_field_mul(t, z_inv));
+    let (x, y, den_inv) = if rotate {
+        (
+            math_field_mul(y, sqrt_m1),
+            math_field_mul(x, sqrt_m1),
+            math_field_mul(i1, spec_field_element(&INVSQRT_A_MINUS_D)),
+        )
+    } else {
+        (x, y, i2)
+    };
+    let y = if math_field_is_negative(math_field_mul(x, z_inv)) {
+        math_field_neg(y)
+    } else {
+        y
+    };
+    math_field_abs(math_field_mul(den_inv, math_field_sub(z, y)))
+}
+
+/// The encoding of an `EdwardsPoint` representative
+pub open spec fn spec_ristretto_encode_edwards(point: crate::edwards::EdwardsPoint) -> nat {
+    let (x, y, z, t) = spec_edwards_point(point);
+    spec_ristretto_encode(x, y, z, t)
+}
+
+// =============================================================================
+// Decoding ([RFC9496] Section 4.3.1)
+// =============================================================================
+/// The affine point decoded from a canonical field element s, step for step as
+/// `decompress::step_2` computes it, or `None` if s is rejected
+pub open spec fn spec_ristretto_decode(s: nat) -> Option<(nat, nat)> {
+    if s >= p() || math_field_is_negative(s) {
+        None
+    } else {
+        let ss = math_field_square(s);
+        let u1 = math_field_sub(1, ss);  //  1 + as²
+        let u2 = math_field_add(1, ss);  //  1 - as²    where a=-1
+        let u2_sqr = math_field_square(u2);
+        // v = ad(1+as²)² - (1-as²)²
+        let v = math_field_sub(
+            math_field_mul(
+                math_field_neg(spec_field_element(&EDWARDS_D)),
+                math_field_square(u1),
+            ),
+            u2_sqr,
+        );
+        let (ok, invsqrt) = spec_invsqrt(math_field_mul(v, u2_sqr));
+        let den_x = math_field_mul(invsqrt, u2);
+        let den_y = math_field_mul(invsqrt, math_field_mul(den_x, v));
+        let x = math_field_abs(math_field_mul(math_field_add(s, s), den_x));
+        let y = math_field_mul(u1, den_y);
+        let t = math_field_mul(x, y);
+        if !ok || math_field_is_negative(t) || y == 0 {
+            None
+        } else {
+            Some((x, y))
+        }
+    }
+}
+
+/// The affine point decoded from 32 bytes, or `None` if they are not the
+/// canonical encoding of a Ristretto element
+pub open spec fn spec_ristretto_decompress(bytes: &[u8; 32]) -> Option<(nat, nat)> {
+    if is_canonical_field_encoding(bytes) {
+        spec_ristretto_decode(bytes32_to_nat(bytes))
+    } else {
+        None
+    }
+}
+
+/// The bytes are the canonical encoding of a Ristretto element
+pub open spec fn is_canonical_ristretto_encoding(bytes: &[u8; 32]) -> bool {
+    spec_ristretto_decompress(bytes).is_some()
+}
+
+// =============================================================================
+// Elligator map and the one-way map ([RFC9496] Section 4.3.4)
+// =============================================================================
+/// The completed point (X : Y : Z : T) that the Ristretto Elligator map sends the
+/// field element r_0 to, step for step as `elligator_ristretto_flavor` computes it
+pub open spec fn spec_elligator_ristretto_flavor_completed(r_0: nat) -> (nat, nat, nat, nat) {
+    let d = spec_field_element(&EDWARDS_D);
+    let r = math_field_mul(spec_field_element(&SQRT_M1), math_field_square(r_0));
+    let n_s = math_field_mul(math_field_add(r, 1), math_field_square(math_field_sub(1, d)));
+    let den = math_field_mul(
+        math_field_sub(math_field_neg(1), math_field_mul(d, r)),
+        math_field_add(r, d),
+    );
+    let (was_square, s) = spec_sqrt_ratio_i(n_s, den);
+    // s' = -|s·r_0|
+    let s_prime = math_field_mul(s, r_0);
+    let s_prime = if math_field_is_negative(s_prime) {
+        s_prime
+    } else {
+        math_field_neg(s_prime)
+    };
+    let (s, c) = if was_square {
+        (s, math_field_neg(1))
+    } else {
+        (s_prime, r)
+    };
+    let n_t = math_field_sub(
+        math_field_mul(
+            math_field_mul(c, math_field_sub(r, 1)),
+            math_field_square(math_field_sub(d, 1)),
+        ),
+        den,
+    );
+    let s_sq = math_field_square(s);
+    (
+        math_field_mul(math_field_add(s, s), den),
+        math_field_sub(1, s_sq),
+        math_field_mul(n_t, spec_field_element(&SQRT_AD_MINUS_ONE)),
+        math_field_add(1, s_sq),
+    )
+}
+
+/// The affine point (X/Z, Y/T) of the Elligator output for r_0
+pub open spec fn spec_elligator_ristretto_flavor(r_0: nat) -> (nat, nat) {
+    let (x, y, z, t) = spec_elligator_ristretto_flavor_completed(r_0);
+    (math_field_mul(x, math_field_inv(z)), math_field_mul(y, math_field_inv(t)))
+}
+
+/// `half` holds bytes 32k, ..., 32k + 31 of the 64-byte input
+pub open spec fn is_uniform_bytes_half(bytes: &[u8; 64], k: int, half: [u8; 32]) -> bool {
+    forall|j: int| 0 <= j < 32 ==> #[trigger] half[j] == bytes[32 * k + j]
+}
+
+/// Bytes 32k, ..., 32k + 31 of a 64-byte input, for k = 0, 1
+pub open spec fn spec_uniform_bytes_half(bytes: &[u8; 64], k: int) -> [u8; 32]
+    recommends
+        0 <= k < 2,
+{
+    choose|half: [u8; 32]| is_uniform_bytes_half(bytes, k, half)
+}
+
+/// The one-way map: the sum of the Elligator images of the two halves, each read
+/// as a field element the way `FieldElement::from_bytes` reads it
+pub open spec fn spec_ristretto_from_uniform_bytes(bytes: &[u8; 64]) -> (nat, nat) {
+    let P1 = spec_elligator_ristretto_flavor(
+        spec_field_element_from_bytes(&spec_uniform_bytes_half(bytes, 0)),
+    );
+    let P2 = spec_elligator_ristretto_flavor(
+        spec_field_element_from_bytes(&spec_uniform_bytes_half(bytes, 1)),
+    );
+    edwards_add(P1.0, P1.1, P2.0, P2.1)
+}
+
+/// The field elements that the Elligator map sends into the coset of Q
+pub open spec fn elligator_ristretto_preimages(Q: (nat, nat)) -> Set<nat> {
+    Set::new(
+        |r_0: nat| r_0 < p() && ristretto_equivalent(Q, spec_elligator_ristretto_flavor(r_0)),
+    )
+}
+
+/// For a fixed first field element r_1, the second field elements r_2 for which
+/// the one-way map lands in the coset of Q
+pub open spec fn from_uniform_bytes_preimages(r_1: nat, Q: (nat, nat)) -> Set<nat> {
+    Set::new(
+        |r_2: nat|
+            r_2 < p() && {
+                let P1 = spec_elligator_ristretto_flavor(r_1);
+                let P2 = spec_elligator_ristretto_flavor(r_2);
+                ristretto_equivalent(Q, edwards_add(P1.0, P1.1, P2.0, P2.1))
+            },
+    )
+}
+
+} // verus!
@@ -0,0 +1,88 @@
+//! Specification of the random number generator, and of rejection sampling for scalars
+//!
+//! ## RNG model
+//!
+//! A call of `fill_bytes` on a 64-byte or a 32-byte buffer (the `core_assumes::fill_bytes`
+//! and `fill_bytes32` wrappers) is one draw of a random variable that is uniform over all
+//! byte strings of that length and independent of every other draw. This is what
+//! `CryptoRngCore` promises and what the uninterpreted `is_random_bytes` stands for;
+//! nothing below is proven about the RNG itself.
+//!
+//! Probabilities are ratios of counts. Under the model a 32-byte draw, like the first 32
+//! bytes of a 64-byte one, read little-endian as an integer x < 2^256, is uniform (every
+//! prefix is completed by the same number, 2^256, of suffixes), so the probability that a
+//! draw satisfies a predicate p of its prefix is `count_draws_32(p) / 2^256`.
+//!
+//! ## Rejection sampling for canonical scalars
+//!
+//! Each round of `Scalar::random_canonical` draws 32 bytes, keeps their low 253 bits as its
+//! candidate, and accepts the candidate if it is below ℓ. The lemmas in
+//! `scalar_byte_lemmas::random_scalar_lemmas` show:
+//!
+//! - Uniformity: every v < ℓ is the accepted candidate of exactly 8 prefixes. A round
+//!   therefore outputs each v < ℓ with the same probability 8 / 2^256, and as the rounds
+//!   are independent, so does the loop: P(output = v) = Σ_i (1-q)^i · 8/2^256 = 1/ℓ.
+//! - Expected iterations: 8ℓ prefixes are accepted and 2 · 8ℓ > 2^256, so a round accepts
+//!   with probability q = ℓ/2^253 > 1/2. The number of rounds is geometric with parameter q,
+//!   so its expectation is 1/q < 2.
+#[allow(unused_imports)]
+use super::scalar52_specs::*;
+#[allow(unused_imports)]
+use vstd::arithmetic::power2::*;
+use vstd::prelude::*;
+
+verus! {
+
+// =============================================================================
+// Counting
+// =============================================================================
+/// The number of x in [lo, lo + len) satisfying p
+pub open spec fn count_range(lo: nat, len: nat, p: spec_fn(nat) -> bool) -> nat
+    decreases len,
+{
+    if len == 0 {
+        0
+    } else {
+        count_range(lo, (len - 1) as nat, p) + if p((lo + len - 1) as nat) {
+            1nat
+        } else {
+            0nat
+        }
+    }
+}
+
+/// The number of 32-byte draw prefixes, read as integers x < 2^256, satisfying p
+pub open spec fn count_draws_32(p: spec_fn(nat) -> bool) -> nat {
+    count_range(0, pow2(256), p)
+}
+
+// =============================================================================
+// One round of `Scalar::random_canonical`
+// =============================================================================
+/// The candidate of the draw prefix x: its low 253 bits
+pub open spec fn random_canonical_candidate(x: nat) -> nat {
+    x % pow2(253)
+}
+
+/// The round accepts the draw prefix x
+pub open spec fn random_canonical_accepts(x: nat) -> bool {
+    random_canonical_candidate(x) < group_order()
+}
+
+/// The draw prefixes that the round accepts
+pub open spec fn random_canonical_accepted() -> spec_fn(nat) -> bool {
+    |x: nat| random_canonical_accepts(x)
+}
+
+/// The draw prefixes for which the round returns v
+pub open spec fn random_canonical_outputs(v: nat) -> spec_fn(nat) -> bool {
+    |x: nat| random_canonical_accepts(x) && random_canonical_candidate(x) == v
+}
+
+/// `candidate` is the first 32 bytes of a draw, `prefix`, with the top 3 bits cleared
+pub open spec fn is_random_canonical_candidate_bytes(prefix: &[u8; 32], candidate: &[u8; 32]) -> bool {
+    &&& forall|j: int| 0 <= j < 31 ==> #[trigger] candidate[j] == prefix[j]
+    &&& candidate[31] == prefix[31] & 0b0001_1111
+}
+
+} // verus!
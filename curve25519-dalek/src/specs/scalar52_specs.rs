@@ -3,6 +3,8 @@ use super::core_specs::*;
 #[allow(unused_imports)]
 use crate::backend::serial::u64::scalar::Scalar52;
 #[allow(unused_imports)]
+use vstd::arithmetic::power::*;
+#[allow(unused_imports)]
 use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
 
@@ -88,11 +90,29 @@ pub open spec fn inv_montgomery_radix() -> nat {
         * 0x3614e75438ffa36b_u64 as nat + pow2(192) * 0xc9db6c6f26fe918_u64 as nat
 }
 
+/// Returns true iff `y` is the result of `k` successive Montgomery squarings starting from `y0`.
+///
+/// A Montgomery squaring maps `a` to `a^2 / R (mod L)`, so after `k` of them
+/// `y ≡ y0^(2^k) / R^(2^k - 1) (mod L)`. This is stated without division:
+/// `y * R^(2^k - 1) ≡ y0^(2^k) (mod L)`.
+/// With `k == 1` this is exactly the postcondition of `montgomery_square`.
+pub open spec fn is_montgomery_square_chain(y: nat, y0: nat, k: nat) -> bool {
+    (y * pow(montgomery_radix() as int, (pow2(k) - 1) as nat) as nat) % group_order() == (pow(
+        y0 as int,
+        pow2(k),
+    ) as nat) % group_order()
+}
+
 // Check that all limbs of a Scalar52 are properly bounded (< 2^52)
 pub open spec fn limbs_bounded(s: &Scalar52) -> bool {
     forall|i: int| 0 <= i < 5 ==> s.limbs[i] < (1u64 << 52)
 }
 
+/// Every limb of the scalar is zero, as after `Zeroize::zeroize`
+pub open spec fn is_zeroized_scalar52(s: &Scalar52) -> bool {
+    forall|i: int| 0 <= i < 5 ==> #[trigger] s.limbs[i] == 0
+}
+
 /// Checks if a Scalar52 is in canonical form:
 /// - All limbs are properly bounded (< 2^52)
 /// - The value is reduced modulo group order (< L)
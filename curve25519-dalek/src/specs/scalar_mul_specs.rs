@@ -9,16 +9,26 @@ use core::borrow::Borrow;
 
 use vstd::prelude::*;
 
+#[allow(unused_imports)]
+use crate::backend::serial::curve_models::ProjectiveNielsPoint;
 #[allow(unused_imports)]
 use crate::edwards::EdwardsPoint;
 #[allow(unused_imports)]
 use crate::scalar::Scalar;
 #[cfg(verus_keep_ghost)]
 use crate::specs::edwards_specs::{
-    edwards_add, edwards_point_as_affine, edwards_scalar_mul, is_well_formed_edwards_point,
+    edwards_add, edwards_point_as_affine, edwards_scalar_mul, edwards_scalar_mul_signed,
+    is_well_formed_edwards_point, math_edwards_identity, math_on_edwards_curve,
+    projective_niels_point_as_affine_edwards,
 };
 #[cfg(verus_keep_ghost)]
-use crate::specs::scalar_specs::spec_scalar;
+use crate::specs::field_specs::fe51_limbs_bounded;
+#[cfg(verus_keep_ghost)]
+use crate::specs::field_specs_u64::p;
+#[cfg(verus_keep_ghost)]
+use crate::specs::scalar_specs::{
+    reconstruct, reconstruct_radix_16, reconstruct_radix_2w, scalar_to_nat,
+};
 
 verus! {
 
@@ -122,6 +132,10 @@ pub fn collect_points_from_iter<P, J>(iter: J) -> (result: Vec<EdwardsPoint>) wh
 // ============================================================================
 /// Spec function to compute sum of scalar multiplications.
 /// Returns the affine coordinates of sum(scalars[i] * points[i] for i in 0..min(len_s, len_p)).
+///
+/// Each scalar contributes its unreduced integer value `scalar_to_nat`, which is what the
+/// digit expansions (`as_radix_16`, `non_adjacent_form`) encode. The points may have a
+/// torsion component, so reducing the scalars mod ℓ would change the sum.
 pub open spec fn sum_of_scalar_muls(scalars: Seq<Scalar>, points: Seq<EdwardsPoint>) -> (nat, nat)
     decreases scalars.len(),
 {
@@ -137,10 +151,165 @@ pub open spec fn sum_of_scalar_muls(scalars: Seq<Scalar>, points: Seq<EdwardsPoi
         let last = (len - 1) as int;
         let prev = sum_of_scalar_muls(scalars.subrange(0, last), points.subrange(0, last));
         let point_affine = edwards_point_as_affine(points[last]);
-        let scalar_nat = spec_scalar(&scalars[last]);
+        let scalar_nat = scalar_to_nat(&scalars[last]);
         let scaled = edwards_scalar_mul(point_affine, scalar_nat);
         edwards_add(prev.0, prev.1, scaled.0, scaled.1)
     }
 }
 
+// ============================================================================
+// Spec functions for the multiscalar accumulators
+// ============================================================================
+/// Affine coordinates of each point in a sequence.
+pub open spec fn points_as_affine(points: Seq<EdwardsPoint>) -> Seq<(nat, nat)> {
+    points.map(|_i, point: EdwardsPoint| edwards_point_as_affine(point))
+}
+
+/// Sum of [coeffs[i]]·points[i] over i < n, for affine points and signed coefficients.
+///
+/// The multiscalar algorithms process all points digit by digit, so their accumulator
+/// is such a sum, with coeffs[i] the value of the digits of scalar i processed so far.
+pub open spec fn sum_of_signed_scalar_muls(
+    points: Seq<(nat, nat)>,
+    coeffs: Seq<int>,
+    n: nat,
+) -> (nat, nat)
+    decreases n,
+{
+    if n == 0 {
+        math_edwards_identity()
+    } else {
+        let prev = sum_of_signed_scalar_muls(points, coeffs, (n - 1) as nat);
+        let term = edwards_scalar_mul_signed(points[n - 1], coeffs[n - 1]);
+        edwards_add(prev.0, prev.1, term.0, term.1)
+    }
+}
+
+/// Value of the radix-16 digits at positions ≥ j, for each scalar.
+///
+/// Constant-time Straus processes positions 63 down to 0; before position j - 1,
+/// its accumulator is `sum_of_signed_scalar_muls` of these coefficients.
+pub open spec fn radix_16_suffix_values(digits: Seq<[i8; 64]>, j: int) -> Seq<int> {
+    digits.map(|_i, d: [i8; 64]| reconstruct_radix_16(d@.skip(j)))
+}
+
+/// The radix-16 digit at position j, for each scalar.
+pub open spec fn radix_16_column(digits: Seq<[i8; 64]>, j: int) -> Seq<int> {
+    digits.map(|_i, d: [i8; 64]| d[j] as int)
+}
+
+/// Value of the NAF digits at positions ≥ i, for each scalar.
+///
+/// Variable-time Straus processes positions 255 down to 0; before position i - 1,
+/// its accumulator is `sum_of_signed_scalar_muls` of these coefficients.
+pub open spec fn naf_suffix_values(nafs: Seq<[i8; 256]>, i: int) -> Seq<int> {
+    nafs.map(|_k, naf: [i8; 256]| reconstruct(naf@.skip(i)))
+}
+
+/// The NAF digit at position i, for each scalar.
+pub open spec fn naf_column(nafs: Seq<[i8; 256]>, i: int) -> Seq<int> {
+    nafs.map(|_k, naf: [i8; 256]| naf[i] as int)
+}
+
+// ============================================================================
+// Spec functions for Pippenger's bucket method
+// ============================================================================
+/// Affine points of the (digits, point) pairs Pippenger sorts into buckets.
+pub open spec fn pippenger_points(pairs: Seq<([i8; 64], ProjectiveNielsPoint)>) -> Seq<(nat, nat)> {
+    pairs.map(
+        |_i, pair: ([i8; 64], ProjectiveNielsPoint)| projective_niels_point_as_affine_edwards(pair.1),
+    )
+}
+
+/// The radix-2^w digit at position j, for each pair.
+pub open spec fn pippenger_column(pairs: Seq<([i8; 64], ProjectiveNielsPoint)>, j: int) -> Seq<int> {
+    pairs.map(|_i, pair: ([i8; 64], ProjectiveNielsPoint)| pair.0[j] as int)
+}
+
+/// Value of the first `digits_count` radix-2^w digits from position j on, for each pair.
+///
+/// Pippenger processes positions `digits_count - 1` down to 0; after position j,
+/// its total is `sum_of_signed_scalar_muls` of these coefficients.
+pub open spec fn pippenger_suffix_values(
+    pairs: Seq<([i8; 64], ProjectiveNielsPoint)>,
+    w: nat,
+    digits_count: int,
+    j: int,
+) -> Seq<int> {
+    pairs.map(
+        |_i, pair: ([i8; 64], ProjectiveNielsPoint)|
+            reconstruct_radix_2w(pair.0@.take(digits_count).skip(j), w),
+    )
+}
+
+/// The pairs can be sorted into `buckets_count` buckets at digit position j:
+/// every digit there is in [-buckets_count, buckets_count], and every point
+/// is a reduced curve point with 54-bit limbs.
+pub open spec fn pippenger_pairs_valid(
+    pairs: Seq<([i8; 64], ProjectiveNielsPoint)>,
+    j: int,
+    buckets_count: int,
+) -> bool {
+    forall|i: int|
+        #![trigger pairs[i]]
+        0 <= i < pairs.len() ==> {
+            let (digits, pt) = pairs[i];
+            let affine = projective_niels_point_as_affine_edwards(pt);
+            &&& -buckets_count <= digits[j] <= buckets_count
+            &&& fe51_limbs_bounded(&pt.Y_plus_X, 54)
+            &&& fe51_limbs_bounded(&pt.Y_minus_X, 54)
+            &&& fe51_limbs_bounded(&pt.Z, 54)
+            &&& fe51_limbs_bounded(&pt.T2d, 54)
+            &&& math_on_edwards_curve(affine.0, affine.1)
+            &&& affine.0 < p()
+            &&& affine.1 < p()
+        }
+}
+
+/// Coefficients of bucket b after sorting a column of digits: the point of a pair
+/// goes into bucket b when its digit is ±(b + 1), with the digit's sign.
+pub open spec fn pippenger_bucket_coeffs(column: Seq<int>, b: int) -> Seq<int> {
+    column.map(
+        |_i, d: int|
+            if d == b + 1 {
+                1
+            } else if d == -(b + 1) {
+                -1
+            } else {
+                0
+            },
+    )
+}
+
+/// Coefficients of the intermediate sum of buckets j, j + 1, ...: the sign of
+/// the digit when |d| > j, and 0 otherwise.
+pub open spec fn pippenger_intermediate_coeffs(column: Seq<int>, j: int) -> Seq<int> {
+    column.map(
+        |_i, d: int|
+            if d > j {
+                1
+            } else if d < -j {
+                -1
+            } else {
+                0
+            },
+    )
+}
+
+/// Coefficients of Σ_{b ≥ j} (b + 1 - j)·bucket_b, the sum of the intermediate sums
+/// from the last bucket down to bucket j: the digit moved j towards 0 when |d| > j,
+/// and 0 otherwise. At j = 0 these are the digits themselves.
+pub open spec fn pippenger_weighted_coeffs(column: Seq<int>, j: int) -> Seq<int> {
+    column.map(
+        |_i, d: int|
+            if d > j {
+                d - j
+            } else if d < -j {
+                d + j
+            } else {
+                0
+            },
+    )
+}
+
 } // verus!
@@ -1,6 +1,6 @@
 //! Specification functions for high-level Scalar operations
 #[allow(unused_imports)]
-use crate::scalar::Scalar;
+use crate::scalar::{MontgomeryScalar, Scalar};
 #[allow(unused_imports)]
 use vstd::arithmetic::power2::*;
 use vstd::prelude::*;
@@ -22,6 +22,26 @@ pub open spec fn spec_scalar(s: &Scalar) -> nat {
     bytes32_to_nat(&s.bytes) % group_order()
 }
 
+/// `MontgomeryScalar` invariant: bounded limbs holding a value below ℓ
+pub open spec fn is_canonical_montgomery_scalar(m: &MontgomeryScalar) -> bool {
+    is_canonical_scalar52(&m.spec_limbs())
+}
+
+/// The scalar a held in Montgomery form by m ≡ a·R (mod ℓ), i.e. m·R⁻¹ mod ℓ
+pub open spec fn spec_montgomery_scalar(m: &MontgomeryScalar) -> nat {
+    (scalar52_to_nat(&m.spec_limbs()) * inv_montgomery_radix()) % group_order()
+}
+
+/// Scalar invariant #1: the high bit (bit 255) is clear, i.e. the integer representing
+/// the scalar is less than 2^255.
+///
+/// Every `Scalar` the public API can construct satisfies this, including the output of
+/// `from_bits` (which masks the high bit). `from_bits` is the one constructor that may
+/// break invariant #2, see `is_canonical_scalar`.
+pub open spec fn scalar_high_bit_clear(s: &Scalar) -> bool {
+    s.bytes[31] <= 127
+}
+
 /// Checks if a Scalar satisfies the canonical representation invariants:
 /// - Invariant #1: High bit (bit 255) is clear, ensuring s < 2^255
 /// - Invariant #2: Scalar is reduced modulo group order, i.e., s < ℓ
@@ -30,7 +50,29 @@ pub open spec fn is_canonical_scalar(s: &Scalar) -> bool {
     bytes32_to_nat(&s.bytes)
         < group_order()
     // Invariant #1: High bit is clear (< 2^255)
-     && s.bytes[31] <= 127
+     && scalar_high_bit_clear(s)
+}
+
+/// Every byte of the scalar is zero, as after `Zeroize::zeroize`
+pub open spec fn is_zeroized_scalar(s: &Scalar) -> bool {
+    forall|i: int| 0 <= i < 32 ==> #[trigger] s.bytes[i] == 0u8
+}
+
+/// The value of a little-endian exponent of 64-bit words, counted from word k:
+/// exp[k] + 2^64·exp[k+1] + ... (the exponent of `Scalar::pow_u256` is `exponent_from_words(exp, 0)`)
+pub open spec fn exponent_from_words(exp: &[u64; 4], k: nat) -> nat
+    decreases 4 - k,
+{
+    if k >= 4 {
+        0
+    } else {
+        exp[k as int] as nat + pow2(64) * exponent_from_words(exp, k + 1)
+    }
+}
+
+/// a is a square modulo the group order ℓ (zero included)
+pub open spec fn is_square_mod_l(a: nat) -> bool {
+    exists|y: nat| (#[trigger] (y * y) % group_order()) == (a % group_order())
 }
 
 /// Returns true iff a and b are multiplicative inverses modulo group_order
@@ -69,6 +111,21 @@ pub open spec fn sum_of_scalars(scalars: Seq<Scalar>) -> nat
     }
 }
 
+/// Spec function for the inner product Σ a[i]·b[i] of two sequences of scalars (mod group_order)
+/// Note: Processes from back to front to match iterative loop order
+pub open spec fn inner_product_of_scalars(a: Seq<Scalar>, b: Seq<Scalar>) -> nat
+    decreases a.len(),
+{
+    if a.len() == 0 {
+        0
+    } else {
+        let last = (a.len() - 1) as int;
+        (inner_product_of_scalars(a.subrange(0, last), b.subrange(0, last)) + bytes32_to_nat(
+            &a[last].bytes,
+        ) * bytes32_to_nat(&b[last].bytes)) % group_order()
+    }
+}
+
 /// Returns true iff a scalar's byte representation equals the given natural number (mod group_order)
 pub open spec fn scalar_congruent_nat(s: &Scalar, n: nat) -> bool {
     bytes32_to_nat(&s.bytes) % group_order() == n % group_order()
@@ -107,6 +164,16 @@ pub open spec fn is_clamped_integer(bytes: &[u8; 32]) -> bool {
 /// - Sets bit 6 of byte 31)
 ///
 /// This produces a value in the range [2^254, 2^255) that is divisible by 8.
+///
+/// That value is usually larger than the group order ℓ, so a `Scalar` holding it breaks
+/// invariant #2 (`is_canonical_scalar`) but keeps invariant #1 (`scalar_high_bit_clear`).
+/// The clamped entry points (`EdwardsPoint::{mul_clamped, mul_base_clamped}`,
+/// `MontgomeryPoint::{mul_clamped, mul_base_clamped}`) are specified as [n]P for the
+/// unreduced integer n = bytes32_to_nat(&spec_clamp_integer(bytes)). This is sound because
+/// point multiplication only needs invariant #1: it walks the bits (or radix-16 digits) of
+/// n, and [n]P is defined for every natural n, including n ≥ ℓ when P has a cofactor
+/// component. Nowhere else may an unreduced scalar appear: `Scalar` arithmetic is specified
+/// modulo ℓ and requires canonical inputs.
 pub open spec fn spec_clamp_integer(bytes: [u8; 32]) -> [u8; 32] {
     // Build the result array element by element
     [
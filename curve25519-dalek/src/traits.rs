@@ -56,6 +56,11 @@ impl<T> IsIdentity for T where T: ConstantTimeEq + Identity + IsIdentitySpecImpl
         let choice = self.ct_eq(&T::identity());
         let result: bool = choice.into();
         proof {
+            /* VERIFICATION NOTE: subtle's ct_eq has no generic spec, so this cannot be
+            derived for an arbitrary T. For EdwardsPoint, ct_eq compares affine points
+            and T::identity() represents (0, 1) (lemma_identity_edwards_point_affine),
+            which is exactly is_identity_spec.
+            */
             assume(result == self.is_identity_spec());
         }
         result
@@ -25,18 +25,37 @@ use crate::traits::Identity;
 use crate::backend::serial::curve_models::AffineNielsPoint;
 use crate::backend::serial::curve_models::ProjectiveNielsPoint;
 use crate::backend::serial::u64::subtle_assumes::{
-    conditional_assign_generic, conditional_negate_generic, ct_eq_u16,
+    conditional_negate_affine_niels, conditional_negate_projective_niels, ct_eq_u16,
 };
 use crate::edwards::EdwardsPoint;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::lemmas::common_lemmas::mask_lemmas::lemma_i8_abs_by_sign_mask;
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::lemmas::edwards_lemmas::curve_models_lemmas::{
+    lemma_identity_affine_niels_limbs_bounded, lemma_identity_projective_niels_limbs_bounded,
+};
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::lemmas::edwards_lemmas::scalar_mul_lemmas::{
+    lemma_edwards_point_as_affine_reduced, lemma_edwards_scalar_mul_add_double,
+    lemma_edwards_scalar_mul_signed_unit,
+};
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::lemmas::field_lemmas::limbs_bounded_lemmas::{
+    lemma_fe51_limbs_bounded_weaken, lemma_sum_of_limbs_bounded_from_fe51_bounded,
+};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+#[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::constant_time_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::edwards_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::field_specs::*;
 #[allow(unused_imports)] // Used in verus! blocks
+use crate::specs::field_specs_u64::*;
+#[allow(unused_imports)] // Used in verus! blocks
 use crate::specs::window_specs::*;
 use vstd::prelude::*;
 
@@ -100,16 +119,25 @@ impl LookupTable<AffineNielsPoint> {
     ///
     /// Where P is the base point that was used to create this lookup table.
     /// This table stores [P, 2P, 3P, ..., 8P] (for radix-16).
+    ///
+    /// Every entry is visited and the negation is a masked select, so no
+    /// branch or memory access depends on `x`.
     pub fn select(&self, x: i8) -> (result: AffineNielsPoint)
         requires
             -8 <= x,
             x <= 8,
+            // Table entries must have bounded limbs
+            lookup_table_affine_limbs_bounded(self.0),
         ensures
     // Formal specification for all cases:
 
             (x > 0 ==> result == self.0[(x - 1) as int]),
             (x == 0 ==> result == identity_affine_niels()),
             (x < 0 ==> result == negate_affine_niels(self.0[((-x) - 1) as int])),
+            // Limb bounds for the result (derived from table bounds)
+            fe51_limbs_bounded(&result.y_plus_x, 54),
+            fe51_limbs_bounded(&result.y_minus_x, 54),
+            fe51_limbs_bounded(&result.xy2d, 54),
     {
         // Debug assertions from original macro - ignored by Verus
         #[cfg(not(verus_keep_ghost))]
@@ -118,28 +146,60 @@ impl LookupTable<AffineNielsPoint> {
             debug_assert!(x <= 8);
         }
 
-        assume(false);
-
         // Compute xabs = |x|
         let xmask = x as i16 >> 7;
         let xabs = (x as i16 + xmask) ^ xmask;
+        proof {
+            lemma_i8_abs_by_sign_mask(x, xmask, xabs);
+            lemma_identity_affine_niels_limbs_bounded();
+        }
+
+        // Secret independence: x is secret, the table is public, see `lookup_table_select_trace`
+        let ghost mut trace: Seq<Observation> = Seq::empty();
 
         // Set t = 0 * P = identity
         let mut t = AffineNielsPoint::identity();
-        for j in 1..9 {
+        for j in 1..9
+            invariant
+                lookup_table_affine_limbs_bounded(self.0),
+                0 <= xabs <= 8,
+                // t = |x|·P once j has passed |x|, and the identity before
+                xabs == 0 || xabs >= j ==> t == identity_affine_niels(),
+                1 <= xabs < j ==> t == self.0[(xabs - 1) as int],
+                // Entries 0..j-1 have been read, whatever x is
+                trace == sequential_access_trace((j - 1) as nat),
+        {
             // Copy `points[j-1] == j*P` onto `t` in constant time if `|x| == j`.
             /* ORIGINAL CODE: let c = (xabs as u16).ct_eq(&(j as u16)); */
             let c = ct_eq_u16(&(xabs as u16), &(j as u16));
-            /* ORIGINAL CODE: t.conditional_assign(&self.0[j - 1], c); */
-            conditional_assign_generic(&mut t, &self.0[j - 1], c);
+            t.conditional_assign(&self.0[j - 1], c);
+            proof {
+                lemma_sequential_access_trace_push((j - 1) as nat);
+                trace = trace.push(Observation::Access((j - 1) as int));
+            }
         }
         // Now t == |x| * P.
+        proof {
+            lemma_i8_abs_by_sign_mask(x, xmask, xabs);
+            // t is the identity or a table entry, so its limbs are bounded
+            if xabs > 0 {
+                assert(t == self.0[(xabs - 1) as int]);
+            }
+        }
 
         let neg_mask = Choice::from((xmask & 1) as u8);
         /* ORIGINAL CODE: t.conditional_negate(neg_mask); */
-        conditional_negate_generic(&mut t, neg_mask);
+        conditional_negate_affine_niels(&mut t, neg_mask);
         // Now t == x * P.
 
+        proof {
+            // The swapped y ± x limbs come from the table; -xy2d is 52-bit bounded
+            if x < 0 {
+                lemma_fe51_limbs_bounded_weaken(&t.xy2d, 52, 54);
+            }
+            assert(trace == lookup_table_select_trace());
+        }
+
         t
     }
 }
@@ -150,6 +210,9 @@ impl LookupTable<ProjectiveNielsPoint> {
     ///
     /// Where P is the base point that was used to create this lookup table.
     /// This table stores [P, 2P, 3P, ..., 8P] (for radix-16).
+    ///
+    /// Every entry is visited and the negation is a masked select, so no
+    /// branch or memory access depends on `x`.
     pub fn select(&self, x: i8) -> (result: ProjectiveNielsPoint)
         requires
             -8 <= x,
@@ -199,28 +262,60 @@ impl LookupTable<ProjectiveNielsPoint> {
             debug_assert!(x <= 8);
         }
 
-        assume(false);
-
         // Compute xabs = |x|
         let xmask = x as i16 >> 7;
         let xabs = (x as i16 + xmask) ^ xmask;
+        proof {
+            lemma_i8_abs_by_sign_mask(x, xmask, xabs);
+            lemma_identity_projective_niels_limbs_bounded();
+        }
+
+        // Secret independence: x is secret, the table is public, see `lookup_table_select_trace`
+        let ghost mut trace: Seq<Observation> = Seq::empty();
 
         // Set t = 0 * P = identity
         let mut t = ProjectiveNielsPoint::identity();
-        for j in 1..9 {
+        for j in 1..9
+            invariant
+                lookup_table_projective_limbs_bounded(self.0),
+                0 <= xabs <= 8,
+                // t = |x|·P once j has passed |x|, and the identity before
+                xabs == 0 || xabs >= j ==> t == identity_projective_niels(),
+                1 <= xabs < j ==> t == self.0[(xabs - 1) as int],
+                // Entries 0..j-1 have been read, whatever x is
+                trace == sequential_access_trace((j - 1) as nat),
+        {
             // Copy `points[j-1] == j*P` onto `t` in constant time if `|x| == j`.
             /* ORIGINAL CODE: let c = (xabs as u16).ct_eq(&(j as u16)); */
             let c = ct_eq_u16(&(xabs as u16), &(j as u16));
-            /* ORIGINAL CODE: t.conditional_assign(&self.0[j - 1], c); */
-            conditional_assign_generic(&mut t, &self.0[j - 1], c);
+            t.conditional_assign(&self.0[j - 1], c);
+            proof {
+                lemma_sequential_access_trace_push((j - 1) as nat);
+                trace = trace.push(Observation::Access((j - 1) as int));
+            }
         }
         // Now t == |x| * P.
+        proof {
+            lemma_i8_abs_by_sign_mask(x, xmask, xabs);
+            // t is the identity or a table entry, so its limbs are bounded
+            if xabs > 0 {
+                assert(t == self.0[(xabs - 1) as int]);
+            }
+        }
 
         let neg_mask = Choice::from((xmask & 1) as u8);
         /* ORIGINAL CODE: t.conditional_negate(neg_mask); */
-        conditional_negate_generic(&mut t, neg_mask);
+        conditional_negate_projective_niels(&mut t, neg_mask);
         // Now t == x * P.
 
+        proof {
+            // The swapped Y ± X and Z limbs come from the table; -T2d is 52-bit bounded
+            if x < 0 {
+                lemma_fe51_limbs_bounded_weaken(&t.T2d, 52, 54);
+            }
+            assert(trace == lookup_table_select_trace());
+        }
+
         t
     }
 }
@@ -550,36 +645,39 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable5<ProjectiveNielsPoint> {
         let mut Ai = [A.as_projective_niels();8];
         let A2 = A.double();
 
-        for i in 0..7 {
-            proof {
-                // A2 is 2*A, need to be well-formed for addition
-                assume(is_well_formed_edwards_point(A2));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Y_plus_X, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Y_minus_X, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Z, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].T2d, 54));
-            }
+        let ghost a = edwards_point_as_affine(*A);
+        proof {
+            // A2 = [2]A and Ai[0] = [1]A
+            lemma_edwards_point_as_affine_reduced(*A);
+            lemma_edwards_scalar_mul_add_double(a, 0);
+            lemma_edwards_scalar_mul_signed_unit(a);
+        }
+
+        for i in 0..7
+            invariant
+                a == edwards_point_as_affine(*A),
+                math_on_edwards_curve(a.0, a.1),
+                a.0 < p(),
+                a.1 < p(),
+                is_well_formed_edwards_point(A2),
+                edwards_point_as_affine(A2) == edwards_scalar_mul(a, 2),
+                naf_lookup_table5_projective_limbs_bounded(Ai),
+                // Ai[0..=i] = [A, 3A, ..., (2i+1)A]
+                forall|k: int|
+                    0 <= k <= i ==> projective_niels_point_as_affine_edwards(#[trigger] Ai[k])
+                        == edwards_scalar_mul(a, (2 * k + 1) as nat),
+        {
             // ORIGINAL CODE: Ai[i + 1] = (&A2 + &Ai[i]).as_extended().as_projective_niels();
             let sum = &A2 + &Ai[i];
-            proof {
-                assume(fe51_limbs_bounded(&sum.X, 54));
-                assume(fe51_limbs_bounded(&sum.Y, 54));
-                assume(fe51_limbs_bounded(&sum.Z, 54));
-                assume(fe51_limbs_bounded(&sum.T, 54));
-            }
             let extended = sum.as_extended();
             proof {
-                assume(edwards_point_limbs_bounded(extended));
-                assume(edwards_point_sum_bounded(extended));
+                // [2]A + [2i+1]A = [2i+3]A
+                lemma_edwards_scalar_mul_add_double(a, (2 * i + 1) as nat);
             }
             Ai[i + 1] = extended.as_projective_niels();
         }
         // Now Ai = [A, 3A, 5A, 7A, 9A, 11A, 13A, 15A]
         let result = NafLookupTable5(Ai);
-        proof {
-            assume(is_valid_naf_lookup_table5_projective(result.0, *A));
-            assume(naf_lookup_table5_projective_limbs_bounded(result.0));
-        }
         result
     }
 }
@@ -608,15 +706,31 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable5<AffineNielsPoint> {
         let mut Ai = [A.as_affine_niels();8];
         let A2 = A.double();
 
-        for i in 0..7 {
+        let ghost a = edwards_point_as_affine(*A);
+        proof {
+            // A2 = [2]A and Ai[0] = [1]A
+            lemma_edwards_point_as_affine_reduced(*A);
+            lemma_edwards_scalar_mul_add_double(a, 0);
+            lemma_edwards_scalar_mul_signed_unit(a);
+        }
+
+        for i in 0..7
+            invariant
+                a == edwards_point_as_affine(*A),
+                math_on_edwards_curve(a.0, a.1),
+                a.0 < p(),
+                a.1 < p(),
+                is_well_formed_edwards_point(A2),
+                edwards_point_as_affine(A2) == edwards_scalar_mul(a, 2),
+                naf_lookup_table5_affine_limbs_bounded(Ai),
+                // Ai[0..=i] = [A, 3A, ..., (2i+1)A]
+                forall|k: int|
+                    0 <= k <= i ==> affine_niels_point_as_affine_edwards(#[trigger] Ai[k])
+                        == edwards_scalar_mul(a, (2 * k + 1) as nat),
+        {
             proof {
-                // A2 is 2*A, need to be well-formed for addition
-                assume(is_well_formed_edwards_point(A2));
                 // Additional requirement for EdwardsPoint + AffineNielsPoint
-                assume(sum_of_limbs_bounded(&A2.Z, &A2.Z, u64::MAX));
-                assume(fe51_limbs_bounded(&&Ai[i as int].y_plus_x, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].y_minus_x, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].xy2d, 54));
+                lemma_sum_of_limbs_bounded_from_fe51_bounded(&A2.Z, &A2.Z, 54);
             }
             // ORIGINAL CODE: Ai[i + 1] = (&A2 + &Ai[i]).as_extended().as_affine_niels();
             let sum = &A2 + &Ai[i];
@@ -628,17 +742,13 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable5<AffineNielsPoint> {
             }
             let extended = sum.as_extended();
             proof {
-                assume(edwards_point_limbs_bounded(extended));
-                assume(edwards_point_sum_bounded(extended));
+                // [2]A + [2i+1]A = [2i+3]A
+                lemma_edwards_scalar_mul_add_double(a, (2 * i + 1) as nat);
             }
             Ai[i + 1] = extended.as_affine_niels();
         }
         // Now Ai = [A, 3A, 5A, 7A, 9A, 11A, 13A, 15A]
         let result = NafLookupTable5(Ai);
-        proof {
-            assume(is_valid_naf_lookup_table5_affine(result.0, *A));
-            assume(naf_lookup_table5_affine_limbs_bounded(result.0));
-        }
         result
     }
 }
@@ -774,36 +884,39 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable8<ProjectiveNielsPoint> {
         let mut Ai = [A.as_projective_niels();64];
         let A2 = A.double();
 
-        for i in 0..63 {
-            proof {
-                // A2 is 2*A, need to be well-formed for addition
-                assume(is_well_formed_edwards_point(A2));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Y_plus_X, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Y_minus_X, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].Z, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].T2d, 54));
-            }
+        let ghost a = edwards_point_as_affine(*A);
+        proof {
+            // A2 = [2]A and Ai[0] = [1]A
+            lemma_edwards_point_as_affine_reduced(*A);
+            lemma_edwards_scalar_mul_add_double(a, 0);
+            lemma_edwards_scalar_mul_signed_unit(a);
+        }
+
+        for i in 0..63
+            invariant
+                a == edwards_point_as_affine(*A),
+                math_on_edwards_curve(a.0, a.1),
+                a.0 < p(),
+                a.1 < p(),
+                is_well_formed_edwards_point(A2),
+                edwards_point_as_affine(A2) == edwards_scalar_mul(a, 2),
+                naf_lookup_table8_projective_limbs_bounded(Ai),
+                // Ai[0..=i] = [A, 3A, ..., (2i+1)A]
+                forall|k: int|
+                    0 <= k <= i ==> projective_niels_point_as_affine_edwards(#[trigger] Ai[k])
+                        == edwards_scalar_mul(a, (2 * k + 1) as nat),
+        {
             // ORIGINAL CODE: Ai[i + 1] = (&A2 + &Ai[i]).as_extended().as_projective_niels();
             let sum = &A2 + &Ai[i];
-            proof {
-                assume(fe51_limbs_bounded(&sum.X, 54));
-                assume(fe51_limbs_bounded(&sum.Y, 54));
-                assume(fe51_limbs_bounded(&sum.Z, 54));
-                assume(fe51_limbs_bounded(&sum.T, 54));
-            }
             let extended = sum.as_extended();
             proof {
-                assume(edwards_point_limbs_bounded(extended));
-                assume(edwards_point_sum_bounded(extended));
+                // [2]A + [2i+1]A = [2i+3]A
+                lemma_edwards_scalar_mul_add_double(a, (2 * i + 1) as nat);
             }
             Ai[i + 1] = extended.as_projective_niels();
         }
         // Now Ai = [A, 3A, 5A, 7A, 9A, 11A, 13A, 15A, ..., 127A]
         let result = NafLookupTable8(Ai);
-        proof {
-            assume(is_valid_naf_lookup_table8_projective(result.0, *A));
-            assume(naf_lookup_table8_projective_limbs_bounded(result.0));
-        }
         result
     }
 }
@@ -833,15 +946,31 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable8<AffineNielsPoint> {
         let mut Ai = [A.as_affine_niels();64];
         let A2 = A.double();
 
-        for i in 0..63 {
+        let ghost a = edwards_point_as_affine(*A);
+        proof {
+            // A2 = [2]A and Ai[0] = [1]A
+            lemma_edwards_point_as_affine_reduced(*A);
+            lemma_edwards_scalar_mul_add_double(a, 0);
+            lemma_edwards_scalar_mul_signed_unit(a);
+        }
+
+        for i in 0..63
+            invariant
+                a == edwards_point_as_affine(*A),
+                math_on_edwards_curve(a.0, a.1),
+                a.0 < p(),
+                a.1 < p(),
+                is_well_formed_edwards_point(A2),
+                edwards_point_as_affine(A2) == edwards_scalar_mul(a, 2),
+                naf_lookup_table8_affine_limbs_bounded(Ai),
+                // Ai[0..=i] = [A, 3A, ..., (2i+1)A]
+                forall|k: int|
+                    0 <= k <= i ==> affine_niels_point_as_affine_edwards(#[trigger] Ai[k])
+                        == edwards_scalar_mul(a, (2 * k + 1) as nat),
+        {
             proof {
-                // A2 is 2*A, need to be well-formed for addition
-                assume(is_well_formed_edwards_point(A2));
                 // Additional requirement for EdwardsPoint + AffineNielsPoint
-                assume(sum_of_limbs_bounded(&A2.Z, &A2.Z, u64::MAX));
-                assume(fe51_limbs_bounded(&&Ai[i as int].y_plus_x, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].y_minus_x, 54));
-                assume(fe51_limbs_bounded(&&Ai[i as int].xy2d, 54));
+                lemma_sum_of_limbs_bounded_from_fe51_bounded(&A2.Z, &A2.Z, 54);
             }
             // ORIGINAL CODE: Ai[i + 1] = (&A2 + &Ai[i]).as_extended().as_affine_niels();
             let sum = &A2 + &Ai[i];
@@ -853,17 +982,13 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable8<AffineNielsPoint> {
             }
             let extended = sum.as_extended();
             proof {
-                assume(edwards_point_limbs_bounded(extended));
-                assume(edwards_point_sum_bounded(extended));
+                // [2]A + [2i+1]A = [2i+3]A
+                lemma_edwards_scalar_mul_add_double(a, (2 * i + 1) as nat);
             }
             Ai[i + 1] = extended.as_affine_niels();
         }
         // Now Ai = [A, 3A, 5A, 7A, 9A, 11A, 13A, 15A, ..., 127A]
         let result = NafLookupTable8(Ai);
-        proof {
-            assume(is_valid_naf_lookup_table8_affine(result.0, *A));
-            assume(naf_lookup_table8_affine_limbs_bounded(result.0));
-        }
         result
     }
 }